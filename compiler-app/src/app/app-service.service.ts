@@ -3,6 +3,7 @@ import { HttpClient, HttpHeaders, HttpResponse } from '@angular/common/http'; //
 import { Observable } from 'rxjs';
 import { Program } from './program';
 import { CustomReport } from './report';
+import { FormatReport } from './format-report';
 
 @Injectable({
     providedIn: 'root'
@@ -25,5 +26,16 @@ export class AppService {
         return this.http.post<HttpResponse<CustomReport>>(`compiler/generate`, program, queryParams);
     }
 
+    format(program: Program): Observable<HttpResponse<FormatReport>> {
+
+        let queryParams = {};
+        queryParams = {
+            headers: this.headers,
+            observe: "response",
+        };
+
+        return this.http.post<HttpResponse<FormatReport>>(`compiler/format`, program, queryParams);
+    }
+
 
 }
\ No newline at end of file
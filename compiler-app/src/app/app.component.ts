@@ -1,11 +1,13 @@
-import { Component } from '@angular/core';
+import { Component, ElementRef, ViewChild } from '@angular/core';
 import { AppService } from './app-service.service';
 import { CustomReport } from './report';
 import { Program } from './program';
 import { MatDialog } from '@angular/material/dialog';
 import { DialogComponent } from './dialog/dialog.component';
 import { Diagnostic } from './diagnostic';
+import { BackendType } from './backend-type';
 import { ParserType } from './parser-type';
+import { FormatReport } from './format-report';
 
 @Component({
   selector: 'app-root',
@@ -41,9 +43,11 @@ export class AppComponent {
     d = x;
   }
 }`,
-    parser: ParserType.Recursive
+    parser: ParserType.Recursive,
+    backend: BackendType.TreeWalker
   }
   errors: Diagnostic[] = [];
+  @ViewChild('editor') editor!: ElementRef<HTMLTextAreaElement>;
   server_error: boolean = false;
 
   options = [
@@ -51,6 +55,12 @@ export class AppComponent {
     { value: 'Lr', label: 'Lr' }
   ];
 
+  backendOptions = [
+    { value: 'TreeWalker', label: 'Tree-walker' },
+    { value: 'Bytecode', label: 'Bytecode' },
+    { value: 'Assembly', label: 'x86-64' }
+  ];
+
   constructor(private appService: AppService, public dialog: MatDialog) { }
 
   submitProgram(): void {
@@ -76,10 +86,30 @@ export class AppComponent {
   }
 
 
+  formatProgram(): void {
+    this.errors = [];
+    this.server_error = false;
+    this.appService.format(this.program).subscribe(
+      (response) => {
+        const formatted = response.body as FormatReport;
+        this.program.code = formatted.code;
+        this.errors = formatted.diagnostics;
+      },
+      (error) => {
+        this.server_error = true;
+        console.error('Error posting data', error);
+      }
+    );
+  }
+
   onSelectChange(event: any) {
     this.program.parser = event.target.value;
   }
 
+  onBackendChange(event: any) {
+    this.program.backend = event.target.value;
+  }
+
   checkForErrors(): void {
     this.report.diagnostics.forEach((diagnostic) => {
       if (diagnostic.kind === 'Error') {
@@ -88,6 +118,13 @@ export class AppComponent {
     })
   }
 
+  highlight(diagnostic: Diagnostic): void {
+    const textarea = this.editor.nativeElement;
+    const start = diagnostic.span.start;
+    textarea.focus();
+    textarea.setSelectionRange(start, start + Math.max(diagnostic.span.length, 1));
+  }
+
   openDialog(): void {
     this.dialog.open(DialogComponent, {
       width: '1200px',
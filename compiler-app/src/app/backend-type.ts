@@ -0,0 +1,5 @@
+export enum BackendType {
+    TreeWalker = 'TreeWalker',
+    Bytecode = 'Bytecode',
+    Assembly = 'Assembly'
+}
\ No newline at end of file
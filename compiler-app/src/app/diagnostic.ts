@@ -7,4 +7,6 @@ export interface Diagnostic {
     span: TextSpan;
     place: TextPlace;
     kind: TextType;
+    line: number;
+    column: number;
 }
\ No newline at end of file
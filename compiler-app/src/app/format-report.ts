@@ -0,0 +1,6 @@
+import { Diagnostic } from "./diagnostic";
+
+export interface FormatReport {
+    code: string;
+    diagnostics: Diagnostic[];
+}
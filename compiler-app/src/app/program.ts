@@ -1,6 +1,8 @@
+import { BackendType } from "./backend-type";
 import { ParserType } from "./parser-type";
 
 export interface Program {
     code: string;
-    parser: ParserType
+    parser: ParserType;
+    backend: BackendType
 }
\ No newline at end of file
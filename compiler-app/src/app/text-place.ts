@@ -1,5 +1,6 @@
 export enum TextPlace {
     Syntax = 'Syntax',
     Lexical = 'Lexical',
-    Semantic = 'Semantic'
+    Semantic = 'Semantic',
+    Runtime = 'Runtime'
 }
\ No newline at end of file
@@ -0,0 +1,1309 @@
+use std::collections::HashMap;
+
+use crate::{
+    binding::{
+        bound_array_literal_expression::BoundArrayLiteralExpression,
+        bound_assignment::BoundAssignment, bound_binary_expression::BoundBinaryExpression,
+        bound_binary_operator_kind::BoundBinaryOperatorKind,
+        bound_call_expression::BoundCallExpression,
+        bound_conditional_expression::BoundConditionalExpression,
+        bound_constant_declaration::BoundConstantDeclaration, bound_expression::BoundExpression,
+        bound_expression_statement::BoundExpressionStatement,
+        bound_field_assignment::BoundFieldAssignment, bound_field_expression::BoundFieldExpression,
+        bound_for_statement::BoundForStatement, bound_function::BoundFunction,
+        bound_if_statement::BoundIfStatement, bound_index_assignment::BoundIndexAssignment,
+        bound_index_expression::BoundIndexExpression, bound_kind::BoundKind,
+        bound_length_expression::BoundLengthExpression,
+        bound_literal_expression::BoundLiteralExpression,
+        bound_return_statement::BoundReturnStatement, bound_statement::BoundStatement,
+        bound_statement_list::BoundStatementList,
+        bound_struct_literal_expression::BoundStructLiteralExpression,
+        bound_unary_expression::BoundUnaryExpression,
+        bound_unary_operator_kind::BoundUnaryOperatorKind,
+        bound_variable_declaration::BoundVariableDeclaration,
+        bound_variable_expression::BoundVariableExpression,
+        bound_while_statement::BoundWhileStatement, type_table::TypeTable,
+    },
+    evaluation::runtime_error::RuntimeError,
+    reports::text_span::TextSpan,
+    util::{
+        literals::{LiteralType, LiteralValue},
+        variable_symbol::VariableSymbol,
+    },
+};
+
+// Everything the driver needs to run the program and map its output back.
+pub struct AssemblyProgram {
+    pub source: String,
+    pub globals: Vec<VariableSymbol>,
+    pub errors: Vec<RuntimeError>,
+}
+
+// Values live in %rax (integers and booleans in %eax, floats as raw bits, strings, arrays
+// and structs as pointers). Functions return a flag in %rdx telling whether a value was
+// returned.
+pub struct AssemblyGenerator {
+    text: String,
+    strings: Vec<String>,
+    types: TypeTable,
+    // The runtime copies and prints arrays and structs by walking these type descriptors.
+    descriptors: HashMap<LiteralType, usize>,
+    descriptor_data: String,
+    globals: HashMap<VariableSymbol, usize>,
+    global_symbols: Vec<VariableSymbol>,
+    errors: Vec<RuntimeError>,
+    locals: HashMap<VariableSymbol, i64>,
+    slots: i64,
+    depth: usize,
+    labels: usize,
+    in_function: bool,
+    // The continue and break labels of the loops around the current statement.
+    loops: Vec<(String, String)>,
+}
+
+impl AssemblyGenerator {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            strings: Vec::new(),
+            types: TypeTable::new(),
+            descriptors: HashMap::new(),
+            descriptor_data: String::new(),
+            globals: HashMap::new(),
+            global_symbols: Vec::new(),
+            errors: Vec::new(),
+            locals: HashMap::new(),
+            slots: 0,
+            depth: 0,
+            labels: 0,
+            in_function: false,
+            loops: Vec::new(),
+        }
+    }
+
+    pub fn generate(
+        mut self,
+        statements: Box<dyn BoundStatement>,
+        functions: &[BoundFunction],
+        types: &TypeTable,
+    ) -> AssemblyProgram {
+        self.types = types.clone();
+        self.generate_frame("main", &[], statements);
+
+        for (index, function) in functions.iter().enumerate() {
+            if let Some(body) = function.get_body() {
+                self.in_function = true;
+                let parameters = function.get_parameters().clone();
+                self.generate_frame(&format!("function_{}", index), &parameters, body);
+            }
+        }
+
+        for variable in self.global_symbols.clone() {
+            self.descriptor(&variable.get_type());
+        }
+
+        let mut source = String::from("    .text\n    .globl main\n");
+        source.push_str(&self.text);
+        source.push_str(&self.generate_runtime());
+        source.push_str(&self.generate_data());
+
+        AssemblyProgram {
+            source,
+            globals: self.global_symbols,
+            errors: self.errors,
+        }
+    }
+
+    fn emit(&mut self, line: &str) {
+        self.text.push_str("    ");
+        self.text.push_str(line);
+        self.text.push('\n');
+    }
+
+    fn emit_label(&mut self, label: &str) {
+        self.text.push_str(label);
+        self.text.push_str(":\n");
+    }
+
+    fn new_label(&mut self) -> String {
+        self.labels += 1;
+        format!(".L{}", self.labels)
+    }
+
+    fn push(&mut self, register: &str) {
+        self.emit(&format!("push {}", register));
+        self.depth += 1;
+    }
+
+    fn pop(&mut self, register: &str) {
+        self.emit(&format!("pop {}", register));
+        self.depth -= 1;
+    }
+
+    // Keeps %rsp 16-byte aligned for calls into libc and the runtime helpers.
+    fn emit_call(&mut self, target: &str) {
+        if self.depth % 2 == 1 {
+            self.emit("sub $8, %rsp");
+            self.emit(&format!("call {}", target));
+            self.emit("add $8, %rsp");
+        } else {
+            self.emit(&format!("call {}", target));
+        }
+    }
+
+    fn emit_error(&mut self, error: RuntimeError) {
+        self.errors.push(error);
+        self.emit(&format!("mov ${}, %edi", self.errors.len() - 1));
+        self.emit("jmp rusty_error");
+    }
+
+    fn generate_frame(
+        &mut self,
+        name: &str,
+        parameters: &[VariableSymbol],
+        body: Box<dyn BoundStatement>,
+    ) {
+        self.locals.clear();
+        self.slots = 0;
+        self.depth = 0;
+
+        // Arguments are pushed left to right by the caller, above the return address.
+        for (index, parameter) in parameters.iter().enumerate() {
+            let offset = 16 + 8 * (parameters.len() - 1 - index) as i64;
+            self.locals.insert(parameter.clone(), offset);
+        }
+
+        let outer = std::mem::take(&mut self.text);
+        self.generate_statement(body);
+        let body = std::mem::replace(&mut self.text, outer);
+
+        let frame_size = (self.slots * 8 + 15) / 16 * 16;
+        self.emit_label(name);
+        self.emit("push %rbp");
+        self.emit("mov %rsp, %rbp");
+        if frame_size > 0 {
+            self.emit(&format!("sub ${}, %rsp", frame_size));
+        }
+        self.text.push_str(&body);
+
+        if self.in_function {
+            self.emit("xor %edx, %edx");
+            self.emit("leave");
+            self.emit("ret");
+        } else {
+            self.emit_label(".Lmain_end");
+            self.emit("call rusty_dump");
+            self.emit("xor %eax, %eax");
+            self.emit("leave");
+            self.emit("ret");
+        }
+    }
+
+    fn add_slot(&mut self) -> i64 {
+        self.slots += 1;
+        -8 * self.slots
+    }
+
+    fn local_offset(&mut self, variable: &VariableSymbol) -> i64 {
+        if let Some(offset) = self.locals.get(variable) {
+            return *offset;
+        }
+        let offset = self.add_slot();
+        self.locals.insert(variable.clone(), offset);
+        offset
+    }
+
+    fn global_index(&mut self, variable: &VariableSymbol) -> usize {
+        if let Some(index) = self.globals.get(variable) {
+            return *index;
+        }
+        self.global_symbols.push(variable.clone());
+        let index = self.global_symbols.len() - 1;
+        self.globals.insert(variable.clone(), index);
+        index
+    }
+
+    fn emit_store(&mut self, variable: &VariableSymbol) {
+        if variable.is_global() {
+            let index = self.global_index(variable);
+            self.emit(&format!("mov %rax, global_{}(%rip)", index));
+            self.emit(&format!("movq $1, global_{}+8(%rip)", index));
+        } else {
+            let offset = self.local_offset(variable);
+            self.emit(&format!("mov %rax, {}(%rbp)", offset));
+        }
+    }
+
+    fn generate_statement(&mut self, statement: Box<dyn BoundStatement>) {
+        match *statement.get_type_of_bound() {
+            BoundKind::BoundStatementList => {
+                let statement_list = statement
+                    .as_any()
+                    .downcast_ref::<BoundStatementList>()
+                    .unwrap();
+                for statement in statement_list.get_statements() {
+                    self.generate_statement(statement);
+                }
+            }
+            BoundKind::BoundAssignment => {
+                let assignment = statement
+                    .as_any()
+                    .downcast_ref::<BoundAssignment>()
+                    .unwrap();
+                self.generate_expression(assignment.get_bound_expression());
+                self.emit_store(assignment.get_variable());
+            }
+            BoundKind::BoundIndexAssignment => self.generate_index_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundIndexAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundFieldAssignment => self.generate_field_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundFieldAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundVariableDeclaration => {
+                let declaration = statement
+                    .as_any()
+                    .downcast_ref::<BoundVariableDeclaration>()
+                    .unwrap();
+                self.generate_expression(declaration.get_bound_expression());
+                self.emit_store(declaration.get_variable());
+            }
+            BoundKind::BoundConstantDeclaration => {
+                let declaration = statement
+                    .as_any()
+                    .downcast_ref::<BoundConstantDeclaration>()
+                    .unwrap();
+                self.generate_expression(declaration.get_bound_expression());
+                self.emit_store(declaration.get_variable());
+            }
+            BoundKind::BoundIfStatement => self.generate_if_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundIfStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundWhileStatement => self.generate_while_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundWhileStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundForStatement => self.generate_for_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundForStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundBreakStatement => {
+                let (_, break_label) = self.loops.last().unwrap().clone();
+                self.emit(&format!("jmp {}", break_label));
+            }
+            BoundKind::BoundContinueStatement => {
+                let (continue_label, _) = self.loops.last().unwrap().clone();
+                self.emit(&format!("jmp {}", continue_label));
+            }
+            BoundKind::BoundReturnStatement => {
+                let return_statement = statement
+                    .as_any()
+                    .downcast_ref::<BoundReturnStatement>()
+                    .unwrap();
+                self.generate_expression(return_statement.get_bound_expression());
+                if self.in_function {
+                    self.emit("mov $1, %edx");
+                    self.emit("leave");
+                    self.emit("ret");
+                } else {
+                    self.emit("jmp .Lmain_end");
+                }
+            }
+            BoundKind::BoundExpressionStatement => {
+                let expression = statement
+                    .as_any()
+                    .downcast_ref::<BoundExpressionStatement>()
+                    .unwrap()
+                    .get_bound_expression();
+                match expression.as_any().downcast_ref::<BoundCallExpression>() {
+                    Some(call_expression) => self.generate_call_expression(call_expression, true),
+                    None => self.generate_expression(expression),
+                }
+            }
+            _ => panic!("Assembly generation ERROR: Unexpected bound kind for statement."),
+        }
+    }
+
+    // The element is written in place, the variable keeps pointing at the same block.
+    fn generate_index_assignment(&mut self, index_assignment: &BoundIndexAssignment) {
+        self.generate_expression(index_assignment.get_index());
+        self.push("%rax");
+        self.generate_expression(index_assignment.get_bound_expression());
+        self.push("%rax");
+        self.load_variable(index_assignment.get_variable(), index_assignment.get_span());
+        self.pop("%rdx");
+        self.pop("%rcx");
+        self.emit_index_check(index_assignment.get_span());
+        self.emit("mov %rdx, 8(%rax,%rcx,8)");
+    }
+
+    fn generate_field_assignment(&mut self, field_assignment: &BoundFieldAssignment) {
+        self.generate_expression(field_assignment.get_bound_expression());
+        self.push("%rax");
+        self.load_variable(field_assignment.get_variable(), field_assignment.get_span());
+        self.pop("%rcx");
+        self.emit(&format!(
+            "mov %rcx, {}(%rax)",
+            8 * field_assignment.get_index()
+        ));
+    }
+
+    fn generate_if_statement(&mut self, if_statement: &BoundIfStatement) {
+        let else_label = self.new_label();
+        let end_label = self.new_label();
+
+        self.generate_expression(if_statement.get_condition());
+        self.emit("test %eax, %eax");
+        self.emit(&format!("jz {}", else_label));
+        self.generate_statement(if_statement.get_then_statement());
+        self.emit(&format!("jmp {}", end_label));
+        self.emit_label(&else_label);
+        if let Some(else_statement) = if_statement.get_else_statement() {
+            self.generate_statement(else_statement);
+        }
+        self.emit_label(&end_label);
+    }
+
+    fn generate_while_statement(&mut self, while_statement: &BoundWhileStatement) {
+        let start_label = self.new_label();
+        let end_label = self.new_label();
+
+        self.emit_label(&start_label);
+        self.generate_expression(while_statement.get_condition());
+        self.emit("test %eax, %eax");
+        self.emit(&format!("jz {}", end_label));
+        self.generate_loop_body(while_statement.get_body(), &start_label, &end_label);
+        self.emit(&format!("jmp {}", start_label));
+        self.emit_label(&end_label);
+    }
+
+    fn generate_for_statement(&mut self, for_statement: &BoundForStatement) {
+        let counter = self.add_slot();
+        let upper_bound = self.add_slot();
+        let variable = self.local_offset(for_statement.get_variable());
+        let start_label = self.new_label();
+        let next_label = self.new_label();
+        let end_label = self.new_label();
+
+        self.generate_expression(for_statement.get_lower_bound());
+        self.emit(&format!("mov %rax, {}(%rbp)", counter));
+        self.generate_expression(for_statement.get_upper_bound());
+        self.emit(&format!("mov %rax, {}(%rbp)", upper_bound));
+
+        self.emit_label(&start_label);
+        self.emit(&format!("mov {}(%rbp), %eax", counter));
+        self.emit(&format!("cmp {}(%rbp), %eax", upper_bound));
+        self.emit(&format!("jge {}", end_label));
+        self.emit(&format!("mov %rax, {}(%rbp)", variable));
+        self.generate_loop_body(for_statement.get_body(), &next_label, &end_label);
+        self.emit_label(&next_label);
+        self.emit(&format!("incl {}(%rbp)", counter));
+        self.emit(&format!("jmp {}", start_label));
+        self.emit_label(&end_label);
+    }
+
+    fn generate_loop_body(
+        &mut self,
+        body: Box<dyn BoundStatement>,
+        continue_label: &str,
+        break_label: &str,
+    ) {
+        self.loops
+            .push((continue_label.to_string(), break_label.to_string()));
+        self.generate_statement(body);
+        self.loops.pop();
+    }
+
+    fn generate_expression(&mut self, expression: Box<dyn BoundExpression>) {
+        match *expression.get_type_of_bound() {
+            BoundKind::BoundLiteralExpression => self.generate_literal(
+                &expression
+                    .as_any()
+                    .downcast_ref::<BoundLiteralExpression>()
+                    .unwrap()
+                    .get_value(),
+            ),
+            BoundKind::BoundVariableExpression => {
+                let variable_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundVariableExpression>()
+                    .unwrap();
+                self.load_variable(
+                    variable_expression.get_variable(),
+                    variable_expression.get_span(),
+                );
+                self.emit_copy(expression.get_type());
+            }
+            BoundKind::BoundUnaryExpression => self.generate_unary_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundUnaryExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundBinaryExpression => self.generate_binary_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundBinaryExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundCallExpression => self.generate_call_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundCallExpression>()
+                    .unwrap(),
+                false,
+            ),
+            BoundKind::BoundConditionalExpression => self.generate_conditional_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundConditionalExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundArrayLiteralExpression => self.generate_array_literal_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundArrayLiteralExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundIndexExpression => {
+                self.generate_index_expression(
+                    expression
+                        .as_any()
+                        .downcast_ref::<BoundIndexExpression>()
+                        .unwrap(),
+                );
+                self.emit_copy(expression.get_type());
+            }
+            BoundKind::BoundLengthExpression => {
+                let length_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundLengthExpression>()
+                    .unwrap();
+                self.generate_operand(length_expression.get_array());
+                self.emit("mov (%rax), %eax");
+            }
+            BoundKind::BoundStructLiteralExpression => self.generate_struct_literal_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundStructLiteralExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundFieldExpression => {
+                self.generate_field_expression(
+                    expression
+                        .as_any()
+                        .downcast_ref::<BoundFieldExpression>()
+                        .unwrap(),
+                );
+                self.emit_copy(expression.get_type());
+            }
+            _ => panic!("Assembly generation ERROR: Unexpected bound kind for expression."),
+        }
+    }
+
+    fn generate_literal(&mut self, value: &LiteralValue) {
+        match value {
+            LiteralValue::Integer(value) => self.emit(&format!("mov ${}, %eax", value)),
+            LiteralValue::Boolean(value) => self.emit(&format!("mov ${}, %eax", *value as i32)),
+            LiteralValue::Float(value) => {
+                self.emit(&format!("movabs ${}, %rax", value.to_bits() as i64))
+            }
+            LiteralValue::String(value) => {
+                let index = self.add_string(value);
+                self.emit(&format!("lea string_{}(%rip), %rax", index));
+            }
+            LiteralValue::Array(_) | LiteralValue::Struct(_, _) => {
+                panic!("Assembly generation ERROR: Array and struct values are built by literals.")
+            }
+        }
+    }
+
+    fn load_variable(&mut self, variable: &VariableSymbol, span: TextSpan) {
+        if !variable.is_global() {
+            let offset = self.local_offset(variable);
+            self.emit(&format!("mov {}(%rbp), %rax", offset));
+            return;
+        }
+
+        let index = self.global_index(variable);
+        let initialized = self.new_label();
+        self.emit(&format!("cmpq $0, global_{}+8(%rip)", index));
+        self.emit(&format!("jne {}", initialized));
+        self.emit_error(RuntimeError::UninitializedVariable(variable.id(), span));
+        self.emit_label(&initialized);
+        self.emit(&format!("mov global_{}(%rip), %rax", index));
+    }
+
+    // Arrays and structs are values, so one that is read as a whole is copied. One that is
+    // only indexed, measured or has a field read is used in place, see generate_operand.
+    fn emit_copy(&mut self, kind: &LiteralType) {
+        if matches!(kind, LiteralType::Array(_) | LiteralType::Struct(_)) {
+            let descriptor = self.descriptor(kind);
+            self.emit("mov %rax, %rdi");
+            self.emit(&format!("lea descriptor_{}(%rip), %rsi", descriptor));
+            self.emit_call("rusty_copy");
+        }
+    }
+
+    fn generate_operand(&mut self, operand: Box<dyn BoundExpression>) {
+        match *operand.get_type_of_bound() {
+            BoundKind::BoundVariableExpression => {
+                let variable_expression = operand
+                    .as_any()
+                    .downcast_ref::<BoundVariableExpression>()
+                    .unwrap();
+                self.load_variable(
+                    variable_expression.get_variable(),
+                    variable_expression.get_span(),
+                );
+            }
+            BoundKind::BoundIndexExpression => self.generate_index_expression(
+                operand
+                    .as_any()
+                    .downcast_ref::<BoundIndexExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundFieldExpression => self.generate_field_expression(
+                operand
+                    .as_any()
+                    .downcast_ref::<BoundFieldExpression>()
+                    .unwrap(),
+            ),
+            _ => self.generate_expression(operand),
+        }
+    }
+
+    // Scalars are a single code: 0 for integers, 1 for floats, 2 for strings and 3 for
+    // booleans. An array is 4 followed by its element descriptor, a struct is 5 followed by
+    // the field count, its name and a name and descriptor for every field.
+    fn descriptor(&mut self, kind: &LiteralType) -> usize {
+        if let Some(index) = self.descriptors.get(kind) {
+            return *index;
+        }
+
+        let data = match kind {
+            LiteralType::Integer => String::from("0"),
+            LiteralType::Float => String::from("1"),
+            LiteralType::String => String::from("2"),
+            LiteralType::Boolean => String::from("3"),
+            LiteralType::Array(element) => {
+                format!("4, descriptor_{}", self.descriptor(element))
+            }
+            LiteralType::Struct(name) => {
+                let fields = self.types.lookup(name).unwrap().get_fields();
+                let mut data = format!("5, {}, string_{}", fields.len(), self.add_string(name));
+                for (field, field_type) in fields {
+                    data.push_str(&format!(
+                        ", string_{}, descriptor_{}",
+                        self.add_string(&field),
+                        self.descriptor(&field_type)
+                    ));
+                }
+                data
+            }
+        };
+
+        let index = self.descriptors.len();
+        self.descriptors.insert(kind.clone(), index);
+        self.descriptor_data
+            .push_str(&format!("descriptor_{}:\n    .quad {}\n", index, data));
+        index
+    }
+
+    fn add_string(&mut self, value: &str) -> usize {
+        self.strings.push(value.to_string());
+        self.strings.len() - 1
+    }
+
+    // An array is a heap block holding the length followed by one 8-byte slot per element.
+    fn generate_array_literal_expression(
+        &mut self,
+        array_literal_expression: &BoundArrayLiteralExpression,
+    ) {
+        let elements = array_literal_expression.get_elements();
+        let count = elements.len();
+
+        for element in elements {
+            self.generate_expression(element);
+            self.push("%rax");
+        }
+        self.emit(&format!("mov ${}, %edi", 8 * (count + 1)));
+        self.emit_call("malloc");
+        self.emit(&format!("movq ${}, (%rax)", count));
+        for slot in (1..=count).rev() {
+            self.pop("%rcx");
+            self.emit(&format!("mov %rcx, {}(%rax)", 8 * slot));
+        }
+    }
+
+    // A struct is a heap block with one 8-byte slot per field, in declaration order.
+    fn generate_struct_literal_expression(
+        &mut self,
+        struct_literal_expression: &BoundStructLiteralExpression,
+    ) {
+        let fields = struct_literal_expression.get_fields();
+        let count = fields.len();
+
+        for field in fields {
+            self.generate_expression(field);
+            self.push("%rax");
+        }
+        self.emit(&format!("mov ${}, %edi", 8 * count.max(1)));
+        self.emit_call("malloc");
+        for slot in (0..count).rev() {
+            self.pop("%rcx");
+            self.emit(&format!("mov %rcx, {}(%rax)", 8 * slot));
+        }
+    }
+
+    fn generate_field_expression(&mut self, field_expression: &BoundFieldExpression) {
+        self.generate_operand(field_expression.get_structure());
+        self.emit(&format!(
+            "mov {}(%rax), %rax",
+            8 * field_expression.get_index()
+        ));
+    }
+
+    fn generate_index_expression(&mut self, index_expression: &BoundIndexExpression) {
+        self.generate_operand(index_expression.get_array());
+        self.push("%rax");
+        self.generate_expression(index_expression.get_index());
+        self.emit("mov %eax, %ecx");
+        self.pop("%rax");
+        self.emit_index_check(index_expression.get_span());
+        self.emit("mov 8(%rax,%rcx,8), %rax");
+    }
+
+    // Expects the array in %rax and the index in %ecx. The index and the length are only
+    // known at run time, so rusty_index_error passes them on to the driver.
+    fn emit_index_check(&mut self, span: TextSpan) {
+        let in_bounds = self.new_label();
+
+        self.emit("movslq %ecx, %rcx");
+        self.emit("cmp (%rax), %rcx");
+        self.emit(&format!("jb {}", in_bounds));
+        self.errors.push(RuntimeError::IndexOutOfBounds(0, 0, span));
+        self.emit(&format!("mov ${}, %edi", self.errors.len() - 1));
+        self.emit("mov %ecx, %esi");
+        self.emit("mov (%rax), %edx");
+        self.emit("jmp rusty_index_error");
+        self.emit_label(&in_bounds);
+    }
+
+    fn generate_unary_expression(&mut self, unary_expression: &BoundUnaryExpression) {
+        let operand = unary_expression.get_operand();
+        let is_float = *operand.get_type() == LiteralType::Float;
+        self.generate_expression(operand);
+
+        match unary_expression.get_operator().get_kind() {
+            BoundUnaryOperatorKind::Identity => {}
+            BoundUnaryOperatorKind::Negation if is_float => self.emit("btc $63, %rax"),
+            BoundUnaryOperatorKind::Negation => {
+                self.emit("neg %eax");
+                self.emit_overflow_check(unary_expression.get_span());
+            }
+            BoundUnaryOperatorKind::LogicalNegation => self.emit("xor $1, %eax"),
+            BoundUnaryOperatorKind::BitwiseNot => self.emit("not %eax"),
+        }
+    }
+
+    fn emit_overflow_check(&mut self, span: TextSpan) {
+        let ok = self.new_label();
+        self.emit(&format!("jno {}", ok));
+        self.emit_error(RuntimeError::IntegerOverflow(span));
+        self.emit_label(&ok);
+    }
+
+    fn emit_set(&mut self, condition: &str) {
+        self.emit(&format!("set{} %al", condition));
+        self.emit("movzbl %al, %eax");
+    }
+
+    // Left operand ends up in %rax, right operand in %rcx.
+    fn generate_binary_expression(&mut self, binary_expression: &BoundBinaryExpression) {
+        let left = binary_expression.get_left();
+        let right = binary_expression.get_right();
+        let left_type = left.get_type().clone();
+        let right_type = right.get_type().clone();
+        let span = binary_expression.get_span();
+
+        self.generate_expression(left);
+        self.push("%rax");
+        self.generate_expression(right);
+        self.emit("mov %rax, %rcx");
+        self.pop("%rax");
+
+        let kind = binary_expression.get_operator().get_kind();
+        if left_type == LiteralType::Float || right_type == LiteralType::Float {
+            self.load_float("%rax", "%xmm0", left_type);
+            self.load_float("%rcx", "%xmm1", right_type);
+            self.generate_float_operation(kind);
+        } else if left_type == LiteralType::String {
+            self.generate_string_operation(kind);
+        } else {
+            self.generate_integer_operation(kind, span);
+        }
+    }
+
+    fn load_float(&mut self, register: &str, xmm: &str, kind: LiteralType) {
+        if kind == LiteralType::Integer {
+            let register = if register == "%rax" { "%eax" } else { "%ecx" };
+            self.emit(&format!("cvtsi2sdl {}, {}", register, xmm));
+        } else {
+            self.emit(&format!("movq {}, {}", register, xmm));
+        }
+    }
+
+    fn generate_integer_operation(&mut self, kind: &BoundBinaryOperatorKind, span: TextSpan) {
+        match kind {
+            BoundBinaryOperatorKind::Addition => {
+                self.emit("add %ecx, %eax");
+                self.emit_overflow_check(span);
+            }
+            BoundBinaryOperatorKind::Subtraction => {
+                self.emit("sub %ecx, %eax");
+                self.emit_overflow_check(span);
+            }
+            BoundBinaryOperatorKind::Multiplication => {
+                self.emit("imul %ecx, %eax");
+                self.emit_overflow_check(span);
+            }
+            BoundBinaryOperatorKind::Division => self.emit_division(span),
+            BoundBinaryOperatorKind::Modulo => {
+                self.emit_division(span);
+                self.emit("mov %edx, %eax");
+            }
+            BoundBinaryOperatorKind::Power => self.generate_integer_power(span),
+            BoundBinaryOperatorKind::BitwiseAnd => self.emit("and %ecx, %eax"),
+            BoundBinaryOperatorKind::BitwiseOr => self.emit("or %ecx, %eax"),
+            BoundBinaryOperatorKind::BitwiseXor => self.emit("xor %ecx, %eax"),
+            BoundBinaryOperatorKind::ShiftLeft => self.generate_shift("shl", span),
+            BoundBinaryOperatorKind::ShiftRight => self.generate_shift("sar", span),
+            BoundBinaryOperatorKind::LogicalAnd => self.emit("and %ecx, %eax"),
+            BoundBinaryOperatorKind::LogicalOr => self.emit("or %ecx, %eax"),
+            BoundBinaryOperatorKind::Equals => self.generate_comparison("e"),
+            BoundBinaryOperatorKind::NotEquals => self.generate_comparison("ne"),
+            BoundBinaryOperatorKind::Less => self.generate_comparison("l"),
+            BoundBinaryOperatorKind::LessOrEquals => self.generate_comparison("le"),
+            BoundBinaryOperatorKind::Greater => self.generate_comparison("g"),
+            BoundBinaryOperatorKind::GreaterOrEquals => self.generate_comparison("ge"),
+            BoundBinaryOperatorKind::Concatenation => {
+                panic!("Assembly generation ERROR: Concatenation of non-string operands.")
+            }
+        }
+    }
+
+    // Leaves the quotient in %eax and the remainder in %edx.
+    fn emit_division(&mut self, span: TextSpan) {
+        let non_zero = self.new_label();
+        let divide = self.new_label();
+
+        self.emit("test %ecx, %ecx");
+        self.emit(&format!("jnz {}", non_zero));
+        self.emit_error(RuntimeError::DivisionByZero(span.clone()));
+        self.emit_label(&non_zero);
+        self.emit("cmp $-1, %ecx");
+        self.emit(&format!("jne {}", divide));
+        self.emit("cmp $-2147483648, %eax");
+        self.emit(&format!("jne {}", divide));
+        self.emit_error(RuntimeError::IntegerOverflow(span));
+        self.emit_label(&divide);
+        self.emit("cltd");
+        self.emit("idiv %ecx");
+    }
+
+    // Multiplies one factor at a time, so an intermediate result only overflows when the
+    // final one does.
+    fn generate_integer_power(&mut self, span: TextSpan) {
+        let non_negative = self.new_label();
+        let start = self.new_label();
+        let end = self.new_label();
+
+        self.emit("test %ecx, %ecx");
+        self.emit(&format!("jns {}", non_negative));
+        self.emit_error(RuntimeError::NegativeExponent(span.clone()));
+        self.emit_label(&non_negative);
+        self.emit("mov %eax, %edx");
+        self.emit("mov $1, %eax");
+        self.emit_label(&start);
+        self.emit("test %ecx, %ecx");
+        self.emit(&format!("jz {}", end));
+        self.emit("imul %edx, %eax");
+        self.emit_overflow_check(span);
+        self.emit("dec %ecx");
+        self.emit(&format!("jmp {}", start));
+        self.emit_label(&end);
+    }
+
+    // The shift amount is in %cl, which the processor would otherwise reduce modulo 32.
+    fn generate_shift(&mut self, instruction: &str, span: TextSpan) {
+        let in_range = self.new_label();
+
+        self.emit("cmp $31, %ecx");
+        self.emit(&format!("jbe {}", in_range));
+        self.emit_error(RuntimeError::InvalidShift(span));
+        self.emit_label(&in_range);
+        self.emit(&format!("{} %cl, %eax", instruction));
+    }
+
+    fn generate_comparison(&mut self, condition: &str) {
+        self.emit("cmp %ecx, %eax");
+        self.emit_set(condition);
+    }
+
+    // ucomisd reports "unordered" through ZF, PF and CF, so every comparison with NaN is false.
+    fn generate_float_operation(&mut self, kind: &BoundBinaryOperatorKind) {
+        let arithmetic = match kind {
+            BoundBinaryOperatorKind::Addition => Some("addsd"),
+            BoundBinaryOperatorKind::Subtraction => Some("subsd"),
+            BoundBinaryOperatorKind::Multiplication => Some("mulsd"),
+            BoundBinaryOperatorKind::Division => Some("divsd"),
+            _ => None,
+        };
+        if let Some(instruction) = arithmetic {
+            self.emit(&format!("{} %xmm1, %xmm0", instruction));
+            self.emit("movq %xmm0, %rax");
+            return;
+        }
+
+        let function = match kind {
+            BoundBinaryOperatorKind::Modulo => Some("fmod"),
+            BoundBinaryOperatorKind::Power => Some("pow"),
+            _ => None,
+        };
+        if let Some(function) = function {
+            self.emit_call(function);
+            self.emit("movq %xmm0, %rax");
+            return;
+        }
+
+        match kind {
+            BoundBinaryOperatorKind::Equals => {
+                self.emit("ucomisd %xmm1, %xmm0");
+                self.emit("sete %al");
+                self.emit("setnp %cl");
+                self.emit("and %cl, %al");
+                self.emit("movzbl %al, %eax");
+            }
+            BoundBinaryOperatorKind::NotEquals => {
+                self.emit("ucomisd %xmm1, %xmm0");
+                self.emit("setne %al");
+                self.emit("setp %cl");
+                self.emit("or %cl, %al");
+                self.emit("movzbl %al, %eax");
+            }
+            BoundBinaryOperatorKind::Less => {
+                self.emit("ucomisd %xmm0, %xmm1");
+                self.emit_set("a");
+            }
+            BoundBinaryOperatorKind::LessOrEquals => {
+                self.emit("ucomisd %xmm0, %xmm1");
+                self.emit_set("ae");
+            }
+            BoundBinaryOperatorKind::Greater => {
+                self.emit("ucomisd %xmm1, %xmm0");
+                self.emit_set("a");
+            }
+            BoundBinaryOperatorKind::GreaterOrEquals => {
+                self.emit("ucomisd %xmm1, %xmm0");
+                self.emit_set("ae");
+            }
+            _ => panic!("Assembly generation ERROR: Unexpected operator for floats."),
+        }
+    }
+
+    fn generate_string_operation(&mut self, kind: &BoundBinaryOperatorKind) {
+        self.emit("mov %rax, %rdi");
+        self.emit("mov %rcx, %rsi");
+
+        if let BoundBinaryOperatorKind::Concatenation = kind {
+            self.emit_call("rusty_concat");
+            return;
+        }
+
+        self.emit_call("strcmp");
+        self.emit("cmp $0, %eax");
+        match kind {
+            BoundBinaryOperatorKind::Equals => self.emit_set("e"),
+            BoundBinaryOperatorKind::NotEquals => self.emit_set("ne"),
+            BoundBinaryOperatorKind::Less => self.emit_set("l"),
+            BoundBinaryOperatorKind::LessOrEquals => self.emit_set("le"),
+            BoundBinaryOperatorKind::Greater => self.emit_set("g"),
+            BoundBinaryOperatorKind::GreaterOrEquals => self.emit_set("ge"),
+            _ => panic!("Assembly generation ERROR: Unexpected operator for strings."),
+        }
+    }
+
+    fn generate_conditional_expression(
+        &mut self,
+        conditional_expression: &BoundConditionalExpression,
+    ) {
+        let false_label = self.new_label();
+        let end_label = self.new_label();
+
+        self.generate_expression(conditional_expression.get_condition());
+        self.emit("test %eax, %eax");
+        self.emit(&format!("jz {}", false_label));
+        self.generate_expression(conditional_expression.get_when_true());
+        self.emit(&format!("jmp {}", end_label));
+        self.emit_label(&false_label);
+        self.generate_expression(conditional_expression.get_when_false());
+        self.emit_label(&end_label);
+    }
+
+    fn generate_call_expression(&mut self, call_expression: &BoundCallExpression, discard: bool) {
+        let arguments = call_expression.get_arguments();
+        let padding = (self.depth + arguments.len()) % 2;
+
+        if padding == 1 {
+            self.emit("sub $8, %rsp");
+            self.depth += 1;
+        }
+        let count = arguments.len();
+        for argument in arguments {
+            self.generate_expression(argument);
+            self.push("%rax");
+        }
+
+        // max_call_depth is defined by the driver when the program is assembled, the limit
+        // reported is filled in there as well.
+        let within_limit = self.new_label();
+        self.emit("incq call_depth(%rip)");
+        self.emit("cmpq $max_call_depth, call_depth(%rip)");
+        self.emit(&format!("jbe {}", within_limit));
+        self.emit_error(RuntimeError::CallDepthExceeded(
+            0,
+            call_expression.get_span(),
+        ));
+        self.emit_label(&within_limit);
+
+        let function = call_expression.get_function();
+        self.emit(&format!("call function_{}", function.index()));
+        self.emit("decq call_depth(%rip)");
+        self.emit(&format!("add ${}, %rsp", 8 * (count + padding)));
+        self.depth -= count + padding;
+
+        if !discard {
+            let returned = self.new_label();
+            self.emit("test %edx, %edx");
+            self.emit(&format!("jnz {}", returned));
+            self.emit_error(RuntimeError::MissingReturnValue(
+                function.id(),
+                call_expression.get_span(),
+            ));
+            self.emit_label(&returned);
+        }
+    }
+
+    // rusty_dump prints every initialized global as "<index> <type> <value>", strings are
+    // length-prefixed so they may contain newlines. Arrays and structs are written by
+    // rusty_print, arrays as "a <length>" followed by their elements and structs as
+    // "t <name> <count>" followed by a name and a value per field. rusty_error dumps and
+    // exits with status 1, rusty_index_error also prints the index and the length.
+    // rusty_copy deeply copies a value, rusty_print and rusty_copy both take the value and
+    // its descriptor.
+    fn generate_runtime(&self) -> String {
+        let mut runtime = String::new();
+
+        runtime.push_str("rusty_dump:\n    push %rbp\n    mov %rsp, %rbp\n");
+        for (index, variable) in self.global_symbols.iter().enumerate() {
+            let skip = format!(".Ldump_{}", index);
+            runtime.push_str(&format!(
+                "    cmpq $0, global_{0}+8(%rip)\n    je {1}\n",
+                index, skip
+            ));
+            match variable.get_type() {
+                kind @ (LiteralType::Array(_) | LiteralType::Struct(_)) => {
+                    runtime.push_str(&format!(
+                        "    lea format_index(%rip), %rdi\n    mov ${0}, %esi\n    \
+                         xor %eax, %eax\n    call printf\n    mov global_{0}(%rip), %rdi\n    \
+                         lea descriptor_{1}(%rip), %rsi\n    call rusty_print\n    \
+                         lea format_end(%rip), %rdi\n",
+                        index, self.descriptors[&kind]
+                    ));
+                }
+                LiteralType::String => runtime.push_str(&format!(
+                    "    mov global_{0}(%rip), %rdi\n    call strlen\n    mov %rax, %rdx\n    \
+                     mov global_{0}(%rip), %rcx\n    lea format_string(%rip), %rdi\n",
+                    index
+                )),
+                kind => runtime.push_str(&format!(
+                    "    mov global_{}(%rip), %rdx\n    lea {}(%rip), %rdi\n",
+                    index,
+                    match kind {
+                        LiteralType::Integer => "format_integer",
+                        LiteralType::Float => "format_float",
+                        _ => "format_boolean",
+                    }
+                )),
+            }
+            runtime.push_str(&format!(
+                "    mov ${}, %esi\n    xor %eax, %eax\n    call printf\n{}:\n",
+                index, skip
+            ));
+        }
+        runtime.push_str("    leave\n    ret\n");
+
+        runtime.push_str(
+            "rusty_error:
+    and $-16, %rsp
+    mov %edi, %ebx
+    call rusty_dump
+    lea format_error(%rip), %rdi
+    mov %ebx, %esi
+    xor %eax, %eax
+    call printf
+    mov $1, %edi
+    call exit
+rusty_index_error:
+    and $-16, %rsp
+    mov %edi, %ebx
+    mov %esi, %r12d
+    mov %edx, %r13d
+    call rusty_dump
+    lea format_index_error(%rip), %rdi
+    mov %ebx, %esi
+    mov %r12d, %edx
+    mov %r13d, %ecx
+    xor %eax, %eax
+    call printf
+    mov $1, %edi
+    call exit
+rusty_copy:
+    push %rbp
+    mov %rsp, %rbp
+    push %r12
+    push %r13
+    push %r14
+    push %r15
+    mov %rdi, %r12
+    mov %rsi, %r13
+    mov %rdi, %rax
+    cmpq $4, (%r13)
+    jb .Lcopy_return
+    je .Lcopy_array
+    mov 8(%r13), %rdi
+    lea 8(,%rdi,8), %rdi
+    call malloc
+    mov %rax, %r14
+    xor %r15, %r15
+.Lcopy_field:
+    cmp 8(%r13), %r15
+    jge .Lcopy_done
+    mov (%r12,%r15,8), %rdi
+    mov %r15, %rax
+    shl $4, %rax
+    mov 32(%r13,%rax), %rsi
+    call rusty_copy
+    mov %rax, (%r14,%r15,8)
+    inc %r15
+    jmp .Lcopy_field
+.Lcopy_array:
+    mov (%r12), %rdi
+    lea 8(,%rdi,8), %rdi
+    call malloc
+    mov %rax, %r14
+    mov (%r12), %rcx
+    mov %rcx, (%r14)
+    xor %r15, %r15
+.Lcopy_next:
+    cmp (%r12), %r15
+    jge .Lcopy_done
+    mov 8(%r12,%r15,8), %rdi
+    mov 8(%r13), %rsi
+    call rusty_copy
+    mov %rax, 8(%r14,%r15,8)
+    inc %r15
+    jmp .Lcopy_next
+.Lcopy_done:
+    mov %r14, %rax
+.Lcopy_return:
+    pop %r15
+    pop %r14
+    pop %r13
+    pop %r12
+    leave
+    ret
+rusty_print:
+    push %rbp
+    mov %rsp, %rbp
+    push %r12
+    push %r13
+    push %r14
+    push %r15
+    mov %rdi, %r12
+    mov %rsi, %r13
+    mov (%r13), %r14
+    cmp $4, %r14
+    je .Lprint_array
+    cmp $5, %r14
+    je .Lprint_struct
+    cmp $2, %r14
+    je .Lprint_string
+    lea element_integer(%rip), %rdi
+    cmp $1, %r14
+    jne .Lprint_boolean
+    lea element_float(%rip), %rdi
+.Lprint_boolean:
+    cmp $3, %r14
+    jne .Lprint_scalar
+    lea element_boolean(%rip), %rdi
+.Lprint_scalar:
+    mov %r12, %rsi
+    xor %eax, %eax
+    call printf
+    jmp .Lprint_done
+.Lprint_string:
+    mov %r12, %rdi
+    call strlen
+    mov %rax, %rsi
+    mov %r12, %rdx
+    lea element_string(%rip), %rdi
+    xor %eax, %eax
+    call printf
+    jmp .Lprint_done
+.Lprint_array:
+    lea element_array(%rip), %rdi
+    mov (%r12), %rsi
+    xor %eax, %eax
+    call printf
+    xor %r15, %r15
+.Lprint_next:
+    cmp (%r12), %r15
+    jge .Lprint_done
+    mov 8(%r12,%r15,8), %rdi
+    mov 8(%r13), %rsi
+    call rusty_print
+    inc %r15
+    jmp .Lprint_next
+.Lprint_struct:
+    lea element_struct(%rip), %rdi
+    mov 16(%r13), %rsi
+    mov 8(%r13), %rdx
+    xor %eax, %eax
+    call printf
+    xor %r15, %r15
+.Lprint_field:
+    cmp 8(%r13), %r15
+    jge .Lprint_done
+    mov %r15, %r14
+    shl $4, %r14
+    lea element_field(%rip), %rdi
+    mov 24(%r13,%r14), %rsi
+    xor %eax, %eax
+    call printf
+    mov (%r12,%r15,8), %rdi
+    mov 32(%r13,%r14), %rsi
+    call rusty_print
+    inc %r15
+    jmp .Lprint_field
+.Lprint_done:
+    pop %r15
+    pop %r14
+    pop %r13
+    pop %r12
+    leave
+    ret
+rusty_concat:
+    push %rbp
+    mov %rsp, %rbp
+    push %r12
+    push %r13
+    push %r14
+    push %r15
+    mov %rdi, %r12
+    mov %rsi, %r13
+    call strlen
+    mov %rax, %r14
+    mov %r13, %rdi
+    call strlen
+    lea 1(%r14, %rax), %rdi
+    call malloc
+    mov %rax, %r15
+    mov %rax, %rdi
+    mov %r12, %rsi
+    call strcpy
+    lea (%r15, %r14), %rdi
+    mov %r13, %rsi
+    call strcpy
+    mov %r15, %rax
+    pop %r15
+    pop %r14
+    pop %r13
+    pop %r12
+    leave
+    ret
+",
+        );
+        runtime
+    }
+
+    fn generate_data(&self) -> String {
+        let mut data = String::from(
+            "    .section .rodata
+format_integer:
+    .asciz \"%d i %d\\n\"
+format_boolean:
+    .asciz \"%d b %d\\n\"
+format_float:
+    .asciz \"%d f %lx\\n\"
+format_string:
+    .asciz \"%d s %zu %s\\n\"
+format_error:
+    .asciz \"error %d\\n\"
+format_index_error:
+    .asciz \"error %d %d %d\\n\"
+format_index:
+    .asciz \"%d\"
+format_end:
+    .asciz \"\\n\"
+element_integer:
+    .asciz \" i %d\"
+element_boolean:
+    .asciz \" b %d\"
+element_float:
+    .asciz \" f %lx\"
+element_string:
+    .asciz \" s %zu %s\"
+element_array:
+    .asciz \" a %zu\"
+element_struct:
+    .asciz \" t %s %zu\"
+element_field:
+    .asciz \" %s\"
+",
+        );
+        data.push_str(&self.descriptor_data);
+        for (index, string) in self.strings.iter().enumerate() {
+            let bytes: Vec<String> = string
+                .bytes()
+                .chain(std::iter::once(0))
+                .map(|byte| byte.to_string())
+                .collect();
+            data.push_str(&format!(
+                "string_{}:\n    .byte {}\n",
+                index,
+                bytes.join(", ")
+            ));
+        }
+
+        data.push_str("    .data\ncall_depth:\n    .quad 0\n");
+        for index in 0..self.global_symbols.len() {
+            data.push_str(&format!("global_{}:\n    .quad 0, 0\n", index));
+        }
+        data.push_str("    .section .note.GNU-stack,\"\",@progbits\n");
+        data
+    }
+}
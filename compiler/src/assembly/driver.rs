@@ -0,0 +1,253 @@
+use std::{
+    cell::RefCell,
+    fs,
+    io::Read,
+    os::unix::process::ExitStatusExt,
+    path::Path,
+    process::{Command, ExitStatus, Stdio},
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    environment::Environment,
+    evaluation::{execution_limits::ExecutionLimits, runtime_error::RuntimeError},
+    util::literals::LiteralValue,
+};
+
+use super::assembly_generator::AssemblyProgram;
+
+static BUILD_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+// The stack of the native program in KiB, far more than the call depth limit needs.
+const STACK_LIMIT_KB: u64 = 8 * 1024;
+
+// Assembles and links the program with the system gcc, runs it and copies the globals it
+// prints back into the environment. The step limit has no meaning for native code, the
+// time limit, the call depth and the memory of the process are enforced.
+pub fn execute(
+    program: &AssemblyProgram,
+    limits: &ExecutionLimits,
+    environment: Rc<RefCell<Environment>>,
+) -> Result<(), RuntimeError> {
+    let directory = std::env::temp_dir().join(format!(
+        "rusty-{}-{}",
+        std::process::id(),
+        BUILD_COUNTER.fetch_add(1, Ordering::SeqCst)
+    ));
+    fs::create_dir_all(&directory).map_err(|error| toolchain_error(&error.to_string()))?;
+
+    let result = build_and_run(program, limits, &directory);
+    let _ = fs::remove_dir_all(&directory);
+    let (output, status) = result?;
+
+    // A runtime error is printed before the program exits with a failure status, any other
+    // failure means the program crashed or was killed.
+    let (values, error) = match parse_output(&output) {
+        Some((_, None)) | None if !status.success() => return Err(exit_error(status)),
+        Some(parsed) => parsed,
+        None => return Err(toolchain_error("the program produced malformed output")),
+    };
+
+    let mut environment = environment.borrow_mut();
+    for (index, value) in values {
+        environment.insert(&program.globals[index], Some(value));
+    }
+
+    match error {
+        Some((index, operands)) => Err(match (&program.errors[index], operands.as_slice()) {
+            (RuntimeError::IndexOutOfBounds(_, _, span), [index, length]) => {
+                RuntimeError::IndexOutOfBounds(*index as i32, *length as usize, span.clone())
+            }
+            (RuntimeError::CallDepthExceeded(_, span), _) => {
+                RuntimeError::CallDepthExceeded(limits.max_call_depth, span.clone())
+            }
+            (error, _) => error.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn toolchain_error(message: &str) -> RuntimeError {
+    RuntimeError::NativeExecutionFailed(message.to_string())
+}
+
+fn exit_error(status: ExitStatus) -> RuntimeError {
+    match status.signal() {
+        Some(signal) => toolchain_error(&format!("the program was killed by signal {}", signal)),
+        None => toolchain_error(&format!(
+            "the program exited with status {}",
+            status.code().unwrap_or_default()
+        )),
+    }
+}
+
+fn build_and_run(
+    program: &AssemblyProgram,
+    limits: &ExecutionLimits,
+    directory: &Path,
+) -> Result<(Vec<u8>, ExitStatus), RuntimeError> {
+    let source = directory.join("program.s");
+    let binary = directory.join("program");
+    fs::write(&source, &program.source).map_err(|error| toolchain_error(&error.to_string()))?;
+
+    // The depth is compared against an immediate, which is at most 32 bits wide.
+    let max_call_depth = limits.max_call_depth.min(i32::MAX as u64);
+    let assembled = Command::new("gcc")
+        .arg("-no-pie")
+        .arg(format!("-Wa,--defsym,max_call_depth={}", max_call_depth))
+        .arg("-o")
+        .arg(&binary)
+        .arg(&source)
+        .arg("-lm")
+        .output()
+        .map_err(|error| toolchain_error(&format!("could not run gcc: {}", error)))?;
+    if !assembled.status.success() {
+        return Err(toolchain_error(
+            String::from_utf8_lossy(&assembled.stderr).trim(),
+        ));
+    }
+
+    // The limits are set by the shell, which then replaces itself with the program.
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(format!(
+            "ulimit -s {} && ulimit -v {} && exec \"$0\"",
+            STACK_LIMIT_KB,
+            limits.max_memory_mb * 1024
+        ))
+        .arg(&binary)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|error| toolchain_error(&error.to_string()))?;
+
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = stdout.read_to_end(&mut output);
+        output
+    });
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() > limits.timeout() => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(RuntimeError::TimeLimitExceeded(limits.timeout_ms));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(1)),
+            Err(error) => return Err(toolchain_error(&error.to_string())),
+        }
+    };
+
+    Ok((reader.join().unwrap_or_default(), status))
+}
+
+type Output = (Vec<(usize, LiteralValue)>, Option<(usize, Vec<i64>)>);
+
+// Lines look like "<index> <type> <value>" and an optional final "error <site>", followed
+// by the index and the length for an index out of bounds. Strings are written as
+// "s <length> <bytes>" since they may contain newlines, arrays as "a <length>" followed by
+// the elements and structs as "t <name> <count>" followed by a name and a value per field.
+fn parse_output(output: &[u8]) -> Option<Output> {
+    let mut values = Vec::new();
+    let mut error = None;
+    let mut rest = output;
+
+    while !rest.is_empty() {
+        let (first, tail) = next_word(rest)?;
+        rest = tail;
+
+        if first == "error" {
+            let (site, tail) = next_word(rest)?;
+            rest = tail;
+            let mut operands = Vec::new();
+            while rest.first().is_some_and(|byte| *byte != b'\n') {
+                let (operand, tail) = next_word(rest)?;
+                operands.push(operand.parse().ok()?);
+                rest = tail;
+            }
+            error = Some((site.parse().ok()?, operands));
+            rest = skip_line(rest);
+            continue;
+        }
+
+        let index: usize = first.parse().ok()?;
+        let (value, tail) = parse_value(rest)?;
+        rest = skip_line(tail);
+        values.push((index, value));
+    }
+    Some((values, error))
+}
+
+fn parse_value(input: &[u8]) -> Option<(LiteralValue, &[u8])> {
+    let (kind, rest) = next_word(input)?;
+    if kind == "s" {
+        let (length, tail) = next_word(rest)?;
+        let length: usize = length.parse().ok()?;
+        let bytes = tail.get(..length)?;
+        let rest = match tail.get(length) {
+            Some(b' ') => &tail[length + 1..],
+            _ => &tail[length..],
+        };
+        return Some((
+            LiteralValue::String(String::from_utf8_lossy(bytes).to_string()),
+            rest,
+        ));
+    }
+
+    let (word, mut rest) = next_word(rest)?;
+    let value = match kind.as_str() {
+        "i" => LiteralValue::Integer(word.parse().ok()?),
+        "b" => LiteralValue::Boolean(word == "1"),
+        "f" => LiteralValue::Float(f64::from_bits(u64::from_str_radix(&word, 16).ok()?)),
+        "a" => {
+            let mut elements = Vec::new();
+            for _ in 0..word.parse::<usize>().ok()? {
+                let (element, tail) = parse_value(rest)?;
+                elements.push(element);
+                rest = tail;
+            }
+            LiteralValue::Array(elements)
+        }
+        "t" => {
+            let (count, tail) = next_word(rest)?;
+            rest = tail;
+            let mut fields = Vec::new();
+            for _ in 0..count.parse::<usize>().ok()? {
+                let (field, tail) = next_word(rest)?;
+                let (value, tail) = parse_value(tail)?;
+                fields.push((field, value));
+                rest = tail;
+            }
+            LiteralValue::Struct(word, fields)
+        }
+        _ => return None,
+    };
+    Some((value, rest))
+}
+
+// Returns the next space separated word and everything after the separator.
+fn next_word(input: &[u8]) -> Option<(String, &[u8])> {
+    let end = input
+        .iter()
+        .position(|byte| *byte == b' ' || *byte == b'\n')
+        .unwrap_or(input.len());
+    let word = String::from_utf8(input[..end].to_vec()).ok()?;
+    let rest = match input.get(end) {
+        Some(b' ') => &input[end + 1..],
+        _ => &input[end..],
+    };
+    Some((word, rest))
+}
+
+fn skip_line(input: &[u8]) -> &[u8] {
+    match input.first() {
+        Some(b'\n') => &input[1..],
+        _ => input,
+    }
+}
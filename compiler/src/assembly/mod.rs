@@ -0,0 +1,2 @@
+pub mod assembly_generator;
+pub mod driver;
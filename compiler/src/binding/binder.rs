@@ -1,14 +1,32 @@
-use crate::global_state::{get_key_from_symbol_table, insert_into_symbol_table};
+use crate::syntax_analyzer::array_literal_expression::ArrayLiteralExpressionSyntax;
+use crate::syntax_analyzer::break_statement::BreakStatement;
+use crate::syntax_analyzer::call_expression::CallExpressionSyntax;
+use crate::syntax_analyzer::conditional_expression::ConditionalExpressionSyntax;
 use crate::syntax_analyzer::constant_declaration::ConstantDeclaration;
+use crate::syntax_analyzer::continue_statement::ContinueStatement;
 use crate::syntax_analyzer::else_statement::ElseStatement;
+use crate::syntax_analyzer::expression_statement::ExpressionStatement;
+use crate::syntax_analyzer::field_assignment::FieldAssignment;
+use crate::syntax_analyzer::field_expression::FieldExpressionSyntax;
 use crate::syntax_analyzer::for_statement::ForStatement;
+use crate::syntax_analyzer::function_declaration::FunctionDeclaration;
 use crate::syntax_analyzer::if_statement::IfStatement;
+use crate::syntax_analyzer::increment_statement::IncrementStatement;
+use crate::syntax_analyzer::index_assignment::IndexAssignment;
+use crate::syntax_analyzer::index_expression::IndexExpressionSyntax;
 use crate::syntax_analyzer::name_expression::NameExpressionSyntax;
+use crate::syntax_analyzer::return_statement::ReturnStatement;
+use crate::syntax_analyzer::struct_declaration::StructDeclaration;
+use crate::syntax_analyzer::struct_literal_expression::StructLiteralExpressionSyntax;
+use crate::syntax_analyzer::type_clause::TypeClause;
 use crate::syntax_analyzer::variable_declaration::VariableDeclaration;
 use crate::syntax_analyzer::while_statement::WhileStatement;
-use crate::util::literals::LiteralType;
+use crate::util::function_symbol::FunctionSymbol;
+use crate::util::literals::{LiteralType, LiteralValue};
+use crate::util::struct_symbol::StructSymbol;
 use crate::util::syntax_token::SyntaxToken;
 use crate::{
+    environment::Environment,
     reports::{
         diagnostics::Diagnostics, text_place::TextPlace, text_span::TextSpan, text_type::TextType,
     },
@@ -26,12 +44,29 @@ use crate::{
 
 use std::{cell::RefCell, rc::Rc};
 
+use super::bound_array_literal_expression::BoundArrayLiteralExpression;
+use super::bound_break_statement::BoundBreakStatement;
+use super::bound_call_expression::BoundCallExpression;
+use super::bound_conditional_expression::BoundConditionalExpression;
 use super::bound_constant_declaration::BoundConstantDeclaration;
+use super::bound_continue_statement::BoundContinueStatement;
+use super::bound_expression_statement::BoundExpressionStatement;
+use super::bound_field_assignment::BoundFieldAssignment;
+use super::bound_field_expression::BoundFieldExpression;
 use super::bound_for_statement::BoundForStatement;
+use super::bound_function::BoundFunction;
 use super::bound_if_statement::BoundIfStatement;
+use super::bound_index_assignment::BoundIndexAssignment;
+use super::bound_index_expression::BoundIndexExpression;
+use super::bound_length_expression::BoundLengthExpression;
+use super::bound_return_statement::BoundReturnStatement;
 use super::bound_scope::BoundScope;
+use super::bound_struct_literal_expression::BoundStructLiteralExpression;
 use super::bound_variable_declaration::BoundVariableDeclaration;
+use super::bound_variable_expression::BoundVariableExpression;
 use super::bound_while_statement::BoundWhileStatement;
+use super::symbol_reference::{ReferencedSymbol, SymbolReference};
+use super::type_table::TypeTable;
 use super::{
     bound_assignment::BoundAssignment, bound_binary_expression::BoundBinaryExpression,
     bound_binary_operator::BoundBinaryOperator, bound_expression::BoundExpression,
@@ -40,16 +75,75 @@ use super::{
     bound_unary_operator::BoundUnaryOperator,
 };
 
+#[derive(Clone)]
 pub struct Binder {
     diagnostics: Rc<RefCell<Diagnostics>>,
+    environment: Rc<RefCell<Environment>>,
     scope: BoundScope,
+    types: TypeTable,
+    functions: Vec<BoundFunction>,
+    function: Option<FunctionSymbol>,
+    variable_count: usize,
+    struct_count: usize,
+    loop_depth: usize,
+    references: Vec<SymbolReference>,
 }
 
 impl Binder {
-    pub fn new(diagnostics: Rc<RefCell<Diagnostics>>) -> Self {
+    pub fn new(
+        diagnostics: Rc<RefCell<Diagnostics>>,
+        environment: Rc<RefCell<Environment>>,
+    ) -> Self {
         Self {
             diagnostics,
+            environment,
             scope: BoundScope::new(None),
+            types: TypeTable::new(),
+            functions: Vec::new(),
+            function: None,
+            variable_count: 0,
+            struct_count: 0,
+            loop_depth: 0,
+            references: Vec::new(),
+        }
+    }
+
+    pub fn get_functions(&self) -> Vec<BoundFunction> {
+        self.functions.clone()
+    }
+
+    pub fn get_types(&self) -> TypeTable {
+        self.types.clone()
+    }
+
+    pub fn get_references(&self) -> Vec<SymbolReference> {
+        self.references.clone()
+    }
+
+    // Binds REPL input in the program scope, which unlike a statement list stays open so
+    // that later input can use the variables and functions declared here.
+    pub fn bind_global_statements(
+        &mut self,
+        statements: Vec<Box<dyn Statement>>,
+    ) -> Box<dyn BoundStatement> {
+        self.enter_global_scope();
+
+        let statements = self.bind_statements(statements);
+
+        Box::new(BoundStatementList::new(statements)) as Box<dyn BoundStatement>
+    }
+
+    pub fn bind_global_expression(
+        &mut self,
+        expression: Box<dyn Expression>,
+    ) -> Box<dyn BoundExpression> {
+        self.enter_global_scope();
+        self.bind_expression(expression)
+    }
+
+    fn enter_global_scope(&mut self) {
+        if self.scope.get_parent().is_none() {
+            self.scope = BoundScope::new(Some(Rc::new(RefCell::new(self.scope.clone()))));
         }
     }
 
@@ -75,6 +169,24 @@ impl Binder {
             SyntaxKind::IfStatement => {
                 self.bind_if_statement(statement.as_any().downcast_ref::<IfStatement>().unwrap())
             }
+            SyntaxKind::IndexAssignment => self.bind_index_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<IndexAssignment>()
+                    .unwrap(),
+            ),
+            SyntaxKind::FieldAssignment => self.bind_field_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<FieldAssignment>()
+                    .unwrap(),
+            ),
+            SyntaxKind::IncrementStatement => self.bind_increment_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<IncrementStatement>()
+                    .unwrap(),
+            ),
             SyntaxKind::ElseStatement => self
                 .bind_else_statement(statement.as_any().downcast_ref::<ElseStatement>().unwrap()),
             SyntaxKind::WhileStatement => self
@@ -82,6 +194,38 @@ impl Binder {
             SyntaxKind::ForStatement => {
                 self.bind_for_statement(statement.as_any().downcast_ref::<ForStatement>().unwrap())
             }
+            SyntaxKind::FunctionDeclaration => self.bind_function_declaration(
+                statement
+                    .as_any()
+                    .downcast_ref::<FunctionDeclaration>()
+                    .unwrap(),
+            ),
+            SyntaxKind::StructDeclaration => self.bind_struct_declaration(
+                statement
+                    .as_any()
+                    .downcast_ref::<StructDeclaration>()
+                    .unwrap(),
+            ),
+            SyntaxKind::ReturnStatement => self.bind_return_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<ReturnStatement>()
+                    .unwrap(),
+            ),
+            SyntaxKind::BreakStatement => self
+                .bind_break_statement(statement.as_any().downcast_ref::<BreakStatement>().unwrap()),
+            SyntaxKind::ContinueStatement => self.bind_continue_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<ContinueStatement>()
+                    .unwrap(),
+            ),
+            SyntaxKind::ExpressionStatement => self.bind_expression_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<ExpressionStatement>()
+                    .unwrap(),
+            ),
             _ => panic!("Binding ERROR: Unexpected syntax kind for statement."),
         }
     }
@@ -89,12 +233,7 @@ impl Binder {
     fn bind_statement_list(&mut self, statement_list: &StatementList) -> Box<dyn BoundStatement> {
         self.scope = BoundScope::new(Some(Rc::new(RefCell::new(self.scope.clone()))));
 
-        let mut statements: Vec<Box<dyn BoundStatement>> = Vec::new();
-
-        for statement in statement_list.get_statements() {
-            let bound_statement = self.bind_statement(statement);
-            statements.push(bound_statement);
-        }
+        let statements = self.bind_statements(statement_list.get_statements());
 
         self.scope = self
             .scope
@@ -107,6 +246,28 @@ impl Binder {
         Box::new(BoundStatementList::new(statements)) as Box<dyn BoundStatement>
     }
 
+    // Structs and function signatures are declared before the statements of the block, in
+    // order, so a function can be called before its declaration and functions can call each
+    // other.
+    fn bind_statements(
+        &mut self,
+        statements: Vec<Box<dyn Statement>>,
+    ) -> Vec<Box<dyn BoundStatement>> {
+        for statement in &statements {
+            let any = statement.as_any();
+            if let Some(struct_declaration) = any.downcast_ref::<StructDeclaration>() {
+                self.declare_struct(struct_declaration);
+            } else if let Some(function_declaration) = any.downcast_ref::<FunctionDeclaration>() {
+                self.declare_function(function_declaration);
+            }
+        }
+
+        statements
+            .into_iter()
+            .map(|statement| self.bind_statement(statement))
+            .collect()
+    }
+
     fn bind_for_statement(&mut self, for_statement: &ForStatement) -> Box<dyn BoundStatement> {
         let lower_bound = self
             .bind_expression_and_check_type(for_statement.get_lower_bound(), LiteralType::Integer);
@@ -115,21 +276,22 @@ impl Binder {
 
         let token: &SyntaxToken = for_statement.get_identifier();
 
-        let key = get_key_from_symbol_table(token.name());
+        self.scope = BoundScope::new(Some(Rc::new(RefCell::new(self.scope.clone()))));
 
-        self.check_if_variable_is_already_declared(
-            &key,
-            token.name(),
-            token.position(),
-            token.length(),
-        );
+        let variable = self.create_variable(token.name(), LiteralType::Integer, false, false);
 
-        let variable = VariableSymbol::new(token.name(), LiteralType::Integer, false, false);
+        self.declare_variable(&variable);
+        self.record_reference(&variable, token, true);
 
-        insert_into_symbol_table(&variable, None);
-        self.add_variable_to_scope(&variable);
+        let body = self.bind_loop_body(for_statement.get_body());
 
-        let body = self.bind_statement(for_statement.get_body());
+        self.scope = self
+            .scope
+            .get_parent()
+            .as_mut()
+            .unwrap()
+            .borrow()
+            .to_owned();
 
         Box::new(BoundForStatement::new(
             variable,
@@ -145,11 +307,45 @@ impl Binder {
     ) -> Box<dyn BoundStatement> {
         let condition = self
             .bind_expression_and_check_type(while_statement.get_condition(), LiteralType::Boolean);
-        let body = self.bind_statement(while_statement.get_body());
+        let body = self.bind_loop_body(while_statement.get_body());
 
         Box::new(BoundWhileStatement::new(condition, body)) as Box<dyn BoundStatement>
     }
 
+    fn bind_loop_body(&mut self, body: Box<dyn Statement>) -> Box<dyn BoundStatement> {
+        self.loop_depth += 1;
+        let body = self.bind_statement(body);
+        self.loop_depth -= 1;
+        body
+    }
+
+    fn bind_break_statement(
+        &mut self,
+        break_statement: &BreakStatement,
+    ) -> Box<dyn BoundStatement> {
+        self.check_inside_loop("Break", break_statement.get_break_token());
+        Box::new(BoundBreakStatement) as Box<dyn BoundStatement>
+    }
+
+    fn bind_continue_statement(
+        &mut self,
+        continue_statement: &ContinueStatement,
+    ) -> Box<dyn BoundStatement> {
+        self.check_inside_loop("Continue", continue_statement.get_continue_token());
+        Box::new(BoundContinueStatement) as Box<dyn BoundStatement>
+    }
+
+    fn check_inside_loop(&mut self, statement: &str, token: &SyntaxToken) {
+        if self.loop_depth == 0 {
+            self.diagnostics.borrow_mut().report_outside_loop(
+                statement,
+                TextSpan::new(token.position(), token.length()),
+                TextPlace::Semantic,
+                TextType::Error,
+            );
+        }
+    }
+
     fn bind_if_statement(&mut self, if_statement: &IfStatement) -> Box<dyn BoundStatement> {
         let condition =
             self.bind_expression_and_check_type(if_statement.get_condition(), LiteralType::Boolean);
@@ -177,33 +373,15 @@ impl Binder {
     ) -> Box<dyn BoundStatement> {
         let token = constant_declaration.get_variable();
 
-        let key = get_key_from_symbol_table(token.name());
-
-        self.check_if_variable_is_already_declared(
-            &key,
-            token.name(),
-            token.position(),
-            token.length(),
-        );
+        self.check_if_variable_is_already_declared(token.name(), token.position(), token.length());
 
         let expr = self.bind_expression(constant_declaration.get_expression());
 
-        let variable = VariableSymbol::new(
-            token.name(),
-            *expr.get_type(),
-            true,
-            self.scope
-                .get_parent()
-                .as_mut()
-                .unwrap()
-                .borrow()
-                .to_owned()
-                .get_parent()
-                .is_none(),
-        );
+        let is_global = self.is_global_scope();
+        let variable = self.create_variable(token.name(), expr.get_type().clone(), true, is_global);
 
-        insert_into_symbol_table(&variable, None);
-        self.add_variable_to_scope(&variable);
+        self.declare_variable(&variable);
+        self.record_reference(&variable, token, true);
 
         Box::new(BoundConstantDeclaration::new(variable, expr))
     }
@@ -214,44 +392,137 @@ impl Binder {
     ) -> Box<dyn BoundStatement> {
         let token = variable_declaration.get_variable();
 
-        let key = get_key_from_symbol_table(token.name());
-
-        self.check_if_variable_is_already_declared(
-            &key,
-            token.name(),
-            token.position(),
-            token.length(),
-        );
+        self.check_if_variable_is_already_declared(token.name(), token.position(), token.length());
 
         let expr = self.bind_expression(variable_declaration.get_expression());
 
-        let variable = VariableSymbol::new(
-            token.name(),
-            *expr.get_type(),
-            false,
-            self.scope
-                .get_parent()
-                .as_mut()
-                .unwrap()
-                .borrow()
-                .to_owned()
-                .get_parent()
-                .is_none(),
-        );
+        let is_global = self.is_global_scope();
+        let variable =
+            self.create_variable(token.name(), expr.get_type().clone(), false, is_global);
 
-        insert_into_symbol_table(&variable, None);
-        self.add_variable_to_scope(&variable);
+        self.declare_variable(&variable);
+        self.record_reference(&variable, token, true);
 
         Box::new(BoundVariableDeclaration::new(variable, expr))
     }
 
     fn bind_assignment(&mut self, assignment: &Assignment) -> Box<dyn BoundStatement> {
-        let token = assignment.get_variable();
+        let expr = self.bind_expression(assignment.get_expression());
+        self.bind_assignment_to(assignment.get_variable(), assignment.get_equals(), expr)
+    }
+
+    // `x++` is bound as `x += 1`.
+    fn bind_increment_statement(
+        &mut self,
+        increment_statement: &IncrementStatement,
+    ) -> Box<dyn BoundStatement> {
+        let one = Box::new(BoundLiteralExpression::new(
+            LiteralValue::Integer(1),
+            LiteralType::Integer,
+        )) as Box<dyn BoundExpression>;
+        self.bind_assignment_to(
+            increment_statement.get_variable(),
+            increment_statement.get_operator(),
+            one,
+        )
+    }
+
+    // A compound operator combines the current value with the expression, after the
+    // variable is known to be writable.
+    fn bind_assignment_to(
+        &mut self,
+        token: &SyntaxToken,
+        operator: &SyntaxToken,
+        mut expr: Box<dyn BoundExpression>,
+    ) -> Box<dyn BoundStatement> {
+        let variable = match self.lookup_variable(token) {
+            None => {
+                self.diagnostics.borrow_mut().report_variable_not_declared(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                let is_global = self.is_global_scope();
+                let variable =
+                    self.create_variable(token.name(), expr.get_type().clone(), false, is_global);
+                self.declare_variable(&variable);
+                variable
+            }
+            Some(variable) => {
+                self.record_reference(&variable, token, false);
+                if variable.is_read_only() {
+                    self.diagnostics.borrow_mut().report_constant_redefined(
+                        token.name(),
+                        TextSpan::new(token.position(), token.length()),
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+                } else {
+                    if let Some(binary_operator) = operator.kind().get_compound_operator() {
+                        let current = Box::new(BoundVariableExpression::new(
+                            variable.clone(),
+                            TextSpan::new(token.position(), token.length()),
+                        )) as Box<dyn BoundExpression>;
+                        expr = self.bind_binary_operator(
+                            current,
+                            binary_operator,
+                            expr,
+                            TextSpan::new(operator.position(), operator.length()),
+                        );
+                    }
+                    if variable.get_type() != expr.get_type().clone() {
+                        self.diagnostics.borrow_mut().report_invalid_literal_type(
+                            expr.get_type().clone(),
+                            variable.get_type(),
+                            TextSpan::new(token.position(), token.length()),
+                            TextPlace::Semantic,
+                            TextType::Error,
+                        );
+                    }
+                }
+                variable
+            }
+        };
+
+        Box::new(BoundAssignment::new(variable, expr))
+    }
 
-        let key = get_key_from_symbol_table(token.name());
+    // A compound operator reads the element through the same index, so the index
+    // expression is evaluated twice.
+    fn bind_index_assignment(
+        &mut self,
+        index_assignment: &IndexAssignment,
+    ) -> Box<dyn BoundStatement> {
+        let token = index_assignment.get_variable();
+        let operator = index_assignment.get_equals();
+        let open_bracket = index_assignment.get_open_bracket();
+        let span = TextSpan::new(open_bracket.position(), open_bracket.length());
+
+        let index =
+            self.bind_expression_and_check_type(index_assignment.get_index(), LiteralType::Integer);
+        let mut expr = self.bind_expression(index_assignment.get_expression());
+
+        let variable = match self.lookup_variable(token) {
+            Some(variable) => variable,
+            None => {
+                self.diagnostics.borrow_mut().report_variable_not_declared(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                let is_global = self.is_global_scope();
+                let array_type = LiteralType::Array(Box::new(expr.get_type().clone()));
+                let variable = self.create_variable(token.name(), array_type, false, is_global);
+                self.declare_variable(&variable);
+                return Box::new(BoundIndexAssignment::new(variable, index, expr, span));
+            }
+        };
 
-        if key.is_none() || (key.is_some() && !self.check_scope_of_variable(&key.unwrap())) {
-            self.diagnostics.borrow_mut().report_variable_not_declared(
+        self.record_reference(&variable, token, false);
+        if variable.is_read_only() {
+            self.diagnostics.borrow_mut().report_constant_redefined(
                 token.name(),
                 TextSpan::new(token.position(), token.length()),
                 TextPlace::Semantic,
@@ -259,12 +530,80 @@ impl Binder {
             );
         }
 
-        let key2 = get_key_from_symbol_table(token.name());
+        let element_type = self.bind_element_type(
+            variable.get_type(),
+            TextSpan::new(token.position(), token.length()),
+        );
+        let mut index = index;
+        let mut temporary = None;
+        if let Some(binary_operator) = operator.kind().get_compound_operator() {
+            // The index is kept in a hidden local, so it is evaluated once for the read and the store.
+            let slot = self.create_variable(String::new(), index.get_type().clone(), true, false);
+            temporary = Some(BoundVariableDeclaration::new(slot.clone(), index));
+            index = Box::new(BoundVariableExpression::new(slot, span.clone()));
+
+            let current = Box::new(BoundIndexExpression::new(
+                Box::new(BoundVariableExpression::new(
+                    variable.clone(),
+                    TextSpan::new(token.position(), token.length()),
+                )),
+                index.clone(),
+                element_type.clone(),
+                span.clone(),
+            )) as Box<dyn BoundExpression>;
+            expr = self.bind_binary_operator(
+                current,
+                binary_operator,
+                expr,
+                TextSpan::new(operator.position(), operator.length()),
+            );
+        }
+        if *expr.get_type() != element_type {
+            self.diagnostics.borrow_mut().report_invalid_literal_type(
+                expr.get_type().clone(),
+                element_type,
+                TextSpan::new(token.position(), token.length()),
+                TextPlace::Semantic,
+                TextType::Error,
+            );
+        }
+
+        let assignment = Box::new(BoundIndexAssignment::new(variable, index, expr, span));
+        match temporary {
+            Some(declaration) => Box::new(BoundStatementList::new(vec![
+                Box::new(declaration) as Box<dyn BoundStatement>,
+                assignment,
+            ])),
+            None => assignment,
+        }
+    }
 
-        if key2.is_some()
-            && key2.clone().unwrap().is_read_only()
-            && self.check_scope_of_variable(&key2.unwrap())
-        {
+    // A compound operator reads the field through the variable, like an index assignment.
+    fn bind_field_assignment(
+        &mut self,
+        field_assignment: &FieldAssignment,
+    ) -> Box<dyn BoundStatement> {
+        let token = field_assignment.get_variable();
+        let field = field_assignment.get_field();
+        let operator = field_assignment.get_equals();
+
+        let mut expr = self.bind_expression(field_assignment.get_expression());
+
+        let variable = match self.lookup_variable(token) {
+            Some(variable) => variable,
+            None => {
+                self.diagnostics.borrow_mut().report_variable_not_declared(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                return Box::new(BoundStatementList::new(Vec::new())) as Box<dyn BoundStatement>;
+            }
+        };
+
+        self.record_reference(&variable, token, false);
+        if variable.is_read_only() {
             self.diagnostics.borrow_mut().report_constant_redefined(
                 token.name(),
                 TextSpan::new(token.position(), token.length()),
@@ -273,35 +612,332 @@ impl Binder {
             );
         }
 
-        let expr = self.bind_expression(assignment.get_expression());
+        let (index, field_type) = self.bind_field(variable.get_type(), field);
+        if let Some(binary_operator) = operator.kind().get_compound_operator() {
+            let current = Box::new(BoundFieldExpression::new(
+                Box::new(BoundVariableExpression::new(
+                    variable.clone(),
+                    TextSpan::new(token.position(), token.length()),
+                )),
+                index,
+                field_type.clone(),
+            )) as Box<dyn BoundExpression>;
+            expr = self.bind_binary_operator(
+                current,
+                binary_operator,
+                expr,
+                TextSpan::new(operator.position(), operator.length()),
+            );
+        }
+        if *expr.get_type() != field_type {
+            self.diagnostics.borrow_mut().report_invalid_literal_type(
+                expr.get_type().clone(),
+                field_type,
+                TextSpan::new(field.position(), field.length()),
+                TextPlace::Semantic,
+                TextType::Error,
+            );
+        }
 
-        let variable = VariableSymbol::new(
-            token.name(),
-            *expr.get_type(),
-            false,
-            self.scope
+        Box::new(BoundFieldAssignment::new(
+            variable,
+            index,
+            expr,
+            TextSpan::new(field.position(), field.length()),
+        ))
+    }
+
+    // A struct declaration produces no code of its own, the struct was already declared with
+    // the other declarations of its block.
+    fn bind_struct_declaration(
+        &mut self,
+        _struct_declaration: &StructDeclaration,
+    ) -> Box<dyn BoundStatement> {
+        Box::new(BoundStatementList::new(Vec::new())) as Box<dyn BoundStatement>
+    }
+
+    // The struct is only declared after its fields, so it cannot contain itself.
+    fn declare_struct(&mut self, struct_declaration: &StructDeclaration) {
+        let token = struct_declaration.get_identifier();
+
+        let mut fields: Vec<(String, LiteralType)> = Vec::new();
+        for field in struct_declaration.get_fields() {
+            let identifier = field.get_identifier();
+            if fields.iter().any(|(name, _)| *name == identifier.name()) {
+                self.diagnostics.borrow_mut().report_field_already_declared(
+                    identifier.name(),
+                    TextSpan::new(identifier.position(), identifier.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                continue;
+            }
+            let field_type = self.bind_type_clause(field.get_type_clause());
+            fields.push((identifier.name(), field_type));
+        }
+
+        if self
+            .scope
+            .structs
+            .iter()
+            .any(|symbol| symbol.name() == token.name())
+        {
+            self.diagnostics
+                .borrow_mut()
+                .report_struct_already_declared(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+            return;
+        }
+
+        self.struct_count += 1;
+        let id = match self.types.lookup(&token.name()) {
+            Some(_) => format!("{}#{}", token.name(), self.struct_count),
+            None => token.name(),
+        };
+        let symbol = StructSymbol::new(id, token.name(), fields);
+        self.types.declare(symbol.clone());
+        self.scope.structs.push(symbol);
+    }
+
+    fn bind_type_clause(&mut self, type_clause: &TypeClause) -> LiteralType {
+        let token = type_clause.get_type_name();
+        let mut literal_type = match token.name().as_str() {
+            "int" => LiteralType::Integer,
+            "float" => LiteralType::Float,
+            "bool" => LiteralType::Boolean,
+            "string" => LiteralType::String,
+            name => match self.lookup_struct(name) {
+                Some(symbol) => LiteralType::Struct(symbol.id()),
+                None => {
+                    self.diagnostics.borrow_mut().report_undefined_type(
+                        token.name(),
+                        TextSpan::new(token.position(), token.length()),
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+                    LiteralType::Integer
+                }
+            },
+        };
+        for _ in 0..type_clause.get_depth() {
+            literal_type = LiteralType::Array(Box::new(literal_type));
+        }
+        literal_type
+    }
+
+    // A name that is already taken is reported here, and the declaration is bound later as a
+    // function that cannot be called.
+    fn declare_function(&mut self, function_declaration: &FunctionDeclaration) {
+        let token = function_declaration.get_identifier();
+
+        if self.lookup_function(&token.name()).is_some() {
+            self.diagnostics
+                .borrow_mut()
+                .report_variable_already_declared(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+            return;
+        }
+
+        self.scope
+            .functions
+            .push(FunctionSymbol::new(token.name(), self.functions.len()));
+        let function = self.bind_function_signature(function_declaration);
+        self.functions.push(function);
+    }
+
+    fn bind_function_signature(
+        &mut self,
+        function_declaration: &FunctionDeclaration,
+    ) -> BoundFunction {
+        let parameters = function_declaration
+            .get_parameters()
+            .iter()
+            .map(|parameter| {
+                let parameter_type = match parameter.get_type_clause() {
+                    Some(type_clause) => self.bind_type_clause(type_clause),
+                    None => LiteralType::Integer,
+                };
+                self.create_variable(
+                    parameter.get_identifier().name(),
+                    parameter_type,
+                    false,
+                    false,
+                )
+            })
+            .collect();
+        let return_type = match function_declaration.get_return_type() {
+            Some(type_clause) => Some(self.bind_type_clause(type_clause)),
+            None if returns_value(function_declaration.get_body().as_ref()) => {
+                Some(LiteralType::Integer)
+            }
+            None => None,
+        };
+
+        BoundFunction::new(parameters, return_type)
+    }
+
+    // The body is bound once, where the function is declared, whether it is called or not.
+    fn bind_function_declaration(
+        &mut self,
+        function_declaration: &FunctionDeclaration,
+    ) -> Box<dyn BoundStatement> {
+        let token = function_declaration.get_identifier();
+
+        let declared = self
+            .scope
+            .functions
+            .iter()
+            .find(|function| function.id() == token.name())
+            .map(|function| function.index());
+        let index = match declared {
+            Some(index) if self.functions[index].get_body().is_none() => index,
+            _ => {
+                let function = self.bind_function_signature(function_declaration);
+                self.functions.push(function);
+                self.functions.len() - 1
+            }
+        };
+
+        let function = FunctionSymbol::new(token.name(), index);
+        self.record_function_reference(function.clone(), token, true);
+
+        let caller_scope = self.scope.clone();
+        self.scope = BoundScope::new(Some(Rc::new(RefCell::new(self.scope.clone()))));
+        self.scope.is_function = true;
+        let caller_function = self.function.replace(function);
+        // A function declared inside a loop is not part of that loop.
+        let caller_loop_depth = std::mem::take(&mut self.loop_depth);
+
+        let parameters = self.functions[index].get_parameters().clone();
+        for (variable, parameter) in parameters.iter().zip(function_declaration.get_parameters()) {
+            let token = parameter.get_identifier();
+            self.check_if_variable_is_already_declared(
+                token.name(),
+                token.position(),
+                token.length(),
+            );
+            self.declare_variable(variable);
+            self.record_reference(variable, token, true);
+        }
+
+        let body = self.bind_statement(function_declaration.get_body());
+        self.functions[index].set_body(body);
+
+        self.scope = caller_scope;
+        self.function = caller_function;
+        self.loop_depth = caller_loop_depth;
+
+        Box::new(BoundStatementList::new(Vec::new())) as Box<dyn BoundStatement>
+    }
+
+    fn bind_return_statement(
+        &mut self,
+        return_statement: &ReturnStatement,
+    ) -> Box<dyn BoundStatement> {
+        let expr = self.bind_expression(return_statement.get_expression());
+
+        match &self.function {
+            None => {
+                let token = return_statement.get_return_token();
+                self.diagnostics
+                    .borrow_mut()
+                    .report_return_outside_function(
+                        TextSpan::new(token.position(), token.length()),
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+            }
+            Some(function) => match self.functions[function.index()].get_return_type() {
+                Some(return_type) if return_type != expr.get_type().clone() => {
+                    let token = return_statement.get_return_token();
+                    self.diagnostics.borrow_mut().report_mismatched_return_type(
+                        function.id(),
+                        return_type,
+                        expr.get_type().clone(),
+                        TextSpan::new(token.position(), token.length()),
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+                }
+                _ => {}
+            },
+        }
+
+        Box::new(BoundReturnStatement::new(expr)) as Box<dyn BoundStatement>
+    }
+
+    fn bind_expression_statement(
+        &mut self,
+        expression_statement: &ExpressionStatement,
+    ) -> Box<dyn BoundStatement> {
+        let expression = expression_statement.get_expression();
+
+        let expr = match expression.as_any().downcast_ref::<CallExpressionSyntax>() {
+            Some(call_expression) => self.bind_call_expression(call_expression, false),
+            None => self.bind_expression(expression),
+        };
+
+        Box::new(BoundExpressionStatement::new(expr)) as Box<dyn BoundStatement>
+    }
+
+    fn is_global_scope(&mut self) -> bool {
+        self.function.is_none()
+            && self
+                .scope
                 .get_parent()
                 .as_mut()
                 .unwrap()
                 .borrow()
                 .to_owned()
                 .get_parent()
-                .is_none(),
-        );
+                .is_none()
+    }
 
-        insert_into_symbol_table(&variable, None);
-        self.add_variable_to_scope(&variable);
+    // A function frame only holds the parameters and locals of that function, so besides
+    // those only globals can be used in its body. The variable is still returned after the
+    // error to avoid a second one about an undeclared name.
+    fn lookup_variable(&mut self, token: &SyntaxToken) -> Option<VariableSymbol> {
+        let mut local_scope = self.scope.clone();
+        let mut outside_function = false;
 
-        Box::new(BoundAssignment::new(variable, expr))
+        loop {
+            for variable in local_scope.variables.iter().rev() {
+                if variable.id() == token.name() {
+                    if outside_function && !variable.is_global() {
+                        self.diagnostics.borrow_mut().report_captured_variable(
+                            token.name(),
+                            TextSpan::new(token.position(), token.length()),
+                            TextPlace::Semantic,
+                            TextType::Error,
+                        );
+                    }
+                    return Some(variable.clone());
+                }
+            }
+            outside_function |= local_scope.is_function;
+            if local_scope.get_parent().is_none() {
+                break;
+            }
+            local_scope = local_scope.get_parent().unwrap().borrow().to_owned()
+        }
+        None
     }
 
-    fn check_scope_of_variable(&self, v: &VariableSymbol) -> bool {
+    fn lookup_function(&self, name: &str) -> Option<FunctionSymbol> {
         let mut local_scope = self.scope.clone();
 
         loop {
-            for variable in &local_scope.variables {
-                if variable.id() == v.id() {
-                    return true;
+            for function in &local_scope.functions {
+                if function.id() == name {
+                    return Some(function.clone());
                 }
             }
             if local_scope.get_parent().is_none() {
@@ -309,46 +945,108 @@ impl Binder {
             }
             local_scope = local_scope.get_parent().unwrap().borrow().to_owned()
         }
-        return false;
+        None
     }
 
-    fn add_variable_to_scope(&mut self, v: &VariableSymbol) {
+    fn lookup_struct(&self, name: &str) -> Option<StructSymbol> {
+        let mut local_scope = self.scope.clone();
+
+        loop {
+            for symbol in local_scope.structs.iter().rev() {
+                if symbol.name() == name {
+                    return Some(symbol.clone());
+                }
+            }
+            if local_scope.get_parent().is_none() {
+                break;
+            }
+            local_scope = local_scope.get_parent().unwrap().borrow().to_owned()
+        }
+        None
+    }
+
+    fn create_variable(
+        &mut self,
+        name: String,
+        kind: LiteralType,
+        read_only: bool,
+        is_global: bool,
+    ) -> VariableSymbol {
+        self.variable_count += 1;
+        VariableSymbol::new(name, kind, read_only, is_global, self.variable_count)
+    }
+
+    fn declare_variable(&mut self, v: &VariableSymbol) {
+        // Locals live in runtime frames, so only program variables are reported.
+        if v.is_global() {
+            self.environment.borrow_mut().insert(v, None);
+        }
         self.scope.variables.push(v.clone());
     }
 
+    fn record_reference(
+        &mut self,
+        variable: &VariableSymbol,
+        token: &SyntaxToken,
+        is_declaration: bool,
+    ) {
+        self.references.push(SymbolReference::new(
+            ReferencedSymbol::Variable(variable.clone()),
+            TextSpan::new(token.position(), token.length()),
+            is_declaration,
+        ));
+    }
+
+    fn record_function_reference(
+        &mut self,
+        function: FunctionSymbol,
+        token: &SyntaxToken,
+        is_declaration: bool,
+    ) {
+        self.references.push(SymbolReference::new(
+            ReferencedSymbol::Function(function),
+            TextSpan::new(token.position(), token.length()),
+            is_declaration,
+        ));
+    }
+
     fn check_if_variable_is_already_declared(
         &mut self,
-        key: &Option<VariableSymbol>,
         name: String,
         position: usize,
         length: usize,
     ) {
-        if let Some(key) = key {
-            if self.check_scope_of_variable(&key) {
-                self.diagnostics
-                    .borrow_mut()
-                    .report_variable_already_declared(
-                        name,
-                        TextSpan::new(position, length),
-                        TextPlace::Semantic,
-                        TextType::Error,
-                    );
-            }
+        // Inner blocks may shadow outer names, only the current scope is checked.
+        if self
+            .scope
+            .variables
+            .iter()
+            .any(|variable| variable.id() == name)
+        {
+            self.diagnostics
+                .borrow_mut()
+                .report_variable_already_declared(
+                    name,
+                    TextSpan::new(position, length),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
         }
     }
 
     fn bind_expression_and_check_type(
-        &self,
+        &mut self,
         expression: Box<dyn Expression>,
         target_type: LiteralType,
     ) -> Box<dyn BoundExpression> {
+        let span = expression_span(expression.as_ref());
         let result = self.bind_expression(expression);
 
-        if *result.get_type() != target_type {
+        if result.get_type().clone() != target_type {
             self.diagnostics.borrow_mut().report_invalid_literal_type(
-                *result.get_type(),
+                result.get_type().clone(),
                 target_type,
-                TextSpan::new(0, 2),
+                span,
                 TextPlace::Semantic,
                 TextType::Error,
             );
@@ -357,7 +1055,7 @@ impl Binder {
         result
     }
 
-    fn bind_expression(&self, expression: Box<dyn Expression>) -> Box<dyn BoundExpression> {
+    fn bind_expression(&mut self, expression: Box<dyn Expression>) -> Box<dyn BoundExpression> {
         match *expression.get_kind() {
             SyntaxKind::NameExpression => self.bind_name_expression(
                 expression
@@ -389,33 +1087,350 @@ impl Binder {
                     .downcast_ref::<ParenthesizedExpressionSyntax>()
                     .unwrap(),
             ),
+            SyntaxKind::CallExpression => self.bind_call_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<CallExpressionSyntax>()
+                    .unwrap(),
+                true,
+            ),
+            SyntaxKind::ConditionalExpression => self.bind_conditional_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<ConditionalExpressionSyntax>()
+                    .unwrap(),
+            ),
+            SyntaxKind::ArrayLiteralExpression => self.bind_array_literal_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<ArrayLiteralExpressionSyntax>()
+                    .unwrap(),
+            ),
+            SyntaxKind::IndexExpression => self.bind_index_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<IndexExpressionSyntax>()
+                    .unwrap(),
+            ),
+            SyntaxKind::StructLiteralExpression => self.bind_struct_literal_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<StructLiteralExpressionSyntax>()
+                    .unwrap(),
+            ),
+            SyntaxKind::FieldExpression => self.bind_field_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<FieldExpressionSyntax>()
+                    .unwrap(),
+            ),
             _ => panic!("Binding ERROR: Unexpected syntax kind for expression."),
         }
     }
 
+    // The first element fixes the element type, so `[]` has nothing to infer it from.
+    fn bind_array_literal_expression(
+        &mut self,
+        array_literal_expression: &ArrayLiteralExpressionSyntax,
+    ) -> Box<dyn BoundExpression> {
+        let open_bracket = array_literal_expression.get_open_bracket();
+        let span = TextSpan::new(open_bracket.position(), open_bracket.length());
+
+        let elements: Vec<Box<dyn BoundExpression>> = array_literal_expression
+            .get_elements()
+            .into_iter()
+            .map(|element| self.bind_expression(element))
+            .collect();
+
+        let element_type = match elements.first() {
+            Some(first) => first.get_type().clone(),
+            None => {
+                self.diagnostics.borrow_mut().report_empty_array(
+                    span.clone(),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                LiteralType::Integer
+            }
+        };
+
+        for element in elements.iter().skip(1) {
+            if *element.get_type() != element_type {
+                self.diagnostics
+                    .borrow_mut()
+                    .report_mismatched_element_types(
+                        element_type.clone(),
+                        element.get_type().clone(),
+                        span.clone(),
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+            }
+        }
+
+        Box::new(BoundArrayLiteralExpression::new(
+            elements,
+            LiteralType::Array(Box::new(element_type)),
+        )) as Box<dyn BoundExpression>
+    }
+
+    fn bind_index_expression(
+        &mut self,
+        index_expression: &IndexExpressionSyntax,
+    ) -> Box<dyn BoundExpression> {
+        let open_bracket = index_expression.get_open_bracket();
+        let span = TextSpan::new(open_bracket.position(), open_bracket.length());
+
+        let array = self.bind_expression(index_expression.get_array());
+        let index =
+            self.bind_expression_and_check_type(index_expression.get_index(), LiteralType::Integer);
+        let element_type = self.bind_element_type(array.get_type().clone(), span.clone());
+
+        Box::new(BoundIndexExpression::new(array, index, element_type, span))
+            as Box<dyn BoundExpression>
+    }
+
+    // Anything that is not an array is reported, and binding goes on as if it held integers.
+    fn bind_element_type(&mut self, array_type: LiteralType, span: TextSpan) -> LiteralType {
+        match array_type.element_type() {
+            Some(element_type) => element_type,
+            None => {
+                self.diagnostics.borrow_mut().report_not_an_array(
+                    array_type,
+                    span,
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                LiteralType::Integer
+            }
+        }
+    }
+
+    fn bind_struct_literal_expression(
+        &mut self,
+        struct_literal_expression: &StructLiteralExpressionSyntax,
+    ) -> Box<dyn BoundExpression> {
+        let token = struct_literal_expression.get_identifier();
+        let span = TextSpan::new(token.position(), token.length());
+
+        let initializers: Vec<(SyntaxToken, Box<dyn BoundExpression>)> = struct_literal_expression
+            .get_fields()
+            .into_iter()
+            .map(|field| {
+                let expr = self.bind_expression(field.get_expression());
+                (field.get_identifier().clone(), expr)
+            })
+            .collect();
+
+        let symbol = match self.lookup_struct(&token.name()) {
+            Some(symbol) => symbol,
+            None => {
+                self.diagnostics.borrow_mut().report_undefined_type(
+                    token.name(),
+                    span,
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                let value = token.get_value();
+                return Box::new(BoundLiteralExpression::new(value.clone(), value.get_type()))
+                    as Box<dyn BoundExpression>;
+            }
+        };
+
+        let mut fields: Vec<Option<Box<dyn BoundExpression>>> =
+            symbol.get_fields().iter().map(|_| None).collect();
+        for (identifier, expr) in initializers {
+            let field_span = TextSpan::new(identifier.position(), identifier.length());
+            let (index, field_type) = match symbol.get_field(&identifier.name()) {
+                Some(field) => field,
+                None => {
+                    self.diagnostics.borrow_mut().report_undefined_field(
+                        symbol.name(),
+                        identifier.name(),
+                        field_span,
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+                    continue;
+                }
+            };
+            if fields[index].is_some() {
+                self.diagnostics
+                    .borrow_mut()
+                    .report_field_initialized_twice(
+                        identifier.name(),
+                        field_span,
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+                continue;
+            }
+            if *expr.get_type() != field_type {
+                self.diagnostics.borrow_mut().report_invalid_literal_type(
+                    expr.get_type().clone(),
+                    field_type,
+                    field_span,
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+            }
+            fields[index] = Some(expr);
+        }
+
+        let mut field_names = Vec::new();
+        let mut bound_fields = Vec::new();
+        for ((name, field_type), field) in symbol.get_fields().into_iter().zip(fields) {
+            let field = field.unwrap_or_else(|| {
+                self.diagnostics.borrow_mut().report_missing_field(
+                    symbol.name(),
+                    name.clone(),
+                    span.clone(),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                Box::new(BoundLiteralExpression::new(
+                    LiteralValue::Integer(0),
+                    field_type,
+                ))
+            });
+            field_names.push(name);
+            bound_fields.push(field);
+        }
+
+        Box::new(BoundStructLiteralExpression::new(
+            symbol.id(),
+            field_names,
+            bound_fields,
+        )) as Box<dyn BoundExpression>
+    }
+
+    fn bind_field_expression(
+        &mut self,
+        field_expression: &FieldExpressionSyntax,
+    ) -> Box<dyn BoundExpression> {
+        let structure = self.bind_expression(field_expression.get_structure());
+        let (index, field_type) =
+            self.bind_field(structure.get_type().clone(), field_expression.get_field());
+
+        Box::new(BoundFieldExpression::new(structure, index, field_type))
+            as Box<dyn BoundExpression>
+    }
+
+    // Like a bad index, a bad field is reported and binding goes on as if it held an integer.
+    fn bind_field(
+        &mut self,
+        struct_type: LiteralType,
+        field: &SyntaxToken,
+    ) -> (usize, LiteralType) {
+        let span = TextSpan::new(field.position(), field.length());
+        let symbol = match &struct_type {
+            LiteralType::Struct(name) => self.types.lookup(name),
+            _ => None,
+        };
+
+        match symbol {
+            None => {
+                self.diagnostics.borrow_mut().report_not_a_struct(
+                    struct_type,
+                    span,
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                (0, LiteralType::Integer)
+            }
+            Some(symbol) => match symbol.get_field(&field.name()) {
+                Some(field) => field,
+                None => {
+                    self.diagnostics.borrow_mut().report_undefined_field(
+                        symbol.name(),
+                        field.name(),
+                        span,
+                        TextPlace::Semantic,
+                        TextType::Error,
+                    );
+                    (0, LiteralType::Integer)
+                }
+            },
+        }
+    }
+
+    // `len` is a builtin, unless the program declares a function with the same name.
+    fn bind_length_expression(
+        &mut self,
+        token: &SyntaxToken,
+        mut arguments: Vec<Box<dyn BoundExpression>>,
+    ) -> Box<dyn BoundExpression> {
+        let span = TextSpan::new(token.position(), token.length());
+
+        if arguments.len() != 1 {
+            self.diagnostics.borrow_mut().report_wrong_argument_count(
+                token.name(),
+                1,
+                arguments.len(),
+                span,
+                TextPlace::Semantic,
+                TextType::Error,
+            );
+            return Box::new(BoundLiteralExpression::new(
+                LiteralValue::Integer(0),
+                LiteralType::Integer,
+            )) as Box<dyn BoundExpression>;
+        }
+
+        let array = arguments.remove(0);
+        self.bind_element_type(array.get_type().clone(), span);
+        Box::new(BoundLengthExpression::new(array)) as Box<dyn BoundExpression>
+    }
+
+    fn bind_conditional_expression(
+        &mut self,
+        conditional_expression: &ConditionalExpressionSyntax,
+    ) -> Box<dyn BoundExpression> {
+        let condition = self.bind_expression_and_check_type(
+            conditional_expression.get_condition(),
+            LiteralType::Boolean,
+        );
+        let when_true = self.bind_expression(conditional_expression.get_when_true());
+        let when_false = self.bind_expression(conditional_expression.get_when_false());
+
+        if when_true.get_type() != when_false.get_type() {
+            let if_token = conditional_expression.get_if_token();
+            self.diagnostics
+                .borrow_mut()
+                .report_mismatched_branch_types(
+                    when_true.get_type().clone(),
+                    when_false.get_type().clone(),
+                    TextSpan::new(if_token.position(), if_token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+        }
+
+        Box::new(BoundConditionalExpression::new(
+            condition, when_true, when_false,
+        )) as Box<dyn BoundExpression>
+    }
+
     fn bind_parenthesized_expression(
-        &self,
+        &mut self,
         parenthesized_expression: &ParenthesizedExpressionSyntax,
     ) -> Box<dyn BoundExpression> {
         self.bind_expression(parenthesized_expression.get_expression())
     }
 
     fn bind_name_expression(
-        &self,
+        &mut self,
         name_expression: &NameExpressionSyntax,
     ) -> Box<dyn BoundExpression> {
         let token = name_expression.get_token();
         let value = name_expression.get_value();
-        let key = get_key_from_symbol_table(token.name());
-
-        if let Some(key) = key {
-            if self.check_scope_of_variable(&key) {
-                return Box::new(BoundLiteralExpression::new(
-                    value.clone(),
-                    key.get_type(),
-                    SyntaxKind::Variable,
-                )) as Box<dyn BoundExpression>;
-            }
+
+        if let Some(variable) = self.lookup_variable(token) {
+            self.record_reference(&variable, token, false);
+            return Box::new(BoundVariableExpression::new(
+                variable,
+                TextSpan::new(token.position(), token.length()),
+            )) as Box<dyn BoundExpression>;
         }
 
         self.diagnostics.borrow_mut().report_undefined_name(
@@ -425,45 +1440,125 @@ impl Binder {
             TextType::Error,
         );
 
-        Box::new(BoundLiteralExpression::new(
-            value.clone(),
-            *value.get_type(),
-            SyntaxKind::Error,
+        Box::new(BoundLiteralExpression::new(value.clone(), value.get_type()))
+            as Box<dyn BoundExpression>
+    }
+
+    fn bind_call_expression(
+        &mut self,
+        call_expression: &CallExpressionSyntax,
+        value_required: bool,
+    ) -> Box<dyn BoundExpression> {
+        let token = call_expression.get_identifier();
+        let value = token.get_value();
+
+        let arguments: Vec<Box<dyn BoundExpression>> = call_expression
+            .get_arguments()
+            .into_iter()
+            .map(|argument| self.bind_expression(argument))
+            .collect();
+
+        let function = match self.lookup_function(&token.name()) {
+            Some(function) => function,
+            None if token.name() == "len" => {
+                return self.bind_length_expression(token, arguments);
+            }
+            None => {
+                self.diagnostics.borrow_mut().report_undefined_function(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+                return Box::new(BoundLiteralExpression::new(value.clone(), value.get_type()))
+                    as Box<dyn BoundExpression>;
+            }
+        };
+        self.record_function_reference(function.clone(), token, false);
+        let index = function.index();
+        let parameters = self.functions[index].get_parameters().clone();
+
+        if parameters.len() != arguments.len() {
+            self.diagnostics.borrow_mut().report_wrong_argument_count(
+                token.name(),
+                parameters.len(),
+                arguments.len(),
+                TextSpan::new(token.position(), token.length()),
+                TextPlace::Semantic,
+                TextType::Error,
+            );
+            return Box::new(BoundLiteralExpression::new(value.clone(), value.get_type()))
+                as Box<dyn BoundExpression>;
+        }
+
+        for (parameter, argument) in parameters.iter().zip(&arguments) {
+            if parameter.get_type() != argument.get_type().clone() {
+                self.diagnostics.borrow_mut().report_invalid_literal_type(
+                    argument.get_type().clone(),
+                    parameter.get_type(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+            }
+        }
+
+        let return_type = self.functions[index].get_return_type();
+        if return_type.is_none() && value_required {
+            self.diagnostics
+                .borrow_mut()
+                .report_function_without_return_value(
+                    token.name(),
+                    TextSpan::new(token.position(), token.length()),
+                    TextPlace::Semantic,
+                    TextType::Error,
+                );
+        }
+
+        Box::new(BoundCallExpression::new(
+            function,
+            arguments,
+            return_type.unwrap_or(LiteralType::Integer),
+            TextSpan::new(token.position(), token.length()),
         )) as Box<dyn BoundExpression>
     }
 
     fn bind_literal_expression(
-        &self,
+        &mut self,
         literal_expression: &LiteralExpressionSyntax,
     ) -> Box<dyn BoundExpression> {
         let value = literal_expression.get_value();
 
-        Box::new(BoundLiteralExpression::new(
-            value.clone(),
-            *value.get_type(),
-            SyntaxKind::None,
-        )) as Box<dyn BoundExpression>
+        Box::new(BoundLiteralExpression::new(value.clone(), value.get_type()))
+            as Box<dyn BoundExpression>
     }
 
     fn bind_unary_expression(
-        &self,
+        &mut self,
         unary_expression: &UnaryExpressionSyntax,
     ) -> Box<dyn BoundExpression> {
         let bound_operand: Box<dyn BoundExpression> =
             self.bind_expression(unary_expression.operand());
         let bound_operator = BoundUnaryOperator::bind(
             *unary_expression.operator().kind(),
-            *bound_operand.get_type(),
+            bound_operand.get_type().clone(),
         );
 
         if let Some(operator) = bound_operator {
-            Box::new(BoundUnaryExpression::new(operator, bound_operand)) as Box<dyn BoundExpression>
+            Box::new(BoundUnaryExpression::new(
+                operator,
+                bound_operand,
+                TextSpan::new(
+                    unary_expression.operator().position(),
+                    unary_expression.operator().length(),
+                ),
+            )) as Box<dyn BoundExpression>
         } else {
             self.diagnostics
                 .borrow_mut()
                 .report_undefined_unary_operator(
                     *unary_expression.operator().kind(),
-                    *bound_operand.get_type(),
+                    bound_operand.get_type().clone(),
                     TextSpan::new(
                         unary_expression.operator().position(),
                         unary_expression.operator().length(),
@@ -476,17 +1571,36 @@ impl Binder {
     }
 
     fn bind_binary_expression(
-        &self,
+        &mut self,
         binary_expression: &BinaryExpressionSyntax,
     ) -> Box<dyn BoundExpression> {
         let bound_left: Box<dyn BoundExpression> =
             self.bind_expression(binary_expression.get_left());
         let bound_right: Box<dyn BoundExpression> =
             self.bind_expression(binary_expression.get_right());
-        let bound_operator: Option<BoundBinaryOperator> = BoundBinaryOperator::bind(
+
+        self.bind_binary_operator(
+            bound_left,
             *binary_expression.get_operator().kind(),
-            *bound_left.get_type(),
-            *bound_right.get_type(),
+            bound_right,
+            TextSpan::new(
+                binary_expression.get_operator().position(),
+                binary_expression.get_operator().length(),
+            ),
+        )
+    }
+
+    fn bind_binary_operator(
+        &mut self,
+        bound_left: Box<dyn BoundExpression>,
+        operator_kind: SyntaxKind,
+        bound_right: Box<dyn BoundExpression>,
+        span: TextSpan,
+    ) -> Box<dyn BoundExpression> {
+        let bound_operator: Option<BoundBinaryOperator> = BoundBinaryOperator::bind(
+            operator_kind,
+            bound_left.get_type().clone(),
+            bound_right.get_type().clone(),
         );
 
         if let Some(operator) = bound_operator {
@@ -494,18 +1608,16 @@ impl Binder {
                 bound_left,
                 operator,
                 bound_right,
+                span,
             )) as Box<dyn BoundExpression>
         } else {
             self.diagnostics
                 .borrow_mut()
                 .report_undefined_binary_operator(
-                    *binary_expression.get_operator().kind(),
-                    *bound_left.get_type(),
-                    *bound_right.get_type(),
-                    TextSpan::new(
-                        binary_expression.get_operator().position(),
-                        binary_expression.get_operator().length(),
-                    ),
+                    operator_kind,
+                    bound_left.get_type().clone(),
+                    bound_right.get_type().clone(),
+                    span,
                     TextPlace::Semantic,
                     TextType::Error,
                 );
@@ -513,3 +1625,44 @@ impl Binder {
         }
     }
 }
+
+// Covers the expression from the start of its first token to the end of its last one.
+fn expression_span(expression: &dyn Expression) -> TextSpan {
+    let tokens = expression.get_tokens();
+    let start = tokens
+        .iter()
+        .map(|token| token.position())
+        .min()
+        .unwrap_or(0);
+    let end = tokens
+        .iter()
+        .map(|token| token.position() + token.length())
+        .max()
+        .unwrap_or(start);
+    TextSpan::new(start, end - start)
+}
+
+// Whether a function body has a return statement of its own, outside the functions declared in
+// it. A function without a return type returns an `int` when it does.
+fn returns_value(statement: &dyn Statement) -> bool {
+    let any = statement.as_any();
+    if let Some(statement_list) = any.downcast_ref::<StatementList>() {
+        statement_list
+            .get_statements()
+            .iter()
+            .any(|statement| returns_value(statement.as_ref()))
+    } else if let Some(if_statement) = any.downcast_ref::<IfStatement>() {
+        returns_value(if_statement.get_then_statement().as_ref())
+            || if_statement
+                .get_else_clause()
+                .is_some_and(|else_clause| returns_value(else_clause.as_ref()))
+    } else if let Some(else_statement) = any.downcast_ref::<ElseStatement>() {
+        returns_value(else_statement.get_else_statement().as_ref())
+    } else if let Some(while_statement) = any.downcast_ref::<WhileStatement>() {
+        returns_value(while_statement.get_body().as_ref())
+    } else if let Some(for_statement) = any.downcast_ref::<ForStatement>() {
+        returns_value(for_statement.get_body().as_ref())
+    } else {
+        any.is::<ReturnStatement>()
+    }
+}
@@ -0,0 +1,47 @@
+use std::any::Any;
+
+use crate::util::literals::LiteralType;
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+#[derive(Debug)]
+pub struct BoundArrayLiteralExpression {
+    elements: Vec<Box<dyn BoundExpression>>,
+    type_of_value: LiteralType,
+}
+
+impl Clone for BoundArrayLiteralExpression {
+    fn clone(&self) -> Self {
+        BoundArrayLiteralExpression {
+            elements: self.elements.clone(),
+            type_of_value: self.type_of_value.clone(),
+        }
+    }
+}
+
+impl BoundArrayLiteralExpression {
+    pub fn new(elements: Vec<Box<dyn BoundExpression>>, type_of_value: LiteralType) -> Self {
+        Self {
+            elements,
+            type_of_value,
+        }
+    }
+
+    pub fn get_elements(&self) -> Vec<Box<dyn BoundExpression>> {
+        self.elements.clone()
+    }
+}
+
+impl BoundExpression for BoundArrayLiteralExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &self.type_of_value
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundArrayLiteralExpression
+    }
+}
@@ -1,6 +1,6 @@
 use std::any::Any;
 
-use crate::util::literals::LiteralType;
+use crate::{reports::text_span::TextSpan, util::literals::LiteralType};
 
 use super::{
     bound_binary_operator::BoundBinaryOperator, bound_expression::BoundExpression,
@@ -12,6 +12,7 @@ pub struct BoundBinaryExpression {
     left: Box<dyn BoundExpression>,
     operator: BoundBinaryOperator,
     right: Box<dyn BoundExpression>,
+    span: TextSpan,
 }
 
 impl Clone for BoundBinaryExpression {
@@ -20,6 +21,7 @@ impl Clone for BoundBinaryExpression {
             left: self.left.clone(),
             operator: self.operator.clone(),
             right: self.right.clone(),
+            span: self.span.clone(),
         }
     }
 }
@@ -29,11 +31,13 @@ impl BoundBinaryExpression {
         left: Box<dyn BoundExpression>,
         operator: BoundBinaryOperator,
         right: Box<dyn BoundExpression>,
+        span: TextSpan,
     ) -> Self {
         Self {
             left,
             operator,
             right,
+            span,
         }
     }
 
@@ -48,6 +52,10 @@ impl BoundBinaryExpression {
     pub fn get_operator(&self) -> &BoundBinaryOperator {
         &self.operator
     }
+
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
 }
 
 impl BoundExpression for BoundBinaryExpression {
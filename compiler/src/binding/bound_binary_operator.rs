@@ -42,7 +42,13 @@ impl BoundBinaryOperator {
         operand_type: LiteralType,
         result_type: LiteralType,
     ) -> Self {
-        BoundBinaryOperator::new(syntax_kind, kind, operand_type, operand_type, result_type)
+        BoundBinaryOperator::new(
+            syntax_kind,
+            kind,
+            operand_type.clone(),
+            operand_type,
+            result_type,
+        )
     }
 
     fn build_binary(
@@ -50,7 +56,13 @@ impl BoundBinaryOperator {
         kind: BoundBinaryOperatorKind,
         result_type: LiteralType,
     ) -> Self {
-        BoundBinaryOperator::new(syntax_kind, kind, result_type, result_type, result_type)
+        BoundBinaryOperator::new(
+            syntax_kind,
+            kind,
+            result_type.clone(),
+            result_type.clone(),
+            result_type,
+        )
     }
 
     pub fn bind(
@@ -58,6 +70,15 @@ impl BoundBinaryOperator {
         left_type: LiteralType,
         right_type: LiteralType,
     ) -> Option<Self> {
+        // an integer operand next to a float operand is promoted to float
+        let (left_type, right_type) = match (left_type, right_type) {
+            (LiteralType::Integer, LiteralType::Float)
+            | (LiteralType::Float, LiteralType::Integer) => {
+                (LiteralType::Float, LiteralType::Float)
+            }
+            types => types,
+        };
+
         let operators = [
             BoundBinaryOperator::build_binary(
                 SyntaxKind::Plus,
@@ -79,6 +100,41 @@ impl BoundBinaryOperator {
                 BoundBinaryOperatorKind::Division,
                 LiteralType::Integer,
             ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Percent,
+                BoundBinaryOperatorKind::Modulo,
+                LiteralType::Integer,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::StarStar,
+                BoundBinaryOperatorKind::Power,
+                LiteralType::Integer,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Ampersand,
+                BoundBinaryOperatorKind::BitwiseAnd,
+                LiteralType::Integer,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Pipe,
+                BoundBinaryOperatorKind::BitwiseOr,
+                LiteralType::Integer,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Caret,
+                BoundBinaryOperatorKind::BitwiseXor,
+                LiteralType::Integer,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::LessLess,
+                BoundBinaryOperatorKind::ShiftLeft,
+                LiteralType::Integer,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::GreaterGreater,
+                BoundBinaryOperatorKind::ShiftRight,
+                LiteralType::Integer,
+            ),
             BoundBinaryOperator::build_unary(
                 SyntaxKind::EqualsEquals,
                 BoundBinaryOperatorKind::Equals,
@@ -135,6 +191,113 @@ impl BoundBinaryOperator {
                 LiteralType::Integer,
                 LiteralType::Boolean,
             ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Plus,
+                BoundBinaryOperatorKind::Addition,
+                LiteralType::Float,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Minus,
+                BoundBinaryOperatorKind::Subtraction,
+                LiteralType::Float,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Mul,
+                BoundBinaryOperatorKind::Multiplication,
+                LiteralType::Float,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Div,
+                BoundBinaryOperatorKind::Division,
+                LiteralType::Float,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Percent,
+                BoundBinaryOperatorKind::Modulo,
+                LiteralType::Float,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::StarStar,
+                BoundBinaryOperatorKind::Power,
+                LiteralType::Float,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::EqualsEquals,
+                BoundBinaryOperatorKind::Equals,
+                LiteralType::Float,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::BangEquals,
+                BoundBinaryOperatorKind::NotEquals,
+                LiteralType::Float,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::Less,
+                BoundBinaryOperatorKind::Less,
+                LiteralType::Float,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::LessOrEquals,
+                BoundBinaryOperatorKind::LessOrEquals,
+                LiteralType::Float,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::Greater,
+                BoundBinaryOperatorKind::Greater,
+                LiteralType::Float,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::GreaterOrEquals,
+                BoundBinaryOperatorKind::GreaterOrEquals,
+                LiteralType::Float,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_binary(
+                SyntaxKind::Plus,
+                BoundBinaryOperatorKind::Concatenation,
+                LiteralType::String,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::EqualsEquals,
+                BoundBinaryOperatorKind::Equals,
+                LiteralType::String,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::BangEquals,
+                BoundBinaryOperatorKind::NotEquals,
+                LiteralType::String,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::Less,
+                BoundBinaryOperatorKind::Less,
+                LiteralType::String,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::LessOrEquals,
+                BoundBinaryOperatorKind::LessOrEquals,
+                LiteralType::String,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::Greater,
+                BoundBinaryOperatorKind::Greater,
+                LiteralType::String,
+                LiteralType::Boolean,
+            ),
+            BoundBinaryOperator::build_unary(
+                SyntaxKind::GreaterOrEquals,
+                BoundBinaryOperatorKind::GreaterOrEquals,
+                LiteralType::String,
+                LiteralType::Boolean,
+            ),
         ];
 
         for operator in &operators {
@@ -4,6 +4,14 @@ pub enum BoundBinaryOperatorKind {
     Subtraction,
     Multiplication,
     Division,
+    Modulo,
+    Power,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
+    Concatenation,
     LogicalAnd,
     LogicalOr,
     Equals,
@@ -0,0 +1,16 @@
+use std::any::Any;
+
+use super::{bound_kind::BoundKind, bound_statement::BoundStatement};
+
+#[derive(Debug, Clone)]
+pub struct BoundBreakStatement;
+
+impl BoundStatement for BoundBreakStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundBreakStatement
+    }
+}
@@ -0,0 +1,69 @@
+use std::any::Any;
+
+use crate::{
+    reports::text_span::TextSpan,
+    util::{function_symbol::FunctionSymbol, literals::LiteralType},
+};
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+#[derive(Debug)]
+pub struct BoundCallExpression {
+    function: FunctionSymbol,
+    arguments: Vec<Box<dyn BoundExpression>>,
+    type_of_value: LiteralType,
+    span: TextSpan,
+}
+
+impl Clone for BoundCallExpression {
+    fn clone(&self) -> Self {
+        BoundCallExpression {
+            function: self.function.clone(),
+            arguments: self.arguments.clone(),
+            type_of_value: self.type_of_value.clone(),
+            span: self.span.clone(),
+        }
+    }
+}
+
+impl BoundCallExpression {
+    pub fn new(
+        function: FunctionSymbol,
+        arguments: Vec<Box<dyn BoundExpression>>,
+        type_of_value: LiteralType,
+        span: TextSpan,
+    ) -> Self {
+        Self {
+            function,
+            arguments,
+            type_of_value,
+            span,
+        }
+    }
+
+    pub fn get_function(&self) -> &FunctionSymbol {
+        &self.function
+    }
+
+    pub fn get_arguments(&self) -> Vec<Box<dyn BoundExpression>> {
+        self.arguments.clone()
+    }
+
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
+}
+
+impl BoundExpression for BoundCallExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &self.type_of_value
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundCallExpression
+    }
+}
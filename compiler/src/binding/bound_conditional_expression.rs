@@ -0,0 +1,63 @@
+use std::any::Any;
+
+use crate::util::literals::LiteralType;
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+#[derive(Debug)]
+pub struct BoundConditionalExpression {
+    condition: Box<dyn BoundExpression>,
+    when_true: Box<dyn BoundExpression>,
+    when_false: Box<dyn BoundExpression>,
+}
+
+impl Clone for BoundConditionalExpression {
+    fn clone(&self) -> Self {
+        BoundConditionalExpression {
+            condition: self.condition.clone(),
+            when_true: self.when_true.clone(),
+            when_false: self.when_false.clone(),
+        }
+    }
+}
+
+impl BoundConditionalExpression {
+    pub fn new(
+        condition: Box<dyn BoundExpression>,
+        when_true: Box<dyn BoundExpression>,
+        when_false: Box<dyn BoundExpression>,
+    ) -> Self {
+        Self {
+            condition,
+            when_true,
+            when_false,
+        }
+    }
+
+    pub fn get_condition(&self) -> Box<dyn BoundExpression> {
+        self.condition.clone()
+    }
+
+    pub fn get_when_true(&self) -> Box<dyn BoundExpression> {
+        self.when_true.clone()
+    }
+
+    pub fn get_when_false(&self) -> Box<dyn BoundExpression> {
+        self.when_false.clone()
+    }
+}
+
+impl BoundExpression for BoundConditionalExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    // The binder makes sure both branches have the same type.
+    fn get_type(&self) -> &LiteralType {
+        self.when_true.get_type()
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundConditionalExpression
+    }
+}
@@ -0,0 +1,16 @@
+use std::any::Any;
+
+use super::{bound_kind::BoundKind, bound_statement::BoundStatement};
+
+#[derive(Debug, Clone)]
+pub struct BoundContinueStatement;
+
+impl BoundStatement for BoundContinueStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundContinueStatement
+    }
+}
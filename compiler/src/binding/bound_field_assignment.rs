@@ -0,0 +1,68 @@
+use std::any::Any;
+
+use crate::{reports::text_span::TextSpan, util::variable_symbol::VariableSymbol};
+
+use super::{
+    bound_expression::BoundExpression, bound_kind::BoundKind, bound_statement::BoundStatement,
+};
+
+#[derive(Debug)]
+pub struct BoundFieldAssignment {
+    variable: VariableSymbol,
+    index: usize,
+    expression: Box<dyn BoundExpression>,
+    span: TextSpan,
+}
+
+impl Clone for BoundFieldAssignment {
+    fn clone(&self) -> Self {
+        BoundFieldAssignment {
+            variable: self.variable.clone(),
+            index: self.index,
+            expression: self.expression.clone(),
+            span: self.span.clone(),
+        }
+    }
+}
+
+impl BoundFieldAssignment {
+    pub fn new(
+        variable: VariableSymbol,
+        index: usize,
+        expression: Box<dyn BoundExpression>,
+        span: TextSpan,
+    ) -> Self {
+        Self {
+            variable,
+            index,
+            expression,
+            span,
+        }
+    }
+
+    pub fn get_variable(&self) -> &VariableSymbol {
+        &self.variable
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_bound_expression(&self) -> Box<dyn BoundExpression> {
+        self.expression.clone()
+    }
+
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
+}
+
+impl BoundStatement for BoundFieldAssignment {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundFieldAssignment
+    }
+}
@@ -0,0 +1,59 @@
+use std::any::Any;
+
+use crate::util::literals::LiteralType;
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+#[derive(Debug)]
+pub struct BoundFieldExpression {
+    structure: Box<dyn BoundExpression>,
+    index: usize,
+    type_of_value: LiteralType,
+}
+
+impl Clone for BoundFieldExpression {
+    fn clone(&self) -> Self {
+        BoundFieldExpression {
+            structure: self.structure.clone(),
+            index: self.index,
+            type_of_value: self.type_of_value.clone(),
+        }
+    }
+}
+
+impl BoundFieldExpression {
+    pub fn new(
+        structure: Box<dyn BoundExpression>,
+        index: usize,
+        type_of_value: LiteralType,
+    ) -> Self {
+        Self {
+            structure,
+            index,
+            type_of_value,
+        }
+    }
+
+    pub fn get_structure(&self) -> Box<dyn BoundExpression> {
+        self.structure.clone()
+    }
+
+    // The position of the field in the struct declaration.
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+}
+
+impl BoundExpression for BoundFieldExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &self.type_of_value
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundFieldExpression
+    }
+}
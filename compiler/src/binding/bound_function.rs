@@ -0,0 +1,38 @@
+use crate::util::{literals::LiteralType, variable_symbol::VariableSymbol};
+
+use super::bound_statement::BoundStatement;
+
+// The signature is bound before the statements of the block, the body when the declaration
+// itself is reached.
+#[derive(Debug, Clone)]
+pub struct BoundFunction {
+    parameters: Vec<VariableSymbol>,
+    return_type: Option<LiteralType>,
+    body: Option<Box<dyn BoundStatement>>,
+}
+
+impl BoundFunction {
+    pub fn new(parameters: Vec<VariableSymbol>, return_type: Option<LiteralType>) -> Self {
+        Self {
+            parameters,
+            return_type,
+            body: None,
+        }
+    }
+
+    pub fn get_parameters(&self) -> &Vec<VariableSymbol> {
+        &self.parameters
+    }
+
+    pub fn get_return_type(&self) -> Option<LiteralType> {
+        self.return_type.clone()
+    }
+
+    pub fn get_body(&self) -> Option<Box<dyn BoundStatement>> {
+        self.body.clone()
+    }
+
+    pub fn set_body(&mut self, body: Box<dyn BoundStatement>) {
+        self.body = Some(body);
+    }
+}
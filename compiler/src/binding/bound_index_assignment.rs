@@ -0,0 +1,68 @@
+use std::any::Any;
+
+use crate::{reports::text_span::TextSpan, util::variable_symbol::VariableSymbol};
+
+use super::{
+    bound_expression::BoundExpression, bound_kind::BoundKind, bound_statement::BoundStatement,
+};
+
+#[derive(Debug)]
+pub struct BoundIndexAssignment {
+    variable: VariableSymbol,
+    index: Box<dyn BoundExpression>,
+    expression: Box<dyn BoundExpression>,
+    span: TextSpan,
+}
+
+impl Clone for BoundIndexAssignment {
+    fn clone(&self) -> Self {
+        BoundIndexAssignment {
+            variable: self.variable.clone(),
+            index: self.index.clone(),
+            expression: self.expression.clone(),
+            span: self.span.clone(),
+        }
+    }
+}
+
+impl BoundIndexAssignment {
+    pub fn new(
+        variable: VariableSymbol,
+        index: Box<dyn BoundExpression>,
+        expression: Box<dyn BoundExpression>,
+        span: TextSpan,
+    ) -> Self {
+        Self {
+            variable,
+            index,
+            expression,
+            span,
+        }
+    }
+
+    pub fn get_variable(&self) -> &VariableSymbol {
+        &self.variable
+    }
+
+    pub fn get_index(&self) -> Box<dyn BoundExpression> {
+        self.index.clone()
+    }
+
+    pub fn get_bound_expression(&self) -> Box<dyn BoundExpression> {
+        self.expression.clone()
+    }
+
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
+}
+
+impl BoundStatement for BoundIndexAssignment {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundIndexAssignment
+    }
+}
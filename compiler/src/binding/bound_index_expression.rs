@@ -0,0 +1,67 @@
+use std::any::Any;
+
+use crate::{reports::text_span::TextSpan, util::literals::LiteralType};
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+#[derive(Debug)]
+pub struct BoundIndexExpression {
+    array: Box<dyn BoundExpression>,
+    index: Box<dyn BoundExpression>,
+    type_of_value: LiteralType,
+    span: TextSpan,
+}
+
+impl Clone for BoundIndexExpression {
+    fn clone(&self) -> Self {
+        BoundIndexExpression {
+            array: self.array.clone(),
+            index: self.index.clone(),
+            type_of_value: self.type_of_value.clone(),
+            span: self.span.clone(),
+        }
+    }
+}
+
+impl BoundIndexExpression {
+    pub fn new(
+        array: Box<dyn BoundExpression>,
+        index: Box<dyn BoundExpression>,
+        type_of_value: LiteralType,
+        span: TextSpan,
+    ) -> Self {
+        Self {
+            array,
+            index,
+            type_of_value,
+            span,
+        }
+    }
+
+    pub fn get_array(&self) -> Box<dyn BoundExpression> {
+        self.array.clone()
+    }
+
+    pub fn get_index(&self) -> Box<dyn BoundExpression> {
+        self.index.clone()
+    }
+
+    // The opening bracket, where an out of bounds index is reported.
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
+}
+
+impl BoundExpression for BoundIndexExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &self.type_of_value
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundIndexExpression
+    }
+}
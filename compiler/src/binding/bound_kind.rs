@@ -3,14 +3,28 @@ pub enum BoundKind {
     // statements
     BoundStatementList,
     BoundAssignment,
+    BoundIndexAssignment,
+    BoundFieldAssignment,
     BoundVariableDeclaration,
     BoundConstantDeclaration,
     BoundIfStatement,
     BoundWhileStatement,
     BoundForStatement,
+    BoundReturnStatement,
+    BoundBreakStatement,
+    BoundContinueStatement,
+    BoundExpressionStatement,
 
     // expressions
     BoundLiteralExpression,
     BoundUnaryExpression,
     BoundBinaryExpression,
+    BoundCallExpression,
+    BoundConditionalExpression,
+    BoundVariableExpression,
+    BoundArrayLiteralExpression,
+    BoundIndexExpression,
+    BoundLengthExpression,
+    BoundStructLiteralExpression,
+    BoundFieldExpression,
 }
@@ -0,0 +1,43 @@
+use std::any::Any;
+
+use crate::util::literals::LiteralType;
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+// The `len(array)` builtin.
+#[derive(Debug)]
+pub struct BoundLengthExpression {
+    array: Box<dyn BoundExpression>,
+}
+
+impl Clone for BoundLengthExpression {
+    fn clone(&self) -> Self {
+        BoundLengthExpression {
+            array: self.array.clone(),
+        }
+    }
+}
+
+impl BoundLengthExpression {
+    pub fn new(array: Box<dyn BoundExpression>) -> Self {
+        Self { array }
+    }
+
+    pub fn get_array(&self) -> Box<dyn BoundExpression> {
+        self.array.clone()
+    }
+}
+
+impl BoundExpression for BoundLengthExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &LiteralType::Integer
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundLengthExpression
+    }
+}
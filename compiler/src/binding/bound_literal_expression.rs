@@ -1,9 +1,6 @@
 use std::any::Any;
 
-use crate::util::{
-    literals::{LiteralType, LiteralValue},
-    syntax_kind::SyntaxKind,
-};
+use crate::util::literals::{LiteralType, LiteralValue};
 
 use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
 
@@ -11,7 +8,6 @@ use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
 pub struct BoundLiteralExpression {
     value: LiteralValue,
     type_of_value: LiteralType,
-    kind: SyntaxKind,
 }
 
 impl Clone for BoundLiteralExpression {
@@ -19,27 +15,21 @@ impl Clone for BoundLiteralExpression {
         BoundLiteralExpression {
             value: self.value.clone(),
             type_of_value: self.type_of_value.clone(),
-            kind: self.kind.clone(),
         }
     }
 }
 
 impl BoundLiteralExpression {
-    pub fn new(value: LiteralValue, type_of_value: LiteralType, kind: SyntaxKind) -> Self {
+    pub fn new(value: LiteralValue, type_of_value: LiteralType) -> Self {
         Self {
             value,
             type_of_value,
-            kind,
         }
     }
 
     pub fn get_value(&self) -> LiteralValue {
         self.value.clone()
     }
-
-    pub fn get_kind(&self) -> &SyntaxKind {
-        &self.kind
-    }
 }
 
 impl BoundExpression for BoundLiteralExpression {
@@ -0,0 +1,38 @@
+use std::any::Any;
+
+use super::{
+    bound_expression::BoundExpression, bound_kind::BoundKind, bound_statement::BoundStatement,
+};
+
+#[derive(Debug)]
+pub struct BoundReturnStatement {
+    expression: Box<dyn BoundExpression>,
+}
+
+impl Clone for BoundReturnStatement {
+    fn clone(&self) -> Self {
+        BoundReturnStatement {
+            expression: self.expression.clone(),
+        }
+    }
+}
+
+impl BoundReturnStatement {
+    pub fn new(expression: Box<dyn BoundExpression>) -> Self {
+        Self { expression }
+    }
+
+    pub fn get_bound_expression(&self) -> Box<dyn BoundExpression> {
+        self.expression.clone()
+    }
+}
+
+impl BoundStatement for BoundReturnStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundReturnStatement
+    }
+}
@@ -1,10 +1,16 @@
 use std::{cell::RefCell, rc::Rc};
 
-use crate::util::variable_symbol::VariableSymbol;
+use crate::util::{
+    function_symbol::FunctionSymbol, struct_symbol::StructSymbol, variable_symbol::VariableSymbol,
+};
 
 #[derive(Clone)]
 pub struct BoundScope {
     pub variables: Vec<VariableSymbol>,
+    pub functions: Vec<FunctionSymbol>,
+    pub structs: Vec<StructSymbol>,
+    // The outermost scope of a function body, which holds the parameters.
+    pub is_function: bool,
     parent: Option<Rc<RefCell<BoundScope>>>,
 }
 
@@ -12,6 +18,9 @@ impl BoundScope {
     pub fn new(parent: Option<Rc<RefCell<BoundScope>>>) -> Self {
         Self {
             variables: Vec::new(),
+            functions: Vec::new(),
+            structs: Vec::new(),
+            is_function: false,
             parent,
         }
     }
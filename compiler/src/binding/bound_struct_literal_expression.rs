@@ -0,0 +1,66 @@
+use std::any::Any;
+
+use crate::util::literals::LiteralType;
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+// The fields are kept in declaration order, whatever order the literal wrote them in.
+#[derive(Debug)]
+pub struct BoundStructLiteralExpression {
+    name: String,
+    field_names: Vec<String>,
+    fields: Vec<Box<dyn BoundExpression>>,
+    type_of_value: LiteralType,
+}
+
+impl Clone for BoundStructLiteralExpression {
+    fn clone(&self) -> Self {
+        BoundStructLiteralExpression {
+            name: self.name.clone(),
+            field_names: self.field_names.clone(),
+            fields: self.fields.clone(),
+            type_of_value: self.type_of_value.clone(),
+        }
+    }
+}
+
+impl BoundStructLiteralExpression {
+    pub fn new(
+        name: String,
+        field_names: Vec<String>,
+        fields: Vec<Box<dyn BoundExpression>>,
+    ) -> Self {
+        Self {
+            type_of_value: LiteralType::Struct(name.clone()),
+            name,
+            field_names,
+            fields,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_field_names(&self) -> Vec<String> {
+        self.field_names.clone()
+    }
+
+    pub fn get_fields(&self) -> Vec<Box<dyn BoundExpression>> {
+        self.fields.clone()
+    }
+}
+
+impl BoundExpression for BoundStructLiteralExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &self.type_of_value
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundStructLiteralExpression
+    }
+}
@@ -1,6 +1,6 @@
 use std::any::Any;
 
-use crate::util::literals::LiteralType;
+use crate::{reports::text_span::TextSpan, util::literals::LiteralType};
 
 use super::{
     bound_expression::BoundExpression, bound_kind::BoundKind,
@@ -11,6 +11,7 @@ use super::{
 pub struct BoundUnaryExpression {
     operator: BoundUnaryOperator,
     operand: Box<dyn BoundExpression>,
+    span: TextSpan,
 }
 
 impl Clone for BoundUnaryExpression {
@@ -18,13 +19,22 @@ impl Clone for BoundUnaryExpression {
         BoundUnaryExpression {
             operator: self.operator.clone(),
             operand: self.operand.clone(),
+            span: self.span.clone(),
         }
     }
 }
 
 impl BoundUnaryExpression {
-    pub fn new(operator: BoundUnaryOperator, operand: Box<dyn BoundExpression>) -> Self {
-        Self { operator, operand }
+    pub fn new(
+        operator: BoundUnaryOperator,
+        operand: Box<dyn BoundExpression>,
+        span: TextSpan,
+    ) -> Self {
+        Self {
+            operator,
+            operand,
+            span,
+        }
     }
 
     pub fn get_operand(&self) -> Box<dyn BoundExpression> {
@@ -34,6 +44,10 @@ impl BoundUnaryExpression {
     pub fn get_operator(&self) -> &BoundUnaryOperator {
         &self.operator
     }
+
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
 }
 
 impl BoundExpression for BoundUnaryExpression {
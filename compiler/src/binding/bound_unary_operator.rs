@@ -38,7 +38,7 @@ impl BoundUnaryOperator {
         kind: BoundUnaryOperatorKind,
         operand_type: LiteralType,
     ) -> Self {
-        BoundUnaryOperator::new(syntax_kind, kind, operand_type, operand_type)
+        BoundUnaryOperator::new(syntax_kind, kind, operand_type.clone(), operand_type)
     }
 
     pub fn bind(syntax_kind: SyntaxKind, operand_type: LiteralType) -> Option<Self> {
@@ -58,6 +58,21 @@ impl BoundUnaryOperator {
                 BoundUnaryOperatorKind::Negation,
                 LiteralType::Integer,
             ),
+            BoundUnaryOperator::build_unary(
+                SyntaxKind::Tilde,
+                BoundUnaryOperatorKind::BitwiseNot,
+                LiteralType::Integer,
+            ),
+            BoundUnaryOperator::build_unary(
+                SyntaxKind::Plus,
+                BoundUnaryOperatorKind::Identity,
+                LiteralType::Float,
+            ),
+            BoundUnaryOperator::build_unary(
+                SyntaxKind::Minus,
+                BoundUnaryOperatorKind::Negation,
+                LiteralType::Float,
+            ),
         ];
 
         for operator in &operators {
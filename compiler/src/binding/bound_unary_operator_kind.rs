@@ -3,4 +3,5 @@ pub enum BoundUnaryOperatorKind {
     Identity,
     Negation,
     LogicalNegation,
+    BitwiseNot,
 }
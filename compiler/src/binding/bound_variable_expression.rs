@@ -0,0 +1,57 @@
+use std::any::Any;
+
+use crate::{
+    reports::text_span::TextSpan,
+    util::{literals::LiteralType, variable_symbol::VariableSymbol},
+};
+
+use super::{bound_expression::BoundExpression, bound_kind::BoundKind};
+
+#[derive(Debug)]
+pub struct BoundVariableExpression {
+    variable: VariableSymbol,
+    type_of_value: LiteralType,
+    span: TextSpan,
+}
+
+impl Clone for BoundVariableExpression {
+    fn clone(&self) -> Self {
+        BoundVariableExpression {
+            variable: self.variable.clone(),
+            type_of_value: self.type_of_value.clone(),
+            span: self.span.clone(),
+        }
+    }
+}
+
+impl BoundVariableExpression {
+    pub fn new(variable: VariableSymbol, span: TextSpan) -> Self {
+        Self {
+            type_of_value: variable.get_type(),
+            variable,
+            span,
+        }
+    }
+
+    pub fn get_variable(&self) -> &VariableSymbol {
+        &self.variable
+    }
+
+    pub fn get_span(&self) -> TextSpan {
+        self.span.clone()
+    }
+}
+
+impl BoundExpression for BoundVariableExpression {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_type(&self) -> &LiteralType {
+        &self.type_of_value
+    }
+
+    fn get_type_of_bound(&self) -> &BoundKind {
+        &BoundKind::BoundVariableExpression
+    }
+}
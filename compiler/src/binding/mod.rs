@@ -1,19 +1,36 @@
 pub mod binder;
+pub mod bound_array_literal_expression;
 pub mod bound_assignment;
 pub mod bound_binary_expression;
 pub mod bound_binary_operator;
 pub mod bound_binary_operator_kind;
+pub mod bound_break_statement;
+pub mod bound_call_expression;
+pub mod bound_conditional_expression;
 pub mod bound_constant_declaration;
+pub mod bound_continue_statement;
 pub mod bound_expression;
+pub mod bound_expression_statement;
+pub mod bound_field_assignment;
+pub mod bound_field_expression;
 pub mod bound_for_statement;
+pub mod bound_function;
 pub mod bound_if_statement;
+pub mod bound_index_assignment;
+pub mod bound_index_expression;
 pub mod bound_kind;
+pub mod bound_length_expression;
 pub mod bound_literal_expression;
+pub mod bound_return_statement;
 pub mod bound_scope;
 pub mod bound_statement;
 pub mod bound_statement_list;
+pub mod bound_struct_literal_expression;
 pub mod bound_unary_expression;
 pub mod bound_unary_operator;
 pub mod bound_unary_operator_kind;
 pub mod bound_variable_declaration;
+pub mod bound_variable_expression;
 pub mod bound_while_statement;
+pub mod symbol_reference;
+pub mod type_table;
@@ -0,0 +1,40 @@
+use crate::{
+    reports::text_span::TextSpan,
+    util::{function_symbol::FunctionSymbol, variable_symbol::VariableSymbol},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferencedSymbol {
+    Variable(VariableSymbol),
+    Function(FunctionSymbol),
+}
+
+// A place where a variable or a function is declared or used, recorded for editor tooling.
+#[derive(Debug, Clone)]
+pub struct SymbolReference {
+    symbol: ReferencedSymbol,
+    span: TextSpan,
+    is_declaration: bool,
+}
+
+impl SymbolReference {
+    pub fn new(symbol: ReferencedSymbol, span: TextSpan, is_declaration: bool) -> Self {
+        Self {
+            symbol,
+            span,
+            is_declaration,
+        }
+    }
+
+    pub fn get_symbol(&self) -> &ReferencedSymbol {
+        &self.symbol
+    }
+
+    pub fn get_span(&self) -> &TextSpan {
+        &self.span
+    }
+
+    pub fn is_declaration(&self) -> bool {
+        self.is_declaration
+    }
+}
@@ -0,0 +1,25 @@
+use crate::util::struct_symbol::StructSymbol;
+
+// Every struct of the program by id, for the backends. Names are resolved through the scopes of
+// the binder.
+#[derive(Debug, Clone, Default)]
+pub struct TypeTable {
+    structs: Vec<StructSymbol>,
+}
+
+impl TypeTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn declare(&mut self, symbol: StructSymbol) {
+        self.structs.push(symbol);
+    }
+
+    pub fn lookup(&self, id: &str) -> Option<StructSymbol> {
+        self.structs
+            .iter()
+            .find(|symbol| symbol.id() == id)
+            .cloned()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::util::variable_symbol::VariableSymbol;
+
+use super::instruction::Instruction;
+
+#[derive(Debug, Clone, Default)]
+pub struct Chunk {
+    pub instructions: Vec<Instruction>,
+    pub locals: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub main: Chunk,
+    pub functions: Vec<Option<Chunk>>,
+    pub globals: Vec<VariableSymbol>,
+}
@@ -0,0 +1,500 @@
+use std::collections::HashMap;
+
+use crate::{
+    binding::{
+        bound_array_literal_expression::BoundArrayLiteralExpression,
+        bound_assignment::BoundAssignment, bound_binary_expression::BoundBinaryExpression,
+        bound_binary_operator_kind::BoundBinaryOperatorKind,
+        bound_call_expression::BoundCallExpression,
+        bound_conditional_expression::BoundConditionalExpression,
+        bound_constant_declaration::BoundConstantDeclaration, bound_expression::BoundExpression,
+        bound_expression_statement::BoundExpressionStatement,
+        bound_field_assignment::BoundFieldAssignment, bound_field_expression::BoundFieldExpression,
+        bound_for_statement::BoundForStatement, bound_function::BoundFunction,
+        bound_if_statement::BoundIfStatement, bound_index_assignment::BoundIndexAssignment,
+        bound_index_expression::BoundIndexExpression, bound_kind::BoundKind,
+        bound_length_expression::BoundLengthExpression,
+        bound_literal_expression::BoundLiteralExpression,
+        bound_return_statement::BoundReturnStatement, bound_statement::BoundStatement,
+        bound_statement_list::BoundStatementList,
+        bound_struct_literal_expression::BoundStructLiteralExpression,
+        bound_unary_expression::BoundUnaryExpression,
+        bound_variable_declaration::BoundVariableDeclaration,
+        bound_variable_expression::BoundVariableExpression,
+        bound_while_statement::BoundWhileStatement,
+    },
+    reports::text_span::TextSpan,
+    util::{literals::LiteralValue, variable_symbol::VariableSymbol},
+};
+
+use super::{
+    chunk::{Chunk, Module},
+    instruction::Instruction,
+};
+
+// Jumps out of the innermost loops, patched once the loop has been generated.
+#[derive(Default)]
+struct LoopJumps {
+    breaks: Vec<usize>,
+    continues: Vec<usize>,
+}
+
+pub struct CodeGenerator {
+    chunk: Chunk,
+    locals: HashMap<VariableSymbol, usize>,
+    globals: HashMap<VariableSymbol, usize>,
+    global_symbols: Vec<VariableSymbol>,
+    loops: Vec<LoopJumps>,
+}
+
+impl CodeGenerator {
+    pub fn new() -> Self {
+        Self {
+            chunk: Chunk::default(),
+            locals: HashMap::new(),
+            globals: HashMap::new(),
+            global_symbols: Vec::new(),
+            loops: Vec::new(),
+        }
+    }
+
+    pub fn generate(
+        mut self,
+        statements: Box<dyn BoundStatement>,
+        functions: &[BoundFunction],
+    ) -> Module {
+        self.generate_statement(statements);
+        let main = self.finish_chunk();
+
+        let functions = functions
+            .iter()
+            .map(|function| {
+                let body = function.get_body()?;
+                for parameter in function.get_parameters() {
+                    self.declare_local(parameter);
+                }
+                self.generate_statement(body);
+                Some(self.finish_chunk())
+            })
+            .collect();
+
+        Module {
+            main,
+            functions,
+            globals: self.global_symbols,
+        }
+    }
+
+    fn finish_chunk(&mut self) -> Chunk {
+        self.emit(Instruction::End);
+        self.locals.clear();
+        std::mem::take(&mut self.chunk)
+    }
+
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.chunk.instructions.push(instruction);
+        self.chunk.instructions.len() - 1
+    }
+
+    fn patch_jump(&mut self, at: usize) {
+        self.patch_jump_to(at, self.chunk.instructions.len());
+    }
+
+    fn patch_jump_to(&mut self, at: usize, target: usize) {
+        match &mut self.chunk.instructions[at] {
+            Instruction::Jump(address) | Instruction::JumpIfFalse(address) => *address = target,
+            _ => panic!("Code generation ERROR: Only jumps can be patched."),
+        }
+    }
+
+    fn add_local(&mut self, name: String) -> usize {
+        self.chunk.locals.push(name);
+        self.chunk.locals.len() - 1
+    }
+
+    fn declare_local(&mut self, variable: &VariableSymbol) -> usize {
+        if let Some(slot) = self.locals.get(variable) {
+            return *slot;
+        }
+        let slot = self.add_local(variable.id());
+        self.locals.insert(variable.clone(), slot);
+        slot
+    }
+
+    fn global_slot(&mut self, variable: &VariableSymbol) -> usize {
+        if let Some(slot) = self.globals.get(variable) {
+            return *slot;
+        }
+        self.global_symbols.push(variable.clone());
+        let slot = self.global_symbols.len() - 1;
+        self.globals.insert(variable.clone(), slot);
+        slot
+    }
+
+    fn emit_load(&mut self, variable: &VariableSymbol, span: TextSpan) {
+        if variable.is_global() {
+            let slot = self.global_slot(variable);
+            self.emit(Instruction::LoadGlobal(slot, span));
+        } else {
+            let slot = self.declare_local(variable);
+            self.emit(Instruction::LoadLocal(slot, span));
+        }
+    }
+
+    fn emit_store(&mut self, variable: &VariableSymbol) {
+        if variable.is_global() {
+            let slot = self.global_slot(variable);
+            self.emit(Instruction::StoreGlobal(slot));
+        } else {
+            let slot = self.declare_local(variable);
+            self.emit(Instruction::StoreLocal(slot));
+        }
+    }
+
+    fn generate_statement(&mut self, statement: Box<dyn BoundStatement>) {
+        match *statement.get_type_of_bound() {
+            BoundKind::BoundStatementList => self.generate_statement_list(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundStatementList>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundAssignment => self.generate_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundIndexAssignment => self.generate_index_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundIndexAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundFieldAssignment => self.generate_field_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundFieldAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundVariableDeclaration => {
+                let declaration = statement
+                    .as_any()
+                    .downcast_ref::<BoundVariableDeclaration>()
+                    .unwrap();
+                self.generate_expression(declaration.get_bound_expression());
+                self.emit_store(declaration.get_variable());
+            }
+            BoundKind::BoundConstantDeclaration => {
+                let declaration = statement
+                    .as_any()
+                    .downcast_ref::<BoundConstantDeclaration>()
+                    .unwrap();
+                self.generate_expression(declaration.get_bound_expression());
+                self.emit_store(declaration.get_variable());
+            }
+            BoundKind::BoundIfStatement => self.generate_if_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundIfStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundWhileStatement => self.generate_while_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundWhileStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundForStatement => self.generate_for_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundForStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundReturnStatement => {
+                let return_statement = statement
+                    .as_any()
+                    .downcast_ref::<BoundReturnStatement>()
+                    .unwrap();
+                self.generate_expression(return_statement.get_bound_expression());
+                self.emit(Instruction::Return);
+            }
+            BoundKind::BoundBreakStatement => {
+                let jump = self.emit(Instruction::Jump(0));
+                self.loops.last_mut().unwrap().breaks.push(jump);
+            }
+            BoundKind::BoundContinueStatement => {
+                let jump = self.emit(Instruction::Jump(0));
+                self.loops.last_mut().unwrap().continues.push(jump);
+            }
+            BoundKind::BoundExpressionStatement => self.generate_expression_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundExpressionStatement>()
+                    .unwrap(),
+            ),
+            _ => panic!("Code generation ERROR: Unexpected bound kind for statement."),
+        }
+    }
+
+    fn generate_statement_list(&mut self, statement_list: &BoundStatementList) {
+        for statement in statement_list.get_statements() {
+            self.generate_statement(statement);
+        }
+    }
+
+    fn generate_assignment(&mut self, assignment: &BoundAssignment) {
+        self.generate_expression(assignment.get_bound_expression());
+        self.emit_store(assignment.get_variable());
+    }
+
+    // The element is stored in place, without copying the array out of its slot.
+    fn generate_index_assignment(&mut self, index_assignment: &BoundIndexAssignment) {
+        let span = index_assignment.get_span();
+        let variable = index_assignment.get_variable();
+        self.generate_expression(index_assignment.get_index());
+        self.generate_expression(index_assignment.get_bound_expression());
+        if variable.is_global() {
+            let slot = self.global_slot(variable);
+            self.emit(Instruction::StoreIndexGlobal(slot, span));
+        } else {
+            let slot = self.declare_local(variable);
+            self.emit(Instruction::StoreIndexLocal(slot, span));
+        }
+    }
+
+    fn generate_field_assignment(&mut self, field_assignment: &BoundFieldAssignment) {
+        self.generate_expression(field_assignment.get_bound_expression());
+        self.emit_load(field_assignment.get_variable(), field_assignment.get_span());
+        self.emit(Instruction::SetField(field_assignment.get_index()));
+        self.emit_store(field_assignment.get_variable());
+    }
+
+    fn generate_if_statement(&mut self, if_statement: &BoundIfStatement) {
+        self.generate_expression(if_statement.get_condition());
+        let jump_to_else = self.emit(Instruction::JumpIfFalse(0));
+
+        self.generate_statement(if_statement.get_then_statement());
+
+        match if_statement.get_else_statement() {
+            Some(else_statement) => {
+                let jump_to_end = self.emit(Instruction::Jump(0));
+                self.patch_jump(jump_to_else);
+                self.generate_statement(else_statement);
+                self.patch_jump(jump_to_end);
+            }
+            None => self.patch_jump(jump_to_else),
+        }
+    }
+
+    fn generate_while_statement(&mut self, while_statement: &BoundWhileStatement) {
+        let start = self.chunk.instructions.len();
+
+        self.generate_expression(while_statement.get_condition());
+        let jump_to_end = self.emit(Instruction::JumpIfFalse(0));
+
+        self.generate_loop_body(while_statement.get_body(), |generator| {
+            generator.emit(Instruction::Jump(start));
+        });
+        self.patch_jump(jump_to_end);
+    }
+
+    // The counter lives in a hidden slot, so assignments to the loop variable in the
+    // body don't change the number of iterations, same as in the tree-walker.
+    fn generate_for_statement(&mut self, for_statement: &BoundForStatement) {
+        let counter = self.add_local(String::new());
+        let upper_bound = self.add_local(String::new());
+        let variable = self.declare_local(for_statement.get_variable());
+
+        self.generate_expression(for_statement.get_lower_bound());
+        self.emit(Instruction::StoreLocal(counter));
+        self.generate_expression(for_statement.get_upper_bound());
+        self.emit(Instruction::StoreLocal(upper_bound));
+
+        let start = self.chunk.instructions.len();
+        self.emit(Instruction::LoadLocal(counter, TextSpan::new(0, 0)));
+        self.emit(Instruction::LoadLocal(upper_bound, TextSpan::new(0, 0)));
+        self.emit(Instruction::Binary(
+            BoundBinaryOperatorKind::Less,
+            TextSpan::new(0, 0),
+        ));
+        let jump_to_end = self.emit(Instruction::JumpIfFalse(0));
+
+        self.emit(Instruction::LoadLocal(counter, TextSpan::new(0, 0)));
+        self.emit(Instruction::StoreLocal(variable));
+        self.generate_loop_body(for_statement.get_body(), |generator| {
+            generator.emit(Instruction::LoadLocal(counter, TextSpan::new(0, 0)));
+            generator.emit(Instruction::Constant(LiteralValue::Integer(1)));
+            generator.emit(Instruction::Binary(
+                BoundBinaryOperatorKind::Addition,
+                TextSpan::new(0, 0),
+            ));
+            generator.emit(Instruction::StoreLocal(counter));
+            generator.emit(Instruction::Jump(start));
+        });
+        self.patch_jump(jump_to_end);
+    }
+
+    // Continue jumps to the code that starts the next iteration, break jumps past it.
+    fn generate_loop_body(
+        &mut self,
+        body: Box<dyn BoundStatement>,
+        next_iteration: impl FnOnce(&mut Self),
+    ) {
+        self.loops.push(LoopJumps::default());
+        self.generate_statement(body);
+
+        let jumps = self.loops.pop().unwrap();
+        let next = self.chunk.instructions.len();
+        for jump in jumps.continues {
+            self.patch_jump_to(jump, next);
+        }
+        next_iteration(self);
+        for jump in jumps.breaks {
+            self.patch_jump(jump);
+        }
+    }
+
+    fn generate_expression_statement(&mut self, expression_statement: &BoundExpressionStatement) {
+        let expression = expression_statement.get_bound_expression();
+
+        match expression.as_any().downcast_ref::<BoundCallExpression>() {
+            Some(call_expression) => self.generate_call_expression(call_expression, true),
+            None => {
+                self.generate_expression(expression);
+                self.emit(Instruction::Pop);
+            }
+        }
+    }
+
+    fn generate_expression(&mut self, expression: Box<dyn BoundExpression>) {
+        match *expression.get_type_of_bound() {
+            BoundKind::BoundLiteralExpression => {
+                let literal_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundLiteralExpression>()
+                    .unwrap();
+                self.emit(Instruction::Constant(literal_expression.get_value()));
+            }
+            BoundKind::BoundVariableExpression => {
+                let variable_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundVariableExpression>()
+                    .unwrap();
+                self.emit_load(
+                    variable_expression.get_variable(),
+                    variable_expression.get_span(),
+                );
+            }
+            BoundKind::BoundUnaryExpression => {
+                let unary_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundUnaryExpression>()
+                    .unwrap();
+                self.generate_expression(unary_expression.get_operand());
+                self.emit(Instruction::Unary(
+                    unary_expression.get_operator().get_kind().clone(),
+                    unary_expression.get_span(),
+                ));
+            }
+            BoundKind::BoundBinaryExpression => {
+                let binary_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundBinaryExpression>()
+                    .unwrap();
+                self.generate_expression(binary_expression.get_left());
+                self.generate_expression(binary_expression.get_right());
+                self.emit(Instruction::Binary(
+                    binary_expression.get_operator().get_kind().clone(),
+                    binary_expression.get_span(),
+                ));
+            }
+            BoundKind::BoundCallExpression => self.generate_call_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundCallExpression>()
+                    .unwrap(),
+                false,
+            ),
+            BoundKind::BoundConditionalExpression => {
+                let conditional_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundConditionalExpression>()
+                    .unwrap();
+                self.generate_expression(conditional_expression.get_condition());
+                let jump_to_false = self.emit(Instruction::JumpIfFalse(0));
+                self.generate_expression(conditional_expression.get_when_true());
+                let jump_to_end = self.emit(Instruction::Jump(0));
+                self.patch_jump(jump_to_false);
+                self.generate_expression(conditional_expression.get_when_false());
+                self.patch_jump(jump_to_end);
+            }
+            BoundKind::BoundArrayLiteralExpression => {
+                let elements = expression
+                    .as_any()
+                    .downcast_ref::<BoundArrayLiteralExpression>()
+                    .unwrap()
+                    .get_elements();
+                let count = elements.len();
+                for element in elements {
+                    self.generate_expression(element);
+                }
+                self.emit(Instruction::MakeArray(count));
+            }
+            BoundKind::BoundIndexExpression => {
+                let index_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundIndexExpression>()
+                    .unwrap();
+                self.generate_expression(index_expression.get_array());
+                self.generate_expression(index_expression.get_index());
+                self.emit(Instruction::Index(index_expression.get_span()));
+            }
+            BoundKind::BoundLengthExpression => {
+                let length_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundLengthExpression>()
+                    .unwrap();
+                self.generate_expression(length_expression.get_array());
+                self.emit(Instruction::Length);
+            }
+            BoundKind::BoundStructLiteralExpression => {
+                let struct_literal_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundStructLiteralExpression>()
+                    .unwrap();
+                for field in struct_literal_expression.get_fields() {
+                    self.generate_expression(field);
+                }
+                self.emit(Instruction::MakeStruct(
+                    struct_literal_expression.get_name(),
+                    struct_literal_expression.get_field_names(),
+                ));
+            }
+            BoundKind::BoundFieldExpression => {
+                let field_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundFieldExpression>()
+                    .unwrap();
+                self.generate_expression(field_expression.get_structure());
+                self.emit(Instruction::GetField(field_expression.get_index()));
+            }
+            _ => panic!("Code generation ERROR: Unexpected bound kind for expression."),
+        }
+    }
+
+    fn generate_call_expression(&mut self, call_expression: &BoundCallExpression, discard: bool) {
+        let arguments = call_expression.get_arguments();
+        let count = arguments.len();
+
+        for argument in arguments {
+            self.generate_expression(argument);
+        }
+        self.emit(Instruction::Call {
+            function: call_expression.get_function().clone(),
+            arguments: count,
+            discard,
+            span: call_expression.get_span(),
+        });
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    binding::{
+        bound_binary_operator_kind::BoundBinaryOperatorKind,
+        bound_unary_operator_kind::BoundUnaryOperatorKind,
+    },
+    reports::text_span::TextSpan,
+    util::{function_symbol::FunctionSymbol, literals::LiteralValue},
+};
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Constant(LiteralValue),
+    LoadLocal(usize, TextSpan),
+    StoreLocal(usize),
+    LoadGlobal(usize, TextSpan),
+    StoreGlobal(usize),
+    Unary(BoundUnaryOperatorKind, TextSpan),
+    Binary(BoundBinaryOperatorKind, TextSpan),
+    // builds an array from the given number of values on top of the stack
+    MakeArray(usize),
+    Index(TextSpan),
+    // pops the value and the index and updates that element of the array in the slot
+    StoreIndexLocal(usize, TextSpan),
+    StoreIndexGlobal(usize, TextSpan),
+    Length,
+    // builds a struct from one value per field, pushed in declaration order
+    MakeStruct(String, Vec<String>),
+    GetField(usize),
+    // pops the struct and the value and pushes the updated struct
+    SetField(usize),
+    Jump(usize),
+    JumpIfFalse(usize),
+    Call {
+        function: FunctionSymbol,
+        arguments: usize,
+        discard: bool,
+        span: TextSpan,
+    },
+    Pop,
+    Return,
+    // reached when a chunk runs off its end without a return
+    End,
+}
@@ -0,0 +1,4 @@
+pub mod chunk;
+pub mod code_generator;
+pub mod instruction;
+pub mod virtual_machine;
@@ -0,0 +1,275 @@
+use std::{cell::RefCell, rc::Rc, time::Instant};
+
+use crate::{
+    environment::Environment,
+    evaluation::{
+        execution_limits::ExecutionLimits,
+        operations::{check_index, evaluate_binary_operation, evaluate_unary_operation},
+        runtime_error::RuntimeError,
+    },
+    reports::text_span::TextSpan,
+    util::{function_symbol::FunctionSymbol, literals::LiteralValue},
+};
+
+use super::{
+    chunk::{Chunk, Module},
+    instruction::Instruction,
+};
+
+// The clock is only checked every so many instructions, reading it is not free.
+const TIME_CHECK_INTERVAL: u64 = 1024;
+
+struct Frame {
+    function: Option<FunctionSymbol>,
+    ip: usize,
+    locals: Vec<Option<LiteralValue>>,
+    discard: bool,
+    span: TextSpan,
+}
+
+pub struct VirtualMachine {
+    module: Module,
+    limits: ExecutionLimits,
+    environment: Rc<RefCell<Environment>>,
+    globals: Vec<Option<LiteralValue>>,
+    stack: Vec<LiteralValue>,
+    frames: Vec<Frame>,
+}
+
+impl VirtualMachine {
+    pub fn new(
+        module: Module,
+        limits: ExecutionLimits,
+        environment: Rc<RefCell<Environment>>,
+    ) -> Self {
+        let globals = vec![None; module.globals.len()];
+        Self {
+            module,
+            limits,
+            environment,
+            globals,
+            stack: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn run(&mut self) -> Result<(), RuntimeError> {
+        self.stack.clear();
+        self.frames.clear();
+        self.frames.push(Frame {
+            function: None,
+            ip: 0,
+            locals: vec![None; self.module.main.locals.len()],
+            discard: true,
+            span: TextSpan::new(0, 0),
+        });
+
+        let result = self.execute();
+        self.flush_globals();
+        result
+    }
+
+    fn flush_globals(&mut self) {
+        let mut environment = self.environment.borrow_mut();
+        for (variable, value) in self.module.globals.iter().zip(self.globals.iter()) {
+            if value.is_some() {
+                environment.insert(variable, value.clone());
+            }
+        }
+    }
+
+    fn chunk(&self, function: &Option<FunctionSymbol>) -> &Chunk {
+        match function {
+            Some(function) => self.module.functions[function.index()].as_ref().unwrap(),
+            None => &self.module.main,
+        }
+    }
+
+    fn pop(&mut self) -> LiteralValue {
+        self.stack.pop().unwrap()
+    }
+
+    fn execute(&mut self) -> Result<(), RuntimeError> {
+        let start = Instant::now();
+        let mut steps: u64 = 0;
+
+        loop {
+            steps += 1;
+            if steps > self.limits.max_steps {
+                return Err(RuntimeError::StepLimitExceeded(self.limits.max_steps));
+            }
+            if steps.is_multiple_of(TIME_CHECK_INTERVAL) && start.elapsed() > self.limits.timeout()
+            {
+                return Err(RuntimeError::TimeLimitExceeded(self.limits.timeout_ms));
+            }
+
+            let frame = self.frames.last_mut().unwrap();
+            let ip = frame.ip;
+            frame.ip += 1;
+            let function = frame.function.clone();
+            let instruction = self.chunk(&function).instructions[ip].clone();
+
+            match instruction {
+                Instruction::Constant(value) => self.stack.push(value),
+                Instruction::LoadLocal(slot, span) => {
+                    match self.frames.last().unwrap().locals[slot].clone() {
+                        Some(value) => self.stack.push(value),
+                        None => {
+                            let name = self.chunk(&function).locals[slot].clone();
+                            return Err(RuntimeError::UninitializedVariable(name, span));
+                        }
+                    }
+                }
+                Instruction::StoreLocal(slot) => {
+                    let value = self.pop();
+                    self.frames.last_mut().unwrap().locals[slot] = Some(value);
+                }
+                Instruction::LoadGlobal(slot, span) => match self.globals[slot].clone() {
+                    Some(value) => self.stack.push(value),
+                    None => {
+                        return Err(RuntimeError::UninitializedVariable(
+                            self.module.globals[slot].id(),
+                            span,
+                        ))
+                    }
+                },
+                Instruction::StoreGlobal(slot) => {
+                    let value = self.pop();
+                    self.globals[slot] = Some(value);
+                }
+                Instruction::Unary(kind, span) => {
+                    let operand = self.pop();
+                    let result = evaluate_unary_operation(&kind, operand, &span)?;
+                    self.stack.push(result);
+                }
+                Instruction::Binary(kind, span) => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    let result = evaluate_binary_operation(&kind, left, right, &span)?;
+                    self.stack.push(result);
+                }
+                Instruction::MakeArray(count) => {
+                    let first = self.stack.len() - count;
+                    let elements = self.stack.drain(first..).collect();
+                    self.stack.push(LiteralValue::Array(elements));
+                }
+                Instruction::Index(span) => {
+                    let index = self.pop();
+                    let mut elements = self.pop().as_array().unwrap();
+                    let index = check_index(&index, elements.len(), &span)?;
+                    self.stack.push(elements.swap_remove(index));
+                }
+                Instruction::StoreIndexLocal(slot, span) => {
+                    let value = self.pop();
+                    let index = self.pop();
+                    match &mut self.frames.last_mut().unwrap().locals[slot] {
+                        Some(LiteralValue::Array(elements)) => {
+                            let index = check_index(&index, elements.len(), &span)?;
+                            elements[index] = value;
+                        }
+                        _ => {
+                            let name = self.chunk(&function).locals[slot].clone();
+                            return Err(RuntimeError::UninitializedVariable(name, span));
+                        }
+                    }
+                }
+                Instruction::StoreIndexGlobal(slot, span) => {
+                    let value = self.pop();
+                    let index = self.pop();
+                    match &mut self.globals[slot] {
+                        Some(LiteralValue::Array(elements)) => {
+                            let index = check_index(&index, elements.len(), &span)?;
+                            elements[index] = value;
+                        }
+                        _ => {
+                            return Err(RuntimeError::UninitializedVariable(
+                                self.module.globals[slot].id(),
+                                span,
+                            ))
+                        }
+                    }
+                }
+                Instruction::Length => {
+                    let length = self.pop().as_array().unwrap().len();
+                    self.stack.push(LiteralValue::Integer(length as i32));
+                }
+                Instruction::MakeStruct(name, field_names) => {
+                    let first = self.stack.len() - field_names.len();
+                    let values = self.stack.drain(first..);
+                    let fields = field_names.into_iter().zip(values).collect();
+                    self.stack.push(LiteralValue::Struct(name, fields));
+                }
+                Instruction::GetField(index) => {
+                    let mut fields = self.pop().as_struct().unwrap();
+                    self.stack.push(fields.swap_remove(index).1);
+                }
+                Instruction::SetField(index) => {
+                    let (name, mut fields) = match self.pop() {
+                        LiteralValue::Struct(name, fields) => (name, fields),
+                        _ => unreachable!(),
+                    };
+                    fields[index].1 = self.pop();
+                    self.stack.push(LiteralValue::Struct(name, fields));
+                }
+                Instruction::Jump(address) => self.frames.last_mut().unwrap().ip = address,
+                Instruction::JumpIfFalse(address) => {
+                    if !self.pop().as_boolean().unwrap() {
+                        self.frames.last_mut().unwrap().ip = address;
+                    }
+                }
+                Instruction::Call {
+                    function,
+                    arguments,
+                    discard,
+                    span,
+                } => {
+                    // The main frame is not a call.
+                    if self.frames.len() as u64 > self.limits.max_call_depth {
+                        return Err(RuntimeError::CallDepthExceeded(
+                            self.limits.max_call_depth,
+                            span,
+                        ));
+                    }
+                    let mut locals = vec![None; self.chunk(&Some(function.clone())).locals.len()];
+                    let first = self.stack.len() - arguments;
+                    for (slot, argument) in self.stack.drain(first..).enumerate() {
+                        locals[slot] = Some(argument);
+                    }
+                    self.frames.push(Frame {
+                        function: Some(function),
+                        ip: 0,
+                        locals,
+                        discard,
+                        span,
+                    });
+                }
+                Instruction::Pop => {
+                    self.pop();
+                }
+                Instruction::Return => {
+                    let value = self.pop();
+                    let frame = self.frames.pop().unwrap();
+                    if frame.function.is_none() {
+                        return Ok(());
+                    }
+                    if !frame.discard {
+                        self.stack.push(value);
+                    }
+                }
+                Instruction::End => {
+                    let frame = self.frames.pop().unwrap();
+                    match frame.function {
+                        None => return Ok(()),
+                        Some(function) if !frame.discard => {
+                            return Err(RuntimeError::MissingReturnValue(
+                                function.id(),
+                                frame.span,
+                            ));
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    cell::RefCell,
+    fs,
+    io::{self, Read},
+    rc::Rc,
+};
+
+use crate::{
+    bind_program, compile_program,
+    environment::Environment,
+    evaluation::execution_limits::ExecutionLimits,
+    format_program,
+    language_server::server::LanguageServer,
+    lexical_analyzer::lexer::Lexer,
+    parse_program,
+    repl::Repl,
+    reports::{
+        diagnostics::Diagnostics, renderer::render, source_map::SourceMap, text_type::TextType,
+    },
+    util::{backend_type::BackendType, parser_type::ParserType, syntax_kind::SyntaxKind},
+    Report,
+};
+
+const USAGE: &str = "Usage: compiler [serve]
+       compiler <command> [options] [file]
+
+Commands:
+    run       Compile and evaluate the program, then print the symbol table
+    check     Report diagnostics without evaluating the program
+    tokens    Print the tokens produced by the lexer
+    ast       Print the syntax tree
+    bound     Print the bound tree and the bound functions
+    format    Print the program with canonical spacing and indentation
+    repl      Start an interactive session
+    lsp       Start a language server on standard input and output
+
+Options:
+    --parser recursive|lr                   Parser to use (default: recursive)
+    --backend tree-walker|bytecode|assembly Backend used by run (default: tree-walker)
+
+The program is read from standard input when no file is given.";
+
+struct Options {
+    command: String,
+    file: Option<String>,
+    parser: ParserType,
+    backend: BackendType,
+}
+
+// Runs a command line invocation and returns the process exit code.
+pub fn run(arguments: &[String]) -> i32 {
+    if arguments
+        .iter()
+        .any(|argument| argument == "-h" || argument == "--help")
+    {
+        println!("{}", USAGE);
+        return 0;
+    }
+
+    let options = match parse_arguments(arguments) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return 2;
+        }
+    };
+
+    if options.command == "repl" {
+        Repl::new(options.parser).run();
+        return 0;
+    }
+
+    if options.command == "lsp" {
+        let mut reader = io::stdin().lock();
+        return match LanguageServer::new().run(&mut reader, &mut io::stdout()) {
+            Ok(exit_code) => exit_code,
+            Err(error) => {
+                eprintln!("{}", error);
+                1
+            }
+        };
+    }
+
+    let code = match read_source(&options.file) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!(
+                "Could not read {}: {}",
+                options.file.as_deref().unwrap_or("standard input"),
+                error
+            );
+            return 2;
+        }
+    };
+
+    let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+    let environment = Rc::new(RefCell::new(Environment::new()));
+
+    match options.command.as_str() {
+        "run" => {
+            compile_program(
+                Rc::clone(&diagnostics),
+                Rc::clone(&environment),
+                &code,
+                &options.parser,
+                &options.backend,
+                &ExecutionLimits::default(),
+            );
+
+            print_symbol_table(&environment.borrow());
+        }
+        "check" => {
+            bind_program(
+                Rc::clone(&diagnostics),
+                Rc::clone(&environment),
+                &code,
+                &options.parser,
+            );
+        }
+        "tokens" => print_tokens(Rc::clone(&diagnostics), &code),
+        "ast" => {
+            if let Some(root) = parse_program(Rc::clone(&diagnostics), &code, &options.parser) {
+                println!("{:#?}", root);
+            }
+        }
+        "format" => {
+            if let Some(formatted) = format_program(Rc::clone(&diagnostics), &code) {
+                print!("{}", formatted);
+            }
+        }
+        "bound" => {
+            if let Some((root, functions, _)) = bind_program(
+                Rc::clone(&diagnostics),
+                Rc::clone(&environment),
+                &code,
+                &options.parser,
+            ) {
+                println!("{:#?}", root);
+                for (index, function) in functions.iter().enumerate() {
+                    println!("function {}: {:#?}", index, function);
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    let exit_code = report_errors(&diagnostics.borrow(), &code);
+    exit_code
+}
+
+fn parse_arguments(arguments: &[String]) -> Result<Options, String> {
+    let mut positional = Vec::new();
+    let mut parser = ParserType::Recursive;
+    let mut backend = BackendType::TreeWalker;
+
+    let mut arguments = arguments.iter();
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--parser" => {
+                parser = match arguments.next().map(|value| value.as_str()) {
+                    Some("recursive") => ParserType::Recursive,
+                    Some("lr") => ParserType::Lr,
+                    value => return Err(format!("Unknown parser {:?}.", value.unwrap_or(""))),
+                }
+            }
+            "--backend" => {
+                backend = match arguments.next().map(|value| value.as_str()) {
+                    Some("tree-walker") => BackendType::TreeWalker,
+                    Some("bytecode") => BackendType::Bytecode,
+                    Some("assembly") => BackendType::Assembly,
+                    value => return Err(format!("Unknown backend {:?}.", value.unwrap_or(""))),
+                }
+            }
+            option if option.starts_with("--") => {
+                return Err(format!("Unknown option {}.", option))
+            }
+            _ => positional.push(argument.clone()),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let command = positional.next().unwrap_or_default();
+    if ![
+        "run", "check", "tokens", "ast", "bound", "format", "repl", "lsp",
+    ]
+    .contains(&command.as_str())
+    {
+        return Err(format!("Unknown command {:?}.", command));
+    }
+
+    let file = positional.next();
+    if let Some(extra) = positional.next() {
+        return Err(format!("Unexpected argument {}.", extra));
+    }
+
+    Ok(Options {
+        command,
+        file,
+        parser,
+        backend,
+    })
+}
+
+fn read_source(file: &Option<String>) -> io::Result<String> {
+    match file {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut code = String::new();
+            io::stdin().read_to_string(&mut code)?;
+            Ok(code)
+        }
+    }
+}
+
+fn print_tokens(diagnostics: Rc<RefCell<Diagnostics>>, code: &str) {
+    diagnostics.borrow_mut().set_source(code);
+    let source_map = SourceMap::new(code);
+    let mut lexer = Lexer::in_memory_reader(code, diagnostics);
+
+    loop {
+        let token = lexer.next_token();
+        let kind = *token.kind();
+
+        if kind != SyntaxKind::WhiteSpace {
+            let (line, column) = source_map.location(token.position());
+            println!("{}:{} {:?} {:?}", line, column, kind, token.name());
+        }
+        if kind == SyntaxKind::Eof || kind == SyntaxKind::BadToken {
+            break;
+        }
+    }
+}
+
+pub fn print_symbol_table(environment: &Environment) {
+    let mut report = Report::new(Vec::new());
+    report.report_symbol_table(environment);
+    report.symbol_table.sort_by(|a, b| a.id.cmp(&b.id));
+    for pair in &report.symbol_table {
+        println!("{} = {}", pair.id, pair.value);
+    }
+}
+
+// Renders the errors to standard error; informational messages are left out.
+pub fn report_errors(diagnostics: &Diagnostics, code: &str) -> i32 {
+    let source_map = SourceMap::new(code);
+    let errors = diagnostics.filter_type(TextType::Error);
+
+    for error in &errors {
+        eprintln!("{}\n", render(error, &source_map));
+    }
+
+    if errors.is_empty() {
+        0
+    } else {
+        1
+    }
+}
@@ -18,22 +18,32 @@ use rustemo::debug::{log, logn};
 #[cfg(debug_assertions)]
 use colored::*;
 pub type Input = str;
-const STATE_COUNT: usize = 87usize;
-const MAX_RECOGNIZERS: usize = 15usize;
+const STATE_COUNT: usize = 236usize;
+const MAX_RECOGNIZERS: usize = 30usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 31usize;
+const TERMINAL_COUNT: usize = 62usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
     #[default]
     STOP,
     Number,
+    StringLiteral,
     Plus,
     Minus,
     Mul,
     Div,
-    True,
-    False,
+    Percent,
+    StarStar,
+    PlusEquals,
+    MinusEquals,
+    MulEquals,
+    DivEquals,
+    PercentEquals,
+    PlusPlus,
+    MinusMinus,
+    TrueKeyword,
+    FalseKeyword,
     OpenParenthesis,
     CloseParenthesis,
     Bang,
@@ -45,18 +55,39 @@ pub enum TokenKind {
     GreaterOrEquals,
     AmpersandAmpersand,
     PipePipe,
+    Ampersand,
+    Pipe,
+    Caret,
+    Tilde,
+    LessLess,
+    GreaterGreater,
     Identifier,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
     Equals,
     SemiColon,
-    If,
-    Else,
-    Let,
-    Const,
-    While,
-    For,
-    To,
+    IfKeyword,
+    ElseKeyword,
+    LetKeyword,
+    ConstKeyword,
+    WhileKeyword,
+    ForKeyword,
+    ToKeyword,
+    FnKeyword,
+    ReturnKeyword,
+    BreakKeyword,
+    ContinueKeyword,
+    Comma,
+    Dot,
+    Colon,
+    StructKeyword,
+    WhiteSpace,
+    LineComment,
+    CommentStart,
+    CommentEnd,
+    NotComment,
 }
 use TokenKind as TK;
 impl From<TokenKind> for usize {
@@ -71,6 +102,8 @@ pub enum ProdKind {
     StatementListP1,
     Statement1P1,
     Statement1P2,
+    Statement0P1,
+    Statement0P2,
     StatementP1,
     StatementP2,
     StatementP3,
@@ -78,75 +111,250 @@ pub enum ProdKind {
     StatementP5,
     StatementP6,
     StatementP7,
+    StatementP8,
+    StatementP9,
+    StatementP10,
+    StatementP11,
+    StatementP12,
+    StatementP13,
+    StatementP14,
+    StatementP15,
+    StatementP16,
     AssignmentAssignment,
+    AssignmentCompoundAssignment,
+    CompoundOperatorP1,
+    CompoundOperatorP2,
+    CompoundOperatorP3,
+    CompoundOperatorP4,
+    CompoundOperatorP5,
+    IncrementStatementIncrementStatement,
+    IncrementOperatorP1,
+    IncrementOperatorP2,
+    IndexAssignmentIndexAssignment,
+    IndexAssignmentCompoundIndexAssignment,
+    FieldAssignmentFieldAssignment,
+    FieldAssignmentCompoundFieldAssignment,
     IfStatementIfStatement,
     ElseClauseOptP1,
     ElseClauseOptP2,
     ElseClauseElseStatement,
+    ElseClauseElseIf,
     VariableDeclarationVariableDeclaration,
     ConstantDeclarationConstantDeclaration,
     WhileStatementWhile,
     ForStatementFor,
+    FunctionDeclarationFunctionDeclaration,
+    Parameter1P1,
+    Parameter1P2,
+    Parameter0P1,
+    Parameter0P2,
+    TypeClauseOptP1,
+    TypeClauseOptP2,
+    ParameterParameter,
+    StructDeclarationStructDeclaration,
+    FieldDeclaration1P1,
+    FieldDeclaration1P2,
+    FieldDeclaration0P1,
+    FieldDeclaration0P2,
+    FieldDeclarationFieldDeclaration,
+    TypeClauseTypeClause,
+    TypeNameTypeName,
+    TypeNameArrayTypeName,
+    ReturnStatementReturnStatement,
+    BreakStatementBreakStatement,
+    ContinueStatementContinueStatement,
+    ExpressionStatementExpressionStatement,
+    CallExpressionCallExpression,
+    Expression1P1,
+    Expression1P2,
+    Expression0P1,
+    Expression0P2,
     ExpressionAdd,
     ExpressionSub,
     ExpressionMul,
     ExpressionDiv,
+    ExpressionModulo,
+    ExpressionPower,
+    ExpressionShiftLeft,
+    ExpressionShiftRight,
     ExpressionEquals,
     ExpressionNotEquals,
     ExpressionLess,
     ExpressionLessOrEquals,
     ExpressionGreater,
     ExpressionGreaterOrEquals,
+    ExpressionBitwiseAnd,
+    ExpressionBitwiseXor,
+    ExpressionBitwiseOr,
     ExpressionAnd,
     ExpressionOr,
+    ExpressionConditional,
     ExpressionUnaryMinus,
     ExpressionUnaryNegation,
-    ExpressionP15,
-    ExpressionP16,
-    ExpressionP17,
-    ExpressionP18,
-    ExpressionP19,
+    ExpressionUnaryBitwiseNot,
+    ExpressionIndex,
+    ExpressionArrayLiteral,
+    ExpressionField,
+    ExpressionStructLiteral,
+    FieldInitializer1P1,
+    FieldInitializer1P2,
+    FieldInitializer0P1,
+    FieldInitializer0P2,
+    ExpressionP28,
+    ExpressionP29,
+    ExpressionP30,
+    ExpressionTrue,
+    ExpressionFalse,
+    ExpressionP33,
+    ExpressionP34,
+    FieldInitializerFieldInitializer,
+    LayoutP1,
+    LayoutItem1P1,
+    LayoutItem1P2,
+    LayoutItem0P1,
+    LayoutItem0P2,
+    LayoutItemP1,
+    LayoutItemP2,
+    CommentP1,
+    CommentP2,
+    BlockCommentP1,
+    CommentContent1P1,
+    CommentContent1P2,
+    CommentContent0P1,
+    CommentContent0P2,
+    CommentContentP1,
+    CommentContentP2,
+    CommentContentP3,
 }
 use ProdKind as PK;
 impl std::fmt::Debug for ProdKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = match self {
             ProdKind::ProgramP1 => "Program: StatementList",
-            ProdKind::StatementListP1 => "StatementList: OpenBrace Statement1 CloseBrace",
+            ProdKind::StatementListP1 => "StatementList: OpenBrace Statement0 CloseBrace",
             ProdKind::Statement1P1 => "Statement1: Statement1 Statement",
             ProdKind::Statement1P2 => "Statement1: Statement",
+            ProdKind::Statement0P1 => "Statement0: Statement1",
+            ProdKind::Statement0P2 => "Statement0: ",
             ProdKind::StatementP1 => "Statement: Assignment",
             ProdKind::StatementP2 => "Statement: IfStatement",
             ProdKind::StatementP3 => "Statement: VariableDeclaration",
             ProdKind::StatementP4 => "Statement: ConstantDeclaration",
             ProdKind::StatementP5 => "Statement: WhileStatement",
             ProdKind::StatementP6 => "Statement: ForStatement",
-            ProdKind::StatementP7 => "Statement: StatementList",
+            ProdKind::StatementP7 => "Statement: FunctionDeclaration",
+            ProdKind::StatementP8 => "Statement: ReturnStatement",
+            ProdKind::StatementP9 => "Statement: IncrementStatement",
+            ProdKind::StatementP10 => "Statement: IndexAssignment",
+            ProdKind::StatementP11 => "Statement: FieldAssignment",
+            ProdKind::StatementP12 => "Statement: StructDeclaration",
+            ProdKind::StatementP13 => "Statement: BreakStatement",
+            ProdKind::StatementP14 => "Statement: ContinueStatement",
+            ProdKind::StatementP15 => "Statement: ExpressionStatement",
+            ProdKind::StatementP16 => "Statement: StatementList",
             ProdKind::AssignmentAssignment => {
                 "Assignment: Identifier Equals Expression SemiColon"
             }
+            ProdKind::AssignmentCompoundAssignment => {
+                "Assignment: Identifier CompoundOperator Expression SemiColon"
+            }
+            ProdKind::CompoundOperatorP1 => "CompoundOperator: PlusEquals",
+            ProdKind::CompoundOperatorP2 => "CompoundOperator: MinusEquals",
+            ProdKind::CompoundOperatorP3 => "CompoundOperator: MulEquals",
+            ProdKind::CompoundOperatorP4 => "CompoundOperator: DivEquals",
+            ProdKind::CompoundOperatorP5 => "CompoundOperator: PercentEquals",
+            ProdKind::IncrementStatementIncrementStatement => {
+                "IncrementStatement: Identifier IncrementOperator SemiColon"
+            }
+            ProdKind::IncrementOperatorP1 => "IncrementOperator: PlusPlus",
+            ProdKind::IncrementOperatorP2 => "IncrementOperator: MinusMinus",
+            ProdKind::IndexAssignmentIndexAssignment => {
+                "IndexAssignment: Identifier OpenBracket Expression CloseBracket Equals Expression SemiColon"
+            }
+            ProdKind::IndexAssignmentCompoundIndexAssignment => {
+                "IndexAssignment: Identifier OpenBracket Expression CloseBracket CompoundOperator Expression SemiColon"
+            }
+            ProdKind::FieldAssignmentFieldAssignment => {
+                "FieldAssignment: Identifier Dot Identifier Equals Expression SemiColon"
+            }
+            ProdKind::FieldAssignmentCompoundFieldAssignment => {
+                "FieldAssignment: Identifier Dot Identifier CompoundOperator Expression SemiColon"
+            }
             ProdKind::IfStatementIfStatement => {
-                "IfStatement: If OpenParenthesis Expression CloseParenthesis StatementList ElseClauseOpt"
+                "IfStatement: IfKeyword OpenParenthesis Expression CloseParenthesis StatementList ElseClauseOpt"
             }
             ProdKind::ElseClauseOptP1 => "ElseClauseOpt: ElseClause",
             ProdKind::ElseClauseOptP2 => "ElseClauseOpt: ",
-            ProdKind::ElseClauseElseStatement => "ElseClause: Else StatementList",
+            ProdKind::ElseClauseElseStatement => "ElseClause: ElseKeyword StatementList",
+            ProdKind::ElseClauseElseIf => "ElseClause: ElseKeyword IfStatement",
             ProdKind::VariableDeclarationVariableDeclaration => {
-                "VariableDeclaration: Let Identifier Equals Expression SemiColon"
+                "VariableDeclaration: LetKeyword Identifier Equals Expression SemiColon"
             }
             ProdKind::ConstantDeclarationConstantDeclaration => {
-                "ConstantDeclaration: Const Identifier Equals Expression SemiColon"
+                "ConstantDeclaration: ConstKeyword Identifier Equals Expression SemiColon"
             }
             ProdKind::WhileStatementWhile => {
-                "WhileStatement: While OpenParenthesis Expression CloseParenthesis StatementList"
+                "WhileStatement: WhileKeyword OpenParenthesis Expression CloseParenthesis StatementList"
             }
             ProdKind::ForStatementFor => {
-                "ForStatement: For OpenParenthesis Identifier Equals Expression To Expression CloseParenthesis StatementList"
+                "ForStatement: ForKeyword OpenParenthesis Identifier Equals Expression ToKeyword Expression CloseParenthesis StatementList"
+            }
+            ProdKind::FunctionDeclarationFunctionDeclaration => {
+                "FunctionDeclaration: FnKeyword Identifier OpenParenthesis Parameter0 CloseParenthesis TypeClauseOpt StatementList"
+            }
+            ProdKind::Parameter1P1 => "Parameter1: Parameter1 Comma Parameter",
+            ProdKind::Parameter1P2 => "Parameter1: Parameter",
+            ProdKind::Parameter0P1 => "Parameter0: Parameter1",
+            ProdKind::Parameter0P2 => "Parameter0: ",
+            ProdKind::TypeClauseOptP1 => "TypeClauseOpt: TypeClause",
+            ProdKind::TypeClauseOptP2 => "TypeClauseOpt: ",
+            ProdKind::ParameterParameter => "Parameter: Identifier TypeClauseOpt",
+            ProdKind::StructDeclarationStructDeclaration => {
+                "StructDeclaration: StructKeyword Identifier OpenBrace FieldDeclaration0 CloseBrace"
+            }
+            ProdKind::FieldDeclaration1P1 => {
+                "FieldDeclaration1: FieldDeclaration1 Comma FieldDeclaration"
+            }
+            ProdKind::FieldDeclaration1P2 => "FieldDeclaration1: FieldDeclaration",
+            ProdKind::FieldDeclaration0P1 => "FieldDeclaration0: FieldDeclaration1",
+            ProdKind::FieldDeclaration0P2 => "FieldDeclaration0: ",
+            ProdKind::FieldDeclarationFieldDeclaration => {
+                "FieldDeclaration: Identifier TypeClause"
+            }
+            ProdKind::TypeClauseTypeClause => "TypeClause: Colon TypeName",
+            ProdKind::TypeNameTypeName => "TypeName: Identifier",
+            ProdKind::TypeNameArrayTypeName => {
+                "TypeName: OpenBracket TypeName CloseBracket"
+            }
+            ProdKind::ReturnStatementReturnStatement => {
+                "ReturnStatement: ReturnKeyword Expression SemiColon"
+            }
+            ProdKind::BreakStatementBreakStatement => {
+                "BreakStatement: BreakKeyword SemiColon"
+            }
+            ProdKind::ContinueStatementContinueStatement => {
+                "ContinueStatement: ContinueKeyword SemiColon"
+            }
+            ProdKind::ExpressionStatementExpressionStatement => {
+                "ExpressionStatement: CallExpression SemiColon"
+            }
+            ProdKind::CallExpressionCallExpression => {
+                "CallExpression: Identifier OpenParenthesis Expression0 CloseParenthesis"
             }
+            ProdKind::Expression1P1 => "Expression1: Expression1 Comma Expression",
+            ProdKind::Expression1P2 => "Expression1: Expression",
+            ProdKind::Expression0P1 => "Expression0: Expression1",
+            ProdKind::Expression0P2 => "Expression0: ",
             ProdKind::ExpressionAdd => "Expression: Expression Plus Expression",
             ProdKind::ExpressionSub => "Expression: Expression Minus Expression",
             ProdKind::ExpressionMul => "Expression: Expression Mul Expression",
             ProdKind::ExpressionDiv => "Expression: Expression Div Expression",
+            ProdKind::ExpressionModulo => "Expression: Expression Percent Expression",
+            ProdKind::ExpressionPower => "Expression: Expression StarStar Expression",
+            ProdKind::ExpressionShiftLeft => "Expression: Expression LessLess Expression",
+            ProdKind::ExpressionShiftRight => {
+                "Expression: Expression GreaterGreater Expression"
+            }
             ProdKind::ExpressionEquals => {
                 "Expression: Expression EqualsEquals Expression"
             }
@@ -161,19 +369,70 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::ExpressionGreaterOrEquals => {
                 "Expression: Expression GreaterOrEquals Expression"
             }
+            ProdKind::ExpressionBitwiseAnd => {
+                "Expression: Expression Ampersand Expression"
+            }
+            ProdKind::ExpressionBitwiseXor => "Expression: Expression Caret Expression",
+            ProdKind::ExpressionBitwiseOr => "Expression: Expression Pipe Expression",
             ProdKind::ExpressionAnd => {
                 "Expression: Expression AmpersandAmpersand Expression"
             }
             ProdKind::ExpressionOr => "Expression: Expression PipePipe Expression",
+            ProdKind::ExpressionConditional => {
+                "Expression: IfKeyword OpenParenthesis Expression CloseParenthesis Expression ElseKeyword Expression"
+            }
             ProdKind::ExpressionUnaryMinus => "Expression: Minus Expression",
             ProdKind::ExpressionUnaryNegation => "Expression: Bang Expression",
-            ProdKind::ExpressionP15 => {
+            ProdKind::ExpressionUnaryBitwiseNot => "Expression: Tilde Expression",
+            ProdKind::ExpressionIndex => {
+                "Expression: Expression OpenBracket Expression CloseBracket"
+            }
+            ProdKind::ExpressionArrayLiteral => {
+                "Expression: OpenBracket Expression0 CloseBracket"
+            }
+            ProdKind::ExpressionField => "Expression: Expression Dot Identifier",
+            ProdKind::ExpressionStructLiteral => {
+                "Expression: Identifier OpenBrace FieldInitializer0 CloseBrace"
+            }
+            ProdKind::FieldInitializer1P1 => {
+                "FieldInitializer1: FieldInitializer1 Comma FieldInitializer"
+            }
+            ProdKind::FieldInitializer1P2 => "FieldInitializer1: FieldInitializer",
+            ProdKind::FieldInitializer0P1 => "FieldInitializer0: FieldInitializer1",
+            ProdKind::FieldInitializer0P2 => "FieldInitializer0: ",
+            ProdKind::ExpressionP28 => {
                 "Expression: OpenParenthesis Expression CloseParenthesis"
             }
-            ProdKind::ExpressionP16 => "Expression: Number",
-            ProdKind::ExpressionP17 => "Expression: True",
-            ProdKind::ExpressionP18 => "Expression: False",
-            ProdKind::ExpressionP19 => "Expression: Identifier",
+            ProdKind::ExpressionP29 => "Expression: Number",
+            ProdKind::ExpressionP30 => "Expression: StringLiteral",
+            ProdKind::ExpressionTrue => "Expression: TrueKeyword",
+            ProdKind::ExpressionFalse => "Expression: FalseKeyword",
+            ProdKind::ExpressionP33 => "Expression: CallExpression",
+            ProdKind::ExpressionP34 => "Expression: Identifier",
+            ProdKind::FieldInitializerFieldInitializer => {
+                "FieldInitializer: Identifier Colon Expression"
+            }
+            ProdKind::LayoutP1 => "Layout: LayoutItem0",
+            ProdKind::LayoutItem1P1 => "LayoutItem1: LayoutItem1 LayoutItem",
+            ProdKind::LayoutItem1P2 => "LayoutItem1: LayoutItem",
+            ProdKind::LayoutItem0P1 => "LayoutItem0: LayoutItem1",
+            ProdKind::LayoutItem0P2 => "LayoutItem0: ",
+            ProdKind::LayoutItemP1 => "LayoutItem: WhiteSpace",
+            ProdKind::LayoutItemP2 => "LayoutItem: Comment",
+            ProdKind::CommentP1 => "Comment: BlockComment",
+            ProdKind::CommentP2 => "Comment: LineComment",
+            ProdKind::BlockCommentP1 => {
+                "BlockComment: CommentStart CommentContent0 CommentEnd"
+            }
+            ProdKind::CommentContent1P1 => {
+                "CommentContent1: CommentContent1 CommentContent"
+            }
+            ProdKind::CommentContent1P2 => "CommentContent1: CommentContent",
+            ProdKind::CommentContent0P1 => "CommentContent0: CommentContent1",
+            ProdKind::CommentContent0P2 => "CommentContent0: ",
+            ProdKind::CommentContentP1 => "CommentContent: BlockComment",
+            ProdKind::CommentContentP2 => "CommentContent: NotComment",
+            ProdKind::CommentContentP3 => "CommentContent: WhiteSpace",
         };
         write!(f, "{}", name)
     }
@@ -184,11 +443,18 @@ impl std::fmt::Debug for ProdKind {
 pub enum NonTermKind {
     EMPTY,
     AUG,
+    AUGL,
     Program,
     StatementList,
     Statement1,
+    Statement0,
     Statement,
     Assignment,
+    CompoundOperator,
+    IncrementStatement,
+    IncrementOperator,
+    IndexAssignment,
+    FieldAssignment,
     IfStatement,
     ElseClauseOpt,
     ElseClause,
@@ -196,7 +462,37 @@ pub enum NonTermKind {
     ConstantDeclaration,
     WhileStatement,
     ForStatement,
+    FunctionDeclaration,
+    Parameter1,
+    Parameter0,
+    TypeClauseOpt,
+    Parameter,
+    StructDeclaration,
+    FieldDeclaration1,
+    FieldDeclaration0,
+    FieldDeclaration,
+    TypeClause,
+    TypeName,
+    ReturnStatement,
+    BreakStatement,
+    ContinueStatement,
+    ExpressionStatement,
+    CallExpression,
+    Expression1,
+    Expression0,
     Expression,
+    FieldInitializer1,
+    FieldInitializer0,
+    FieldInitializer,
+    Layout,
+    LayoutItem1,
+    LayoutItem0,
+    LayoutItem,
+    Comment,
+    BlockComment,
+    CommentContent1,
+    CommentContent0,
+    CommentContent,
 }
 impl From<ProdKind> for NonTermKind {
     fn from(prod: ProdKind) -> Self {
@@ -205,6 +501,8 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::StatementListP1 => NonTermKind::StatementList,
             ProdKind::Statement1P1 => NonTermKind::Statement1,
             ProdKind::Statement1P2 => NonTermKind::Statement1,
+            ProdKind::Statement0P1 => NonTermKind::Statement0,
+            ProdKind::Statement0P2 => NonTermKind::Statement0,
             ProdKind::StatementP1 => NonTermKind::Statement,
             ProdKind::StatementP2 => NonTermKind::Statement,
             ProdKind::StatementP3 => NonTermKind::Statement,
@@ -212,11 +510,40 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::StatementP5 => NonTermKind::Statement,
             ProdKind::StatementP6 => NonTermKind::Statement,
             ProdKind::StatementP7 => NonTermKind::Statement,
+            ProdKind::StatementP8 => NonTermKind::Statement,
+            ProdKind::StatementP9 => NonTermKind::Statement,
+            ProdKind::StatementP10 => NonTermKind::Statement,
+            ProdKind::StatementP11 => NonTermKind::Statement,
+            ProdKind::StatementP12 => NonTermKind::Statement,
+            ProdKind::StatementP13 => NonTermKind::Statement,
+            ProdKind::StatementP14 => NonTermKind::Statement,
+            ProdKind::StatementP15 => NonTermKind::Statement,
+            ProdKind::StatementP16 => NonTermKind::Statement,
             ProdKind::AssignmentAssignment => NonTermKind::Assignment,
+            ProdKind::AssignmentCompoundAssignment => NonTermKind::Assignment,
+            ProdKind::CompoundOperatorP1 => NonTermKind::CompoundOperator,
+            ProdKind::CompoundOperatorP2 => NonTermKind::CompoundOperator,
+            ProdKind::CompoundOperatorP3 => NonTermKind::CompoundOperator,
+            ProdKind::CompoundOperatorP4 => NonTermKind::CompoundOperator,
+            ProdKind::CompoundOperatorP5 => NonTermKind::CompoundOperator,
+            ProdKind::IncrementStatementIncrementStatement => {
+                NonTermKind::IncrementStatement
+            }
+            ProdKind::IncrementOperatorP1 => NonTermKind::IncrementOperator,
+            ProdKind::IncrementOperatorP2 => NonTermKind::IncrementOperator,
+            ProdKind::IndexAssignmentIndexAssignment => NonTermKind::IndexAssignment,
+            ProdKind::IndexAssignmentCompoundIndexAssignment => {
+                NonTermKind::IndexAssignment
+            }
+            ProdKind::FieldAssignmentFieldAssignment => NonTermKind::FieldAssignment,
+            ProdKind::FieldAssignmentCompoundFieldAssignment => {
+                NonTermKind::FieldAssignment
+            }
             ProdKind::IfStatementIfStatement => NonTermKind::IfStatement,
             ProdKind::ElseClauseOptP1 => NonTermKind::ElseClauseOpt,
             ProdKind::ElseClauseOptP2 => NonTermKind::ElseClauseOpt,
             ProdKind::ElseClauseElseStatement => NonTermKind::ElseClause,
+            ProdKind::ElseClauseElseIf => NonTermKind::ElseClause,
             ProdKind::VariableDeclarationVariableDeclaration => {
                 NonTermKind::VariableDeclaration
             }
@@ -225,25 +552,96 @@ impl From<ProdKind> for NonTermKind {
             }
             ProdKind::WhileStatementWhile => NonTermKind::WhileStatement,
             ProdKind::ForStatementFor => NonTermKind::ForStatement,
+            ProdKind::FunctionDeclarationFunctionDeclaration => {
+                NonTermKind::FunctionDeclaration
+            }
+            ProdKind::Parameter1P1 => NonTermKind::Parameter1,
+            ProdKind::Parameter1P2 => NonTermKind::Parameter1,
+            ProdKind::Parameter0P1 => NonTermKind::Parameter0,
+            ProdKind::Parameter0P2 => NonTermKind::Parameter0,
+            ProdKind::TypeClauseOptP1 => NonTermKind::TypeClauseOpt,
+            ProdKind::TypeClauseOptP2 => NonTermKind::TypeClauseOpt,
+            ProdKind::ParameterParameter => NonTermKind::Parameter,
+            ProdKind::StructDeclarationStructDeclaration => {
+                NonTermKind::StructDeclaration
+            }
+            ProdKind::FieldDeclaration1P1 => NonTermKind::FieldDeclaration1,
+            ProdKind::FieldDeclaration1P2 => NonTermKind::FieldDeclaration1,
+            ProdKind::FieldDeclaration0P1 => NonTermKind::FieldDeclaration0,
+            ProdKind::FieldDeclaration0P2 => NonTermKind::FieldDeclaration0,
+            ProdKind::FieldDeclarationFieldDeclaration => NonTermKind::FieldDeclaration,
+            ProdKind::TypeClauseTypeClause => NonTermKind::TypeClause,
+            ProdKind::TypeNameTypeName => NonTermKind::TypeName,
+            ProdKind::TypeNameArrayTypeName => NonTermKind::TypeName,
+            ProdKind::ReturnStatementReturnStatement => NonTermKind::ReturnStatement,
+            ProdKind::BreakStatementBreakStatement => NonTermKind::BreakStatement,
+            ProdKind::ContinueStatementContinueStatement => {
+                NonTermKind::ContinueStatement
+            }
+            ProdKind::ExpressionStatementExpressionStatement => {
+                NonTermKind::ExpressionStatement
+            }
+            ProdKind::CallExpressionCallExpression => NonTermKind::CallExpression,
+            ProdKind::Expression1P1 => NonTermKind::Expression1,
+            ProdKind::Expression1P2 => NonTermKind::Expression1,
+            ProdKind::Expression0P1 => NonTermKind::Expression0,
+            ProdKind::Expression0P2 => NonTermKind::Expression0,
             ProdKind::ExpressionAdd => NonTermKind::Expression,
             ProdKind::ExpressionSub => NonTermKind::Expression,
             ProdKind::ExpressionMul => NonTermKind::Expression,
             ProdKind::ExpressionDiv => NonTermKind::Expression,
+            ProdKind::ExpressionModulo => NonTermKind::Expression,
+            ProdKind::ExpressionPower => NonTermKind::Expression,
+            ProdKind::ExpressionShiftLeft => NonTermKind::Expression,
+            ProdKind::ExpressionShiftRight => NonTermKind::Expression,
             ProdKind::ExpressionEquals => NonTermKind::Expression,
             ProdKind::ExpressionNotEquals => NonTermKind::Expression,
             ProdKind::ExpressionLess => NonTermKind::Expression,
             ProdKind::ExpressionLessOrEquals => NonTermKind::Expression,
             ProdKind::ExpressionGreater => NonTermKind::Expression,
             ProdKind::ExpressionGreaterOrEquals => NonTermKind::Expression,
+            ProdKind::ExpressionBitwiseAnd => NonTermKind::Expression,
+            ProdKind::ExpressionBitwiseXor => NonTermKind::Expression,
+            ProdKind::ExpressionBitwiseOr => NonTermKind::Expression,
             ProdKind::ExpressionAnd => NonTermKind::Expression,
             ProdKind::ExpressionOr => NonTermKind::Expression,
+            ProdKind::ExpressionConditional => NonTermKind::Expression,
             ProdKind::ExpressionUnaryMinus => NonTermKind::Expression,
             ProdKind::ExpressionUnaryNegation => NonTermKind::Expression,
-            ProdKind::ExpressionP15 => NonTermKind::Expression,
-            ProdKind::ExpressionP16 => NonTermKind::Expression,
-            ProdKind::ExpressionP17 => NonTermKind::Expression,
-            ProdKind::ExpressionP18 => NonTermKind::Expression,
-            ProdKind::ExpressionP19 => NonTermKind::Expression,
+            ProdKind::ExpressionUnaryBitwiseNot => NonTermKind::Expression,
+            ProdKind::ExpressionIndex => NonTermKind::Expression,
+            ProdKind::ExpressionArrayLiteral => NonTermKind::Expression,
+            ProdKind::ExpressionField => NonTermKind::Expression,
+            ProdKind::ExpressionStructLiteral => NonTermKind::Expression,
+            ProdKind::FieldInitializer1P1 => NonTermKind::FieldInitializer1,
+            ProdKind::FieldInitializer1P2 => NonTermKind::FieldInitializer1,
+            ProdKind::FieldInitializer0P1 => NonTermKind::FieldInitializer0,
+            ProdKind::FieldInitializer0P2 => NonTermKind::FieldInitializer0,
+            ProdKind::ExpressionP28 => NonTermKind::Expression,
+            ProdKind::ExpressionP29 => NonTermKind::Expression,
+            ProdKind::ExpressionP30 => NonTermKind::Expression,
+            ProdKind::ExpressionTrue => NonTermKind::Expression,
+            ProdKind::ExpressionFalse => NonTermKind::Expression,
+            ProdKind::ExpressionP33 => NonTermKind::Expression,
+            ProdKind::ExpressionP34 => NonTermKind::Expression,
+            ProdKind::FieldInitializerFieldInitializer => NonTermKind::FieldInitializer,
+            ProdKind::LayoutP1 => NonTermKind::Layout,
+            ProdKind::LayoutItem1P1 => NonTermKind::LayoutItem1,
+            ProdKind::LayoutItem1P2 => NonTermKind::LayoutItem1,
+            ProdKind::LayoutItem0P1 => NonTermKind::LayoutItem0,
+            ProdKind::LayoutItem0P2 => NonTermKind::LayoutItem0,
+            ProdKind::LayoutItemP1 => NonTermKind::LayoutItem,
+            ProdKind::LayoutItemP2 => NonTermKind::LayoutItem,
+            ProdKind::CommentP1 => NonTermKind::Comment,
+            ProdKind::CommentP2 => NonTermKind::Comment,
+            ProdKind::BlockCommentP1 => NonTermKind::BlockComment,
+            ProdKind::CommentContent1P1 => NonTermKind::CommentContent1,
+            ProdKind::CommentContent1P2 => NonTermKind::CommentContent1,
+            ProdKind::CommentContent0P1 => NonTermKind::CommentContent0,
+            ProdKind::CommentContent0P2 => NonTermKind::CommentContent0,
+            ProdKind::CommentContentP1 => NonTermKind::CommentContent,
+            ProdKind::CommentContentP2 => NonTermKind::CommentContent,
+            ProdKind::CommentContentP3 => NonTermKind::CommentContent,
         }
     }
 }
@@ -256,92 +654,241 @@ pub enum State {
     ProgramS2,
     StatementListS3,
     IdentifierS4,
-    IfS5,
-    LetS6,
-    ConstS7,
-    WhileS8,
-    ForS9,
-    StatementListS10,
-    Statement1S11,
-    StatementS12,
-    AssignmentS13,
-    IfStatementS14,
-    VariableDeclarationS15,
-    ConstantDeclarationS16,
-    WhileStatementS17,
-    ForStatementS18,
-    EqualsS19,
-    OpenParenthesisS20,
-    IdentifierS21,
-    IdentifierS22,
-    OpenParenthesisS23,
-    OpenParenthesisS24,
-    CloseBraceS25,
-    StatementS26,
-    NumberS27,
-    MinusS28,
-    TrueS29,
-    FalseS30,
-    OpenParenthesisS31,
-    BangS32,
-    IdentifierS33,
-    ExpressionS34,
-    ExpressionS35,
-    EqualsS36,
-    EqualsS37,
-    ExpressionS38,
-    IdentifierS39,
-    ExpressionS40,
-    ExpressionS41,
-    ExpressionS42,
-    PlusS43,
-    MinusS44,
-    MulS45,
-    DivS46,
-    EqualsEqualsS47,
-    BangEqualsS48,
-    LessS49,
-    LessOrEqualsS50,
-    GreaterS51,
-    GreaterOrEqualsS52,
-    AmpersandAmpersandS53,
-    PipePipeS54,
-    SemiColonS55,
-    CloseParenthesisS56,
-    ExpressionS57,
-    ExpressionS58,
-    CloseParenthesisS59,
-    EqualsS60,
-    CloseParenthesisS61,
-    ExpressionS62,
-    ExpressionS63,
-    ExpressionS64,
-    ExpressionS65,
+    IfKeywordS5,
+    LetKeywordS6,
+    ConstKeywordS7,
+    WhileKeywordS8,
+    ForKeywordS9,
+    FnKeywordS10,
+    ReturnKeywordS11,
+    BreakKeywordS12,
+    ContinueKeywordS13,
+    StructKeywordS14,
+    StatementListS15,
+    Statement1S16,
+    Statement0S17,
+    StatementS18,
+    AssignmentS19,
+    IncrementStatementS20,
+    IndexAssignmentS21,
+    FieldAssignmentS22,
+    IfStatementS23,
+    VariableDeclarationS24,
+    ConstantDeclarationS25,
+    WhileStatementS26,
+    ForStatementS27,
+    FunctionDeclarationS28,
+    StructDeclarationS29,
+    ReturnStatementS30,
+    BreakStatementS31,
+    ContinueStatementS32,
+    ExpressionStatementS33,
+    CallExpressionS34,
+    PlusEqualsS35,
+    MinusEqualsS36,
+    MulEqualsS37,
+    DivEqualsS38,
+    PercentEqualsS39,
+    PlusPlusS40,
+    MinusMinusS41,
+    OpenParenthesisS42,
+    OpenBracketS43,
+    EqualsS44,
+    DotS45,
+    CompoundOperatorS46,
+    IncrementOperatorS47,
+    OpenParenthesisS48,
+    IdentifierS49,
+    IdentifierS50,
+    OpenParenthesisS51,
+    OpenParenthesisS52,
+    IdentifierS53,
+    NumberS54,
+    StringLiteralS55,
+    MinusS56,
+    TrueKeywordS57,
+    FalseKeywordS58,
+    OpenParenthesisS59,
+    BangS60,
+    TildeS61,
+    IdentifierS62,
+    OpenBracketS63,
+    IfKeywordS64,
+    CallExpressionS65,
     ExpressionS66,
-    ExpressionS67,
-    ExpressionS68,
-    ExpressionS69,
-    ExpressionS70,
-    ExpressionS71,
-    ExpressionS72,
-    ExpressionS73,
-    StatementListS74,
-    SemiColonS75,
-    SemiColonS76,
-    StatementListS77,
-    ExpressionS78,
-    ElseS79,
-    ElseClauseOptS80,
-    ElseClauseS81,
-    ToS82,
-    StatementListS83,
+    SemiColonS67,
+    SemiColonS68,
+    IdentifierS69,
+    StatementS70,
+    CloseBraceS71,
+    SemiColonS72,
+    Expression1S73,
+    Expression0S74,
+    ExpressionS75,
+    ExpressionS76,
+    ExpressionS77,
+    IdentifierS78,
+    ExpressionS79,
+    SemiColonS80,
+    ExpressionS81,
+    EqualsS82,
+    EqualsS83,
     ExpressionS84,
-    CloseParenthesisS85,
-    StatementListS86,
+    IdentifierS85,
+    OpenParenthesisS86,
+    ExpressionS87,
+    ExpressionS88,
+    ExpressionS89,
+    ExpressionS90,
+    OpenBraceS91,
+    Expression0S92,
+    OpenParenthesisS93,
+    PlusS94,
+    MinusS95,
+    MulS96,
+    DivS97,
+    PercentS98,
+    StarStarS99,
+    EqualsEqualsS100,
+    BangEqualsS101,
+    LessS102,
+    LessOrEqualsS103,
+    GreaterS104,
+    GreaterOrEqualsS105,
+    AmpersandAmpersandS106,
+    PipePipeS107,
+    AmpersandS108,
+    PipeS109,
+    CaretS110,
+    LessLessS111,
+    GreaterGreaterS112,
+    OpenBracketS113,
+    SemiColonS114,
+    DotS115,
+    OpenBraceS116,
+    CommaS117,
+    CloseParenthesisS118,
+    CloseBracketS119,
+    SemiColonS120,
+    EqualsS121,
+    CompoundOperatorS122,
+    SemiColonS123,
+    CloseParenthesisS124,
+    ExpressionS125,
+    ExpressionS126,
+    CloseParenthesisS127,
+    EqualsS128,
+    IdentifierS129,
+    Parameter1S130,
+    Parameter0S131,
+    ParameterS132,
+    CloseParenthesisS133,
+    IdentifierS134,
+    FieldInitializer1S135,
+    FieldInitializer0S136,
+    FieldInitializerS137,
+    CloseBracketS138,
+    ExpressionS139,
+    ExpressionS140,
+    ExpressionS141,
+    ExpressionS142,
+    ExpressionS143,
+    ExpressionS144,
+    ExpressionS145,
+    ExpressionS146,
+    ExpressionS147,
+    ExpressionS148,
+    ExpressionS149,
+    ExpressionS150,
+    ExpressionS151,
+    ExpressionS152,
+    ExpressionS153,
+    ExpressionS154,
+    ExpressionS155,
+    ExpressionS156,
+    ExpressionS157,
+    ExpressionS158,
+    ExpressionS159,
+    IdentifierS160,
+    IdentifierS161,
+    FieldDeclaration1S162,
+    FieldDeclaration0S163,
+    FieldDeclarationS164,
+    ExpressionS165,
+    EqualsS166,
+    CompoundOperatorS167,
+    ExpressionS168,
+    ExpressionS169,
+    StatementListS170,
+    SemiColonS171,
+    SemiColonS172,
+    StatementListS173,
+    ExpressionS174,
+    ColonS175,
+    TypeClauseOptS176,
+    TypeClauseS177,
+    CommaS178,
+    CloseParenthesisS179,
+    ColonS180,
+    CommaS181,
+    CloseBraceS182,
+    CloseParenthesisS183,
+    CloseBracketS184,
+    TypeClauseS185,
+    CommaS186,
+    CloseBraceS187,
+    ExpressionS188,
+    ExpressionS189,
+    SemiColonS190,
+    SemiColonS191,
+    ElseKeywordS192,
+    ElseClauseOptS193,
+    ElseClauseS194,
+    ToKeywordS195,
+    IdentifierS196,
+    OpenBracketS197,
+    TypeNameS198,
+    ParameterS199,
+    TypeClauseOptS200,
+    ExpressionS201,
+    FieldInitializerS202,
+    ExpressionS203,
+    FieldDeclarationS204,
+    SemiColonS205,
+    SemiColonS206,
+    StatementListS207,
+    IfStatementS208,
+    ExpressionS209,
+    TypeNameS210,
+    StatementListS211,
+    ElseKeywordS212,
+    CloseParenthesisS213,
+    CloseBracketS214,
+    ExpressionS215,
+    StatementListS216,
+    AUGLS217,
+    WhiteSpaceS218,
+    LineCommentS219,
+    CommentStartS220,
+    LayoutS221,
+    LayoutItem1S222,
+    LayoutItem0S223,
+    LayoutItemS224,
+    CommentS225,
+    BlockCommentS226,
+    WhiteSpaceS227,
+    NotCommentS228,
+    BlockCommentS229,
+    CommentContent1S230,
+    CommentContent0S231,
+    CommentContentS232,
+    LayoutItemS233,
+    CommentContentS234,
+    CommentEndS235,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
-        None
+        Some(State::AUGLS217)
     }
 }
 impl From<State> for usize {
@@ -357,88 +904,237 @@ impl std::fmt::Debug for State {
             State::ProgramS2 => "2:Program",
             State::StatementListS3 => "3:StatementList",
             State::IdentifierS4 => "4:Identifier",
-            State::IfS5 => "5:If",
-            State::LetS6 => "6:Let",
-            State::ConstS7 => "7:Const",
-            State::WhileS8 => "8:While",
-            State::ForS9 => "9:For",
-            State::StatementListS10 => "10:StatementList",
-            State::Statement1S11 => "11:Statement1",
-            State::StatementS12 => "12:Statement",
-            State::AssignmentS13 => "13:Assignment",
-            State::IfStatementS14 => "14:IfStatement",
-            State::VariableDeclarationS15 => "15:VariableDeclaration",
-            State::ConstantDeclarationS16 => "16:ConstantDeclaration",
-            State::WhileStatementS17 => "17:WhileStatement",
-            State::ForStatementS18 => "18:ForStatement",
-            State::EqualsS19 => "19:Equals",
-            State::OpenParenthesisS20 => "20:OpenParenthesis",
-            State::IdentifierS21 => "21:Identifier",
-            State::IdentifierS22 => "22:Identifier",
-            State::OpenParenthesisS23 => "23:OpenParenthesis",
-            State::OpenParenthesisS24 => "24:OpenParenthesis",
-            State::CloseBraceS25 => "25:CloseBrace",
-            State::StatementS26 => "26:Statement",
-            State::NumberS27 => "27:Number",
-            State::MinusS28 => "28:Minus",
-            State::TrueS29 => "29:True",
-            State::FalseS30 => "30:False",
-            State::OpenParenthesisS31 => "31:OpenParenthesis",
-            State::BangS32 => "32:Bang",
-            State::IdentifierS33 => "33:Identifier",
-            State::ExpressionS34 => "34:Expression",
-            State::ExpressionS35 => "35:Expression",
-            State::EqualsS36 => "36:Equals",
-            State::EqualsS37 => "37:Equals",
-            State::ExpressionS38 => "38:Expression",
-            State::IdentifierS39 => "39:Identifier",
-            State::ExpressionS40 => "40:Expression",
-            State::ExpressionS41 => "41:Expression",
-            State::ExpressionS42 => "42:Expression",
-            State::PlusS43 => "43:Plus",
-            State::MinusS44 => "44:Minus",
-            State::MulS45 => "45:Mul",
-            State::DivS46 => "46:Div",
-            State::EqualsEqualsS47 => "47:EqualsEquals",
-            State::BangEqualsS48 => "48:BangEquals",
-            State::LessS49 => "49:Less",
-            State::LessOrEqualsS50 => "50:LessOrEquals",
-            State::GreaterS51 => "51:Greater",
-            State::GreaterOrEqualsS52 => "52:GreaterOrEquals",
-            State::AmpersandAmpersandS53 => "53:AmpersandAmpersand",
-            State::PipePipeS54 => "54:PipePipe",
-            State::SemiColonS55 => "55:SemiColon",
-            State::CloseParenthesisS56 => "56:CloseParenthesis",
-            State::ExpressionS57 => "57:Expression",
-            State::ExpressionS58 => "58:Expression",
-            State::CloseParenthesisS59 => "59:CloseParenthesis",
-            State::EqualsS60 => "60:Equals",
-            State::CloseParenthesisS61 => "61:CloseParenthesis",
-            State::ExpressionS62 => "62:Expression",
-            State::ExpressionS63 => "63:Expression",
-            State::ExpressionS64 => "64:Expression",
-            State::ExpressionS65 => "65:Expression",
+            State::IfKeywordS5 => "5:IfKeyword",
+            State::LetKeywordS6 => "6:LetKeyword",
+            State::ConstKeywordS7 => "7:ConstKeyword",
+            State::WhileKeywordS8 => "8:WhileKeyword",
+            State::ForKeywordS9 => "9:ForKeyword",
+            State::FnKeywordS10 => "10:FnKeyword",
+            State::ReturnKeywordS11 => "11:ReturnKeyword",
+            State::BreakKeywordS12 => "12:BreakKeyword",
+            State::ContinueKeywordS13 => "13:ContinueKeyword",
+            State::StructKeywordS14 => "14:StructKeyword",
+            State::StatementListS15 => "15:StatementList",
+            State::Statement1S16 => "16:Statement1",
+            State::Statement0S17 => "17:Statement0",
+            State::StatementS18 => "18:Statement",
+            State::AssignmentS19 => "19:Assignment",
+            State::IncrementStatementS20 => "20:IncrementStatement",
+            State::IndexAssignmentS21 => "21:IndexAssignment",
+            State::FieldAssignmentS22 => "22:FieldAssignment",
+            State::IfStatementS23 => "23:IfStatement",
+            State::VariableDeclarationS24 => "24:VariableDeclaration",
+            State::ConstantDeclarationS25 => "25:ConstantDeclaration",
+            State::WhileStatementS26 => "26:WhileStatement",
+            State::ForStatementS27 => "27:ForStatement",
+            State::FunctionDeclarationS28 => "28:FunctionDeclaration",
+            State::StructDeclarationS29 => "29:StructDeclaration",
+            State::ReturnStatementS30 => "30:ReturnStatement",
+            State::BreakStatementS31 => "31:BreakStatement",
+            State::ContinueStatementS32 => "32:ContinueStatement",
+            State::ExpressionStatementS33 => "33:ExpressionStatement",
+            State::CallExpressionS34 => "34:CallExpression",
+            State::PlusEqualsS35 => "35:PlusEquals",
+            State::MinusEqualsS36 => "36:MinusEquals",
+            State::MulEqualsS37 => "37:MulEquals",
+            State::DivEqualsS38 => "38:DivEquals",
+            State::PercentEqualsS39 => "39:PercentEquals",
+            State::PlusPlusS40 => "40:PlusPlus",
+            State::MinusMinusS41 => "41:MinusMinus",
+            State::OpenParenthesisS42 => "42:OpenParenthesis",
+            State::OpenBracketS43 => "43:OpenBracket",
+            State::EqualsS44 => "44:Equals",
+            State::DotS45 => "45:Dot",
+            State::CompoundOperatorS46 => "46:CompoundOperator",
+            State::IncrementOperatorS47 => "47:IncrementOperator",
+            State::OpenParenthesisS48 => "48:OpenParenthesis",
+            State::IdentifierS49 => "49:Identifier",
+            State::IdentifierS50 => "50:Identifier",
+            State::OpenParenthesisS51 => "51:OpenParenthesis",
+            State::OpenParenthesisS52 => "52:OpenParenthesis",
+            State::IdentifierS53 => "53:Identifier",
+            State::NumberS54 => "54:Number",
+            State::StringLiteralS55 => "55:StringLiteral",
+            State::MinusS56 => "56:Minus",
+            State::TrueKeywordS57 => "57:TrueKeyword",
+            State::FalseKeywordS58 => "58:FalseKeyword",
+            State::OpenParenthesisS59 => "59:OpenParenthesis",
+            State::BangS60 => "60:Bang",
+            State::TildeS61 => "61:Tilde",
+            State::IdentifierS62 => "62:Identifier",
+            State::OpenBracketS63 => "63:OpenBracket",
+            State::IfKeywordS64 => "64:IfKeyword",
+            State::CallExpressionS65 => "65:CallExpression",
             State::ExpressionS66 => "66:Expression",
-            State::ExpressionS67 => "67:Expression",
-            State::ExpressionS68 => "68:Expression",
-            State::ExpressionS69 => "69:Expression",
-            State::ExpressionS70 => "70:Expression",
-            State::ExpressionS71 => "71:Expression",
-            State::ExpressionS72 => "72:Expression",
-            State::ExpressionS73 => "73:Expression",
-            State::StatementListS74 => "74:StatementList",
-            State::SemiColonS75 => "75:SemiColon",
-            State::SemiColonS76 => "76:SemiColon",
-            State::StatementListS77 => "77:StatementList",
-            State::ExpressionS78 => "78:Expression",
-            State::ElseS79 => "79:Else",
-            State::ElseClauseOptS80 => "80:ElseClauseOpt",
-            State::ElseClauseS81 => "81:ElseClause",
-            State::ToS82 => "82:To",
-            State::StatementListS83 => "83:StatementList",
+            State::SemiColonS67 => "67:SemiColon",
+            State::SemiColonS68 => "68:SemiColon",
+            State::IdentifierS69 => "69:Identifier",
+            State::StatementS70 => "70:Statement",
+            State::CloseBraceS71 => "71:CloseBrace",
+            State::SemiColonS72 => "72:SemiColon",
+            State::Expression1S73 => "73:Expression1",
+            State::Expression0S74 => "74:Expression0",
+            State::ExpressionS75 => "75:Expression",
+            State::ExpressionS76 => "76:Expression",
+            State::ExpressionS77 => "77:Expression",
+            State::IdentifierS78 => "78:Identifier",
+            State::ExpressionS79 => "79:Expression",
+            State::SemiColonS80 => "80:SemiColon",
+            State::ExpressionS81 => "81:Expression",
+            State::EqualsS82 => "82:Equals",
+            State::EqualsS83 => "83:Equals",
             State::ExpressionS84 => "84:Expression",
-            State::CloseParenthesisS85 => "85:CloseParenthesis",
-            State::StatementListS86 => "86:StatementList",
+            State::IdentifierS85 => "85:Identifier",
+            State::OpenParenthesisS86 => "86:OpenParenthesis",
+            State::ExpressionS87 => "87:Expression",
+            State::ExpressionS88 => "88:Expression",
+            State::ExpressionS89 => "89:Expression",
+            State::ExpressionS90 => "90:Expression",
+            State::OpenBraceS91 => "91:OpenBrace",
+            State::Expression0S92 => "92:Expression0",
+            State::OpenParenthesisS93 => "93:OpenParenthesis",
+            State::PlusS94 => "94:Plus",
+            State::MinusS95 => "95:Minus",
+            State::MulS96 => "96:Mul",
+            State::DivS97 => "97:Div",
+            State::PercentS98 => "98:Percent",
+            State::StarStarS99 => "99:StarStar",
+            State::EqualsEqualsS100 => "100:EqualsEquals",
+            State::BangEqualsS101 => "101:BangEquals",
+            State::LessS102 => "102:Less",
+            State::LessOrEqualsS103 => "103:LessOrEquals",
+            State::GreaterS104 => "104:Greater",
+            State::GreaterOrEqualsS105 => "105:GreaterOrEquals",
+            State::AmpersandAmpersandS106 => "106:AmpersandAmpersand",
+            State::PipePipeS107 => "107:PipePipe",
+            State::AmpersandS108 => "108:Ampersand",
+            State::PipeS109 => "109:Pipe",
+            State::CaretS110 => "110:Caret",
+            State::LessLessS111 => "111:LessLess",
+            State::GreaterGreaterS112 => "112:GreaterGreater",
+            State::OpenBracketS113 => "113:OpenBracket",
+            State::SemiColonS114 => "114:SemiColon",
+            State::DotS115 => "115:Dot",
+            State::OpenBraceS116 => "116:OpenBrace",
+            State::CommaS117 => "117:Comma",
+            State::CloseParenthesisS118 => "118:CloseParenthesis",
+            State::CloseBracketS119 => "119:CloseBracket",
+            State::SemiColonS120 => "120:SemiColon",
+            State::EqualsS121 => "121:Equals",
+            State::CompoundOperatorS122 => "122:CompoundOperator",
+            State::SemiColonS123 => "123:SemiColon",
+            State::CloseParenthesisS124 => "124:CloseParenthesis",
+            State::ExpressionS125 => "125:Expression",
+            State::ExpressionS126 => "126:Expression",
+            State::CloseParenthesisS127 => "127:CloseParenthesis",
+            State::EqualsS128 => "128:Equals",
+            State::IdentifierS129 => "129:Identifier",
+            State::Parameter1S130 => "130:Parameter1",
+            State::Parameter0S131 => "131:Parameter0",
+            State::ParameterS132 => "132:Parameter",
+            State::CloseParenthesisS133 => "133:CloseParenthesis",
+            State::IdentifierS134 => "134:Identifier",
+            State::FieldInitializer1S135 => "135:FieldInitializer1",
+            State::FieldInitializer0S136 => "136:FieldInitializer0",
+            State::FieldInitializerS137 => "137:FieldInitializer",
+            State::CloseBracketS138 => "138:CloseBracket",
+            State::ExpressionS139 => "139:Expression",
+            State::ExpressionS140 => "140:Expression",
+            State::ExpressionS141 => "141:Expression",
+            State::ExpressionS142 => "142:Expression",
+            State::ExpressionS143 => "143:Expression",
+            State::ExpressionS144 => "144:Expression",
+            State::ExpressionS145 => "145:Expression",
+            State::ExpressionS146 => "146:Expression",
+            State::ExpressionS147 => "147:Expression",
+            State::ExpressionS148 => "148:Expression",
+            State::ExpressionS149 => "149:Expression",
+            State::ExpressionS150 => "150:Expression",
+            State::ExpressionS151 => "151:Expression",
+            State::ExpressionS152 => "152:Expression",
+            State::ExpressionS153 => "153:Expression",
+            State::ExpressionS154 => "154:Expression",
+            State::ExpressionS155 => "155:Expression",
+            State::ExpressionS156 => "156:Expression",
+            State::ExpressionS157 => "157:Expression",
+            State::ExpressionS158 => "158:Expression",
+            State::ExpressionS159 => "159:Expression",
+            State::IdentifierS160 => "160:Identifier",
+            State::IdentifierS161 => "161:Identifier",
+            State::FieldDeclaration1S162 => "162:FieldDeclaration1",
+            State::FieldDeclaration0S163 => "163:FieldDeclaration0",
+            State::FieldDeclarationS164 => "164:FieldDeclaration",
+            State::ExpressionS165 => "165:Expression",
+            State::EqualsS166 => "166:Equals",
+            State::CompoundOperatorS167 => "167:CompoundOperator",
+            State::ExpressionS168 => "168:Expression",
+            State::ExpressionS169 => "169:Expression",
+            State::StatementListS170 => "170:StatementList",
+            State::SemiColonS171 => "171:SemiColon",
+            State::SemiColonS172 => "172:SemiColon",
+            State::StatementListS173 => "173:StatementList",
+            State::ExpressionS174 => "174:Expression",
+            State::ColonS175 => "175:Colon",
+            State::TypeClauseOptS176 => "176:TypeClauseOpt",
+            State::TypeClauseS177 => "177:TypeClause",
+            State::CommaS178 => "178:Comma",
+            State::CloseParenthesisS179 => "179:CloseParenthesis",
+            State::ColonS180 => "180:Colon",
+            State::CommaS181 => "181:Comma",
+            State::CloseBraceS182 => "182:CloseBrace",
+            State::CloseParenthesisS183 => "183:CloseParenthesis",
+            State::CloseBracketS184 => "184:CloseBracket",
+            State::TypeClauseS185 => "185:TypeClause",
+            State::CommaS186 => "186:Comma",
+            State::CloseBraceS187 => "187:CloseBrace",
+            State::ExpressionS188 => "188:Expression",
+            State::ExpressionS189 => "189:Expression",
+            State::SemiColonS190 => "190:SemiColon",
+            State::SemiColonS191 => "191:SemiColon",
+            State::ElseKeywordS192 => "192:ElseKeyword",
+            State::ElseClauseOptS193 => "193:ElseClauseOpt",
+            State::ElseClauseS194 => "194:ElseClause",
+            State::ToKeywordS195 => "195:ToKeyword",
+            State::IdentifierS196 => "196:Identifier",
+            State::OpenBracketS197 => "197:OpenBracket",
+            State::TypeNameS198 => "198:TypeName",
+            State::ParameterS199 => "199:Parameter",
+            State::TypeClauseOptS200 => "200:TypeClauseOpt",
+            State::ExpressionS201 => "201:Expression",
+            State::FieldInitializerS202 => "202:FieldInitializer",
+            State::ExpressionS203 => "203:Expression",
+            State::FieldDeclarationS204 => "204:FieldDeclaration",
+            State::SemiColonS205 => "205:SemiColon",
+            State::SemiColonS206 => "206:SemiColon",
+            State::StatementListS207 => "207:StatementList",
+            State::IfStatementS208 => "208:IfStatement",
+            State::ExpressionS209 => "209:Expression",
+            State::TypeNameS210 => "210:TypeName",
+            State::StatementListS211 => "211:StatementList",
+            State::ElseKeywordS212 => "212:ElseKeyword",
+            State::CloseParenthesisS213 => "213:CloseParenthesis",
+            State::CloseBracketS214 => "214:CloseBracket",
+            State::ExpressionS215 => "215:Expression",
+            State::StatementListS216 => "216:StatementList",
+            State::AUGLS217 => "217:AUGL",
+            State::WhiteSpaceS218 => "218:WhiteSpace",
+            State::LineCommentS219 => "219:LineComment",
+            State::CommentStartS220 => "220:CommentStart",
+            State::LayoutS221 => "221:Layout",
+            State::LayoutItem1S222 => "222:LayoutItem1",
+            State::LayoutItem0S223 => "223:LayoutItem0",
+            State::LayoutItemS224 => "224:LayoutItem",
+            State::CommentS225 => "225:Comment",
+            State::BlockCommentS226 => "226:BlockComment",
+            State::WhiteSpaceS227 => "227:WhiteSpace",
+            State::NotCommentS228 => "228:NotComment",
+            State::BlockCommentS229 => "229:BlockComment",
+            State::CommentContent1S230 => "230:CommentContent1",
+            State::CommentContent0S231 => "231:CommentContent0",
+            State::CommentContentS232 => "232:CommentContent",
+            State::LayoutItemS233 => "233:LayoutItem",
+            State::CommentContentS234 => "234:CommentContent",
+            State::CommentEndS235 => "235:CommentEnd",
         };
         write!(f, "{name}")
     }
@@ -452,43 +1148,75 @@ pub enum Symbol {
 #[derive(Debug)]
 pub enum Terminal {
     Number(compiler_actions::Number),
-    Plus,
-    Minus,
-    Mul,
-    Div,
-    True,
-    False,
-    OpenParenthesis,
-    CloseParenthesis,
-    Bang,
-    EqualsEquals,
-    BangEquals,
-    Less,
-    LessOrEquals,
-    Greater,
-    GreaterOrEquals,
-    AmpersandAmpersand,
-    PipePipe,
+    StringLiteral(compiler_actions::StringLiteral),
+    Plus(compiler_actions::Plus),
+    Minus(compiler_actions::Minus),
+    Mul(compiler_actions::Mul),
+    Div(compiler_actions::Div),
+    Percent(compiler_actions::Percent),
+    StarStar(compiler_actions::StarStar),
+    PlusEquals(compiler_actions::PlusEquals),
+    MinusEquals(compiler_actions::MinusEquals),
+    MulEquals(compiler_actions::MulEquals),
+    DivEquals(compiler_actions::DivEquals),
+    PercentEquals(compiler_actions::PercentEquals),
+    PlusPlus(compiler_actions::PlusPlus),
+    MinusMinus(compiler_actions::MinusMinus),
+    TrueKeyword(compiler_actions::TrueKeyword),
+    FalseKeyword(compiler_actions::FalseKeyword),
+    OpenParenthesis(compiler_actions::OpenParenthesis),
+    CloseParenthesis(compiler_actions::CloseParenthesis),
+    Bang(compiler_actions::Bang),
+    EqualsEquals(compiler_actions::EqualsEquals),
+    BangEquals(compiler_actions::BangEquals),
+    Less(compiler_actions::Less),
+    LessOrEquals(compiler_actions::LessOrEquals),
+    Greater(compiler_actions::Greater),
+    GreaterOrEquals(compiler_actions::GreaterOrEquals),
+    AmpersandAmpersand(compiler_actions::AmpersandAmpersand),
+    PipePipe(compiler_actions::PipePipe),
+    Ampersand(compiler_actions::Ampersand),
+    Pipe(compiler_actions::Pipe),
+    Caret(compiler_actions::Caret),
+    Tilde(compiler_actions::Tilde),
+    LessLess(compiler_actions::LessLess),
+    GreaterGreater(compiler_actions::GreaterGreater),
     Identifier(compiler_actions::Identifier),
-    OpenBrace,
-    CloseBrace,
-    Equals,
-    SemiColon,
-    If,
-    Else,
-    Let,
-    Const,
-    While,
-    For,
-    To,
+    OpenBrace(compiler_actions::OpenBrace),
+    CloseBrace(compiler_actions::CloseBrace),
+    OpenBracket(compiler_actions::OpenBracket),
+    CloseBracket(compiler_actions::CloseBracket),
+    Equals(compiler_actions::Equals),
+    SemiColon(compiler_actions::SemiColon),
+    IfKeyword(compiler_actions::IfKeyword),
+    ElseKeyword(compiler_actions::ElseKeyword),
+    LetKeyword(compiler_actions::LetKeyword),
+    ConstKeyword(compiler_actions::ConstKeyword),
+    WhileKeyword(compiler_actions::WhileKeyword),
+    ForKeyword(compiler_actions::ForKeyword),
+    ToKeyword(compiler_actions::ToKeyword),
+    FnKeyword(compiler_actions::FnKeyword),
+    ReturnKeyword(compiler_actions::ReturnKeyword),
+    BreakKeyword(compiler_actions::BreakKeyword),
+    ContinueKeyword(compiler_actions::ContinueKeyword),
+    Comma(compiler_actions::Comma),
+    Dot(compiler_actions::Dot),
+    Colon(compiler_actions::Colon),
+    StructKeyword(compiler_actions::StructKeyword),
 }
 #[derive(Debug)]
 pub enum NonTerminal {
     Program(compiler_actions::Program),
     StatementList(compiler_actions::StatementList),
     Statement1(compiler_actions::Statement1),
+    Statement0(compiler_actions::Statement0),
     Statement(compiler_actions::Statement),
     Assignment(compiler_actions::Assignment),
+    CompoundOperator(compiler_actions::CompoundOperator),
+    IncrementStatement(compiler_actions::IncrementStatement),
+    IncrementOperator(compiler_actions::IncrementOperator),
+    IndexAssignment(compiler_actions::IndexAssignment),
+    FieldAssignment(compiler_actions::FieldAssignment),
     IfStatement(compiler_actions::IfStatement),
     ElseClauseOpt(compiler_actions::ElseClauseOpt),
     ElseClause(compiler_actions::ElseClause),
@@ -496,7 +1224,28 @@ pub enum NonTerminal {
     ConstantDeclaration(compiler_actions::ConstantDeclaration),
     WhileStatement(compiler_actions::WhileStatement),
     ForStatement(compiler_actions::ForStatement),
+    FunctionDeclaration(compiler_actions::FunctionDeclaration),
+    Parameter1(compiler_actions::Parameter1),
+    Parameter0(compiler_actions::Parameter0),
+    TypeClauseOpt(compiler_actions::TypeClauseOpt),
+    Parameter(compiler_actions::Parameter),
+    StructDeclaration(compiler_actions::StructDeclaration),
+    FieldDeclaration1(compiler_actions::FieldDeclaration1),
+    FieldDeclaration0(compiler_actions::FieldDeclaration0),
+    FieldDeclaration(compiler_actions::FieldDeclaration),
+    TypeClause(compiler_actions::TypeClause),
+    TypeName(compiler_actions::TypeName),
+    ReturnStatement(compiler_actions::ReturnStatement),
+    BreakStatement(compiler_actions::BreakStatement),
+    ContinueStatement(compiler_actions::ContinueStatement),
+    ExpressionStatement(compiler_actions::ExpressionStatement),
+    CallExpression(compiler_actions::CallExpression),
+    Expression1(compiler_actions::Expression1),
+    Expression0(compiler_actions::Expression0),
     Expression(compiler_actions::Expression),
+    FieldInitializer1(compiler_actions::FieldInitializer1),
+    FieldInitializer0(compiler_actions::FieldInitializer0),
+    FieldInitializer(compiler_actions::FieldInitializer),
 }
 type ActionFn = fn(token: TokenKind) -> Vec<Action<State, ProdKind>>;
 pub struct CompilerParserDefinition {
@@ -514,11 +1263,17 @@ fn action_openbrace_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Shift(State::IdentifierS4)]),
         TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
-        TK::If => Vec::from(&[Shift(State::IfS5)]),
-        TK::Let => Vec::from(&[Shift(State::LetS6)]),
-        TK::Const => Vec::from(&[Shift(State::ConstS7)]),
-        TK::While => Vec::from(&[Shift(State::WhileS8)]),
-        TK::For => Vec::from(&[Shift(State::ForS9)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::Statement0P2, 0usize)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS5)]),
+        TK::LetKeyword => Vec::from(&[Shift(State::LetKeywordS6)]),
+        TK::ConstKeyword => Vec::from(&[Shift(State::ConstKeywordS7)]),
+        TK::WhileKeyword => Vec::from(&[Shift(State::WhileKeywordS8)]),
+        TK::ForKeyword => Vec::from(&[Shift(State::ForKeywordS9)]),
+        TK::FnKeyword => Vec::from(&[Shift(State::FnKeywordS10)]),
+        TK::ReturnKeyword => Vec::from(&[Shift(State::ReturnKeywordS11)]),
+        TK::BreakKeyword => Vec::from(&[Shift(State::BreakKeywordS12)]),
+        TK::ContinueKeyword => Vec::from(&[Shift(State::ContinueKeywordS13)]),
+        TK::StructKeyword => Vec::from(&[Shift(State::StructKeywordS14)]),
         _ => vec![],
     }
 }
@@ -536,1630 +1291,5546 @@ fn action_statementlist_s3(token_kind: TokenKind) -> Vec<Action<State, ProdKind>
 }
 fn action_identifier_s4(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Equals => Vec::from(&[Shift(State::EqualsS19)]),
+        TK::PlusEquals => Vec::from(&[Shift(State::PlusEqualsS35)]),
+        TK::MinusEquals => Vec::from(&[Shift(State::MinusEqualsS36)]),
+        TK::MulEquals => Vec::from(&[Shift(State::MulEqualsS37)]),
+        TK::DivEquals => Vec::from(&[Shift(State::DivEqualsS38)]),
+        TK::PercentEquals => Vec::from(&[Shift(State::PercentEqualsS39)]),
+        TK::PlusPlus => Vec::from(&[Shift(State::PlusPlusS40)]),
+        TK::MinusMinus => Vec::from(&[Shift(State::MinusMinusS41)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS42)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS43)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS44)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS45)]),
         _ => vec![],
     }
 }
-fn action_if_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ifkeyword_s5(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS20)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS48)]),
         _ => vec![],
     }
 }
-fn action_let_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_letkeyword_s6(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS21)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS49)]),
         _ => vec![],
     }
 }
-fn action_const_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_constkeyword_s7(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS22)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS50)]),
         _ => vec![],
     }
 }
-fn action_while_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_whilekeyword_s8(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS23)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS51)]),
         _ => vec![],
     }
 }
-fn action_for_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_forkeyword_s9(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS24)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS52)]),
         _ => vec![],
     }
 }
-fn action_statementlist_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_fnkeyword_s10(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS53)]),
+        _ => vec![],
+    }
+}
+fn action_returnkeyword_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_breakkeyword_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS67)]),
+        _ => vec![],
+    }
+}
+fn action_continuekeyword_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS68)]),
+        _ => vec![],
+    }
+}
+fn action_structkeyword_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS69)]),
+        _ => vec![],
+    }
+}
+fn action_statementlist_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP16, 1usize)]),
         _ => vec![],
     }
 }
-fn action_statement1_s11(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_statement1_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Shift(State::IdentifierS4)]),
         TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
-        TK::CloseBrace => Vec::from(&[Shift(State::CloseBraceS25)]),
-        TK::If => Vec::from(&[Shift(State::IfS5)]),
-        TK::Let => Vec::from(&[Shift(State::LetS6)]),
-        TK::Const => Vec::from(&[Shift(State::ConstS7)]),
-        TK::While => Vec::from(&[Shift(State::WhileS8)]),
-        TK::For => Vec::from(&[Shift(State::ForS9)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::Statement0P1, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS5)]),
+        TK::LetKeyword => Vec::from(&[Shift(State::LetKeywordS6)]),
+        TK::ConstKeyword => Vec::from(&[Shift(State::ConstKeywordS7)]),
+        TK::WhileKeyword => Vec::from(&[Shift(State::WhileKeywordS8)]),
+        TK::ForKeyword => Vec::from(&[Shift(State::ForKeywordS9)]),
+        TK::FnKeyword => Vec::from(&[Shift(State::FnKeywordS10)]),
+        TK::ReturnKeyword => Vec::from(&[Shift(State::ReturnKeywordS11)]),
+        TK::BreakKeyword => Vec::from(&[Shift(State::BreakKeywordS12)]),
+        TK::ContinueKeyword => Vec::from(&[Shift(State::ContinueKeywordS13)]),
+        TK::StructKeyword => Vec::from(&[Shift(State::StructKeywordS14)]),
+        _ => vec![],
+    }
+}
+fn action_statement0_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Shift(State::CloseBraceS71)]),
         _ => vec![],
     }
 }
-fn action_statement_s12(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_statement_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::Statement1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_assignment_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_assignment_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_incrementstatement_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP9, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_indexassignment_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP10, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_fieldassignment_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP11, 1usize)]),
         _ => vec![],
     }
 }
-fn action_ifstatement_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ifstatement_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_variabledeclaration_s15(
+fn action_variabledeclaration_s24(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_constantdeclaration_s16(
+fn action_constantdeclaration_s25(
     token_kind: TokenKind,
 ) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_whilestatement_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_whilestatement_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP5, 1usize)]),
         _ => vec![],
     }
 }
-fn action_forstatement_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_forstatement_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::Identifier => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
         TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
         TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP6, 1usize)]),
         _ => vec![],
     }
 }
-fn action_equals_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_functiondeclaration_s28(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP7, 1usize)]),
         _ => vec![],
     }
 }
-fn action_openparenthesis_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_structdeclaration_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP12, 1usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_returnstatement_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Equals => Vec::from(&[Shift(State::EqualsS36)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP8, 1usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_breakstatement_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Equals => Vec::from(&[Shift(State::EqualsS37)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP13, 1usize)]),
         _ => vec![],
     }
 }
-fn action_openparenthesis_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_continuestatement_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP14, 1usize)]),
         _ => vec![],
     }
 }
-fn action_openparenthesis_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expressionstatement_s33(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS39)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementP15, 1usize)]),
         _ => vec![],
     }
 }
-fn action_closebrace_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_callexpression_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::If => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::Else => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::While => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
-        TK::For => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS72)]),
         _ => vec![],
     }
 }
-fn action_statement_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_plusequals_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::If => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::While => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
-        TK::For => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::StringLiteral => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::TrueKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::FalseKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::OpenParenthesis => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::Bang => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::Tilde => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_number_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_minusequals_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionP16, 1usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::StringLiteral => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::TrueKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::FalseKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::OpenParenthesis => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::Bang => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::Tilde => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_minus_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_mulequals_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
-        _ => vec![],
-    }
-}
-fn action_true_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+        TK::Number => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::StringLiteral => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::TrueKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::FalseKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::OpenParenthesis => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::Bang => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::Tilde => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_divequals_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionP17, 1usize)]),
-        _ => vec![],
-    }
-}
-fn action_false_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+        TK::Number => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::StringLiteral => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::TrueKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::FalseKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::OpenParenthesis => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::Bang => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::Tilde => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP4, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_percentequals_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionP18, 1usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::StringLiteral => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::TrueKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::FalseKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::OpenParenthesis => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::Bang => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::Tilde => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::CompoundOperatorP5, 1usize)]),
         _ => vec![],
     }
-}
-fn action_openparenthesis_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+}
+fn action_plusplus_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::IncrementOperatorP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_bang_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_minusminus_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::IncrementOperatorP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparenthesis_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionP19, 1usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Expression0P2, 0usize)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_expression_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openbracket_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS55)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_expression_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_equals_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS56)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_equals_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_dot_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
         _ => vec![],
     }
 }
-fn action_equals_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_compoundoperator_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_expression_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_incrementoperator_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS59)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS80)]),
         _ => vec![],
     }
 }
-fn action_identifier_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparenthesis_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Equals => Vec::from(&[Shift(State::EqualsS60)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_expression_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS82)]),
         _ => vec![],
     }
 }
-fn action_expression_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS61)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS83)]),
         _ => vec![],
     }
 }
-fn action_expression_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparenthesis_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::AmpersandAmpersand => {
-            Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)])
-        }
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_plus_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparenthesis_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS85)]),
         _ => vec![],
     }
 }
-fn action_minus_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS86)]),
         _ => vec![],
     }
 }
-fn action_mul_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_number_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionP29, 1usize)]),
         _ => vec![],
     }
 }
-fn action_div_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_stringliteral_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionP30, 1usize)]),
         _ => vec![],
     }
 }
-fn action_equalsequals_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_minus_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_bangequals_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_truekeyword_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionTrue, 1usize)]),
         _ => vec![],
     }
 }
-fn action_less_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_falsekeyword_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionFalse, 1usize)]),
         _ => vec![],
     }
 }
-fn action_lessorequals_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparenthesis_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_greater_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_bang_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_greaterorequals_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_tilde_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_ampersandampersand_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS42)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS91)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionP34, 1usize)]),
         _ => vec![],
     }
 }
-fn action_pipepipe_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openbracket_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::Expression0P2, 0usize)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ifkeyword_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::If => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::While => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
-        TK::For => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS93)]),
         _ => vec![],
     }
 }
-fn action_closeparenthesis_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_callexpression_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionP33, 1usize)]),
         _ => vec![],
     }
 }
-fn action_expression_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS75)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS114)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_expression_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
-        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS76)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)])
+        }
+        TK::ForKeyword => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)]),
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::BreakStatementBreakStatement, 2usize)])
+        }
         _ => vec![],
     }
 }
-fn action_closeparenthesis_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::ContinueStatementContinueStatement, 2usize)])
+        }
         _ => vec![],
     }
 }
-fn action_equals_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS116)]),
         _ => vec![],
     }
 }
-fn action_closeparenthesis_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_statement_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionP15, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::Statement1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_expression_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_closebrace_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::STOP => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::StatementListP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_expression_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::ExpressionStatementExpressionStatement, 2usize)])
+        }
         _ => vec![],
     }
 }
-fn action_expression_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression1_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Expression0P1, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::Expression0P1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS117)]),
         _ => vec![],
     }
 }
-fn action_expression_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression0_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::Minus => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::Mul => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::Div => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS118)]),
         _ => vec![],
     }
 }
-fn action_expression_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Expression1P2, 1usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::Expression1P2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::Expression1P2, 1usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_expression_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Shift(State::CloseBracketS119)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_expression_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS120)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_expression_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::AmpersandAmpersand => {
-            Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)])
-        }
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::PlusEquals => Vec::from(&[Shift(State::PlusEqualsS35)]),
+        TK::MinusEquals => Vec::from(&[Shift(State::MinusEqualsS36)]),
+        TK::MulEquals => Vec::from(&[Shift(State::MulEqualsS37)]),
+        TK::DivEquals => Vec::from(&[Shift(State::DivEqualsS38)]),
+        TK::PercentEquals => Vec::from(&[Shift(State::PercentEqualsS39)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS121)]),
         _ => vec![],
     }
 }
-fn action_expression_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS123)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_expression_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_semicolon_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => {
-            Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)])
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
         }
-        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::Less => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::Greater => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::GreaterOrEquals => {
-            Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)])
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
         }
-        TK::AmpersandAmpersand => {
-            Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)])
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::IncrementStatementIncrementStatement, 3usize)])
         }
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
         _ => vec![],
     }
 }
-fn action_expression_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS124)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_expression_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_equals_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
-        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
-        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
-        TK::To => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_statementlist_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_equals_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::If => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::Else => Vec::from(&[Shift(State::ElseS79)]),
-        TK::Let => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::While => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
-        TK::For => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::OpenBrace => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::CloseBrace => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::If => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::Let => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::Const => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::While => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
-        TK::For => {
-            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
-        }
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS127)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
-        }
-        TK::OpenBrace => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
-        }
-        TK::CloseBrace => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
-        }
-        TK::If => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
-        }
-        TK::Let => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        TK::Equals => Vec::from(&[Shift(State::EqualsS128)]),
+        _ => vec![],
+    }
+}
+fn action_openparenthesis_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Parameter0P2, 0usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS129)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionUnaryMinus, 2usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS133)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)])
         }
-        TK::Const => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionUnaryNegation, 2usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::CloseParenthesis => {
+            Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)])
         }
-        TK::While => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::GreaterOrEquals => {
+            Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)])
         }
-        TK::For => {
-            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)])
         }
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionUnaryBitwiseNot, 2usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
         _ => vec![],
     }
 }
-fn action_statementlist_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openbrace_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::If => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::While => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
-        TK::For => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS134)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldInitializer0P2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_expression_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_expression0_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
-        TK::To => Vec::from(&[Shift(State::ToS82)]),
+        TK::CloseBracket => Vec::from(&[Shift(State::CloseBracketS138)]),
         _ => vec![],
     }
 }
-fn action_else_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparenthesis_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_elseclauseopt_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_plus_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::If => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::While => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
-        TK::For => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_elseclause_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_minus_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::If => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::While => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
-        TK::For => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_to_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_mul_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Number => Vec::from(&[Shift(State::NumberS27)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS28)]),
-        TK::True => Vec::from(&[Shift(State::TrueS29)]),
-        TK::False => Vec::from(&[Shift(State::FalseS30)]),
-        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS31)]),
-        TK::Bang => Vec::from(&[Shift(State::BangS32)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS33)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_statementlist_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_div_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::If => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::While => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
-        TK::For => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_expression_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_percent_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Plus => Vec::from(&[Shift(State::PlusS43)]),
-        TK::Minus => Vec::from(&[Shift(State::MinusS44)]),
-        TK::Mul => Vec::from(&[Shift(State::MulS45)]),
-        TK::Div => Vec::from(&[Shift(State::DivS46)]),
-        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS85)]),
-        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS47)]),
-        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS48)]),
-        TK::Less => Vec::from(&[Shift(State::LessS49)]),
-        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS50)]),
-        TK::Greater => Vec::from(&[Shift(State::GreaterS51)]),
-        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS52)]),
-        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS53)]),
-        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_closeparenthesis_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_starstar_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn action_statementlist_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_equalsequals_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::OpenBrace => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::CloseBrace => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::If => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::Let => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::Const => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::While => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
-        TK::For => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
         _ => vec![],
     }
 }
-fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Program => State::ProgramS2,
-        NonTermKind::StatementList => State::StatementListS3,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGS0
-            )
-        }
+fn action_bangequals_s101(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_openbrace_s1(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::StatementList => State::StatementListS10,
-        NonTermKind::Statement1 => State::Statement1S11,
-        NonTermKind::Statement => State::StatementS12,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::IfStatement => State::IfStatementS14,
-        NonTermKind::VariableDeclaration => State::VariableDeclarationS15,
-        NonTermKind::ConstantDeclaration => State::ConstantDeclarationS16,
-        NonTermKind::WhileStatement => State::WhileStatementS17,
-        NonTermKind::ForStatement => State::ForStatementS18,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OpenBraceS1
-            )
-        }
+fn action_less_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_statement1_s11(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::StatementList => State::StatementListS10,
-        NonTermKind::Statement => State::StatementS26,
-        NonTermKind::Assignment => State::AssignmentS13,
-        NonTermKind::IfStatement => State::IfStatementS14,
-        NonTermKind::VariableDeclaration => State::VariableDeclarationS15,
-        NonTermKind::ConstantDeclaration => State::ConstantDeclarationS16,
-        NonTermKind::WhileStatement => State::WhileStatementS17,
-        NonTermKind::ForStatement => State::ForStatementS18,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Statement1S11
-            )
-        }
+fn action_lessorequals_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_equals_s19(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS34,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsS19
-            )
-        }
+fn action_greater_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_openparenthesis_s20(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS35,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OpenParenthesisS20
-            )
-        }
+fn action_greaterorequals_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_openparenthesis_s23(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OpenParenthesisS23
-            )
-        }
+fn action_ampersandampersand_s106(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_minus_s28(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS40,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::MinusS28
-            )
-        }
+fn action_pipepipe_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_openparenthesis_s31(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS41,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::OpenParenthesisS31
-            )
-        }
+fn action_ampersand_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_bang_s32(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS42,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::BangS32
-            )
-        }
+fn action_pipe_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_equals_s36(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS57,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsS36
-            )
-        }
+fn action_caret_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_equals_s37(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS58,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsS37
-            )
-        }
+fn action_lessless_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_plus_s43(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS62,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::PlusS43
-            )
-        }
+fn action_greatergreater_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_minus_s44(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS63,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::MinusS44
-            )
-        }
+fn action_openbracket_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_mul_s45(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS64,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::MulS45
-            )
+fn action_semicolon_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::OpenBrace => Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)]),
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
         }
+        TK::IfKeyword => Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)]),
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::FnKeyword => Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)]),
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::ReturnStatementReturnStatement, 3usize)])
+        }
+        _ => vec![],
     }
 }
-fn goto_div_s46(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS65,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::DivS46
-            )
-        }
+fn action_dot_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS160)]),
+        _ => vec![],
     }
 }
-fn goto_equalsequals_s47(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS66,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsEqualsS47
-            )
-        }
+fn action_openbrace_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS161)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldDeclaration0P2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_bangequals_s48(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS67,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::BangEqualsS48
-            )
-        }
+fn action_comma_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_less_s49(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS68,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LessS49
-            )
+fn action_closeparenthesis_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::CloseParenthesis => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
+        }
+        TK::EqualsEquals => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
+        }
+        TK::BangEquals => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::LessOrEquals => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
+        }
+        TK::Greater => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::GreaterOrEquals => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
+        }
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
+        }
+        TK::PipePipe => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::GreaterGreater => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
         }
+        TK::CloseBrace => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::CloseBracket => {
+            Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)])
+        }
+        TK::SemiColon => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::CallExpressionCallExpression, 4usize)]),
+        _ => vec![],
     }
 }
-fn goto_lessorequals_s50(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS69,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::LessOrEqualsS50
-            )
-        }
+fn action_closebracket_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::PlusEquals => Vec::from(&[Shift(State::PlusEqualsS35)]),
+        TK::MinusEquals => Vec::from(&[Shift(State::MinusEqualsS36)]),
+        TK::MulEquals => Vec::from(&[Shift(State::MulEqualsS37)]),
+        TK::DivEquals => Vec::from(&[Shift(State::DivEqualsS38)]),
+        TK::PercentEquals => Vec::from(&[Shift(State::PercentEqualsS39)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS166)]),
+        _ => vec![],
     }
 }
-fn goto_greater_s51(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS70,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GreaterS51
-            )
-        }
+fn action_semicolon_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::AssignmentAssignment, 4usize)]),
+        _ => vec![],
     }
 }
-fn goto_greaterorequals_s52(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS71,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::GreaterOrEqualsS52
-            )
-        }
+fn action_equals_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_ampersandampersand_s53(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS72,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AmpersandAmpersandS53
-            )
-        }
+fn action_compoundoperator_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_pipepipe_s54(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS73,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::PipePipeS54
-            )
+fn action_semicolon_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)])
+        }
+        TK::ForKeyword => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)]),
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::AssignmentCompoundAssignment, 4usize)])
         }
+        _ => vec![],
     }
 }
-fn goto_closeparenthesis_s56(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::StatementList => State::StatementListS74,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CloseParenthesisS56
-            )
-        }
+fn action_closeparenthesis_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        _ => vec![],
     }
 }
-fn goto_closeparenthesis_s59(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::StatementList => State::StatementListS77,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CloseParenthesisS59
-            )
-        }
+fn action_expression_s125(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS171)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
     }
 }
-fn goto_equals_s60(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS78,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::EqualsS60
-            )
-        }
+fn action_expression_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS172)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
     }
 }
-fn goto_statementlist_s74(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::ElseClauseOpt => State::ElseClauseOptS80,
-        NonTermKind::ElseClause => State::ElseClauseS81,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::StatementListS74
-            )
-        }
+fn action_closeparenthesis_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        _ => vec![],
     }
 }
-fn goto_else_s79(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::StatementList => State::StatementListS83,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ElseS79
-            )
-        }
+fn action_equals_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
     }
 }
-fn goto_to_s82(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Expression => State::ExpressionS84,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ToS82
-            )
-        }
+fn action_identifier_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::TypeClauseOptP2, 0usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeClauseOptP2, 0usize)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS175)]),
+        _ => vec![],
     }
 }
-fn goto_closeparenthesis_s85(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::StatementList => State::StatementListS86,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CloseParenthesisS85
-            )
+fn action_parameter1_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Parameter0P1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS178)]),
+        _ => vec![],
+    }
+}
+fn action_parameter0_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS179)]),
+        _ => vec![],
+    }
+}
+fn action_parameter_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Parameter1P2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::Parameter1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_closeparenthesis_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionP28, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS180)]),
+        _ => vec![],
+    }
+}
+fn action_fieldinitializer1_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldInitializer0P1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS181)]),
+        _ => vec![],
+    }
+}
+fn action_fieldinitializer0_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Shift(State::CloseBraceS182)]),
+        _ => vec![],
+    }
+}
+fn action_fieldinitializer_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldInitializer1P2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldInitializer1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_closebracket_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)])
         }
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionArrayLiteral, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
-    panic!("Invalid GOTO entry!");
+fn action_expression_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS183)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
 }
-pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDefinition {
-    actions: [
-        action_aug_s0,
-        action_openbrace_s1,
-        action_program_s2,
-        action_statementlist_s3,
-        action_identifier_s4,
-        action_if_s5,
-        action_let_s6,
-        action_const_s7,
-        action_while_s8,
-        action_for_s9,
-        action_statementlist_s10,
-        action_statement1_s11,
-        action_statement_s12,
-        action_assignment_s13,
-        action_ifstatement_s14,
-        action_variabledeclaration_s15,
-        action_constantdeclaration_s16,
-        action_whilestatement_s17,
-        action_forstatement_s18,
-        action_equals_s19,
-        action_openparenthesis_s20,
-        action_identifier_s21,
-        action_identifier_s22,
-        action_openparenthesis_s23,
-        action_openparenthesis_s24,
-        action_closebrace_s25,
-        action_statement_s26,
-        action_number_s27,
-        action_minus_s28,
-        action_true_s29,
-        action_false_s30,
-        action_openparenthesis_s31,
-        action_bang_s32,
-        action_identifier_s33,
-        action_expression_s34,
-        action_expression_s35,
-        action_equals_s36,
-        action_equals_s37,
-        action_expression_s38,
-        action_identifier_s39,
-        action_expression_s40,
-        action_expression_s41,
-        action_expression_s42,
-        action_plus_s43,
-        action_minus_s44,
-        action_mul_s45,
-        action_div_s46,
-        action_equalsequals_s47,
-        action_bangequals_s48,
-        action_less_s49,
-        action_lessorequals_s50,
-        action_greater_s51,
-        action_greaterorequals_s52,
-        action_ampersandampersand_s53,
-        action_pipepipe_s54,
-        action_semicolon_s55,
-        action_closeparenthesis_s56,
-        action_expression_s57,
-        action_expression_s58,
-        action_closeparenthesis_s59,
-        action_equals_s60,
-        action_closeparenthesis_s61,
-        action_expression_s62,
-        action_expression_s63,
-        action_expression_s64,
-        action_expression_s65,
-        action_expression_s66,
-        action_expression_s67,
-        action_expression_s68,
-        action_expression_s69,
-        action_expression_s70,
-        action_expression_s71,
-        action_expression_s72,
-        action_expression_s73,
-        action_statementlist_s74,
-        action_semicolon_s75,
-        action_semicolon_s76,
-        action_statementlist_s77,
-        action_expression_s78,
-        action_else_s79,
-        action_elseclauseopt_s80,
-        action_elseclause_s81,
-        action_to_s82,
-        action_statementlist_s83,
-        action_expression_s84,
-        action_closeparenthesis_s85,
-        action_statementlist_s86,
-    ],
-    gotos: [
-        goto_aug_s0,
-        goto_openbrace_s1,
-        goto_invalid,
-        goto_invalid,
+fn action_expression_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionModulo, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionPower, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s146(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionEquals, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s147(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionNotEquals, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s148(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionLess, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s149(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)])
+        }
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionLessOrEquals, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s150(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionGreater, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s151(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => {
+            Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)])
+        }
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::GreaterOrEquals => {
+            Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)])
+        }
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)])
+        }
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionGreaterOrEquals, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s152(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionAnd, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s153(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionOr, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s154(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionBitwiseAnd, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s155(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionBitwiseOr, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s156(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionBitwiseXor, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s157(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionShiftLeft, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s158(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionShiftRight, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s159(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Shift(State::CloseBracketS184)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s160(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionField, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s161(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS175)]),
+        _ => vec![],
+    }
+}
+fn action_fielddeclaration1_s162(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldDeclaration0P1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS186)]),
+        _ => vec![],
+    }
+}
+fn action_fielddeclaration0_s163(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Shift(State::CloseBraceS187)]),
+        _ => vec![],
+    }
+}
+fn action_fielddeclaration_s164(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldDeclaration1P2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldDeclaration1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s165(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Expression1P1, 3usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::Expression1P1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::Expression1P1, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_equals_s166(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_compoundoperator_s167(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s168(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS190)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s169(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS191)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_statementlist_s170(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::ElseKeyword => Vec::from(&[Shift(State::ElseKeywordS192)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s171(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::VariableDeclarationVariableDeclaration, 5usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_semicolon_s172(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::ConstantDeclarationConstantDeclaration, 5usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_statementlist_s173(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::WhileStatementWhile, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s174(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::ToKeyword => Vec::from(&[Shift(State::ToKeywordS195)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s175(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS196)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS197)]),
+        _ => vec![],
+    }
+}
+fn action_typeclauseopt_s176(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ParameterParameter, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ParameterParameter, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_typeclause_s177(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::TypeClauseOptP1, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::TypeClauseOptP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeClauseOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s178(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS129)]),
+        _ => vec![],
+    }
+}
+fn action_closeparenthesis_s179(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OpenBrace => Vec::from(&[Reduce(PK::TypeClauseOptP2, 0usize)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS175)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s180(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s181(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS134)]),
+        _ => vec![],
+    }
+}
+fn action_closebrace_s182(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::AmpersandAmpersand => {
+            Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)])
+        }
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionStructLiteral, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_closeparenthesis_s183(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_closebracket_s184(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Mul => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Div => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Percent => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::StarStar => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::EqualsEquals => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::BangEquals => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Less => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::LessOrEquals => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Greater => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::GreaterOrEquals => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::AmpersandAmpersand => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::PipePipe => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Pipe => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Caret => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::LessLess => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::GreaterGreater => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        TK::Dot => Vec::from(&[Reduce(PK::ExpressionIndex, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_typeclause_s185(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::FieldDeclarationFieldDeclaration, 2usize)])
+        }
+        TK::Comma => Vec::from(&[Reduce(PK::FieldDeclarationFieldDeclaration, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s186(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS161)]),
+        _ => vec![],
+    }
+}
+fn action_closebrace_s187(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::StructDeclarationStructDeclaration, 5usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_expression_s188(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS205)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s189(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::SemiColon => Vec::from(&[Shift(State::SemiColonS206)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s190(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::OpenBrace => Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)]),
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::IfKeyword => Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)]),
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::FnKeyword => Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)]),
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentFieldAssignment, 6usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_semicolon_s191(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::FieldAssignmentCompoundFieldAssignment, 6usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_elsekeyword_s192(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS5)]),
+        _ => vec![],
+    }
+}
+fn action_elseclauseopt_s193(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::IfStatementIfStatement, 6usize)]),
+        _ => vec![],
+    }
+}
+fn action_elseclause_s194(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::ElseClauseOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_tokeyword_s195(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s196(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::TypeNameTypeName, 1usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::TypeNameTypeName, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::TypeNameTypeName, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::TypeNameTypeName, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeNameTypeName, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_openbracket_s197(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS196)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS197)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s198(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::TypeClauseTypeClause, 2usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::TypeClauseTypeClause, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::TypeClauseTypeClause, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeClauseTypeClause, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_parameter_s199(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::Parameter1P1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::Parameter1P1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_typeclauseopt_s200(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s201(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::FieldInitializerFieldInitializer, 3usize)])
+        }
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldInitializerFieldInitializer, 3usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_fieldinitializer_s202(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldInitializer1P1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldInitializer1P1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s203(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::ElseKeyword => Vec::from(&[Shift(State::ElseKeywordS212)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_fielddeclaration_s204(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldDeclaration1P1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldDeclaration1P1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s205(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::OpenBrace => Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)]),
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::IfKeyword => Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)]),
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::FnKeyword => Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)]),
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentIndexAssignment, 7usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_semicolon_s206(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::IndexAssignmentCompoundIndexAssignment, 7usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_statementlist_s207(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::ElseClauseElseStatement, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_ifstatement_s208(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::ElseClauseElseIf, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s209(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Shift(State::CloseParenthesisS213)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s210(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Shift(State::CloseBracketS214)]),
+        _ => vec![],
+    }
+}
+fn action_statementlist_s211(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::OpenBrace => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::CloseBrace => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::IfKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::LetKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::ConstKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::WhileKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::ForKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::FnKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::ReturnKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::BreakKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::ContinueKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        TK::StructKeyword => {
+            Vec::from(&[Reduce(PK::FunctionDeclarationFunctionDeclaration, 7usize)])
+        }
+        _ => vec![],
+    }
+}
+fn action_elsekeyword_s212(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Number => Vec::from(&[Shift(State::NumberS54)]),
+        TK::StringLiteral => Vec::from(&[Shift(State::StringLiteralS55)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS56)]),
+        TK::TrueKeyword => Vec::from(&[Shift(State::TrueKeywordS57)]),
+        TK::FalseKeyword => Vec::from(&[Shift(State::FalseKeywordS58)]),
+        TK::OpenParenthesis => Vec::from(&[Shift(State::OpenParenthesisS59)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS60)]),
+        TK::Tilde => Vec::from(&[Shift(State::TildeS61)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS62)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS63)]),
+        TK::IfKeyword => Vec::from(&[Shift(State::IfKeywordS64)]),
+        _ => vec![],
+    }
+}
+fn action_closeparenthesis_s213(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS1)]),
+        _ => vec![],
+    }
+}
+fn action_closebracket_s214(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::TypeNameArrayTypeName, 3usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::TypeNameArrayTypeName, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::TypeNameArrayTypeName, 3usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::TypeNameArrayTypeName, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeNameArrayTypeName, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s215(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Plus => Vec::from(&[Shift(State::PlusS94)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS95)]),
+        TK::Mul => Vec::from(&[Shift(State::MulS96)]),
+        TK::Div => Vec::from(&[Shift(State::DivS97)]),
+        TK::Percent => Vec::from(&[Shift(State::PercentS98)]),
+        TK::StarStar => Vec::from(&[Shift(State::StarStarS99)]),
+        TK::CloseParenthesis => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::EqualsEquals => Vec::from(&[Shift(State::EqualsEqualsS100)]),
+        TK::BangEquals => Vec::from(&[Shift(State::BangEqualsS101)]),
+        TK::Less => Vec::from(&[Shift(State::LessS102)]),
+        TK::LessOrEquals => Vec::from(&[Shift(State::LessOrEqualsS103)]),
+        TK::Greater => Vec::from(&[Shift(State::GreaterS104)]),
+        TK::GreaterOrEquals => Vec::from(&[Shift(State::GreaterOrEqualsS105)]),
+        TK::AmpersandAmpersand => Vec::from(&[Shift(State::AmpersandAmpersandS106)]),
+        TK::PipePipe => Vec::from(&[Shift(State::PipePipeS107)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS108)]),
+        TK::Pipe => Vec::from(&[Shift(State::PipeS109)]),
+        TK::Caret => Vec::from(&[Shift(State::CaretS110)]),
+        TK::LessLess => Vec::from(&[Shift(State::LessLessS111)]),
+        TK::GreaterGreater => Vec::from(&[Shift(State::GreaterGreaterS112)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS113)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::SemiColon => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::ElseKeyword => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::ToKeyword => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionConditional, 7usize)]),
+        TK::Dot => Vec::from(&[Shift(State::DotS115)]),
+        _ => vec![],
+    }
+}
+fn action_statementlist_s216(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::OpenBrace => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::IfKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::LetKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::ConstKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::WhileKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::ForKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::FnKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::ReturnKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::BreakKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::ContinueKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        TK::StructKeyword => Vec::from(&[Reduce(PK::ForStatementFor, 9usize)]),
+        _ => vec![],
+    }
+}
+fn action_augl_s217(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
+        TK::WhiteSpace => Vec::from(&[Shift(State::WhiteSpaceS218)]),
+        TK::LineComment => Vec::from(&[Shift(State::LineCommentS219)]),
+        TK::CommentStart => Vec::from(&[Shift(State::CommentStartS220)]),
+        _ => vec![],
+    }
+}
+fn action_whitespace_s218(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_linecomment_s219(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_commentstart_s220(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Shift(State::WhiteSpaceS227)]),
+        TK::CommentStart => Vec::from(&[Shift(State::CommentStartS220)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContent0P2, 0usize)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS228)]),
+        _ => vec![],
+    }
+}
+fn action_layout_s221(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Accept]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem1_s222(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
+        TK::WhiteSpace => Vec::from(&[Shift(State::WhiteSpaceS218)]),
+        TK::LineComment => Vec::from(&[Shift(State::LineCommentS219)]),
+        TK::CommentStart => Vec::from(&[Shift(State::CommentStartS220)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem0_s223(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem_s224(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_comment_s225(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_blockcomment_s226(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::CommentP1, 1usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentP1, 1usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::CommentP1, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_whitespace_s227(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentContentP3, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentContentP3, 1usize)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContentP3, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentContentP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_notcomment_s228(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentContentP2, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentContentP2, 1usize)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContentP2, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentContentP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_blockcomment_s229(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentContentP1, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentContentP1, 1usize)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContentP1, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentContentP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_commentcontent1_s230(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Shift(State::WhiteSpaceS227)]),
+        TK::CommentStart => Vec::from(&[Shift(State::CommentStartS220)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContent0P1, 1usize)]),
+        TK::NotComment => Vec::from(&[Shift(State::NotCommentS228)]),
+        _ => vec![],
+    }
+}
+fn action_commentcontent0_s231(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CommentEnd => Vec::from(&[Shift(State::CommentEndS235)]),
+        _ => vec![],
+    }
+}
+fn action_commentcontent_s232(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentContent1P2, 1usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentContent1P2, 1usize)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContent1P2, 1usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentContent1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem_s233(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_commentcontent_s234(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::CommentContent1P1, 2usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::CommentContent1P1, 2usize)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::CommentContent1P1, 2usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::CommentContent1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_commentend_s235(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::BlockCommentP1, 3usize)]),
+        TK::WhiteSpace => Vec::from(&[Reduce(PK::BlockCommentP1, 3usize)]),
+        TK::LineComment => Vec::from(&[Reduce(PK::BlockCommentP1, 3usize)]),
+        TK::CommentStart => Vec::from(&[Reduce(PK::BlockCommentP1, 3usize)]),
+        TK::CommentEnd => Vec::from(&[Reduce(PK::BlockCommentP1, 3usize)]),
+        TK::NotComment => Vec::from(&[Reduce(PK::BlockCommentP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Program => State::ProgramS2,
+        NonTermKind::StatementList => State::StatementListS3,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGS0
+            )
+        }
+    }
+}
+fn goto_openbrace_s1(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS15,
+        NonTermKind::Statement1 => State::Statement1S16,
+        NonTermKind::Statement0 => State::Statement0S17,
+        NonTermKind::Statement => State::StatementS18,
+        NonTermKind::Assignment => State::AssignmentS19,
+        NonTermKind::IncrementStatement => State::IncrementStatementS20,
+        NonTermKind::IndexAssignment => State::IndexAssignmentS21,
+        NonTermKind::FieldAssignment => State::FieldAssignmentS22,
+        NonTermKind::IfStatement => State::IfStatementS23,
+        NonTermKind::VariableDeclaration => State::VariableDeclarationS24,
+        NonTermKind::ConstantDeclaration => State::ConstantDeclarationS25,
+        NonTermKind::WhileStatement => State::WhileStatementS26,
+        NonTermKind::ForStatement => State::ForStatementS27,
+        NonTermKind::FunctionDeclaration => State::FunctionDeclarationS28,
+        NonTermKind::StructDeclaration => State::StructDeclarationS29,
+        NonTermKind::ReturnStatement => State::ReturnStatementS30,
+        NonTermKind::BreakStatement => State::BreakStatementS31,
+        NonTermKind::ContinueStatement => State::ContinueStatementS32,
+        NonTermKind::ExpressionStatement => State::ExpressionStatementS33,
+        NonTermKind::CallExpression => State::CallExpressionS34,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBraceS1
+            )
+        }
+    }
+}
+fn goto_identifier_s4(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CompoundOperator => State::CompoundOperatorS46,
+        NonTermKind::IncrementOperator => State::IncrementOperatorS47,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IdentifierS4
+            )
+        }
+    }
+}
+fn goto_returnkeyword_s11(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS66,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ReturnKeywordS11
+            )
+        }
+    }
+}
+fn goto_statement1_s16(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS15,
+        NonTermKind::Statement => State::StatementS70,
+        NonTermKind::Assignment => State::AssignmentS19,
+        NonTermKind::IncrementStatement => State::IncrementStatementS20,
+        NonTermKind::IndexAssignment => State::IndexAssignmentS21,
+        NonTermKind::FieldAssignment => State::FieldAssignmentS22,
+        NonTermKind::IfStatement => State::IfStatementS23,
+        NonTermKind::VariableDeclaration => State::VariableDeclarationS24,
+        NonTermKind::ConstantDeclaration => State::ConstantDeclarationS25,
+        NonTermKind::WhileStatement => State::WhileStatementS26,
+        NonTermKind::ForStatement => State::ForStatementS27,
+        NonTermKind::FunctionDeclaration => State::FunctionDeclarationS28,
+        NonTermKind::StructDeclaration => State::StructDeclarationS29,
+        NonTermKind::ReturnStatement => State::ReturnStatementS30,
+        NonTermKind::BreakStatement => State::BreakStatementS31,
+        NonTermKind::ContinueStatement => State::ContinueStatementS32,
+        NonTermKind::ExpressionStatement => State::ExpressionStatementS33,
+        NonTermKind::CallExpression => State::CallExpressionS34,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::Statement1S16
+            )
+        }
+    }
+}
+fn goto_openparenthesis_s42(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression1 => State::Expression1S73,
+        NonTermKind::Expression0 => State::Expression0S74,
+        NonTermKind::Expression => State::ExpressionS75,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenthesisS42
+            )
+        }
+    }
+}
+fn goto_openbracket_s43(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS76,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBracketS43
+            )
+        }
+    }
+}
+fn goto_equals_s44(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS77,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS44
+            )
+        }
+    }
+}
+fn goto_compoundoperator_s46(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS79,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CompoundOperatorS46
+            )
+        }
+    }
+}
+fn goto_openparenthesis_s48(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS81,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenthesisS48
+            )
+        }
+    }
+}
+fn goto_openparenthesis_s51(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS84,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenthesisS51
+            )
+        }
+    }
+}
+fn goto_minus_s56(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS87,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::MinusS56
+            )
+        }
+    }
+}
+fn goto_openparenthesis_s59(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS88,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenthesisS59
+            )
+        }
+    }
+}
+fn goto_bang_s60(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS89,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BangS60
+            )
+        }
+    }
+}
+fn goto_tilde_s61(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS90,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TildeS61
+            )
+        }
+    }
+}
+fn goto_openbracket_s63(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression1 => State::Expression1S73,
+        NonTermKind::Expression0 => State::Expression0S92,
+        NonTermKind::Expression => State::ExpressionS75,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBracketS63
+            )
+        }
+    }
+}
+fn goto_identifier_s78(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CompoundOperator => State::CompoundOperatorS122,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IdentifierS78
+            )
+        }
+    }
+}
+fn goto_equals_s82(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS125,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS82
+            )
+        }
+    }
+}
+fn goto_equals_s83(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS126,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS83
+            )
+        }
+    }
+}
+fn goto_openparenthesis_s86(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Parameter1 => State::Parameter1S130,
+        NonTermKind::Parameter0 => State::Parameter0S131,
+        NonTermKind::Parameter => State::ParameterS132,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenthesisS86
+            )
+        }
+    }
+}
+fn goto_openbrace_s91(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FieldInitializer1 => State::FieldInitializer1S135,
+        NonTermKind::FieldInitializer0 => State::FieldInitializer0S136,
+        NonTermKind::FieldInitializer => State::FieldInitializerS137,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBraceS91
+            )
+        }
+    }
+}
+fn goto_openparenthesis_s93(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS139,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenthesisS93
+            )
+        }
+    }
+}
+fn goto_plus_s94(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS140,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PlusS94
+            )
+        }
+    }
+}
+fn goto_minus_s95(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS141,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::MinusS95
+            )
+        }
+    }
+}
+fn goto_mul_s96(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS142,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::MulS96
+            )
+        }
+    }
+}
+fn goto_div_s97(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS143,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::DivS97
+            )
+        }
+    }
+}
+fn goto_percent_s98(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS144,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PercentS98
+            )
+        }
+    }
+}
+fn goto_starstar_s99(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS145,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::StarStarS99
+            )
+        }
+    }
+}
+fn goto_equalsequals_s100(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS146,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsEqualsS100
+            )
+        }
+    }
+}
+fn goto_bangequals_s101(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS147,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::BangEqualsS101
+            )
+        }
+    }
+}
+fn goto_less_s102(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS148,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LessS102
+            )
+        }
+    }
+}
+fn goto_lessorequals_s103(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS149,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LessOrEqualsS103
+            )
+        }
+    }
+}
+fn goto_greater_s104(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS150,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GreaterS104
+            )
+        }
+    }
+}
+fn goto_greaterorequals_s105(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS151,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GreaterOrEqualsS105
+            )
+        }
+    }
+}
+fn goto_ampersandampersand_s106(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS152,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AmpersandAmpersandS106
+            )
+        }
+    }
+}
+fn goto_pipepipe_s107(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS153,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PipePipeS107
+            )
+        }
+    }
+}
+fn goto_ampersand_s108(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS154,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AmpersandS108
+            )
+        }
+    }
+}
+fn goto_pipe_s109(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS155,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PipeS109
+            )
+        }
+    }
+}
+fn goto_caret_s110(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS156,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CaretS110
+            )
+        }
+    }
+}
+fn goto_lessless_s111(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS157,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LessLessS111
+            )
+        }
+    }
+}
+fn goto_greatergreater_s112(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS158,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::GreaterGreaterS112
+            )
+        }
+    }
+}
+fn goto_openbracket_s113(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS159,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBracketS113
+            )
+        }
+    }
+}
+fn goto_openbrace_s116(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FieldDeclaration1 => State::FieldDeclaration1S162,
+        NonTermKind::FieldDeclaration0 => State::FieldDeclaration0S163,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS164,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBraceS116
+            )
+        }
+    }
+}
+fn goto_comma_s117(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS165,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS117
+            )
+        }
+    }
+}
+fn goto_closebracket_s119(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CompoundOperator => State::CompoundOperatorS167,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CloseBracketS119
+            )
+        }
+    }
+}
+fn goto_equals_s121(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS168,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS121
+            )
+        }
+    }
+}
+fn goto_compoundoperator_s122(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS169,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CompoundOperatorS122
+            )
+        }
+    }
+}
+fn goto_closeparenthesis_s124(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS170,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CloseParenthesisS124
+            )
+        }
+    }
+}
+fn goto_closeparenthesis_s127(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS173,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CloseParenthesisS127
+            )
+        }
+    }
+}
+fn goto_equals_s128(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS174,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS128
+            )
+        }
+    }
+}
+fn goto_identifier_s129(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeClauseOpt => State::TypeClauseOptS176,
+        NonTermKind::TypeClause => State::TypeClauseS177,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IdentifierS129
+            )
+        }
+    }
+}
+fn goto_identifier_s161(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeClause => State::TypeClauseS185,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IdentifierS161
+            )
+        }
+    }
+}
+fn goto_equals_s166(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS188,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS166
+            )
+        }
+    }
+}
+fn goto_compoundoperator_s167(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS189,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CompoundOperatorS167
+            )
+        }
+    }
+}
+fn goto_statementlist_s170(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ElseClauseOpt => State::ElseClauseOptS193,
+        NonTermKind::ElseClause => State::ElseClauseS194,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::StatementListS170
+            )
+        }
+    }
+}
+fn goto_colon_s175(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS198,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS175
+            )
+        }
+    }
+}
+fn goto_comma_s178(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Parameter => State::ParameterS199,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS178
+            )
+        }
+    }
+}
+fn goto_closeparenthesis_s179(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeClauseOpt => State::TypeClauseOptS200,
+        NonTermKind::TypeClause => State::TypeClauseS177,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CloseParenthesisS179
+            )
+        }
+    }
+}
+fn goto_colon_s180(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS201,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS180
+            )
+        }
+    }
+}
+fn goto_comma_s181(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FieldInitializer => State::FieldInitializerS202,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS181
+            )
+        }
+    }
+}
+fn goto_closeparenthesis_s183(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS203,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CloseParenthesisS183
+            )
+        }
+    }
+}
+fn goto_comma_s186(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS204,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS186
+            )
+        }
+    }
+}
+fn goto_elsekeyword_s192(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS207,
+        NonTermKind::IfStatement => State::IfStatementS208,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ElseKeywordS192
+            )
+        }
+    }
+}
+fn goto_tokeyword_s195(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS209,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ToKeywordS195
+            )
+        }
+    }
+}
+fn goto_openbracket_s197(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeName => State::TypeNameS210,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBracketS197
+            )
+        }
+    }
+}
+fn goto_typeclauseopt_s200(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS211,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeClauseOptS200
+            )
+        }
+    }
+}
+fn goto_elsekeyword_s212(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::CallExpression => State::CallExpressionS65,
+        NonTermKind::Expression => State::ExpressionS215,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ElseKeywordS212
+            )
+        }
+    }
+}
+fn goto_closeparenthesis_s213(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::StatementList => State::StatementListS216,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CloseParenthesisS213
+            )
+        }
+    }
+}
+fn goto_augl_s217(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Layout => State::LayoutS221,
+        NonTermKind::LayoutItem1 => State::LayoutItem1S222,
+        NonTermKind::LayoutItem0 => State::LayoutItem0S223,
+        NonTermKind::LayoutItem => State::LayoutItemS224,
+        NonTermKind::Comment => State::CommentS225,
+        NonTermKind::BlockComment => State::BlockCommentS226,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGLS217
+            )
+        }
+    }
+}
+fn goto_commentstart_s220(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::BlockComment => State::BlockCommentS229,
+        NonTermKind::CommentContent1 => State::CommentContent1S230,
+        NonTermKind::CommentContent0 => State::CommentContent0S231,
+        NonTermKind::CommentContent => State::CommentContentS232,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommentStartS220
+            )
+        }
+    }
+}
+fn goto_layoutitem1_s222(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LayoutItem => State::LayoutItemS233,
+        NonTermKind::Comment => State::CommentS225,
+        NonTermKind::BlockComment => State::BlockCommentS226,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LayoutItem1S222
+            )
+        }
+    }
+}
+fn goto_commentcontent1_s230(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::BlockComment => State::BlockCommentS229,
+        NonTermKind::CommentContent => State::CommentContentS234,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommentContent1S230
+            )
+        }
+    }
+}
+fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
+    panic!("Invalid GOTO entry!");
+}
+pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDefinition {
+    actions: [
+        action_aug_s0,
+        action_openbrace_s1,
+        action_program_s2,
+        action_statementlist_s3,
+        action_identifier_s4,
+        action_ifkeyword_s5,
+        action_letkeyword_s6,
+        action_constkeyword_s7,
+        action_whilekeyword_s8,
+        action_forkeyword_s9,
+        action_fnkeyword_s10,
+        action_returnkeyword_s11,
+        action_breakkeyword_s12,
+        action_continuekeyword_s13,
+        action_structkeyword_s14,
+        action_statementlist_s15,
+        action_statement1_s16,
+        action_statement0_s17,
+        action_statement_s18,
+        action_assignment_s19,
+        action_incrementstatement_s20,
+        action_indexassignment_s21,
+        action_fieldassignment_s22,
+        action_ifstatement_s23,
+        action_variabledeclaration_s24,
+        action_constantdeclaration_s25,
+        action_whilestatement_s26,
+        action_forstatement_s27,
+        action_functiondeclaration_s28,
+        action_structdeclaration_s29,
+        action_returnstatement_s30,
+        action_breakstatement_s31,
+        action_continuestatement_s32,
+        action_expressionstatement_s33,
+        action_callexpression_s34,
+        action_plusequals_s35,
+        action_minusequals_s36,
+        action_mulequals_s37,
+        action_divequals_s38,
+        action_percentequals_s39,
+        action_plusplus_s40,
+        action_minusminus_s41,
+        action_openparenthesis_s42,
+        action_openbracket_s43,
+        action_equals_s44,
+        action_dot_s45,
+        action_compoundoperator_s46,
+        action_incrementoperator_s47,
+        action_openparenthesis_s48,
+        action_identifier_s49,
+        action_identifier_s50,
+        action_openparenthesis_s51,
+        action_openparenthesis_s52,
+        action_identifier_s53,
+        action_number_s54,
+        action_stringliteral_s55,
+        action_minus_s56,
+        action_truekeyword_s57,
+        action_falsekeyword_s58,
+        action_openparenthesis_s59,
+        action_bang_s60,
+        action_tilde_s61,
+        action_identifier_s62,
+        action_openbracket_s63,
+        action_ifkeyword_s64,
+        action_callexpression_s65,
+        action_expression_s66,
+        action_semicolon_s67,
+        action_semicolon_s68,
+        action_identifier_s69,
+        action_statement_s70,
+        action_closebrace_s71,
+        action_semicolon_s72,
+        action_expression1_s73,
+        action_expression0_s74,
+        action_expression_s75,
+        action_expression_s76,
+        action_expression_s77,
+        action_identifier_s78,
+        action_expression_s79,
+        action_semicolon_s80,
+        action_expression_s81,
+        action_equals_s82,
+        action_equals_s83,
+        action_expression_s84,
+        action_identifier_s85,
+        action_openparenthesis_s86,
+        action_expression_s87,
+        action_expression_s88,
+        action_expression_s89,
+        action_expression_s90,
+        action_openbrace_s91,
+        action_expression0_s92,
+        action_openparenthesis_s93,
+        action_plus_s94,
+        action_minus_s95,
+        action_mul_s96,
+        action_div_s97,
+        action_percent_s98,
+        action_starstar_s99,
+        action_equalsequals_s100,
+        action_bangequals_s101,
+        action_less_s102,
+        action_lessorequals_s103,
+        action_greater_s104,
+        action_greaterorequals_s105,
+        action_ampersandampersand_s106,
+        action_pipepipe_s107,
+        action_ampersand_s108,
+        action_pipe_s109,
+        action_caret_s110,
+        action_lessless_s111,
+        action_greatergreater_s112,
+        action_openbracket_s113,
+        action_semicolon_s114,
+        action_dot_s115,
+        action_openbrace_s116,
+        action_comma_s117,
+        action_closeparenthesis_s118,
+        action_closebracket_s119,
+        action_semicolon_s120,
+        action_equals_s121,
+        action_compoundoperator_s122,
+        action_semicolon_s123,
+        action_closeparenthesis_s124,
+        action_expression_s125,
+        action_expression_s126,
+        action_closeparenthesis_s127,
+        action_equals_s128,
+        action_identifier_s129,
+        action_parameter1_s130,
+        action_parameter0_s131,
+        action_parameter_s132,
+        action_closeparenthesis_s133,
+        action_identifier_s134,
+        action_fieldinitializer1_s135,
+        action_fieldinitializer0_s136,
+        action_fieldinitializer_s137,
+        action_closebracket_s138,
+        action_expression_s139,
+        action_expression_s140,
+        action_expression_s141,
+        action_expression_s142,
+        action_expression_s143,
+        action_expression_s144,
+        action_expression_s145,
+        action_expression_s146,
+        action_expression_s147,
+        action_expression_s148,
+        action_expression_s149,
+        action_expression_s150,
+        action_expression_s151,
+        action_expression_s152,
+        action_expression_s153,
+        action_expression_s154,
+        action_expression_s155,
+        action_expression_s156,
+        action_expression_s157,
+        action_expression_s158,
+        action_expression_s159,
+        action_identifier_s160,
+        action_identifier_s161,
+        action_fielddeclaration1_s162,
+        action_fielddeclaration0_s163,
+        action_fielddeclaration_s164,
+        action_expression_s165,
+        action_equals_s166,
+        action_compoundoperator_s167,
+        action_expression_s168,
+        action_expression_s169,
+        action_statementlist_s170,
+        action_semicolon_s171,
+        action_semicolon_s172,
+        action_statementlist_s173,
+        action_expression_s174,
+        action_colon_s175,
+        action_typeclauseopt_s176,
+        action_typeclause_s177,
+        action_comma_s178,
+        action_closeparenthesis_s179,
+        action_colon_s180,
+        action_comma_s181,
+        action_closebrace_s182,
+        action_closeparenthesis_s183,
+        action_closebracket_s184,
+        action_typeclause_s185,
+        action_comma_s186,
+        action_closebrace_s187,
+        action_expression_s188,
+        action_expression_s189,
+        action_semicolon_s190,
+        action_semicolon_s191,
+        action_elsekeyword_s192,
+        action_elseclauseopt_s193,
+        action_elseclause_s194,
+        action_tokeyword_s195,
+        action_identifier_s196,
+        action_openbracket_s197,
+        action_typename_s198,
+        action_parameter_s199,
+        action_typeclauseopt_s200,
+        action_expression_s201,
+        action_fieldinitializer_s202,
+        action_expression_s203,
+        action_fielddeclaration_s204,
+        action_semicolon_s205,
+        action_semicolon_s206,
+        action_statementlist_s207,
+        action_ifstatement_s208,
+        action_expression_s209,
+        action_typename_s210,
+        action_statementlist_s211,
+        action_elsekeyword_s212,
+        action_closeparenthesis_s213,
+        action_closebracket_s214,
+        action_expression_s215,
+        action_statementlist_s216,
+        action_augl_s217,
+        action_whitespace_s218,
+        action_linecomment_s219,
+        action_commentstart_s220,
+        action_layout_s221,
+        action_layoutitem1_s222,
+        action_layoutitem0_s223,
+        action_layoutitem_s224,
+        action_comment_s225,
+        action_blockcomment_s226,
+        action_whitespace_s227,
+        action_notcomment_s228,
+        action_blockcomment_s229,
+        action_commentcontent1_s230,
+        action_commentcontent0_s231,
+        action_commentcontent_s232,
+        action_layoutitem_s233,
+        action_commentcontent_s234,
+        action_commentend_s235,
+    ],
+    gotos: [
+        goto_aug_s0,
+        goto_openbrace_s1,
+        goto_invalid,
+        goto_invalid,
+        goto_identifier_s4,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_returnkeyword_s11,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_statement1_s16,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_openparenthesis_s42,
+        goto_openbracket_s43,
+        goto_equals_s44,
+        goto_invalid,
+        goto_compoundoperator_s46,
+        goto_invalid,
+        goto_openparenthesis_s48,
+        goto_invalid,
+        goto_invalid,
+        goto_openparenthesis_s51,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_minus_s56,
+        goto_invalid,
+        goto_invalid,
+        goto_openparenthesis_s59,
+        goto_bang_s60,
+        goto_tilde_s61,
+        goto_invalid,
+        goto_openbracket_s63,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_identifier_s78,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_equals_s82,
+        goto_equals_s83,
+        goto_invalid,
+        goto_invalid,
+        goto_openparenthesis_s86,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_openbrace_s91,
+        goto_invalid,
+        goto_openparenthesis_s93,
+        goto_plus_s94,
+        goto_minus_s95,
+        goto_mul_s96,
+        goto_div_s97,
+        goto_percent_s98,
+        goto_starstar_s99,
+        goto_equalsequals_s100,
+        goto_bangequals_s101,
+        goto_less_s102,
+        goto_lessorequals_s103,
+        goto_greater_s104,
+        goto_greaterorequals_s105,
+        goto_ampersandampersand_s106,
+        goto_pipepipe_s107,
+        goto_ampersand_s108,
+        goto_pipe_s109,
+        goto_caret_s110,
+        goto_lessless_s111,
+        goto_greatergreater_s112,
+        goto_openbracket_s113,
+        goto_invalid,
+        goto_invalid,
+        goto_openbrace_s116,
+        goto_comma_s117,
+        goto_invalid,
+        goto_closebracket_s119,
+        goto_invalid,
+        goto_equals_s121,
+        goto_compoundoperator_s122,
+        goto_invalid,
+        goto_closeparenthesis_s124,
+        goto_invalid,
+        goto_invalid,
+        goto_closeparenthesis_s127,
+        goto_equals_s128,
+        goto_identifier_s129,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -2167,7 +6838,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_statement1_s11,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -2175,78 +6845,6641 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_equals_s19,
-        goto_openparenthesis_s20,
         goto_invalid,
         goto_invalid,
-        goto_openparenthesis_s23,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_minus_s28,
         goto_invalid,
         goto_invalid,
-        goto_openparenthesis_s31,
-        goto_bang_s32,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_equals_s36,
-        goto_equals_s37,
         goto_invalid,
         goto_invalid,
+        goto_identifier_s161,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_plus_s43,
-        goto_minus_s44,
-        goto_mul_s45,
-        goto_div_s46,
-        goto_equalsequals_s47,
-        goto_bangequals_s48,
-        goto_less_s49,
-        goto_lessorequals_s50,
-        goto_greater_s51,
-        goto_greaterorequals_s52,
-        goto_ampersandampersand_s53,
-        goto_pipepipe_s54,
         goto_invalid,
-        goto_closeparenthesis_s56,
+        goto_equals_s166,
+        goto_compoundoperator_s167,
         goto_invalid,
         goto_invalid,
-        goto_closeparenthesis_s59,
-        goto_equals_s60,
+        goto_statementlist_s170,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_colon_s175,
         goto_invalid,
         goto_invalid,
+        goto_comma_s178,
+        goto_closeparenthesis_s179,
+        goto_colon_s180,
+        goto_comma_s181,
         goto_invalid,
+        goto_closeparenthesis_s183,
         goto_invalid,
         goto_invalid,
+        goto_comma_s186,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_statementlist_s74,
         goto_invalid,
+        goto_elsekeyword_s192,
         goto_invalid,
         goto_invalid,
+        goto_tokeyword_s195,
         goto_invalid,
-        goto_else_s79,
+        goto_openbracket_s197,
+        goto_invalid,
+        goto_invalid,
+        goto_typeclauseopt_s200,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_elsekeyword_s212,
+        goto_closeparenthesis_s213,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_augl_s217,
+        goto_invalid,
+        goto_invalid,
+        goto_commentstart_s220,
+        goto_invalid,
+        goto_layoutitem1_s222,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_commentcontent1_s230,
         goto_invalid,
         goto_invalid,
-        goto_to_s82,
         goto_invalid,
         goto_invalid,
-        goto_closeparenthesis_s85,
         goto_invalid,
     ],
     token_kinds: [
         [
-            Some((TK::OpenBrace, true)),
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::PlusEquals, false)),
+            Some((TK::MinusEquals, false)),
+            Some((TK::MulEquals, false)),
+            Some((TK::DivEquals, false)),
+            Some((TK::PercentEquals, false)),
+            Some((TK::PlusPlus, false)),
+            Some((TK::MinusMinus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Equals, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::SemiColon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Equals, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Equals, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::IfKeyword, false)),
+            Some((TK::ElseKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::PlusEquals, false)),
+            Some((TK::MinusEquals, false)),
+            Some((TK::MulEquals, false)),
+            Some((TK::DivEquals, false)),
+            Some((TK::PercentEquals, false)),
+            Some((TK::Equals, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Equals, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::PlusEquals, false)),
+            Some((TK::MinusEquals, false)),
+            Some((TK::MulEquals, false)),
+            Some((TK::DivEquals, false)),
+            Some((TK::PercentEquals, false)),
+            Some((TK::Equals, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Colon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::ElseKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenBrace, false)),
+            Some((TK::Colon, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, true)),
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, false)),
+            Some((TK::Comma, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2263,13 +13496,28 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
             Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2280,7 +13528,22 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::STOP, false)),
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2297,7 +13560,22 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::STOP, false)),
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2314,7 +13592,24 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Equals, true)),
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2327,11 +13622,56 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         ],
         [
-            Some((TK::OpenParenthesis, true)),
+            Some((TK::CloseBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2348,7 +13688,22 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
             Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2365,7 +13720,22 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
+            Some((TK::TrueKeyword, false)),
+            Some((TK::FalseKeyword, false)),
+            Some((TK::IfKeyword, true)),
+            Some((TK::Number, false)),
+            Some((TK::StringLiteral, false)),
+            Some((TK::Minus, false)),
+            Some((TK::OpenParenthesis, false)),
+            Some((TK::Bang, false)),
+            Some((TK::Tilde, false)),
             Some((TK::Identifier, false)),
+            Some((TK::OpenBracket, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2382,7 +13752,22 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::OpenParenthesis, true)),
+            Some((TK::OpenBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2399,7 +13784,12 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::OpenParenthesis, true)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::Comma, false)),
+            None,
             None,
             None,
             None,
@@ -2414,16 +13804,73 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ElseKeyword, false)),
+            Some((TK::ToKeyword, true)),
+            Some((TK::Plus, false)),
+            Some((TK::Minus, false)),
+            Some((TK::Mul, false)),
+            Some((TK::Div, false)),
+            Some((TK::Percent, false)),
+            Some((TK::StarStar, false)),
+            Some((TK::CloseParenthesis, false)),
+            Some((TK::EqualsEquals, false)),
+            Some((TK::BangEquals, false)),
+            Some((TK::Less, false)),
+            Some((TK::LessOrEquals, false)),
+            Some((TK::Greater, false)),
+            Some((TK::GreaterOrEquals, false)),
+            Some((TK::AmpersandAmpersand, false)),
+            Some((TK::PipePipe, false)),
+            Some((TK::Ampersand, false)),
+            Some((TK::Pipe, false)),
+            Some((TK::Caret, false)),
+            Some((TK::LessLess, false)),
+            Some((TK::GreaterGreater, false)),
+            Some((TK::CloseBrace, false)),
+            Some((TK::OpenBracket, false)),
+            Some((TK::CloseBracket, false)),
+            Some((TK::SemiColon, false)),
+            Some((TK::Comma, false)),
+            Some((TK::Dot, false)),
+            None,
+            None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
+            Some((TK::IfKeyword, false)),
+            Some((TK::LetKeyword, false)),
+            Some((TK::ConstKeyword, false)),
+            Some((TK::WhileKeyword, false)),
+            Some((TK::ForKeyword, false)),
+            Some((TK::FnKeyword, false)),
+            Some((TK::ReturnKeyword, false)),
+            Some((TK::BreakKeyword, false)),
+            Some((TK::ContinueKeyword, false)),
+            Some((TK::StructKeyword, true)),
             Some((TK::Identifier, false)),
+            Some((TK::OpenBrace, false)),
+            Some((TK::CloseBrace, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2433,14 +13880,86 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -2448,16 +13967,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2467,14 +13976,10 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
             None,
             None,
             None,
@@ -2482,33 +13987,11 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
-            None,
-            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2516,16 +13999,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2535,31 +14008,13 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
-            None,
+            Some((TK::STOP, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2567,15 +14022,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2584,15 +14030,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2603,9 +14040,10 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Equals, true)),
-            None,
-            None,
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
             None,
             None,
             None,
@@ -2618,9 +14056,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Equals, true)),
             None,
             None,
             None,
@@ -2637,13 +14072,9 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::STOP, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -2652,9 +14083,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2669,18 +14097,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Else, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2688,14 +14104,10 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
             None,
             None,
             None,
@@ -2703,32 +14115,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2737,49 +14123,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2788,83 +14131,25 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
+            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2873,15 +14158,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2892,24 +14168,12 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
             None,
             None,
-        ],
-        [
-            Some((TK::Equals, true)),
             None,
             None,
             None,
@@ -2924,66 +14188,8 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -2994,13 +14200,12 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3009,15 +14214,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3026,15 +14222,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3045,13 +14232,12 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3060,15 +14246,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3077,15 +14254,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3096,13 +14264,12 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -3111,15 +14278,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3128,15 +14286,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3147,13 +14296,10 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
             None,
             None,
             None,
@@ -3162,15 +14308,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3179,16 +14316,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3196,12 +14323,12 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::OpenBrace, true)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::CommentEnd, true)),
             None,
             None,
             None,
@@ -3213,43 +14340,10 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
             None,
             None,
-        ],
-        [
-            Some((TK::OpenBrace, true)),
             None,
             None,
             None,
@@ -3266,269 +14360,22 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
-            None,
-            None,
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
-            Some((TK::SemiColon, true)),
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Else, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3536,16 +14383,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3555,14 +14392,10 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
             None,
             None,
             None,
@@ -3570,26 +14403,8 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::To, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
             None,
             None,
-        ],
-        [
-            Some((TK::OpenBrace, true)),
             None,
             None,
             None,
@@ -3604,33 +14419,19 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
+        ],
+        [
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::NotComment, false)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3638,15 +14439,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::False, true)),
-            Some((TK::True, true)),
-            Some((TK::Minus, true)),
-            Some((TK::OpenParenthesis, true)),
-            Some((TK::Bang, true)),
-            Some((TK::Number, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3655,16 +14447,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3674,24 +14456,15 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
         ],
         [
-            Some((TK::EqualsEquals, true)),
-            Some((TK::BangEquals, true)),
-            Some((TK::LessOrEquals, true)),
-            Some((TK::GreaterOrEquals, true)),
-            Some((TK::AmpersandAmpersand, true)),
-            Some((TK::PipePipe, true)),
-            Some((TK::Plus, true)),
-            Some((TK::Minus, true)),
-            Some((TK::Mul, true)),
-            Some((TK::Div, true)),
-            Some((TK::CloseParenthesis, true)),
-            Some((TK::Less, true)),
-            Some((TK::Greater, true)),
+            Some((TK::STOP, true)),
+            Some((TK::CommentStart, true)),
+            Some((TK::CommentEnd, true)),
+            Some((TK::WhiteSpace, false)),
+            Some((TK::LineComment, false)),
+            Some((TK::NotComment, false)),
+            None,
             None,
             None,
-        ],
-        [
-            Some((TK::OpenBrace, true)),
             None,
             None,
             None,
@@ -3706,16 +14479,6 @@ pub(crate) static PARSER_DEFINITION: CompilerParserDefinition = CompilerParserDe
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Const, true)),
-            Some((TK::While, true)),
-            Some((TK::Let, true)),
-            Some((TK::For, true)),
-            Some((TK::If, true)),
-            Some((TK::OpenBrace, true)),
-            Some((TK::CloseBrace, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -3737,462 +14500,2254 @@ for CompilerParserDefinition {
     fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
         PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
     }
-    fn longest_match() -> bool {
-        true
+    fn longest_match() -> bool {
+        true
+    }
+    fn grammar_order() -> bool {
+        true
+    }
+}
+pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
+pub struct CompilerParser<
+    'i,
+    I: InputT + ?Sized,
+    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
+    B,
+>(
+    LRParser<
+        'i,
+        Context<'i, I>,
+        State,
+        ProdKind,
+        TokenKind,
+        NonTermKind,
+        CompilerParserDefinition,
+        L,
+        B,
+        I,
+    >,
+);
+#[allow(dead_code)]
+impl<
+    'i,
+> CompilerParser<
+    'i,
+    Input,
+    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
+    DefaultBuilder,
+> {
+    pub fn new() -> Self {
+        Self(
+            LRParser::new(
+                &PARSER_DEFINITION,
+                State::default(),
+                false,
+                true,
+                StringLexer::new(false, &RECOGNIZERS),
+                DefaultBuilder::new(),
+            ),
+        )
+    }
+}
+#[allow(dead_code)]
+impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
+for CompilerParser<'i, I, L, B>
+where
+    I: InputT + ?Sized + Debug,
+    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
+    B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind>,
+{
+    type Output = B::Output;
+    fn parse(&self, input: &'i I) -> Result<Self::Output> {
+        self.0.parse(input)
+    }
+    fn parse_with_context(
+        &self,
+        context: &mut Context<'i, I>,
+        input: &'i I,
+    ) -> Result<Self::Output> {
+        self.0.parse_with_context(context, input)
+    }
+    fn parse_file<'a, F: AsRef<std::path::Path>>(
+        &'a mut self,
+        file: F,
+    ) -> Result<Self::Output>
+    where
+        'a: 'i,
+    {
+        self.0.parse_file(file)
     }
-    fn grammar_order() -> bool {
-        true
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Recognizer {
+    Stop,
+    StrMatch(&'static str),
+    RegexMatch(Lazy<Regex>),
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct TokenRecognizer(TokenKind, Recognizer);
+impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
+    fn recognize(&self, input: &'i str) -> Option<&'i str> {
+        match &self {
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                if input.starts_with(s) {
+                    log!("{}", "recognized".bold().green());
+                    Some(s)
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                let match_str = r.find(input);
+                match match_str {
+                    Some(x) => {
+                        let x_str = x.as_str();
+                        log!("{} '{}'", "recognized".bold().green(), x_str);
+                        Some(x_str)
+                    }
+                    _ => {
+                        log!("{}", "not recognized".red());
+                        None
+                    }
+                }
+            }
+            TokenRecognizer(_, Recognizer::Stop) => {
+                logn!("{} STOP -- ", "    Recognizing".green());
+                if input.is_empty() {
+                    log!("{}", "recognized".bold().green());
+                    Some("")
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+        }
     }
 }
-pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
-pub struct CompilerParser<
-    'i,
-    I: InputT + ?Sized,
-    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
-    B,
->(
-    LRParser<
-        'i,
-        Context<'i, I>,
-        State,
-        ProdKind,
-        TokenKind,
-        NonTermKind,
-        CompilerParserDefinition,
-        L,
-        B,
-        I,
-    >,
-);
-#[allow(dead_code)]
-impl<
-    'i,
-> CompilerParser<
-    'i,
-    Input,
-    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-    DefaultBuilder,
-> {
+pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
+    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
+    TokenRecognizer(
+        TokenKind::Number,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\d+(\\.\\d+)?")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::StringLiteral,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "\"(\\\\[nt\"\\\\]|[^\"\\\\])*\"")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Plus,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Minus,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "-")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Mul,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Div,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "/")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Percent,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "%")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::StarStar,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\*\\*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::PlusEquals,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\+=")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::MinusEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "-=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::MulEquals,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\*=")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::DivEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "/=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::PercentEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "%=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::PlusPlus,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\+\\+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::MinusMinus,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "--")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::TrueKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "true\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::FalseKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "false\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::OpenParenthesis,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\(")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::CloseParenthesis,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\)")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Bang,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "!")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::EqualsEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "==")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::BangEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "!=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Less,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "<")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::LessOrEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "<=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Greater,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", ">")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::GreaterOrEquals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", ">=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::AmpersandAmpersand,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "&&")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::PipePipe,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\|\\|")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Ampersand,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "&")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Pipe,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\|")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Caret,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\^")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Tilde,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "~")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::LessLess,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "<<")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::GreaterGreater,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", ">>")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Identifier,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "[a-zA-Z_][a-zA-Z0-9_]*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::OpenBrace,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\{")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::CloseBrace,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\}")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::OpenBracket,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\[")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::CloseBracket,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\]")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Equals,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", "=")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::SemiColon,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", ";")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::IfKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "if\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ElseKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "else\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::LetKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "let\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ConstKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "const\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::WhileKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "while\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ForKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "for\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ToKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "to\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::FnKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "fn\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ReturnKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "return\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::BreakKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "break\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ContinueKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "continue\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Comma,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", ",")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::Dot,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\.")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Colon,
+        Recognizer::RegexMatch(Lazy::new(|| { Regex::new(concat!("^", ":")).unwrap() })),
+    ),
+    TokenRecognizer(
+        TokenKind::StructKeyword,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "struct\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::WhiteSpace,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::LineComment,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "//.*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::CommentStart, Recognizer::StrMatch("/*")),
+    TokenRecognizer(TokenKind::CommentEnd, Recognizer::StrMatch("*/")),
+    TokenRecognizer(
+        TokenKind::NotComment,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "([^\\s*/]|\\*+[^\\s*/]|/+[^\\s*/])+|\\*|/"))
+                    .unwrap()
+            }),
+        ),
+    ),
+];
+pub struct DefaultBuilder {
+    res_stack: Vec<Symbol>,
+}
+impl DefaultBuilder {
+    #[allow(dead_code)]
     pub fn new() -> Self {
-        Self(
-            LRParser::new(
-                &PARSER_DEFINITION,
-                State::default(),
-                false,
-                false,
-                StringLexer::new(true, &RECOGNIZERS),
-                DefaultBuilder::new(),
-            ),
-        )
+        Self { res_stack: vec![] }
     }
 }
-#[allow(dead_code)]
-impl<'i, I, L, B> Parser<'i, I, Context<'i, I>, State, TokenKind>
-for CompilerParser<'i, I, L, B>
-where
-    I: InputT + ?Sized + Debug,
-    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
-    B: LRBuilder<'i, I, Context<'i, I>, State, ProdKind, TokenKind>,
-{
-    type Output = B::Output;
-    fn parse(&self, input: &'i I) -> Result<Self::Output> {
-        self.0.parse(input)
-    }
-    fn parse_with_context(
-        &self,
-        context: &mut Context<'i, I>,
-        input: &'i I,
-    ) -> Result<Self::Output> {
-        self.0.parse_with_context(context, input)
-    }
-    fn parse_file<'a, F: AsRef<std::path::Path>>(
-        &'a mut self,
-        file: F,
-    ) -> Result<Self::Output>
-    where
-        'a: 'i,
-    {
-        self.0.parse_file(file)
+impl Builder for DefaultBuilder {
+    type Output = compiler_actions::Program;
+    fn get_result(&mut self) -> Self::Output {
+        match self.res_stack.pop().unwrap() {
+            Symbol::NonTerminal(NonTerminal::Program(r)) => r,
+            _ => panic!("Invalid result on the parse stack!"),
+        }
     }
 }
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Recognizer {
-    Stop,
-    StrMatch(&'static str),
-    RegexMatch(Lazy<Regex>),
-}
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct TokenRecognizer(TokenKind, Recognizer);
-impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-    fn recognize(&self, input: &'i str) -> Option<&'i str> {
-        match &self {
-            #[allow(unused_variables)]
-            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                if input.starts_with(s) {
-                    log!("{}", "recognized".bold().green());
-                    Some(s)
-                } else {
-                    log!("{}", "not recognized".red());
-                    None
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
+for DefaultBuilder {
+    #![allow(unused_variables)]
+    fn shift_action(
+        &mut self,
+        context: &mut Context<'i, Input>,
+        token: Token<'i, Input, TokenKind>,
+    ) {
+        let val = match token.kind {
+            TokenKind::STOP => panic!("Cannot shift STOP token!"),
+            TokenKind::Number => {
+                Terminal::Number(compiler_actions::number(&*context, token))
+            }
+            TokenKind::StringLiteral => {
+                Terminal::StringLiteral(
+                    compiler_actions::string_literal(&*context, token),
+                )
+            }
+            TokenKind::Plus => Terminal::Plus(compiler_actions::plus(&*context, token)),
+            TokenKind::Minus => {
+                Terminal::Minus(compiler_actions::minus(&*context, token))
+            }
+            TokenKind::Mul => Terminal::Mul(compiler_actions::mul(&*context, token)),
+            TokenKind::Div => Terminal::Div(compiler_actions::div(&*context, token)),
+            TokenKind::Percent => {
+                Terminal::Percent(compiler_actions::percent(&*context, token))
+            }
+            TokenKind::StarStar => {
+                Terminal::StarStar(compiler_actions::star_star(&*context, token))
+            }
+            TokenKind::PlusEquals => {
+                Terminal::PlusEquals(compiler_actions::plus_equals(&*context, token))
+            }
+            TokenKind::MinusEquals => {
+                Terminal::MinusEquals(compiler_actions::minus_equals(&*context, token))
+            }
+            TokenKind::MulEquals => {
+                Terminal::MulEquals(compiler_actions::mul_equals(&*context, token))
+            }
+            TokenKind::DivEquals => {
+                Terminal::DivEquals(compiler_actions::div_equals(&*context, token))
+            }
+            TokenKind::PercentEquals => {
+                Terminal::PercentEquals(
+                    compiler_actions::percent_equals(&*context, token),
+                )
+            }
+            TokenKind::PlusPlus => {
+                Terminal::PlusPlus(compiler_actions::plus_plus(&*context, token))
+            }
+            TokenKind::MinusMinus => {
+                Terminal::MinusMinus(compiler_actions::minus_minus(&*context, token))
+            }
+            TokenKind::TrueKeyword => {
+                Terminal::TrueKeyword(compiler_actions::true_keyword(&*context, token))
+            }
+            TokenKind::FalseKeyword => {
+                Terminal::FalseKeyword(compiler_actions::false_keyword(&*context, token))
+            }
+            TokenKind::OpenParenthesis => {
+                Terminal::OpenParenthesis(
+                    compiler_actions::open_parenthesis(&*context, token),
+                )
+            }
+            TokenKind::CloseParenthesis => {
+                Terminal::CloseParenthesis(
+                    compiler_actions::close_parenthesis(&*context, token),
+                )
+            }
+            TokenKind::Bang => Terminal::Bang(compiler_actions::bang(&*context, token)),
+            TokenKind::EqualsEquals => {
+                Terminal::EqualsEquals(compiler_actions::equals_equals(&*context, token))
+            }
+            TokenKind::BangEquals => {
+                Terminal::BangEquals(compiler_actions::bang_equals(&*context, token))
+            }
+            TokenKind::Less => Terminal::Less(compiler_actions::less(&*context, token)),
+            TokenKind::LessOrEquals => {
+                Terminal::LessOrEquals(
+                    compiler_actions::less_or_equals(&*context, token),
+                )
+            }
+            TokenKind::Greater => {
+                Terminal::Greater(compiler_actions::greater(&*context, token))
+            }
+            TokenKind::GreaterOrEquals => {
+                Terminal::GreaterOrEquals(
+                    compiler_actions::greater_or_equals(&*context, token),
+                )
+            }
+            TokenKind::AmpersandAmpersand => {
+                Terminal::AmpersandAmpersand(
+                    compiler_actions::ampersand_ampersand(&*context, token),
+                )
+            }
+            TokenKind::PipePipe => {
+                Terminal::PipePipe(compiler_actions::pipe_pipe(&*context, token))
+            }
+            TokenKind::Ampersand => {
+                Terminal::Ampersand(compiler_actions::ampersand(&*context, token))
+            }
+            TokenKind::Pipe => Terminal::Pipe(compiler_actions::pipe(&*context, token)),
+            TokenKind::Caret => {
+                Terminal::Caret(compiler_actions::caret(&*context, token))
+            }
+            TokenKind::Tilde => {
+                Terminal::Tilde(compiler_actions::tilde(&*context, token))
+            }
+            TokenKind::LessLess => {
+                Terminal::LessLess(compiler_actions::less_less(&*context, token))
+            }
+            TokenKind::GreaterGreater => {
+                Terminal::GreaterGreater(
+                    compiler_actions::greater_greater(&*context, token),
+                )
+            }
+            TokenKind::Identifier => {
+                Terminal::Identifier(compiler_actions::identifier(&*context, token))
+            }
+            TokenKind::OpenBrace => {
+                Terminal::OpenBrace(compiler_actions::open_brace(&*context, token))
+            }
+            TokenKind::CloseBrace => {
+                Terminal::CloseBrace(compiler_actions::close_brace(&*context, token))
+            }
+            TokenKind::OpenBracket => {
+                Terminal::OpenBracket(compiler_actions::open_bracket(&*context, token))
+            }
+            TokenKind::CloseBracket => {
+                Terminal::CloseBracket(compiler_actions::close_bracket(&*context, token))
+            }
+            TokenKind::Equals => {
+                Terminal::Equals(compiler_actions::equals(&*context, token))
+            }
+            TokenKind::SemiColon => {
+                Terminal::SemiColon(compiler_actions::semi_colon(&*context, token))
+            }
+            TokenKind::IfKeyword => {
+                Terminal::IfKeyword(compiler_actions::if_keyword(&*context, token))
+            }
+            TokenKind::ElseKeyword => {
+                Terminal::ElseKeyword(compiler_actions::else_keyword(&*context, token))
+            }
+            TokenKind::LetKeyword => {
+                Terminal::LetKeyword(compiler_actions::let_keyword(&*context, token))
+            }
+            TokenKind::ConstKeyword => {
+                Terminal::ConstKeyword(compiler_actions::const_keyword(&*context, token))
+            }
+            TokenKind::WhileKeyword => {
+                Terminal::WhileKeyword(compiler_actions::while_keyword(&*context, token))
+            }
+            TokenKind::ForKeyword => {
+                Terminal::ForKeyword(compiler_actions::for_keyword(&*context, token))
+            }
+            TokenKind::ToKeyword => {
+                Terminal::ToKeyword(compiler_actions::to_keyword(&*context, token))
+            }
+            TokenKind::FnKeyword => {
+                Terminal::FnKeyword(compiler_actions::fn_keyword(&*context, token))
+            }
+            TokenKind::ReturnKeyword => {
+                Terminal::ReturnKeyword(
+                    compiler_actions::return_keyword(&*context, token),
+                )
+            }
+            TokenKind::BreakKeyword => {
+                Terminal::BreakKeyword(compiler_actions::break_keyword(&*context, token))
+            }
+            TokenKind::ContinueKeyword => {
+                Terminal::ContinueKeyword(
+                    compiler_actions::continue_keyword(&*context, token),
+                )
+            }
+            TokenKind::Comma => {
+                Terminal::Comma(compiler_actions::comma(&*context, token))
+            }
+            TokenKind::Dot => Terminal::Dot(compiler_actions::dot(&*context, token)),
+            TokenKind::Colon => {
+                Terminal::Colon(compiler_actions::colon(&*context, token))
+            }
+            TokenKind::StructKeyword => {
+                Terminal::StructKeyword(
+                    compiler_actions::struct_keyword(&*context, token),
+                )
+            }
+            _ => panic!("Shift of unreachable terminal!"),
+        };
+        self.res_stack.push(Symbol::Terminal(val));
+    }
+    fn reduce_action(
+        &mut self,
+        context: &mut Context<'i, Input>,
+        prod: ProdKind,
+        _prod_len: usize,
+    ) {
+        let prod = match prod {
+            ProdKind::ProgramP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::StatementList(p0)) => {
+                        NonTerminal::Program(
+                            compiler_actions::program_statement_list(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementListP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::OpenBrace(p0)),
+                        Symbol::NonTerminal(NonTerminal::Statement0(p1)),
+                        Symbol::Terminal(Terminal::CloseBrace(p2)),
+                    ) => {
+                        NonTerminal::StatementList(
+                            compiler_actions::statement_list_c1(&*context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Statement1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Statement1(p0)),
+                        Symbol::NonTerminal(NonTerminal::Statement(p1)),
+                    ) => {
+                        NonTerminal::Statement1(
+                            compiler_actions::statement1_c1(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Statement1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Statement(p0)) => {
+                        NonTerminal::Statement1(
+                            compiler_actions::statement1_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Statement0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Statement1(p0)) => {
+                        NonTerminal::Statement0(
+                            compiler_actions::statement0_statement1(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Statement0P2 => {
+                NonTerminal::Statement0(compiler_actions::statement0_empty(context))
+            }
+            ProdKind::StatementP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Assignment(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_assignment(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::IfStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_if_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::VariableDeclaration(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_variable_declaration(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP4 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ConstantDeclaration(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_constant_declaration(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP5 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::WhileStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_while_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP6 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ForStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_for_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP7 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FunctionDeclaration(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_function_declaration(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP8 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ReturnStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_return_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP9 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::IncrementStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_increment_statement(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP10 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::IndexAssignment(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_index_assignment(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP11 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldAssignment(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_field_assignment(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP12 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::StructDeclaration(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_struct_declaration(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP13 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::BreakStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_break_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP14 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ContinueStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_continue_statement(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP15 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ExpressionStatement(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_expression_statement(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StatementP16 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::StatementList(p0)) => {
+                        NonTerminal::Statement(
+                            compiler_actions::statement_statement_list(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::AssignmentAssignment => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::Equals(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::SemiColon(p3)),
+                    ) => {
+                        NonTerminal::Assignment(
+                            compiler_actions::assignment_assignment(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::AssignmentCompoundAssignment => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::NonTerminal(NonTerminal::CompoundOperator(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::SemiColon(p3)),
+                    ) => {
+                        NonTerminal::Assignment(
+                            compiler_actions::assignment_compound_assignment(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CompoundOperatorP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::PlusEquals(p0)) => {
+                        NonTerminal::CompoundOperator(
+                            compiler_actions::compound_operator_plus_equals(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CompoundOperatorP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::MinusEquals(p0)) => {
+                        NonTerminal::CompoundOperator(
+                            compiler_actions::compound_operator_minus_equals(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CompoundOperatorP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::MulEquals(p0)) => {
+                        NonTerminal::CompoundOperator(
+                            compiler_actions::compound_operator_mul_equals(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CompoundOperatorP4 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::DivEquals(p0)) => {
+                        NonTerminal::CompoundOperator(
+                            compiler_actions::compound_operator_div_equals(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CompoundOperatorP5 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::PercentEquals(p0)) => {
+                        NonTerminal::CompoundOperator(
+                            compiler_actions::compound_operator_percent_equals(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::IncrementStatementIncrementStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::NonTerminal(NonTerminal::IncrementOperator(p1)),
+                        Symbol::Terminal(Terminal::SemiColon(p2)),
+                    ) => {
+                        NonTerminal::IncrementStatement(
+                            compiler_actions::increment_statement_increment_statement(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::IncrementOperatorP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::PlusPlus(p0)) => {
+                        NonTerminal::IncrementOperator(
+                            compiler_actions::increment_operator_plus_plus(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::IncrementOperatorP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::MinusMinus(p0)) => {
+                        NonTerminal::IncrementOperator(
+                            compiler_actions::increment_operator_minus_minus(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::IndexAssignmentIndexAssignment => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 7usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::OpenBracket(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::CloseBracket(p3)),
+                        Symbol::Terminal(Terminal::Equals(p4)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p5)),
+                        Symbol::Terminal(Terminal::SemiColon(p6)),
+                    ) => {
+                        NonTerminal::IndexAssignment(
+                            compiler_actions::index_assignment_index_assignment(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                                p6,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::IndexAssignmentCompoundIndexAssignment => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 7usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::OpenBracket(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::CloseBracket(p3)),
+                        Symbol::NonTerminal(NonTerminal::CompoundOperator(p4)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p5)),
+                        Symbol::Terminal(Terminal::SemiColon(p6)),
+                    ) => {
+                        NonTerminal::IndexAssignment(
+                            compiler_actions::index_assignment_compound_index_assignment(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                                p6,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FieldAssignmentFieldAssignment => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 6usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::Dot(p1)),
+                        Symbol::Terminal(Terminal::Identifier(p2)),
+                        Symbol::Terminal(Terminal::Equals(p3)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p4)),
+                        Symbol::Terminal(Terminal::SemiColon(p5)),
+                    ) => {
+                        NonTerminal::FieldAssignment(
+                            compiler_actions::field_assignment_field_assignment(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FieldAssignmentCompoundFieldAssignment => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 6usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::Dot(p1)),
+                        Symbol::Terminal(Terminal::Identifier(p2)),
+                        Symbol::NonTerminal(NonTerminal::CompoundOperator(p3)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p4)),
+                        Symbol::Terminal(Terminal::SemiColon(p5)),
+                    ) => {
+                        NonTerminal::FieldAssignment(
+                            compiler_actions::field_assignment_compound_field_assignment(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::IfStatementIfStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 6usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::IfKeyword(p0)),
+                        Symbol::Terminal(Terminal::OpenParenthesis(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p3)),
+                        Symbol::NonTerminal(NonTerminal::StatementList(p4)),
+                        Symbol::NonTerminal(NonTerminal::ElseClauseOpt(p5)),
+                    ) => {
+                        NonTerminal::IfStatement(
+                            compiler_actions::if_statement_if_statement(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ElseClauseOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ElseClause(p0)) => {
+                        NonTerminal::ElseClauseOpt(
+                            compiler_actions::else_clause_opt_else_clause(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ElseClauseOptP2 => {
+                NonTerminal::ElseClauseOpt(
+                    compiler_actions::else_clause_opt_empty(context),
+                )
+            }
+            ProdKind::ElseClauseElseStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::ElseKeyword(p0)),
+                        Symbol::NonTerminal(NonTerminal::StatementList(p1)),
+                    ) => {
+                        NonTerminal::ElseClause(
+                            compiler_actions::else_clause_else_statement(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ElseClauseElseIf => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::ElseKeyword(p0)),
+                        Symbol::NonTerminal(NonTerminal::IfStatement(p1)),
+                    ) => {
+                        NonTerminal::ElseClause(
+                            compiler_actions::else_clause_else_if(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::VariableDeclarationVariableDeclaration => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::LetKeyword(p0)),
+                        Symbol::Terminal(Terminal::Identifier(p1)),
+                        Symbol::Terminal(Terminal::Equals(p2)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p3)),
+                        Symbol::Terminal(Terminal::SemiColon(p4)),
+                    ) => {
+                        NonTerminal::VariableDeclaration(
+                            compiler_actions::variable_declaration_variable_declaration(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ConstantDeclarationConstantDeclaration => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::ConstKeyword(p0)),
+                        Symbol::Terminal(Terminal::Identifier(p1)),
+                        Symbol::Terminal(Terminal::Equals(p2)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p3)),
+                        Symbol::Terminal(Terminal::SemiColon(p4)),
+                    ) => {
+                        NonTerminal::ConstantDeclaration(
+                            compiler_actions::constant_declaration_constant_declaration(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::WhileStatementWhile => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::WhileKeyword(p0)),
+                        Symbol::Terminal(Terminal::OpenParenthesis(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p3)),
+                        Symbol::NonTerminal(NonTerminal::StatementList(p4)),
+                    ) => {
+                        NonTerminal::WhileStatement(
+                            compiler_actions::while_statement_while(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ForStatementFor => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 9usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::ForKeyword(p0)),
+                        Symbol::Terminal(Terminal::OpenParenthesis(p1)),
+                        Symbol::Terminal(Terminal::Identifier(p2)),
+                        Symbol::Terminal(Terminal::Equals(p3)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p4)),
+                        Symbol::Terminal(Terminal::ToKeyword(p5)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p6)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p7)),
+                        Symbol::NonTerminal(NonTerminal::StatementList(p8)),
+                    ) => {
+                        NonTerminal::ForStatement(
+                            compiler_actions::for_statement_for(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                                p6,
+                                p7,
+                                p8,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FunctionDeclarationFunctionDeclaration => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 7usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::FnKeyword(p0)),
+                        Symbol::Terminal(Terminal::Identifier(p1)),
+                        Symbol::Terminal(Terminal::OpenParenthesis(p2)),
+                        Symbol::NonTerminal(NonTerminal::Parameter0(p3)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p4)),
+                        Symbol::NonTerminal(NonTerminal::TypeClauseOpt(p5)),
+                        Symbol::NonTerminal(NonTerminal::StatementList(p6)),
+                    ) => {
+                        NonTerminal::FunctionDeclaration(
+                            compiler_actions::function_declaration_function_declaration(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                                p5,
+                                p6,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Parameter1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Parameter1(p0)),
+                        Symbol::Terminal(Terminal::Comma(p1)),
+                        Symbol::NonTerminal(NonTerminal::Parameter(p2)),
+                    ) => {
+                        NonTerminal::Parameter1(
+                            compiler_actions::parameter1_c1(&*context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Parameter1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Parameter(p0)) => {
+                        NonTerminal::Parameter1(
+                            compiler_actions::parameter1_parameter(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Parameter0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Parameter1(p0)) => {
+                        NonTerminal::Parameter0(
+                            compiler_actions::parameter0_parameter1(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Parameter0P2 => {
+                NonTerminal::Parameter0(compiler_actions::parameter0_empty(context))
+            }
+            ProdKind::TypeClauseOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::TypeClause(p0)) => {
+                        NonTerminal::TypeClauseOpt(
+                            compiler_actions::type_clause_opt_type_clause(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeClauseOptP2 => {
+                NonTerminal::TypeClauseOpt(
+                    compiler_actions::type_clause_opt_empty(context),
+                )
+            }
+            ProdKind::ParameterParameter => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::NonTerminal(NonTerminal::TypeClauseOpt(p1)),
+                    ) => {
+                        NonTerminal::Parameter(
+                            compiler_actions::parameter_parameter(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::StructDeclarationStructDeclaration => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::StructKeyword(p0)),
+                        Symbol::Terminal(Terminal::Identifier(p1)),
+                        Symbol::Terminal(Terminal::OpenBrace(p2)),
+                        Symbol::NonTerminal(NonTerminal::FieldDeclaration0(p3)),
+                        Symbol::Terminal(Terminal::CloseBrace(p4)),
+                    ) => {
+                        NonTerminal::StructDeclaration(
+                            compiler_actions::struct_declaration_struct_declaration(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                                p4,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            #[allow(unused_variables)]
-            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                let match_str = r.find(input);
-                match match_str {
-                    Some(x) => {
-                        let x_str = x.as_str();
-                        log!("{} '{}'", "recognized".bold().green(), x_str);
-                        Some(x_str)
+            ProdKind::FieldDeclaration1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::FieldDeclaration1(p0)),
+                        Symbol::Terminal(Terminal::Comma(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldDeclaration(p2)),
+                    ) => {
+                        NonTerminal::FieldDeclaration1(
+                            compiler_actions::field_declaration1_c1(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
+                        )
                     }
-                    _ => {
-                        log!("{}", "not recognized".red());
-                        None
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FieldDeclaration1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldDeclaration(p0)) => {
+                        NonTerminal::FieldDeclaration1(
+                            compiler_actions::field_declaration1_field_declaration(
+                                &*context,
+                                p0,
+                            ),
+                        )
                     }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            TokenRecognizer(_, Recognizer::Stop) => {
-                logn!("{} STOP -- ", "    Recognizing".green());
-                if input.is_empty() {
-                    log!("{}", "recognized".bold().green());
-                    Some("")
-                } else {
-                    log!("{}", "not recognized".red());
-                    None
+            ProdKind::FieldDeclaration0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldDeclaration1(p0)) => {
+                        NonTerminal::FieldDeclaration0(
+                            compiler_actions::field_declaration0_field_declaration1(
+                                &*context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-        }
-    }
-}
-pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
-    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
-    TokenRecognizer(
-        TokenKind::Number,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "\\d+(\\.\\d+)?")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(TokenKind::Plus, Recognizer::StrMatch("+")),
-    TokenRecognizer(TokenKind::Minus, Recognizer::StrMatch("-")),
-    TokenRecognizer(TokenKind::Mul, Recognizer::StrMatch("*")),
-    TokenRecognizer(TokenKind::Div, Recognizer::StrMatch("/")),
-    TokenRecognizer(TokenKind::True, Recognizer::StrMatch("true")),
-    TokenRecognizer(TokenKind::False, Recognizer::StrMatch("false")),
-    TokenRecognizer(TokenKind::OpenParenthesis, Recognizer::StrMatch("(")),
-    TokenRecognizer(TokenKind::CloseParenthesis, Recognizer::StrMatch(")")),
-    TokenRecognizer(TokenKind::Bang, Recognizer::StrMatch("!")),
-    TokenRecognizer(TokenKind::EqualsEquals, Recognizer::StrMatch("==")),
-    TokenRecognizer(TokenKind::BangEquals, Recognizer::StrMatch("!=")),
-    TokenRecognizer(TokenKind::Less, Recognizer::StrMatch("<")),
-    TokenRecognizer(TokenKind::LessOrEquals, Recognizer::StrMatch("<=")),
-    TokenRecognizer(TokenKind::Greater, Recognizer::StrMatch(">")),
-    TokenRecognizer(TokenKind::GreaterOrEquals, Recognizer::StrMatch(">=")),
-    TokenRecognizer(TokenKind::AmpersandAmpersand, Recognizer::StrMatch("&&")),
-    TokenRecognizer(TokenKind::PipePipe, Recognizer::StrMatch("||")),
-    TokenRecognizer(
-        TokenKind::Identifier,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "[a-zA-Z_]+")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(TokenKind::OpenBrace, Recognizer::StrMatch("{")),
-    TokenRecognizer(TokenKind::CloseBrace, Recognizer::StrMatch("}")),
-    TokenRecognizer(TokenKind::Equals, Recognizer::StrMatch("=")),
-    TokenRecognizer(TokenKind::SemiColon, Recognizer::StrMatch(";")),
-    TokenRecognizer(TokenKind::If, Recognizer::StrMatch("if")),
-    TokenRecognizer(TokenKind::Else, Recognizer::StrMatch("else")),
-    TokenRecognizer(TokenKind::Let, Recognizer::StrMatch("let")),
-    TokenRecognizer(TokenKind::Const, Recognizer::StrMatch("const")),
-    TokenRecognizer(TokenKind::While, Recognizer::StrMatch("while")),
-    TokenRecognizer(TokenKind::For, Recognizer::StrMatch("for")),
-    TokenRecognizer(TokenKind::To, Recognizer::StrMatch("to")),
-];
-pub struct DefaultBuilder {
-    res_stack: Vec<Symbol>,
-}
-impl DefaultBuilder {
-    #[allow(dead_code)]
-    pub fn new() -> Self {
-        Self { res_stack: vec![] }
-    }
-}
-impl Builder for DefaultBuilder {
-    type Output = compiler_actions::Program;
-    fn get_result(&mut self) -> Self::Output {
-        match self.res_stack.pop().unwrap() {
-            Symbol::NonTerminal(NonTerminal::Program(r)) => r,
-            _ => panic!("Invalid result on the parse stack!"),
-        }
-    }
-}
-impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
-for DefaultBuilder {
-    #![allow(unused_variables)]
-    fn shift_action(
-        &mut self,
-        context: &mut Context<'i, Input>,
-        token: Token<'i, Input, TokenKind>,
-    ) {
-        let val = match token.kind {
-            TokenKind::STOP => panic!("Cannot shift STOP token!"),
-            TokenKind::Number => {
-                Terminal::Number(compiler_actions::number(&*context, token))
+            ProdKind::FieldDeclaration0P2 => {
+                NonTerminal::FieldDeclaration0(
+                    compiler_actions::field_declaration0_empty(context),
+                )
+            }
+            ProdKind::FieldDeclarationFieldDeclaration => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::NonTerminal(NonTerminal::TypeClause(p1)),
+                    ) => {
+                        NonTerminal::FieldDeclaration(
+                            compiler_actions::field_declaration_field_declaration(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeClauseTypeClause => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Colon(p0)),
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                    ) => {
+                        NonTerminal::TypeClause(
+                            compiler_actions::type_clause_type_clause(&*context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeNameTypeName => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::Identifier(p0)) => {
+                        NonTerminal::TypeName(
+                            compiler_actions::type_name_type_name(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeNameArrayTypeName => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::OpenBracket(p0)),
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                        Symbol::Terminal(Terminal::CloseBracket(p2)),
+                    ) => {
+                        NonTerminal::TypeName(
+                            compiler_actions::type_name_array_type_name(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ReturnStatementReturnStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::ReturnKeyword(p0)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::Terminal(Terminal::SemiColon(p2)),
+                    ) => {
+                        NonTerminal::ReturnStatement(
+                            compiler_actions::return_statement_return_statement(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::BreakStatementBreakStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::BreakKeyword(p0)),
+                        Symbol::Terminal(Terminal::SemiColon(p1)),
+                    ) => {
+                        NonTerminal::BreakStatement(
+                            compiler_actions::break_statement_break_statement(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ContinueStatementContinueStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::ContinueKeyword(p0)),
+                        Symbol::Terminal(Terminal::SemiColon(p1)),
+                    ) => {
+                        NonTerminal::ContinueStatement(
+                            compiler_actions::continue_statement_continue_statement(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ExpressionStatementExpressionStatement => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::CallExpression(p0)),
+                        Symbol::Terminal(Terminal::SemiColon(p1)),
+                    ) => {
+                        NonTerminal::ExpressionStatement(
+                            compiler_actions::expression_statement_expression_statement(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CallExpressionCallExpression => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::OpenParenthesis(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression0(p2)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p3)),
+                    ) => {
+                        NonTerminal::CallExpression(
+                            compiler_actions::call_expression_call_expression(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::Plus => Terminal::Plus,
-            TokenKind::Minus => Terminal::Minus,
-            TokenKind::Mul => Terminal::Mul,
-            TokenKind::Div => Terminal::Div,
-            TokenKind::True => Terminal::True,
-            TokenKind::False => Terminal::False,
-            TokenKind::OpenParenthesis => Terminal::OpenParenthesis,
-            TokenKind::CloseParenthesis => Terminal::CloseParenthesis,
-            TokenKind::Bang => Terminal::Bang,
-            TokenKind::EqualsEquals => Terminal::EqualsEquals,
-            TokenKind::BangEquals => Terminal::BangEquals,
-            TokenKind::Less => Terminal::Less,
-            TokenKind::LessOrEquals => Terminal::LessOrEquals,
-            TokenKind::Greater => Terminal::Greater,
-            TokenKind::GreaterOrEquals => Terminal::GreaterOrEquals,
-            TokenKind::AmpersandAmpersand => Terminal::AmpersandAmpersand,
-            TokenKind::PipePipe => Terminal::PipePipe,
-            TokenKind::Identifier => {
-                Terminal::Identifier(compiler_actions::identifier(&*context, token))
+            ProdKind::Expression1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression1(p0)),
+                        Symbol::Terminal(Terminal::Comma(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression1(
+                            compiler_actions::expression1_c1(&*context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::OpenBrace => Terminal::OpenBrace,
-            TokenKind::CloseBrace => Terminal::CloseBrace,
-            TokenKind::Equals => Terminal::Equals,
-            TokenKind::SemiColon => Terminal::SemiColon,
-            TokenKind::If => Terminal::If,
-            TokenKind::Else => Terminal::Else,
-            TokenKind::Let => Terminal::Let,
-            TokenKind::Const => Terminal::Const,
-            TokenKind::While => Terminal::While,
-            TokenKind::For => Terminal::For,
-            TokenKind::To => Terminal::To,
-        };
-        self.res_stack.push(Symbol::Terminal(val));
-    }
-    fn reduce_action(
-        &mut self,
-        context: &mut Context<'i, Input>,
-        prod: ProdKind,
-        _prod_len: usize,
-    ) {
-        let prod = match prod {
-            ProdKind::ProgramP1 => {
+            ProdKind::Expression1P2 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::StatementList(p0)) => {
-                        NonTerminal::Program(
-                            compiler_actions::program_statement_list(&*context, p0),
+                    Symbol::NonTerminal(NonTerminal::Expression(p0)) => {
+                        NonTerminal::Expression1(
+                            compiler_actions::expression1_expression(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementListP1 => {
+            ProdKind::Expression0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Expression1(p0)) => {
+                        NonTerminal::Expression0(
+                            compiler_actions::expression0_expression1(&*context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::Expression0P2 => {
+                NonTerminal::Expression0(compiler_actions::expression0_empty(context))
+            }
+            ProdKind::ExpressionAdd => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::Statement1(p0)), _) => {
-                        NonTerminal::StatementList(
-                            compiler_actions::statement_list_statement1(&*context, p0),
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Plus(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_add(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::Statement1P1 => {
+            ProdKind::ExpressionSub => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Statement1(p0)),
-                        Symbol::NonTerminal(NonTerminal::Statement(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Minus(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
                     ) => {
-                        NonTerminal::Statement1(
-                            compiler_actions::statement1_c1(&*context, p0, p1),
+                        NonTerminal::Expression(
+                            compiler_actions::expression_sub(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::Statement1P2 => {
+            ProdKind::ExpressionMul => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Statement(p0)) => {
-                        NonTerminal::Statement1(
-                            compiler_actions::statement1_statement(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Mul(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_mul(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP1 => {
+            ProdKind::ExpressionDiv => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Assignment(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_assignment(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Div(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_div(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP2 => {
+            ProdKind::ExpressionModulo => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::IfStatement(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_if_statement(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Percent(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_modulo(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP3 => {
+            ProdKind::ExpressionPower => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::VariableDeclaration(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_variable_declaration(
-                                &*context,
-                                p0,
-                            ),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::StarStar(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_power(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP4 => {
+            ProdKind::ExpressionShiftLeft => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::ConstantDeclaration(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_constant_declaration(
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::LessLess(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_shift_left(
                                 &*context,
                                 p0,
+                                p1,
+                                p2,
                             ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP5 => {
+            ProdKind::ExpressionShiftRight => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::WhileStatement(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_while_statement(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::GreaterGreater(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_shift_right(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP6 => {
+            ProdKind::ExpressionEquals => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::ForStatement(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_for_statement(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::EqualsEquals(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_equals(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::StatementP7 => {
+            ProdKind::ExpressionNotEquals => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::StatementList(p0)) => {
-                        NonTerminal::Statement(
-                            compiler_actions::statement_statement_list(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::BangEquals(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_not_equals(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::AssignmentAssignment => {
+            ProdKind::ExpressionLess => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Less(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
                     ) => {
-                        NonTerminal::Assignment(
-                            compiler_actions::assignment_assignment(&*context, p0, p1),
+                        NonTerminal::Expression(
+                            compiler_actions::expression_less(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::IfStatementIfStatement => {
+            ProdKind::ExpressionLessOrEquals => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 6usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        _,
-                        _,
                         Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::StatementList(p1)),
-                        Symbol::NonTerminal(NonTerminal::ElseClauseOpt(p2)),
+                        Symbol::Terminal(Terminal::LessOrEquals(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
                     ) => {
-                        NonTerminal::IfStatement(
-                            compiler_actions::if_statement_if_statement(
+                        NonTerminal::Expression(
+                            compiler_actions::expression_less_or_equals(
                                 &*context,
                                 p0,
                                 p1,
@@ -4203,129 +16758,156 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ElseClauseOptP1 => {
+            ProdKind::ExpressionGreater => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::ElseClause(p0)) => {
-                        NonTerminal::ElseClauseOpt(
-                            compiler_actions::else_clause_opt_else_clause(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Greater(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_greater(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ElseClauseOptP2 => {
-                NonTerminal::ElseClauseOpt(
-                    compiler_actions::else_clause_opt_empty(context),
-                )
+            ProdKind::ExpressionGreaterOrEquals => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::GreaterOrEquals(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_greater_or_equals(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            ProdKind::ElseClauseElseStatement => {
+            ProdKind::ExpressionBitwiseAnd => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::StatementList(p0))) => {
-                        NonTerminal::ElseClause(
-                            compiler_actions::else_clause_else_statement(&*context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Ampersand(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_bitwise_and(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::VariableDeclarationVariableDeclaration => {
+            ProdKind::ExpressionBitwiseXor => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 5usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        _,
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Caret(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
                     ) => {
-                        NonTerminal::VariableDeclaration(
-                            compiler_actions::variable_declaration_variable_declaration(
+                        NonTerminal::Expression(
+                            compiler_actions::expression_bitwise_xor(
                                 &*context,
                                 p0,
                                 p1,
+                                p2,
                             ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ConstantDeclarationConstantDeclaration => {
+            ProdKind::ExpressionBitwiseOr => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 5usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        _,
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::Pipe(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
                     ) => {
-                        NonTerminal::ConstantDeclaration(
-                            compiler_actions::constant_declaration_constant_declaration(
+                        NonTerminal::Expression(
+                            compiler_actions::expression_bitwise_or(
                                 &*context,
                                 p0,
                                 p1,
+                                p2,
                             ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::WhileStatementWhile => {
+            ProdKind::ExpressionAnd => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 5usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        _,
-                        _,
                         Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::StatementList(p1)),
+                        Symbol::Terminal(Terminal::AmpersandAmpersand(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
                     ) => {
-                        NonTerminal::WhileStatement(
-                            compiler_actions::while_statement_while(&*context, p0, p1),
+                        NonTerminal::Expression(
+                            compiler_actions::expression_and(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ForStatementFor => {
+            ProdKind::ExpressionOr => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 9usize)
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        Symbol::Terminal(Terminal::PipePipe(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::Expression(
+                            compiler_actions::expression_or(&*context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ExpressionConditional => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 7usize)
                     .into_iter();
                 match (
                     i.next().unwrap(),
@@ -4335,124 +16917,139 @@ for DefaultBuilder {
                     i.next().unwrap(),
                     i.next().unwrap(),
                     i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
                 ) {
                     (
-                        _,
-                        _,
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                        _,
+                        Symbol::Terminal(Terminal::IfKeyword(p0)),
+                        Symbol::Terminal(Terminal::OpenParenthesis(p1)),
                         Symbol::NonTerminal(NonTerminal::Expression(p2)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::StatementList(p3)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p3)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p4)),
+                        Symbol::Terminal(Terminal::ElseKeyword(p5)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p6)),
                     ) => {
-                        NonTerminal::ForStatement(
-                            compiler_actions::for_statement_for(
+                        NonTerminal::Expression(
+                            compiler_actions::expression_conditional(
                                 &*context,
                                 p0,
                                 p1,
                                 p2,
                                 p3,
+                                p4,
+                                p5,
+                                p6,
                             ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionAdd => {
+            ProdKind::ExpressionUnaryMinus => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
+                        Symbol::Terminal(Terminal::Minus(p0)),
                         Symbol::NonTerminal(NonTerminal::Expression(p1)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_add(&*context, p0, p1),
+                            compiler_actions::expression_unary_minus(&*context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionSub => {
+            ProdKind::ExpressionUnaryNegation => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
+                        Symbol::Terminal(Terminal::Bang(p0)),
                         Symbol::NonTerminal(NonTerminal::Expression(p1)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_sub(&*context, p0, p1),
+                            compiler_actions::expression_unary_negation(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionMul => {
+            ProdKind::ExpressionUnaryBitwiseNot => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                match (i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
+                        Symbol::Terminal(Terminal::Tilde(p0)),
                         Symbol::NonTerminal(NonTerminal::Expression(p1)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_mul(&*context, p0, p1),
+                            compiler_actions::expression_unary_bitwise_not(
+                                &*context,
+                                p0,
+                                p1,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionDiv => {
+            ProdKind::ExpressionIndex => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 4usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
                     (
                         Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::Terminal(Terminal::OpenBracket(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                        Symbol::Terminal(Terminal::CloseBracket(p3)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_div(&*context, p0, p1),
+                            compiler_actions::expression_index(&*context, p0, p1, p2, p3),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionEquals => {
+            ProdKind::ExpressionArrayLiteral => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::Terminal(Terminal::OpenBracket(p0)),
+                        Symbol::NonTerminal(NonTerminal::Expression0(p1)),
+                        Symbol::Terminal(Terminal::CloseBracket(p2)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_equals(&*context, p0, p1),
+                            compiler_actions::expression_array_literal(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionNotEquals => {
+            ProdKind::ExpressionField => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
@@ -4460,203 +17057,197 @@ for DefaultBuilder {
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
                         Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::Terminal(Terminal::Dot(p1)),
+                        Symbol::Terminal(Terminal::Identifier(p2)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_not_equals(&*context, p0, p1),
+                            compiler_actions::expression_field(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionLess => {
+            ProdKind::ExpressionStructLiteral => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 4usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::OpenBrace(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldInitializer0(p2)),
+                        Symbol::Terminal(Terminal::CloseBrace(p3)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_less(&*context, p0, p1),
+                            compiler_actions::expression_struct_literal(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                                p3,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionLessOrEquals => {
+            ProdKind::FieldInitializer1P1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldInitializer1(p0)),
+                        Symbol::Terminal(Terminal::Comma(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldInitializer(p2)),
                     ) => {
-                        NonTerminal::Expression(
-                            compiler_actions::expression_less_or_equals(
+                        NonTerminal::FieldInitializer1(
+                            compiler_actions::field_initializer1_c1(
                                 &*context,
                                 p0,
                                 p1,
+                                p2,
                             ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionGreater => {
+            ProdKind::FieldInitializer1P2 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                    ) => {
-                        NonTerminal::Expression(
-                            compiler_actions::expression_greater(&*context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldInitializer(p0)) => {
+                        NonTerminal::FieldInitializer1(
+                            compiler_actions::field_initializer1_field_initializer(
+                                &*context,
+                                p0,
+                            ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionGreaterOrEquals => {
+            ProdKind::FieldInitializer0P1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                    ) => {
-                        NonTerminal::Expression(
-                            compiler_actions::expression_greater_or_equals(
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldInitializer1(p0)) => {
+                        NonTerminal::FieldInitializer0(
+                            compiler_actions::field_initializer0_field_initializer1(
                                 &*context,
                                 p0,
-                                p1,
                             ),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionAnd => {
+            ProdKind::FieldInitializer0P2 => {
+                NonTerminal::FieldInitializer0(
+                    compiler_actions::field_initializer0_empty(context),
+                )
+            }
+            ProdKind::ExpressionP28 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
+                        Symbol::Terminal(Terminal::OpenParenthesis(p0)),
                         Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                        Symbol::Terminal(Terminal::CloseParenthesis(p2)),
                     ) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_and(&*context, p0, p1),
+                            compiler_actions::expression_c28(&*context, p0, p1, p2),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionOr => {
+            ProdKind::ExpressionP29 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
-                    ) => {
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::Number(p0)) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_or(&*context, p0, p1),
+                            compiler_actions::expression_number(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionUnaryMinus => {
+            ProdKind::ExpressionP30 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::Expression(p0))) => {
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::StringLiteral(p0)) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_unary_minus(&*context, p0),
+                            compiler_actions::expression_string_literal(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionUnaryNegation => {
+            ProdKind::ExpressionTrue => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::Expression(p0))) => {
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::TrueKeyword(p0)) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_unary_negation(&*context, p0),
+                            compiler_actions::expression_true(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionP15 => {
+            ProdKind::ExpressionFalse => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (_, Symbol::NonTerminal(NonTerminal::Expression(p0)), _) => {
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::FalseKeyword(p0)) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_expression(&*context, p0),
+                            compiler_actions::expression_false(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionP16 => {
+            ProdKind::ExpressionP33 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::Number(p0)) => {
+                    Symbol::NonTerminal(NonTerminal::CallExpression(p0)) => {
                         NonTerminal::Expression(
-                            compiler_actions::expression_number(&*context, p0),
+                            compiler_actions::expression_call_expression(&*context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ExpressionP17 => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
-                NonTerminal::Expression(compiler_actions::expression_true(context))
-            }
-            ProdKind::ExpressionP18 => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
-                NonTerminal::Expression(compiler_actions::expression_false(context))
-            }
-            ProdKind::ExpressionP19 => {
+            ProdKind::ExpressionP34 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
@@ -4670,6 +17261,30 @@ for DefaultBuilder {
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
+            ProdKind::FieldInitializerFieldInitializer => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::Terminal(Terminal::Colon(p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p2)),
+                    ) => {
+                        NonTerminal::FieldInitializer(
+                            compiler_actions::field_initializer_field_initializer(
+                                &*context,
+                                p0,
+                                p1,
+                                p2,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            _ => panic!("Reduce of unreachable nonterminal!"),
         };
         self.res_stack.push(Symbol::NonTerminal(prod));
     }
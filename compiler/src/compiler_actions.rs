@@ -1,22 +1,46 @@
 use super::compiler::{Context, TokenKind};
+use crate::syntax_analyzer::array_literal_expression::ArrayLiteralExpressionSyntax;
 use crate::syntax_analyzer::assignment::Assignment as CustomAssignment;
 use crate::syntax_analyzer::binary_expression::BinaryExpressionSyntax;
+use crate::syntax_analyzer::break_statement::BreakStatement as CustomBreakStatement;
+use crate::syntax_analyzer::call_expression::CallExpressionSyntax;
+use crate::syntax_analyzer::conditional_expression::ConditionalExpressionSyntax;
 use crate::syntax_analyzer::constant_declaration::ConstantDeclaration as CustomConstantDeclaration;
+use crate::syntax_analyzer::continue_statement::ContinueStatement as CustomContinueStatement;
 use crate::syntax_analyzer::else_statement::ElseStatement;
+use crate::syntax_analyzer::expression_statement::ExpressionStatement as CustomExpressionStatement;
+use crate::syntax_analyzer::field_assignment::FieldAssignment as CustomFieldAssignment;
+use crate::syntax_analyzer::field_expression::FieldExpressionSyntax;
 use crate::syntax_analyzer::for_statement::ForStatement as CustomForStatement;
+use crate::syntax_analyzer::function_declaration::{
+    FunctionDeclaration as CustomFunctionDeclaration, Parameter as CustomParameter,
+};
 use crate::syntax_analyzer::if_statement::IfStatement as CustomIfStatement;
+use crate::syntax_analyzer::increment_statement::IncrementStatement as CustomIncrementStatement;
+use crate::syntax_analyzer::index_assignment::IndexAssignment as CustomIndexAssignment;
+use crate::syntax_analyzer::index_expression::IndexExpressionSyntax;
 use crate::syntax_analyzer::literal_expression::LiteralExpressionSyntax;
 use crate::syntax_analyzer::name_expression::NameExpressionSyntax;
 use crate::syntax_analyzer::parenthesized_expression::ParenthesizedExpressionSyntax;
+use crate::syntax_analyzer::return_statement::ReturnStatement as CustomReturnStatement;
 use crate::syntax_analyzer::statement_list::StatementList as CustomStatementList;
+use crate::syntax_analyzer::struct_declaration::{
+    FieldDeclaration as CustomFieldDeclaration, StructDeclaration as CustomStructDeclaration,
+};
+use crate::syntax_analyzer::struct_literal_expression::{
+    FieldInitializer as CustomFieldInitializer, StructLiteralExpressionSyntax,
+};
+use crate::syntax_analyzer::type_clause::TypeClause as CustomTypeClause;
 use crate::syntax_analyzer::unary_expression::UnaryExpressionSyntax;
 use crate::syntax_analyzer::variable_declaration::VariableDeclaration as CustomVariableDeclaration;
 use crate::syntax_analyzer::while_statement::WhileStatement as CustomWhileStatement;
 use crate::util;
 use crate::util::literals::LiteralValue;
 use crate::util::syntax_kind::SyntaxKind;
-use crate::util::utils::transform_str;
+use crate::util::utils::{transform_str, unescape};
+use rustemo::Context as RustemoContext;
 use rustemo::Token as RustemoToken;
+use std::cell::RefCell;
 use std::str::FromStr;
 use util::expression::Expression as CustomExpression;
 use util::statement::Statement as CustomStatement;
@@ -25,33 +49,282 @@ pub type Input = str;
 pub type Ctx<'i> = Context<'i, Input>;
 #[allow(dead_code)]
 pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
-pub type Number = i32;
+// Rustemo positions are byte offsets, while the custom lexer and the diagnostics count
+// characters, so the character offset of every byte of the source is kept here.
+thread_local! {
+    static CHAR_OFFSETS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+pub fn set_source(code: &str) {
+    CHAR_OFFSETS.with(|offsets| {
+        let mut offsets = offsets.borrow_mut();
+        offsets.clear();
+        for (index, character) in code.chars().enumerate() {
+            offsets.extend(std::iter::repeat_n(index, character.len_utf8()));
+        }
+        offsets.push(code.chars().count());
+    });
+}
+fn char_offset(byte: usize) -> usize {
+    CHAR_OFFSETS.with(|offsets| offsets.borrow().get(byte).copied().unwrap_or(byte))
+}
+fn token_start(ctx: &Ctx) -> usize {
+    char_offset(ctx.position())
+}
+// The start and the length in characters of the production being reduced.
+fn production_span(ctx: &Ctx) -> (usize, usize) {
+    let start = char_offset(ctx.range().start);
+    (start, char_offset(ctx.range().end) - start)
+}
+pub type Number = LiteralValue;
+// Numbers that don't fit are reported by the lexer before the source is parsed.
 pub fn number(_ctx: &Ctx, token: Token) -> Number {
-    token.value.parse().unwrap()
+    if token.value.contains('.') {
+        LiteralValue::Float(token.value.parse().expect("the lexer checks every number"))
+    } else {
+        LiteralValue::Integer(token.value.parse().expect("the lexer checks every number"))
+    }
+}
+pub type Identifier = CustomToken;
+pub fn identifier(ctx: &Ctx, token: Token) -> Identifier {
+    CustomToken::new(
+        token.value.to_string(),
+        LiteralValue::String(token.value.to_string()),
+        token_start(ctx),
+        SyntaxKind::IdentifierToken,
+        token.value.len(),
+    )
+}
+pub type StringLiteral = String;
+pub fn string_literal(_ctx: &Ctx, token: Token) -> StringLiteral {
+    unescape(&token.value[1..token.value.len() - 1]).unwrap()
+}
+pub type Plus = CustomToken;
+pub fn plus(ctx: &Ctx, _token: Token) -> Plus {
+    return_operator("Plus", token_start(ctx))
+}
+pub type Minus = CustomToken;
+pub fn minus(ctx: &Ctx, _token: Token) -> Minus {
+    return_operator("Minus", token_start(ctx))
+}
+pub type Mul = CustomToken;
+pub fn mul(ctx: &Ctx, _token: Token) -> Mul {
+    return_operator("Mul", token_start(ctx))
+}
+pub type Div = CustomToken;
+pub fn div(ctx: &Ctx, _token: Token) -> Div {
+    return_operator("Div", token_start(ctx))
+}
+pub type Percent = CustomToken;
+pub fn percent(ctx: &Ctx, _token: Token) -> Percent {
+    return_operator("Percent", token_start(ctx))
+}
+pub type StarStar = CustomToken;
+pub fn star_star(ctx: &Ctx, _token: Token) -> StarStar {
+    return_operator("StarStar", token_start(ctx))
+}
+pub type PlusEquals = CustomToken;
+pub fn plus_equals(ctx: &Ctx, _token: Token) -> PlusEquals {
+    return_operator("PlusEquals", token_start(ctx))
+}
+pub type MinusEquals = CustomToken;
+pub fn minus_equals(ctx: &Ctx, _token: Token) -> MinusEquals {
+    return_operator("MinusEquals", token_start(ctx))
+}
+pub type MulEquals = CustomToken;
+pub fn mul_equals(ctx: &Ctx, _token: Token) -> MulEquals {
+    return_operator("MulEquals", token_start(ctx))
+}
+pub type DivEquals = CustomToken;
+pub fn div_equals(ctx: &Ctx, _token: Token) -> DivEquals {
+    return_operator("DivEquals", token_start(ctx))
+}
+pub type PercentEquals = CustomToken;
+pub fn percent_equals(ctx: &Ctx, _token: Token) -> PercentEquals {
+    return_operator("PercentEquals", token_start(ctx))
+}
+pub type PlusPlus = CustomToken;
+pub fn plus_plus(ctx: &Ctx, _token: Token) -> PlusPlus {
+    return_operator("PlusPlus", token_start(ctx))
+}
+pub type MinusMinus = CustomToken;
+pub fn minus_minus(ctx: &Ctx, _token: Token) -> MinusMinus {
+    return_operator("MinusMinus", token_start(ctx))
+}
+pub type Bang = CustomToken;
+pub fn bang(ctx: &Ctx, _token: Token) -> Bang {
+    return_operator("Bang", token_start(ctx))
+}
+pub type EqualsEquals = CustomToken;
+pub fn equals_equals(ctx: &Ctx, _token: Token) -> EqualsEquals {
+    return_operator("EqualsEquals", token_start(ctx))
+}
+pub type BangEquals = CustomToken;
+pub fn bang_equals(ctx: &Ctx, _token: Token) -> BangEquals {
+    return_operator("BangEquals", token_start(ctx))
+}
+pub type Less = CustomToken;
+pub fn less(ctx: &Ctx, _token: Token) -> Less {
+    return_operator("Less", token_start(ctx))
+}
+pub type LessOrEquals = CustomToken;
+pub fn less_or_equals(ctx: &Ctx, _token: Token) -> LessOrEquals {
+    return_operator("LessOrEquals", token_start(ctx))
+}
+pub type Greater = CustomToken;
+pub fn greater(ctx: &Ctx, _token: Token) -> Greater {
+    return_operator("Greater", token_start(ctx))
+}
+pub type GreaterOrEquals = CustomToken;
+pub fn greater_or_equals(ctx: &Ctx, _token: Token) -> GreaterOrEquals {
+    return_operator("GreaterOrEquals", token_start(ctx))
+}
+pub type AmpersandAmpersand = CustomToken;
+pub fn ampersand_ampersand(ctx: &Ctx, _token: Token) -> AmpersandAmpersand {
+    return_operator("AmpersandAmpersand", token_start(ctx))
+}
+pub type PipePipe = CustomToken;
+pub fn pipe_pipe(ctx: &Ctx, _token: Token) -> PipePipe {
+    return_operator("PipePipe", token_start(ctx))
+}
+pub type Ampersand = CustomToken;
+pub fn ampersand(ctx: &Ctx, _token: Token) -> Ampersand {
+    return_operator("Ampersand", token_start(ctx))
+}
+pub type Pipe = CustomToken;
+pub fn pipe(ctx: &Ctx, _token: Token) -> Pipe {
+    return_operator("Pipe", token_start(ctx))
+}
+pub type Caret = CustomToken;
+pub fn caret(ctx: &Ctx, _token: Token) -> Caret {
+    return_operator("Caret", token_start(ctx))
+}
+pub type Tilde = CustomToken;
+pub fn tilde(ctx: &Ctx, _token: Token) -> Tilde {
+    return_operator("Tilde", token_start(ctx))
+}
+pub type LessLess = CustomToken;
+pub fn less_less(ctx: &Ctx, _token: Token) -> LessLess {
+    return_operator("LessLess", token_start(ctx))
+}
+pub type GreaterGreater = CustomToken;
+pub fn greater_greater(ctx: &Ctx, _token: Token) -> GreaterGreater {
+    return_operator("GreaterGreater", token_start(ctx))
+}
+pub type OpenBracket = CustomToken;
+pub fn open_bracket(ctx: &Ctx, _token: Token) -> OpenBracket {
+    create_custom_token("[", token_start(ctx))
+}
+pub type Dot = CustomToken;
+pub fn dot(ctx: &Ctx, _token: Token) -> Dot {
+    create_custom_token(".", token_start(ctx))
+}
+pub type OpenParenthesis = CustomToken;
+pub fn open_parenthesis(ctx: &Ctx, _token: Token) -> OpenParenthesis {
+    create_custom_token("(", token_start(ctx))
+}
+pub type CloseParenthesis = CustomToken;
+pub fn close_parenthesis(ctx: &Ctx, _token: Token) -> CloseParenthesis {
+    create_custom_token(")", token_start(ctx))
 }
-pub type Identifier = String;
-pub fn identifier(_ctx: &Ctx, token: Token) -> Identifier {
-    token.value.into()
+pub type OpenBrace = CustomToken;
+pub fn open_brace(ctx: &Ctx, _token: Token) -> OpenBrace {
+    create_custom_token("{", token_start(ctx))
 }
-fn return_operator(operator_type: &str) -> CustomToken {
+pub type CloseBrace = CustomToken;
+pub fn close_brace(ctx: &Ctx, _token: Token) -> CloseBrace {
+    create_custom_token("}", token_start(ctx))
+}
+pub type CloseBracket = CustomToken;
+pub fn close_bracket(ctx: &Ctx, _token: Token) -> CloseBracket {
+    create_custom_token("]", token_start(ctx))
+}
+pub type Equals = CustomToken;
+pub fn equals(ctx: &Ctx, _token: Token) -> Equals {
+    create_custom_token("=", token_start(ctx))
+}
+pub type SemiColon = CustomToken;
+pub fn semi_colon(ctx: &Ctx, _token: Token) -> SemiColon {
+    create_custom_token(";", token_start(ctx))
+}
+pub type Comma = CustomToken;
+pub fn comma(ctx: &Ctx, _token: Token) -> Comma {
+    create_custom_token(",", token_start(ctx))
+}
+pub type Colon = CustomToken;
+pub fn colon(ctx: &Ctx, _token: Token) -> Colon {
+    create_custom_token(":", token_start(ctx))
+}
+pub type TrueKeyword = CustomToken;
+pub fn true_keyword(ctx: &Ctx, _token: Token) -> TrueKeyword {
+    create_custom_token("true", token_start(ctx))
+}
+pub type FalseKeyword = CustomToken;
+pub fn false_keyword(ctx: &Ctx, _token: Token) -> FalseKeyword {
+    create_custom_token("false", token_start(ctx))
+}
+pub type IfKeyword = CustomToken;
+pub fn if_keyword(ctx: &Ctx, _token: Token) -> IfKeyword {
+    create_custom_token("if", token_start(ctx))
+}
+pub type ElseKeyword = CustomToken;
+pub fn else_keyword(ctx: &Ctx, _token: Token) -> ElseKeyword {
+    create_custom_token("else", token_start(ctx))
+}
+pub type LetKeyword = CustomToken;
+pub fn let_keyword(ctx: &Ctx, _token: Token) -> LetKeyword {
+    create_custom_token("let", token_start(ctx))
+}
+pub type ConstKeyword = CustomToken;
+pub fn const_keyword(ctx: &Ctx, _token: Token) -> ConstKeyword {
+    create_custom_token("const", token_start(ctx))
+}
+pub type WhileKeyword = CustomToken;
+pub fn while_keyword(ctx: &Ctx, _token: Token) -> WhileKeyword {
+    create_custom_token("while", token_start(ctx))
+}
+pub type ForKeyword = CustomToken;
+pub fn for_keyword(ctx: &Ctx, _token: Token) -> ForKeyword {
+    create_custom_token("for", token_start(ctx))
+}
+pub type ToKeyword = CustomToken;
+pub fn to_keyword(ctx: &Ctx, _token: Token) -> ToKeyword {
+    create_custom_token("to", token_start(ctx))
+}
+pub type FnKeyword = CustomToken;
+pub fn fn_keyword(ctx: &Ctx, _token: Token) -> FnKeyword {
+    create_custom_token("fn", token_start(ctx))
+}
+pub type ReturnKeyword = CustomToken;
+pub fn return_keyword(ctx: &Ctx, _token: Token) -> ReturnKeyword {
+    create_custom_token("return", token_start(ctx))
+}
+pub type BreakKeyword = CustomToken;
+pub fn break_keyword(ctx: &Ctx, _token: Token) -> BreakKeyword {
+    create_custom_token("break", token_start(ctx))
+}
+pub type ContinueKeyword = CustomToken;
+pub fn continue_keyword(ctx: &Ctx, _token: Token) -> ContinueKeyword {
+    create_custom_token("continue", token_start(ctx))
+}
+pub type StructKeyword = CustomToken;
+pub fn struct_keyword(ctx: &Ctx, _token: Token) -> StructKeyword {
+    create_custom_token("struct", token_start(ctx))
+}
+fn return_operator(operator_type: &str, position: usize) -> CustomToken {
     CustomToken::new(
         transform_str(operator_type),
         LiteralValue::String(transform_str(operator_type)),
-        0,
+        position,
         SyntaxKind::from_str(operator_type).unwrap(),
         transform_str(operator_type).len(),
     )
 }
-fn create_custom_token(name: &str, is_id: bool) -> CustomToken {
+fn create_custom_token(name: &str, position: usize) -> CustomToken {
     CustomToken::new(
         name.to_string(),
         LiteralValue::String(name.to_string()),
-        0,
-        if is_id {
-            SyntaxKind::IdentifierToken
-        } else {
-            SyntaxKind::from_str(name).unwrap()
-        },
+        position,
+        SyntaxKind::from_str(name).unwrap(),
         name.len(),
     )
 }
@@ -60,12 +333,15 @@ pub fn program_statement_list(_ctx: &Ctx, statement_list: StatementList) -> Prog
     statement_list
 }
 pub type StatementList = Box<dyn CustomStatement>;
-pub fn statement_list_statement1(_ctx: &Ctx, statement1: Statement1) -> StatementList {
-    let open_brace = create_custom_token("{", false);
-    let close_brace = create_custom_token("}", false);
+pub fn statement_list_c1(
+    _ctx: &Ctx,
+    open_brace: OpenBrace,
+    statement0: Statement0,
+    close_brace: CloseBrace,
+) -> StatementList {
     Box::new(CustomStatementList::new(
         open_brace,
-        statement1,
+        statement0.unwrap_or_default(),
         close_brace,
     )) as Box<dyn CustomStatement>
 }
@@ -81,6 +357,13 @@ pub fn statement1_c1(
 pub fn statement1_statement(_ctx: &Ctx, statement: Box<dyn CustomStatement>) -> Statement1 {
     vec![statement]
 }
+pub type Statement0 = Option<Statement1>;
+pub fn statement0_statement1(_ctx: &Ctx, statement1: Statement1) -> Statement0 {
+    Some(statement1)
+}
+pub fn statement0_empty(_ctx: &Ctx) -> Statement0 {
+    None
+}
 pub type Statement = Box<dyn CustomStatement>;
 pub fn statement_assignment(_ctx: &Ctx, assignment: Assignment) -> Statement {
     assignment
@@ -106,6 +389,54 @@ pub fn statement_while_statement(_ctx: &Ctx, while_statement: WhileStatement) ->
 pub fn statement_for_statement(_ctx: &Ctx, for_statement: ForStatement) -> Statement {
     for_statement
 }
+pub fn statement_function_declaration(
+    _ctx: &Ctx,
+    function_declaration: FunctionDeclaration,
+) -> Statement {
+    function_declaration
+}
+pub fn statement_return_statement(_ctx: &Ctx, return_statement: ReturnStatement) -> Statement {
+    return_statement
+}
+pub fn statement_increment_statement(
+    _ctx: &Ctx,
+    increment_statement: IncrementStatement,
+) -> Statement {
+    increment_statement
+}
+pub fn statement_index_assignment(
+    _ctx: &Ctx,
+    index_assignment: IndexAssignment,
+) -> Statement {
+    index_assignment
+}
+pub fn statement_field_assignment(
+    _ctx: &Ctx,
+    field_assignment: FieldAssignment,
+) -> Statement {
+    field_assignment
+}
+pub fn statement_struct_declaration(
+    _ctx: &Ctx,
+    struct_declaration: StructDeclaration,
+) -> Statement {
+    struct_declaration
+}
+pub fn statement_break_statement(_ctx: &Ctx, break_statement: BreakStatement) -> Statement {
+    break_statement
+}
+pub fn statement_continue_statement(
+    _ctx: &Ctx,
+    continue_statement: ContinueStatement,
+) -> Statement {
+    continue_statement
+}
+pub fn statement_expression_statement(
+    _ctx: &Ctx,
+    expression_statement: ExpressionStatement,
+) -> Statement {
+    expression_statement
+}
 pub fn statement_statement_list(_ctx: &Ctx, statement_list: StatementList) -> Statement {
     statement_list
 }
@@ -113,27 +444,154 @@ pub type Assignment = Box<dyn CustomStatement>;
 pub fn assignment_assignment(
     _ctx: &Ctx,
     identifier: Identifier,
+    equals: Equals,
     expression: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
 ) -> Box<dyn CustomStatement> {
-    let id = create_custom_token(&identifier, true);
-    let equals = create_custom_token("=", false);
-    let semi_colon = create_custom_token(";", false);
-
-    Box::new(CustomAssignment::new(id, equals, expression, semi_colon)) as Box<dyn CustomStatement>
+    Box::new(CustomAssignment::new(
+        identifier, equals, expression, semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+pub fn assignment_compound_assignment(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    compound_operator: CompoundOperator,
+    expression: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomAssignment::new(
+        identifier,
+        compound_operator,
+        expression,
+        semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+pub type CompoundOperator = CustomToken;
+pub fn compound_operator_plus_equals(_ctx: &Ctx, plus_equals: PlusEquals) -> CompoundOperator {
+    plus_equals
+}
+pub fn compound_operator_minus_equals(_ctx: &Ctx, minus_equals: MinusEquals) -> CompoundOperator {
+    minus_equals
+}
+pub fn compound_operator_mul_equals(_ctx: &Ctx, mul_equals: MulEquals) -> CompoundOperator {
+    mul_equals
+}
+pub fn compound_operator_div_equals(_ctx: &Ctx, div_equals: DivEquals) -> CompoundOperator {
+    div_equals
+}
+pub fn compound_operator_percent_equals(
+    _ctx: &Ctx,
+    percent_equals: PercentEquals,
+) -> CompoundOperator {
+    percent_equals
+}
+pub type IncrementStatement = Box<dyn CustomStatement>;
+pub fn increment_statement_increment_statement(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    increment_operator: IncrementOperator,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomIncrementStatement::new(
+        identifier,
+        increment_operator,
+        semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+pub type IncrementOperator = CustomToken;
+pub fn increment_operator_plus_plus(_ctx: &Ctx, plus_plus: PlusPlus) -> IncrementOperator {
+    plus_plus
+}
+pub fn increment_operator_minus_minus(_ctx: &Ctx, minus_minus: MinusMinus) -> IncrementOperator {
+    minus_minus
+}
+pub type IndexAssignment = Box<dyn CustomStatement>;
+#[allow(clippy::too_many_arguments)]
+pub fn index_assignment_index_assignment(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    open_bracket: OpenBracket,
+    index: Box<dyn CustomExpression>,
+    close_bracket: CloseBracket,
+    equals: Equals,
+    value: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomIndexAssignment::new(
+        identifier,
+        open_bracket,
+        index,
+        close_bracket,
+        equals,
+        value,
+        semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+#[allow(clippy::too_many_arguments)]
+pub fn index_assignment_compound_index_assignment(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    open_bracket: OpenBracket,
+    index: Box<dyn CustomExpression>,
+    close_bracket: CloseBracket,
+    compound_operator: CompoundOperator,
+    value: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomIndexAssignment::new(
+        identifier,
+        open_bracket,
+        index,
+        close_bracket,
+        compound_operator,
+        value,
+        semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+pub type FieldAssignment = Box<dyn CustomStatement>;
+pub fn field_assignment_field_assignment(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    dot: Dot,
+    field: Identifier,
+    equals: Equals,
+    value: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomFieldAssignment::new(
+        identifier, dot, field, equals, value, semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+pub fn field_assignment_compound_field_assignment(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    dot: Dot,
+    field: Identifier,
+    compound_operator: CompoundOperator,
+    value: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomFieldAssignment::new(
+        identifier,
+        dot,
+        field,
+        compound_operator,
+        value,
+        semi_colon,
+    )) as Box<dyn CustomStatement>
 }
 pub type IfStatement = Box<dyn CustomStatement>;
 pub fn if_statement_if_statement(
     _ctx: &Ctx,
+    if_keyword: IfKeyword,
+    open_parenthesis: OpenParenthesis,
     expression: Box<dyn CustomExpression>,
+    close_parenthesis: CloseParenthesis,
     statement_list: Box<dyn CustomStatement>,
     else_clause: Option<Box<dyn CustomStatement>>,
 ) -> Box<dyn CustomStatement> {
-    let if_token = create_custom_token("if", false);
-    let open_parenthesis = create_custom_token("(", false);
-    let close_parenthesis = create_custom_token(")", false);
-
     Box::new(CustomIfStatement::new(
-        if_token,
+        if_keyword,
         open_parenthesis,
         expression,
         close_parenthesis,
@@ -151,39 +609,47 @@ pub fn else_clause_opt_empty(_ctx: &Ctx) -> ElseClauseOpt {
 pub type ElseClause = Box<dyn CustomStatement>;
 pub fn else_clause_else_statement(
     _ctx: &Ctx,
+    else_keyword: ElseKeyword,
     statement_list: Box<dyn CustomStatement>,
 ) -> ElseClause {
-    let else_token = create_custom_token("else", false);
-
-    Box::new(ElseStatement::new(else_token, statement_list)) as Box<dyn CustomStatement>
+    Box::new(ElseStatement::new(else_keyword, statement_list)) as Box<dyn CustomStatement>
+}
+pub fn else_clause_else_if(
+    _ctx: &Ctx,
+    else_keyword: ElseKeyword,
+    if_statement: Box<dyn CustomStatement>,
+) -> ElseClause {
+    Box::new(ElseStatement::new(else_keyword, if_statement)) as Box<dyn CustomStatement>
 }
 pub type VariableDeclaration = Box<dyn CustomStatement>;
 pub fn variable_declaration_variable_declaration(
     _ctx: &Ctx,
+    let_keyword: LetKeyword,
     identifier: Identifier,
+    equals: Equals,
     expression: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
 ) -> Box<dyn CustomStatement> {
-    let let_token = create_custom_token("let", false);
-    let id = create_custom_token(&identifier, true);
-    let equals = create_custom_token("=", false);
-    let semi_colon = create_custom_token(";", false);
     Box::new(CustomVariableDeclaration::new(
-        let_token, id, equals, expression, semi_colon,
+        let_keyword,
+        identifier,
+        equals,
+        expression,
+        semi_colon,
     )) as Box<dyn CustomStatement>
 }
 pub type ConstantDeclaration = Box<dyn CustomStatement>;
 pub fn constant_declaration_constant_declaration(
     _ctx: &Ctx,
+    const_keyword: ConstKeyword,
     identifier: Identifier,
+    equals: Equals,
     expression: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
 ) -> Box<dyn CustomStatement> {
-    let const_token = create_custom_token("const", false);
-    let id = create_custom_token(&identifier, true);
-    let equals = create_custom_token("=", false);
-    let semi_colon = create_custom_token(";", false);
     Box::new(CustomConstantDeclaration::new(
-        const_token,
-        id,
+        const_keyword,
+        identifier,
         equals,
         expression,
         semi_colon,
@@ -192,15 +658,14 @@ pub fn constant_declaration_constant_declaration(
 pub type WhileStatement = Box<dyn CustomStatement>;
 pub fn while_statement_while(
     _ctx: &Ctx,
+    while_keyword: WhileKeyword,
+    open_parenthesis: OpenParenthesis,
     expression: Box<dyn CustomExpression>,
+    close_parenthesis: CloseParenthesis,
     statement_list: Box<dyn CustomStatement>,
 ) -> Box<dyn CustomStatement> {
-    let while_token = create_custom_token("while", false);
-    let open_parenthesis = create_custom_token("(", false);
-    let close_parenthesis = create_custom_token(")", false);
-
     Box::new(CustomWhileStatement::new(
-        while_token,
+        while_keyword,
         open_parenthesis,
         expression,
         close_parenthesis,
@@ -208,204 +673,600 @@ pub fn while_statement_while(
     )) as Box<dyn CustomStatement>
 }
 pub type ForStatement = Box<dyn CustomStatement>;
+#[allow(clippy::too_many_arguments)]
 pub fn for_statement_for(
     _ctx: &Ctx,
+    for_keyword: ForKeyword,
+    open_parenthesis: OpenParenthesis,
     identifier: Identifier,
+    equals: Equals,
     expression_5: Box<dyn CustomExpression>,
+    to_keyword: ToKeyword,
     expression_7: Box<dyn CustomExpression>,
+    close_parenthesis: CloseParenthesis,
     statement_list: Box<dyn CustomStatement>,
 ) -> Box<dyn CustomStatement> {
-    let for_token = create_custom_token("for", false);
-    let open_parenthesis = create_custom_token("(", false);
-    let id = create_custom_token(&identifier, true);
-    let equals = create_custom_token("=", false);
-    let to = create_custom_token("to", false);
-    let close_parenthesis = create_custom_token(")", false);
-
     Box::new(CustomForStatement::new(
-        for_token,
+        for_keyword,
         open_parenthesis,
-        id,
+        identifier,
         equals,
         expression_5,
-        to,
+        to_keyword,
         expression_7,
         close_parenthesis,
         statement_list,
     )) as Box<dyn CustomStatement>
 }
+pub type FunctionDeclaration = Box<dyn CustomStatement>;
+#[allow(clippy::too_many_arguments)]
+pub fn function_declaration_function_declaration(
+    _ctx: &Ctx,
+    fn_keyword: FnKeyword,
+    identifier: Identifier,
+    open_parenthesis: OpenParenthesis,
+    parameter0: Parameter0,
+    close_parenthesis: CloseParenthesis,
+    type_clause_opt: TypeClauseOpt,
+    statement_list: StatementList,
+) -> Box<dyn CustomStatement> {
+    let (parameters, commas) = parameter0.unwrap_or_default();
+    let parameters: Vec<CustomParameter> =
+        parameters.into_iter().map(|parameter| *parameter).collect();
+
+    Box::new(CustomFunctionDeclaration::new(
+        fn_keyword,
+        identifier,
+        open_parenthesis,
+        parameters,
+        commas,
+        close_parenthesis,
+        type_clause_opt,
+        statement_list,
+    )) as Box<dyn CustomStatement>
+}
+// The items of the list and the commas between them.
+pub type Parameter1 = (Vec<Parameter>, Vec<CustomToken>);
+pub fn parameter1_c1(
+    _ctx: &Ctx,
+    mut parameter1: Parameter1,
+    comma: Comma,
+    parameter: Parameter,
+) -> Parameter1 {
+    parameter1.0.push(parameter);
+    parameter1.1.push(comma);
+    parameter1
+}
+pub fn parameter1_parameter(_ctx: &Ctx, parameter: Parameter) -> Parameter1 {
+    (vec![parameter], Vec::new())
+}
+pub type Parameter0 = Option<Parameter1>;
+pub fn parameter0_parameter1(_ctx: &Ctx, parameter1: Parameter1) -> Parameter0 {
+    Some(parameter1)
+}
+pub fn parameter0_empty(_ctx: &Ctx) -> Parameter0 {
+    None
+}
+pub type TypeClauseOpt = Option<TypeClause>;
+pub fn type_clause_opt_type_clause(_ctx: &Ctx, type_clause: TypeClause) -> TypeClauseOpt {
+    Some(type_clause)
+}
+pub fn type_clause_opt_empty(_ctx: &Ctx) -> TypeClauseOpt {
+    None
+}
+pub type Parameter = Box<CustomParameter>;
+pub fn parameter_parameter(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_clause_opt: TypeClauseOpt,
+) -> Parameter {
+    Box::new(CustomParameter::new(identifier, type_clause_opt))
+}
+pub type StructDeclaration = Box<dyn CustomStatement>;
+pub fn struct_declaration_struct_declaration(
+    _ctx: &Ctx,
+    struct_keyword: StructKeyword,
+    identifier: Identifier,
+    open_brace: OpenBrace,
+    field_declaration0: FieldDeclaration0,
+    close_brace: CloseBrace,
+) -> Box<dyn CustomStatement> {
+    let (fields, commas) = field_declaration0.unwrap_or_default();
+    let fields: Vec<CustomFieldDeclaration> = fields.into_iter().map(|field| *field).collect();
+
+    Box::new(CustomStructDeclaration::new(
+        struct_keyword,
+        identifier,
+        open_brace,
+        fields,
+        commas,
+        close_brace,
+    )) as Box<dyn CustomStatement>
+}
+// The items of the list and the commas between them.
+pub type FieldDeclaration1 = (Vec<FieldDeclaration>, Vec<CustomToken>);
+pub fn field_declaration1_c1(
+    _ctx: &Ctx,
+    mut field_declaration1: FieldDeclaration1,
+    comma: Comma,
+    field_declaration: FieldDeclaration,
+) -> FieldDeclaration1 {
+    field_declaration1.0.push(field_declaration);
+    field_declaration1.1.push(comma);
+    field_declaration1
+}
+pub fn field_declaration1_field_declaration(
+    _ctx: &Ctx,
+    field_declaration: FieldDeclaration,
+) -> FieldDeclaration1 {
+    (vec![field_declaration], Vec::new())
+}
+pub type FieldDeclaration0 = Option<FieldDeclaration1>;
+pub fn field_declaration0_field_declaration1(
+    _ctx: &Ctx,
+    field_declaration1: FieldDeclaration1,
+) -> FieldDeclaration0 {
+    Some(field_declaration1)
+}
+pub fn field_declaration0_empty(_ctx: &Ctx) -> FieldDeclaration0 {
+    None
+}
+pub type FieldDeclaration = Box<CustomFieldDeclaration>;
+pub fn field_declaration_field_declaration(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_clause: TypeClause,
+) -> FieldDeclaration {
+    Box::new(CustomFieldDeclaration::new(identifier, type_clause))
+}
+pub type TypeClause = CustomTypeClause;
+pub fn type_clause_type_clause(_ctx: &Ctx, colon: Colon, type_name: TypeName) -> TypeClause {
+    let (open_brackets, type_name, close_brackets) = type_name;
+
+    CustomTypeClause::new(colon, open_brackets, type_name, close_brackets)
+}
+// The opening brackets, the name and the closing brackets of a type.
+pub type TypeName = (Vec<CustomToken>, CustomToken, Vec<CustomToken>);
+pub fn type_name_type_name(_ctx: &Ctx, identifier: Identifier) -> TypeName {
+    (Vec::new(), identifier, Vec::new())
+}
+pub fn type_name_array_type_name(
+    _ctx: &Ctx,
+    open_bracket: OpenBracket,
+    type_name: TypeName,
+    close_bracket: CloseBracket,
+) -> TypeName {
+    let (mut open_brackets, name, mut close_brackets) = type_name;
+    open_brackets.insert(0, open_bracket);
+    close_brackets.push(close_bracket);
+    (open_brackets, name, close_brackets)
+}
+pub type ReturnStatement = Box<dyn CustomStatement>;
+pub fn return_statement_return_statement(
+    _ctx: &Ctx,
+    return_keyword: ReturnKeyword,
+    expression: Box<dyn CustomExpression>,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomReturnStatement::new(
+        return_keyword,
+        expression,
+        semi_colon,
+    )) as Box<dyn CustomStatement>
+}
+pub type BreakStatement = Box<dyn CustomStatement>;
+pub fn break_statement_break_statement(
+    _ctx: &Ctx,
+    break_keyword: BreakKeyword,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomBreakStatement::new(break_keyword, semi_colon)) as Box<dyn CustomStatement>
+}
+pub type ContinueStatement = Box<dyn CustomStatement>;
+pub fn continue_statement_continue_statement(
+    _ctx: &Ctx,
+    continue_keyword: ContinueKeyword,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomContinueStatement::new(continue_keyword, semi_colon)) as Box<dyn CustomStatement>
+}
+pub type ExpressionStatement = Box<dyn CustomStatement>;
+pub fn expression_statement_expression_statement(
+    _ctx: &Ctx,
+    call_expression: CallExpression,
+    semi_colon: SemiColon,
+) -> Box<dyn CustomStatement> {
+    Box::new(CustomExpressionStatement::new(call_expression, semi_colon))
+        as Box<dyn CustomStatement>
+}
+pub type CallExpression = Box<dyn CustomExpression>;
+pub fn call_expression_call_expression(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    open_parenthesis: OpenParenthesis,
+    expression0: Expression0,
+    close_parenthesis: CloseParenthesis,
+) -> Box<dyn CustomExpression> {
+    let (arguments, commas) = expression0.unwrap_or_default();
+
+    Box::new(CallExpressionSyntax::new(
+        identifier,
+        open_parenthesis,
+        arguments,
+        commas,
+        close_parenthesis,
+    )) as Box<dyn CustomExpression>
+}
+// The items of the list and the commas between them.
+pub type Expression1 = (Vec<Box<dyn CustomExpression>>, Vec<CustomToken>);
+pub fn expression1_c1(
+    _ctx: &Ctx,
+    mut expression1: Expression1,
+    comma: Comma,
+    expression: Box<dyn CustomExpression>,
+) -> Expression1 {
+    expression1.0.push(expression);
+    expression1.1.push(comma);
+    expression1
+}
+pub fn expression1_expression(_ctx: &Ctx, expression: Box<dyn CustomExpression>) -> Expression1 {
+    (vec![expression], Vec::new())
+}
+pub type Expression0 = Option<Expression1>;
+pub fn expression0_expression1(_ctx: &Ctx, expression1: Expression1) -> Expression0 {
+    Some(expression1)
+}
+pub fn expression0_empty(_ctx: &Ctx) -> Expression0 {
+    None
+}
 pub type Expression = Box<dyn CustomExpression>;
 pub fn expression_add(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: Plus,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("Plus");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_sub(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: Minus,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("Minus");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_mul(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: Mul,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("Mul");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_div(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: Div,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_modulo(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: Percent,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_power(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: StarStar,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_shift_left(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: LessLess,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_shift_right(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: GreaterGreater,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_bitwise_and(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: Ampersand,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_bitwise_xor(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: Caret,
+    right: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
+}
+pub fn expression_bitwise_or(
+    _ctx: &Ctx,
+    left: Box<dyn CustomExpression>,
+    operator: Pipe,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("Div");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_equals(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: EqualsEquals,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("EqualsEquals");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_not_equals(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: BangEquals,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("BangEquals");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_less(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: Less,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("Less");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_less_or_equals(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: LessOrEquals,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("LessOrEquals");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_greater(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: Greater,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator: CustomToken = return_operator("Greater");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_greater_or_equals(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: GreaterOrEquals,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator = return_operator("GreaterOrEquals");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_and(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: AmpersandAmpersand,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator = return_operator("AmpersandAmpersand");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
 pub fn expression_or(
     _ctx: &Ctx,
     left: Box<dyn CustomExpression>,
+    operator: PipePipe,
     right: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator = return_operator("PipePipe");
-
     Box::new(BinaryExpressionSyntax::new(left, operator, right)) as Box<dyn CustomExpression>
 }
+#[allow(clippy::too_many_arguments)]
+pub fn expression_conditional(
+    _ctx: &Ctx,
+    if_keyword: IfKeyword,
+    open_parenthesis: OpenParenthesis,
+    condition: Box<dyn CustomExpression>,
+    close_parenthesis: CloseParenthesis,
+    when_true: Box<dyn CustomExpression>,
+    else_keyword: ElseKeyword,
+    when_false: Box<dyn CustomExpression>,
+) -> Box<dyn CustomExpression> {
+    Box::new(ConditionalExpressionSyntax::new(
+        if_keyword,
+        open_parenthesis,
+        condition,
+        close_parenthesis,
+        when_true,
+        else_keyword,
+        when_false,
+    )) as Box<dyn CustomExpression>
+}
 pub fn expression_unary_minus(
     _ctx: &Ctx,
+    operator: Minus,
     expression: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator = return_operator("Minus");
-
     Box::new(UnaryExpressionSyntax::new(operator, expression)) as Box<dyn CustomExpression>
 }
 pub fn expression_unary_negation(
     _ctx: &Ctx,
+    operator: Bang,
     expression: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let operator = return_operator("Bang");
-
     Box::new(UnaryExpressionSyntax::new(operator, expression)) as Box<dyn CustomExpression>
 }
-pub fn expression_expression(
+pub fn expression_unary_bitwise_not(
     _ctx: &Ctx,
+    operator: Tilde,
     expression: Box<dyn CustomExpression>,
 ) -> Box<dyn CustomExpression> {
-    let open_parenthesis_token = create_custom_token("(", false);
-    let close_parenthesis_token = create_custom_token(")", false);
+    Box::new(UnaryExpressionSyntax::new(operator, expression)) as Box<dyn CustomExpression>
+}
+pub fn expression_index(
+    _ctx: &Ctx,
+    array: Box<dyn CustomExpression>,
+    open_bracket: OpenBracket,
+    index: Box<dyn CustomExpression>,
+    close_bracket: CloseBracket,
+) -> Box<dyn CustomExpression> {
+    Box::new(IndexExpressionSyntax::new(
+        array,
+        open_bracket,
+        index,
+        close_bracket,
+    )) as Box<dyn CustomExpression>
+}
+pub fn expression_array_literal(
+    _ctx: &Ctx,
+    open_bracket: OpenBracket,
+    expression0: Expression0,
+    close_bracket: CloseBracket,
+) -> Box<dyn CustomExpression> {
+    let (elements, commas) = expression0.unwrap_or_default();
 
+    Box::new(ArrayLiteralExpressionSyntax::new(
+        open_bracket,
+        elements,
+        commas,
+        close_bracket,
+    )) as Box<dyn CustomExpression>
+}
+pub fn expression_field(
+    _ctx: &Ctx,
+    structure: Box<dyn CustomExpression>,
+    dot: Dot,
+    field: Identifier,
+) -> Box<dyn CustomExpression> {
+    Box::new(FieldExpressionSyntax::new(structure, dot, field)) as Box<dyn CustomExpression>
+}
+pub fn expression_struct_literal(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    open_brace: OpenBrace,
+    field_initializer0: FieldInitializer0,
+    close_brace: CloseBrace,
+) -> Box<dyn CustomExpression> {
+    let (fields, commas) = field_initializer0.unwrap_or_default();
+    let fields: Vec<CustomFieldInitializer> = fields.into_iter().map(|field| *field).collect();
+
+    Box::new(StructLiteralExpressionSyntax::new(
+        identifier,
+        open_brace,
+        fields,
+        commas,
+        close_brace,
+    )) as Box<dyn CustomExpression>
+}
+pub fn expression_c28(
+    _ctx: &Ctx,
+    open_parenthesis: OpenParenthesis,
+    expression: Box<dyn CustomExpression>,
+    close_parenthesis: CloseParenthesis,
+) -> Box<dyn CustomExpression> {
     Box::new(ParenthesizedExpressionSyntax::new(
-        open_parenthesis_token,
+        open_parenthesis,
         expression,
-        close_parenthesis_token,
+        close_parenthesis,
     )) as Box<dyn CustomExpression>
 }
-pub fn expression_number(_ctx: &Ctx, number: Number) -> Box<dyn CustomExpression> {
+pub fn expression_number(ctx: &Ctx, number: Number) -> Box<dyn CustomExpression> {
+    let (start, length) = production_span(ctx);
+
     Box::new(LiteralExpressionSyntax::new(CustomToken::new(
         number.to_string(),
-        LiteralValue::Integer(number),
-        0,
+        number.clone(),
+        start,
         SyntaxKind::Number,
-        number.to_string().len(),
+        length,
     ))) as Box<dyn CustomExpression>
 }
-pub fn expression_true(_ctx: &Ctx) -> Box<dyn CustomExpression> {
+pub fn expression_string_literal(
+    ctx: &Ctx,
+    string_literal: StringLiteral,
+) -> Box<dyn CustomExpression> {
+    let (start, length) = production_span(ctx);
+
+    Box::new(LiteralExpressionSyntax::new(CustomToken::new(
+        string_literal.to_string(),
+        LiteralValue::String(string_literal.to_string()),
+        start,
+        SyntaxKind::StringLiteral,
+        length,
+    ))) as Box<dyn CustomExpression>
+}
+pub fn expression_true(_ctx: &Ctx, true_keyword: TrueKeyword) -> Box<dyn CustomExpression> {
     Box::new(LiteralExpressionSyntax::new(CustomToken::new(
         "true".to_string(),
         LiteralValue::Boolean(true),
-        0,
+        true_keyword.position(),
         SyntaxKind::True,
         4,
     ))) as Box<dyn CustomExpression>
 }
-pub fn expression_false(_ctx: &Ctx) -> Box<dyn CustomExpression> {
+pub fn expression_false(_ctx: &Ctx, false_keyword: FalseKeyword) -> Box<dyn CustomExpression> {
     Box::new(LiteralExpressionSyntax::new(CustomToken::new(
         "false".to_string(),
         LiteralValue::Boolean(false),
-        0,
+        false_keyword.position(),
         SyntaxKind::False,
         5,
     ))) as Box<dyn CustomExpression>
 }
+pub fn expression_call_expression(
+    _ctx: &Ctx,
+    call_expression: CallExpression,
+) -> Box<dyn CustomExpression> {
+    call_expression
+}
 pub fn expression_identifier(_ctx: &Ctx, identifier: Identifier) -> Box<dyn CustomExpression> {
-    let custom_token = CustomToken::new(
-        identifier.to_string(),
-        LiteralValue::String((identifier.to_string())),
-        0,
-        SyntaxKind::IdentifierToken,
-        identifier.to_string().len(),
-    );
-    Box::new(NameExpressionSyntax::new(custom_token)) as Box<dyn CustomExpression>
+    Box::new(NameExpressionSyntax::new(identifier)) as Box<dyn CustomExpression>
+}
+// The items of the list and the commas between them.
+pub type FieldInitializer1 = (Vec<FieldInitializer>, Vec<CustomToken>);
+pub fn field_initializer1_c1(
+    _ctx: &Ctx,
+    mut field_initializer1: FieldInitializer1,
+    comma: Comma,
+    field_initializer: FieldInitializer,
+) -> FieldInitializer1 {
+    field_initializer1.0.push(field_initializer);
+    field_initializer1.1.push(comma);
+    field_initializer1
+}
+pub fn field_initializer1_field_initializer(
+    _ctx: &Ctx,
+    field_initializer: FieldInitializer,
+) -> FieldInitializer1 {
+    (vec![field_initializer], Vec::new())
+}
+pub type FieldInitializer0 = Option<FieldInitializer1>;
+pub fn field_initializer0_field_initializer1(
+    _ctx: &Ctx,
+    field_initializer1: FieldInitializer1,
+) -> FieldInitializer0 {
+    Some(field_initializer1)
+}
+pub fn field_initializer0_empty(_ctx: &Ctx) -> FieldInitializer0 {
+    None
+}
+pub type FieldInitializer = Box<CustomFieldInitializer>;
+pub fn field_initializer_field_initializer(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    colon: Colon,
+    expression: Box<dyn CustomExpression>,
+) -> FieldInitializer {
+    Box::new(CustomFieldInitializer::new(identifier, colon, expression))
 }
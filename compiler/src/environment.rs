@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use crate::util::{literals::LiteralValue, variable_symbol::VariableSymbol};
+
+#[derive(Debug, Default, Clone)]
+pub struct Environment {
+    variables: HashMap<VariableSymbol, Option<LiteralValue>>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self {
+            variables: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, variable: &VariableSymbol, value: Option<LiteralValue>) {
+        self.variables.insert(variable.clone(), value);
+    }
+
+    pub fn lookup(&self, variable: &VariableSymbol) -> Option<LiteralValue> {
+        self.variables.get(variable).cloned().flatten()
+    }
+
+    pub fn get_variables(&self) -> &HashMap<VariableSymbol, Option<LiteralValue>> {
+        &self.variables
+    }
+}
@@ -0,0 +1,9 @@
+use crate::util::literals::LiteralValue;
+
+#[derive(Debug)]
+pub enum ControlFlow {
+    Next,
+    Break,
+    Continue,
+    Return(LiteralValue),
+}
@@ -1,164 +1,455 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    time::Instant,
+};
+
 use crate::{
     binding::{
+        bound_array_literal_expression::BoundArrayLiteralExpression,
         bound_assignment::BoundAssignment, bound_binary_expression::BoundBinaryExpression,
-        bound_binary_operator_kind::BoundBinaryOperatorKind,
+        bound_call_expression::BoundCallExpression,
+        bound_conditional_expression::BoundConditionalExpression,
         bound_constant_declaration::BoundConstantDeclaration, bound_expression::BoundExpression,
-        bound_for_statement::BoundForStatement, bound_if_statement::BoundIfStatement,
-        bound_kind::BoundKind, bound_literal_expression::BoundLiteralExpression,
-        bound_statement::BoundStatement, bound_statement_list::BoundStatementList,
+        bound_expression_statement::BoundExpressionStatement,
+        bound_field_assignment::BoundFieldAssignment, bound_field_expression::BoundFieldExpression,
+        bound_for_statement::BoundForStatement, bound_function::BoundFunction,
+        bound_if_statement::BoundIfStatement, bound_index_assignment::BoundIndexAssignment,
+        bound_index_expression::BoundIndexExpression, bound_kind::BoundKind,
+        bound_length_expression::BoundLengthExpression,
+        bound_literal_expression::BoundLiteralExpression,
+        bound_return_statement::BoundReturnStatement, bound_statement::BoundStatement,
+        bound_statement_list::BoundStatementList,
+        bound_struct_literal_expression::BoundStructLiteralExpression,
         bound_unary_expression::BoundUnaryExpression,
-        bound_unary_operator_kind::BoundUnaryOperatorKind,
         bound_variable_declaration::BoundVariableDeclaration,
+        bound_variable_expression::BoundVariableExpression,
         bound_while_statement::BoundWhileStatement,
     },
-    global_state::{insert_into_symbol_table, SYMBOL_TABLE},
-    util::{
-        literals::{LiteralType, LiteralValue},
-        syntax_kind::SyntaxKind,
-    },
+    environment::Environment,
+    util::{literals::LiteralValue, variable_symbol::VariableSymbol},
+};
+
+use super::{
+    control_flow::ControlFlow,
+    execution_limits::ExecutionLimits,
+    operations::{check_index, evaluate_binary_operation, evaluate_unary_operation},
+    runtime_error::RuntimeError,
 };
 
 pub struct Evaluator {
     statements: Box<dyn BoundStatement>,
+    functions: Vec<BoundFunction>,
+    frames: RefCell<Vec<HashMap<VariableSymbol, LiteralValue>>>,
+    limits: ExecutionLimits,
+    environment: Rc<RefCell<Environment>>,
+    steps: Cell<u64>,
+    start: Cell<Instant>,
+    depth: Cell<u64>,
 }
 
 impl Evaluator {
-    pub fn new(statements: Box<dyn BoundStatement>) -> Self {
-        Self { statements }
+    pub fn new(
+        statements: Box<dyn BoundStatement>,
+        functions: Vec<BoundFunction>,
+        limits: ExecutionLimits,
+        environment: Rc<RefCell<Environment>>,
+    ) -> Self {
+        Self {
+            statements,
+            functions,
+            frames: RefCell::new(Vec::new()),
+            limits,
+            environment,
+            steps: Cell::new(0),
+            start: Cell::new(Instant::now()),
+            depth: Cell::new(0),
+        }
+    }
+
+    pub fn evaluate(&self) -> Result<(), RuntimeError> {
+        self.steps.set(0);
+        self.start.set(Instant::now());
+        self.evaluate_statements(self.statements.clone())?;
+        Ok(())
+    }
+
+    // Evaluates a single expression against the globals in the environment.
+    pub fn evaluate_value(
+        &self,
+        expression: Box<dyn BoundExpression>,
+    ) -> Result<LiteralValue, RuntimeError> {
+        self.steps.set(0);
+        self.start.set(Instant::now());
+        self.evaluate_expression(expression)
     }
 
-    pub fn evaluate(&self) {
-        self.evaluate_statements(self.statements.clone());
+    fn step(&self) -> Result<(), RuntimeError> {
+        self.steps.set(self.steps.get() + 1);
+
+        if self.steps.get() > self.limits.max_steps {
+            return Err(RuntimeError::StepLimitExceeded(self.limits.max_steps));
+        }
+        if self.start.get().elapsed() > self.limits.timeout() {
+            return Err(RuntimeError::TimeLimitExceeded(self.limits.timeout_ms));
+        }
+        Ok(())
     }
 
-    fn evaluate_statements(&self, statement: Box<dyn BoundStatement>) {
+    fn evaluate_statements(
+        &self,
+        statement: Box<dyn BoundStatement>,
+    ) -> Result<ControlFlow, RuntimeError> {
+        self.step()?;
+
         match *statement.get_type_of_bound() {
-            BoundKind::BoundStatementList => {
-                return self.evaluate_statement_list(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundStatementList>()
-                        .unwrap(),
-                )
-            }
-            BoundKind::BoundAssignment => {
-                return self.evaluate_assignment(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundAssignment>()
-                        .unwrap(),
-                )
-            }
-            BoundKind::BoundVariableDeclaration => {
-                return self.evaluate_variable_declaration(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundVariableDeclaration>()
-                        .unwrap(),
-                )
-            }
-            BoundKind::BoundConstantDeclaration => {
-                return self.evaluate_constant_declaration(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundConstantDeclaration>()
-                        .unwrap(),
-                )
-            }
-            BoundKind::BoundIfStatement => {
-                return self.evaluate_if_statement(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundIfStatement>()
-                        .unwrap(),
-                )
-            }
-            BoundKind::BoundWhileStatement => {
-                return self.evaluate_while_statement(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundWhileStatement>()
-                        .unwrap(),
-                )
-            }
-            BoundKind::BoundForStatement => {
-                return self.evaluate_for_statement(
-                    statement
-                        .as_any()
-                        .downcast_ref::<BoundForStatement>()
-                        .unwrap(),
-                )
-            }
+            BoundKind::BoundStatementList => self.evaluate_statement_list(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundStatementList>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundAssignment => self.evaluate_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundIndexAssignment => self.evaluate_index_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundIndexAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundFieldAssignment => self.evaluate_field_assignment(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundFieldAssignment>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundVariableDeclaration => self.evaluate_variable_declaration(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundVariableDeclaration>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundConstantDeclaration => self.evaluate_constant_declaration(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundConstantDeclaration>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundIfStatement => self.evaluate_if_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundIfStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundWhileStatement => self.evaluate_while_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundWhileStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundForStatement => self.evaluate_for_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundForStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundReturnStatement => self.evaluate_return_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundReturnStatement>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundBreakStatement => Ok(ControlFlow::Break),
+            BoundKind::BoundContinueStatement => Ok(ControlFlow::Continue),
+            BoundKind::BoundExpressionStatement => self.evaluate_expression_statement(
+                statement
+                    .as_any()
+                    .downcast_ref::<BoundExpressionStatement>()
+                    .unwrap(),
+            ),
             _ => panic!("Evaluating ERROR: Unexpected bound kind for statement."),
         }
     }
 
-    fn evaluate_statement_list(&self, statement_list: &BoundStatementList) {
+    fn evaluate_statement_list(
+        &self,
+        statement_list: &BoundStatementList,
+    ) -> Result<ControlFlow, RuntimeError> {
+        self.frames.borrow_mut().push(HashMap::new());
+        let result = self.evaluate_statement_list_in_frame(statement_list);
+        self.frames.borrow_mut().pop();
+        result
+    }
+
+    fn evaluate_statement_list_in_frame(
+        &self,
+        statement_list: &BoundStatementList,
+    ) -> Result<ControlFlow, RuntimeError> {
         for statement in statement_list.get_statements() {
-            self.evaluate_statements(statement);
+            match self.evaluate_statements(statement)? {
+                ControlFlow::Next => {}
+                control_flow => return Ok(control_flow),
+            }
         }
+        Ok(ControlFlow::Next)
     }
 
-    fn evaluate_assignment(&self, assignment: &BoundAssignment) {
-        let value = self.evaluate_expression(assignment.get_bound_expression());
-        insert_into_symbol_table(assignment.get_variable(), Some(value));
+    fn evaluate_assignment(
+        &self,
+        assignment: &BoundAssignment,
+    ) -> Result<ControlFlow, RuntimeError> {
+        let value = self.evaluate_expression(assignment.get_bound_expression())?;
+        self.assign_variable(assignment.get_variable(), value);
+        Ok(ControlFlow::Next)
+    }
+
+    fn evaluate_index_assignment(
+        &self,
+        index_assignment: &BoundIndexAssignment,
+    ) -> Result<ControlFlow, RuntimeError> {
+        let variable = index_assignment.get_variable();
+        let index = self.evaluate_expression(index_assignment.get_index())?;
+        let value = self.evaluate_expression(index_assignment.get_bound_expression())?;
+
+        let mut elements = match self.lookup_variable(variable) {
+            Some(array) => array.as_array().unwrap(),
+            None => {
+                return Err(RuntimeError::UninitializedVariable(
+                    variable.id(),
+                    index_assignment.get_span(),
+                ))
+            }
+        };
+        let index = check_index(&index, elements.len(), &index_assignment.get_span())?;
+        elements[index] = value;
+        self.assign_variable(variable, LiteralValue::Array(elements));
+        Ok(ControlFlow::Next)
+    }
+
+    fn evaluate_field_assignment(
+        &self,
+        field_assignment: &BoundFieldAssignment,
+    ) -> Result<ControlFlow, RuntimeError> {
+        let variable = field_assignment.get_variable();
+        let value = self.evaluate_expression(field_assignment.get_bound_expression())?;
+
+        let (name, mut fields) = match self.lookup_variable(variable) {
+            Some(LiteralValue::Struct(name, fields)) => (name, fields),
+            _ => {
+                return Err(RuntimeError::UninitializedVariable(
+                    variable.id(),
+                    field_assignment.get_span(),
+                ))
+            }
+        };
+        fields[field_assignment.get_index()].1 = value;
+        self.assign_variable(variable, LiteralValue::Struct(name, fields));
+        Ok(ControlFlow::Next)
     }
 
-    fn evaluate_variable_declaration(&self, variable_declaration: &BoundVariableDeclaration) {
-        let value = self.evaluate_expression(variable_declaration.get_bound_expression());
-        insert_into_symbol_table(variable_declaration.get_variable(), Some(value));
+    fn evaluate_variable_declaration(
+        &self,
+        variable_declaration: &BoundVariableDeclaration,
+    ) -> Result<ControlFlow, RuntimeError> {
+        let value = self.evaluate_expression(variable_declaration.get_bound_expression())?;
+        self.declare_variable(variable_declaration.get_variable(), value);
+        Ok(ControlFlow::Next)
     }
 
-    fn evaluate_constant_declaration(&self, constant_declaration: &BoundConstantDeclaration) {
-        let value = self.evaluate_expression(constant_declaration.get_bound_expression());
-        insert_into_symbol_table(constant_declaration.get_variable(), Some(value));
+    fn evaluate_constant_declaration(
+        &self,
+        constant_declaration: &BoundConstantDeclaration,
+    ) -> Result<ControlFlow, RuntimeError> {
+        let value = self.evaluate_expression(constant_declaration.get_bound_expression())?;
+        self.declare_variable(constant_declaration.get_variable(), value);
+        Ok(ControlFlow::Next)
     }
 
-    fn evaluate_if_statement(&self, if_statement: &BoundIfStatement) {
+    fn evaluate_if_statement(
+        &self,
+        if_statement: &BoundIfStatement,
+    ) -> Result<ControlFlow, RuntimeError> {
         let condition = self
-            .evaluate_expression(if_statement.get_condition())
+            .evaluate_expression(if_statement.get_condition())?
             .as_boolean()
             .unwrap();
         if condition {
-            self.evaluate_statements(if_statement.get_then_statement());
-        } else if if_statement.get_else_statement().is_some() {
-            self.evaluate_statements(if_statement.get_else_statement().unwrap());
+            self.evaluate_statements(if_statement.get_then_statement())
+        } else if let Some(else_statement) = if_statement.get_else_statement() {
+            self.evaluate_statements(else_statement)
+        } else {
+            Ok(ControlFlow::Next)
         }
     }
 
-    fn evaluate_while_statement(&self, while_statement: &BoundWhileStatement) {
+    fn evaluate_while_statement(
+        &self,
+        while_statement: &BoundWhileStatement,
+    ) -> Result<ControlFlow, RuntimeError> {
         let mut condition = self
-            .evaluate_expression(while_statement.get_condition())
+            .evaluate_expression(while_statement.get_condition())?
             .as_boolean()
             .unwrap();
 
         while condition {
-            self.evaluate_statements(while_statement.get_body());
+            match self.evaluate_statements(while_statement.get_body())? {
+                ControlFlow::Break => break,
+                ControlFlow::Return(value) => return Ok(ControlFlow::Return(value)),
+                ControlFlow::Next | ControlFlow::Continue => {}
+            }
             condition = self
-                .evaluate_expression(while_statement.get_condition())
+                .evaluate_expression(while_statement.get_condition())?
                 .as_boolean()
                 .unwrap();
         }
+        Ok(ControlFlow::Next)
     }
 
-    fn evaluate_for_statement(&self, for_statement: &BoundForStatement) {
+    fn evaluate_for_statement(
+        &self,
+        for_statement: &BoundForStatement,
+    ) -> Result<ControlFlow, RuntimeError> {
         let lower_bound = self
-            .evaluate_expression(for_statement.get_lower_bound())
+            .evaluate_expression(for_statement.get_lower_bound())?
             .as_integer()
             .unwrap();
         let upper_bound = self
-            .evaluate_expression(for_statement.get_upper_bound())
+            .evaluate_expression(for_statement.get_upper_bound())?
             .as_integer()
             .unwrap();
 
+        self.frames.borrow_mut().push(HashMap::new());
+        let result = self.evaluate_for_loop(for_statement, lower_bound, upper_bound);
+        self.frames.borrow_mut().pop();
+        result
+    }
+
+    fn evaluate_for_loop(
+        &self,
+        for_statement: &BoundForStatement,
+        lower_bound: i32,
+        upper_bound: i32,
+    ) -> Result<ControlFlow, RuntimeError> {
         let variable = for_statement.get_variable();
         for i in lower_bound..upper_bound {
-            insert_into_symbol_table(variable, Some(LiteralValue::Integer(i)));
-            self.evaluate_statements(for_statement.get_body());
+            self.declare_variable(variable, LiteralValue::Integer(i));
+            match self.evaluate_statements(for_statement.get_body())? {
+                ControlFlow::Break => break,
+                ControlFlow::Return(value) => return Ok(ControlFlow::Return(value)),
+                ControlFlow::Next | ControlFlow::Continue => {}
+            }
+        }
+        Ok(ControlFlow::Next)
+    }
+
+    fn evaluate_return_statement(
+        &self,
+        return_statement: &BoundReturnStatement,
+    ) -> Result<ControlFlow, RuntimeError> {
+        Ok(ControlFlow::Return(self.evaluate_expression(
+            return_statement.get_bound_expression(),
+        )?))
+    }
+
+    fn evaluate_expression_statement(
+        &self,
+        expression_statement: &BoundExpressionStatement,
+    ) -> Result<ControlFlow, RuntimeError> {
+        let expression = expression_statement.get_bound_expression();
+
+        match expression.as_any().downcast_ref::<BoundCallExpression>() {
+            Some(call_expression) => {
+                self.evaluate_call_expression(call_expression)?;
+            }
+            None => {
+                self.evaluate_expression(expression)?;
+            }
+        }
+        Ok(ControlFlow::Next)
+    }
+
+    fn declare_variable(&self, variable: &VariableSymbol, value: LiteralValue) {
+        if variable.is_global() {
+            self.environment.borrow_mut().insert(variable, Some(value));
+            return;
+        }
+        match self.frames.borrow_mut().last_mut() {
+            Some(frame) => {
+                frame.insert(variable.clone(), value);
+            }
+            None => self.environment.borrow_mut().insert(variable, Some(value)),
+        }
+    }
+
+    // Symbols are unique per declaration, so the innermost frame holding one is its owner.
+    fn assign_variable(&self, variable: &VariableSymbol, value: LiteralValue) {
+        for frame in self.frames.borrow_mut().iter_mut().rev() {
+            if let Some(slot) = frame.get_mut(variable) {
+                *slot = value;
+                return;
+            }
+        }
+        self.environment.borrow_mut().insert(variable, Some(value));
+    }
+
+    fn lookup_variable(&self, variable: &VariableSymbol) -> Option<LiteralValue> {
+        for frame in self.frames.borrow().iter().rev() {
+            if let Some(value) = frame.get(variable) {
+                return Some(value.clone());
+            }
         }
+        self.environment.borrow().lookup(variable)
     }
 
-    fn evaluate_expression(&self, expression: Box<dyn BoundExpression>) -> LiteralValue {
+    fn evaluate_call_expression(
+        &self,
+        call_expression: &BoundCallExpression,
+    ) -> Result<Option<LiteralValue>, RuntimeError> {
+        let function = &self.functions[call_expression.get_function().index()];
+
+        // Every call recurses on the native stack, so deep recursion is stopped before it
+        // can overflow it.
+        if self.depth.get() >= self.limits.max_call_depth {
+            return Err(RuntimeError::CallDepthExceeded(
+                self.limits.max_call_depth,
+                call_expression.get_span(),
+            ));
+        }
+
+        let arguments = call_expression
+            .get_arguments()
+            .into_iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Result<Vec<LiteralValue>, RuntimeError>>()?;
+
+        let frame: HashMap<VariableSymbol, LiteralValue> = function
+            .get_parameters()
+            .iter()
+            .cloned()
+            .zip(arguments)
+            .collect();
+
+        self.frames.borrow_mut().push(frame);
+        self.depth.set(self.depth.get() + 1);
+        let result = self.evaluate_statements(function.get_body().unwrap());
+        self.depth.set(self.depth.get() - 1);
+        self.frames.borrow_mut().pop();
+
+        match result? {
+            ControlFlow::Return(value) => Ok(Some(value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn evaluate_expression(
+        &self,
+        expression: Box<dyn BoundExpression>,
+    ) -> Result<LiteralValue, RuntimeError> {
         match *expression.get_type_of_bound() {
             BoundKind::BoundLiteralExpression => {
                 return self.evaluate_literal_expression(
@@ -184,6 +475,72 @@ impl Evaluator {
                         .unwrap(),
                 )
             }
+            BoundKind::BoundCallExpression => {
+                let call_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundCallExpression>()
+                    .unwrap();
+                return self
+                    .evaluate_call_expression(call_expression)?
+                    .ok_or_else(|| {
+                        RuntimeError::MissingReturnValue(
+                            call_expression.get_function().id(),
+                            call_expression.get_span(),
+                        )
+                    });
+            }
+            BoundKind::BoundConditionalExpression => self.evaluate_conditional_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundConditionalExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundVariableExpression => {
+                return self.evaluate_variable_expression(
+                    expression
+                        .as_any()
+                        .downcast_ref::<BoundVariableExpression>()
+                        .unwrap(),
+                )
+            }
+            BoundKind::BoundArrayLiteralExpression => self.evaluate_array_literal_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundArrayLiteralExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundIndexExpression => self.evaluate_index_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundIndexExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundLengthExpression => {
+                let array = expression
+                    .as_any()
+                    .downcast_ref::<BoundLengthExpression>()
+                    .unwrap()
+                    .get_array();
+                let length = self.evaluate_expression(array)?.as_array().unwrap().len();
+                Ok(LiteralValue::Integer(length as i32))
+            }
+            BoundKind::BoundStructLiteralExpression => self.evaluate_struct_literal_expression(
+                expression
+                    .as_any()
+                    .downcast_ref::<BoundStructLiteralExpression>()
+                    .unwrap(),
+            ),
+            BoundKind::BoundFieldExpression => {
+                let field_expression = expression
+                    .as_any()
+                    .downcast_ref::<BoundFieldExpression>()
+                    .unwrap();
+                let mut fields = self
+                    .evaluate_expression(field_expression.get_structure())?
+                    .as_struct()
+                    .unwrap();
+                Ok(fields.swap_remove(field_expression.get_index()).1)
+            }
             _ => panic!("Evaluating ERROR: Unexpected bound kind for expression."),
         }
     }
@@ -191,120 +548,107 @@ impl Evaluator {
     fn evaluate_literal_expression(
         &self,
         literal_expression: &BoundLiteralExpression,
-    ) -> LiteralValue {
-        if *literal_expression.get_kind() == SyntaxKind::Variable {
-            let value = {
-                SYMBOL_TABLE
-                    .lock()
-                    .unwrap()
-                    .iter()
-                    .find(|(symbol, _)| {
-                        symbol.id() == literal_expression.get_value().as_string().unwrap()
-                    })
-                    .map(|(_, res)| res.clone())
-                    .flatten()
-            };
-            return value.unwrap();
+    ) -> Result<LiteralValue, RuntimeError> {
+        return Ok(literal_expression.get_value());
+    }
+
+    fn evaluate_conditional_expression(
+        &self,
+        conditional_expression: &BoundConditionalExpression,
+    ) -> Result<LiteralValue, RuntimeError> {
+        let condition = self
+            .evaluate_expression(conditional_expression.get_condition())?
+            .as_boolean()
+            .unwrap();
+        if condition {
+            self.evaluate_expression(conditional_expression.get_when_true())
+        } else {
+            self.evaluate_expression(conditional_expression.get_when_false())
         }
-        return literal_expression.get_value();
     }
 
-    fn evaluate_unary_expression(&self, unary_expression: &BoundUnaryExpression) -> LiteralValue {
-        let operand = self.evaluate_expression(unary_expression.get_operand());
+    fn evaluate_array_literal_expression(
+        &self,
+        array_literal_expression: &BoundArrayLiteralExpression,
+    ) -> Result<LiteralValue, RuntimeError> {
+        let elements = array_literal_expression
+            .get_elements()
+            .into_iter()
+            .map(|element| self.evaluate_expression(element))
+            .collect::<Result<Vec<LiteralValue>, RuntimeError>>()?;
+        Ok(LiteralValue::Array(elements))
+    }
 
-        match unary_expression.get_operator().get_kind() {
-            BoundUnaryOperatorKind::Identity => {
-                return LiteralValue::Integer(operand.as_integer().unwrap());
-            }
-            BoundUnaryOperatorKind::Negation => {
-                return LiteralValue::Integer(-operand.as_integer().unwrap());
-            }
-            BoundUnaryOperatorKind::LogicalNegation => {
-                return LiteralValue::Boolean(operand.as_boolean().unwrap());
-            }
+    fn evaluate_struct_literal_expression(
+        &self,
+        struct_literal_expression: &BoundStructLiteralExpression,
+    ) -> Result<LiteralValue, RuntimeError> {
+        let fields = struct_literal_expression
+            .get_field_names()
+            .into_iter()
+            .zip(struct_literal_expression.get_fields())
+            .map(|(name, field)| Ok((name, self.evaluate_expression(field)?)))
+            .collect::<Result<Vec<(String, LiteralValue)>, RuntimeError>>()?;
+        Ok(LiteralValue::Struct(
+            struct_literal_expression.get_name(),
+            fields,
+        ))
+    }
+
+    fn evaluate_index_expression(
+        &self,
+        index_expression: &BoundIndexExpression,
+    ) -> Result<LiteralValue, RuntimeError> {
+        let mut elements = self
+            .evaluate_expression(index_expression.get_array())?
+            .as_array()
+            .unwrap();
+        let index = self.evaluate_expression(index_expression.get_index())?;
+
+        let index = check_index(&index, elements.len(), &index_expression.get_span())?;
+        Ok(elements.swap_remove(index))
+    }
+
+    fn evaluate_variable_expression(
+        &self,
+        variable_expression: &BoundVariableExpression,
+    ) -> Result<LiteralValue, RuntimeError> {
+        let variable = variable_expression.get_variable();
+
+        match self.lookup_variable(variable) {
+            Some(value) => Ok(value),
+            None => Err(RuntimeError::UninitializedVariable(
+                variable.id(),
+                variable_expression.get_span(),
+            )),
         }
     }
 
+    fn evaluate_unary_expression(
+        &self,
+        unary_expression: &BoundUnaryExpression,
+    ) -> Result<LiteralValue, RuntimeError> {
+        let operand = self.evaluate_expression(unary_expression.get_operand())?;
+
+        evaluate_unary_operation(
+            unary_expression.get_operator().get_kind(),
+            operand,
+            &unary_expression.get_span(),
+        )
+    }
+
     fn evaluate_binary_expression(
         &self,
         binary_expression: &BoundBinaryExpression,
-    ) -> LiteralValue {
-        let left = self.evaluate_expression(binary_expression.get_left());
-        let right = self.evaluate_expression(binary_expression.get_right());
-
-        match binary_expression.get_operator().get_kind() {
-            BoundBinaryOperatorKind::Addition => {
-                return LiteralValue::Integer(
-                    left.as_integer().unwrap() + right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::Subtraction => {
-                return LiteralValue::Integer(
-                    left.as_integer().unwrap() - right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::Multiplication => {
-                return LiteralValue::Integer(
-                    left.as_integer().unwrap() * right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::Division => {
-                return LiteralValue::Integer(
-                    left.as_integer().unwrap() / right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::LogicalAnd => {
-                return LiteralValue::Boolean(
-                    left.as_boolean().unwrap() && right.as_boolean().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::LogicalOr => {
-                return LiteralValue::Boolean(
-                    left.as_boolean().unwrap() || right.as_boolean().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::Equals => {
-                if *left.get_type() == LiteralType::Boolean {
-                    return LiteralValue::Boolean(
-                        left.as_boolean().unwrap() == right.as_boolean().unwrap(),
-                    );
-                } else {
-                    return LiteralValue::Boolean(
-                        left.as_integer().unwrap() == right.as_integer().unwrap(),
-                    );
-                }
-            }
-            BoundBinaryOperatorKind::NotEquals => {
-                if *left.get_type() == LiteralType::Boolean {
-                    return LiteralValue::Boolean(
-                        left.as_boolean().unwrap() != right.as_boolean().unwrap(),
-                    );
-                } else {
-                    return LiteralValue::Boolean(
-                        left.as_integer().unwrap() != right.as_integer().unwrap(),
-                    );
-                }
-            }
-            BoundBinaryOperatorKind::Less => {
-                return LiteralValue::Boolean(
-                    left.as_integer().unwrap() < right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::LessOrEquals => {
-                return LiteralValue::Boolean(
-                    left.as_integer().unwrap() <= right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::Greater => {
-                return LiteralValue::Boolean(
-                    left.as_integer().unwrap() > right.as_integer().unwrap(),
-                );
-            }
-            BoundBinaryOperatorKind::GreaterOrEquals => {
-                return LiteralValue::Boolean(
-                    left.as_integer().unwrap() >= right.as_integer().unwrap(),
-                );
-            }
-        }
+    ) -> Result<LiteralValue, RuntimeError> {
+        let left = self.evaluate_expression(binary_expression.get_left())?;
+        let right = self.evaluate_expression(binary_expression.get_right())?;
+
+        evaluate_binary_operation(
+            binary_expression.get_operator().get_kind(),
+            left,
+            right,
+            &binary_expression.get_span(),
+        )
     }
 }
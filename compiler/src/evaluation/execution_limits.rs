@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use rocket::serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(crate = "rocket::serde", default)]
+pub struct ExecutionLimits {
+    pub max_steps: u64,
+    pub timeout_ms: u64,
+    pub max_call_depth: u64,
+    // Only native programs are limited, the address space of the process in MiB.
+    pub max_memory_mb: u64,
+}
+
+impl ExecutionLimits {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+impl Default for ExecutionLimits {
+    fn default() -> Self {
+        Self {
+            max_steps: 1_000_000,
+            timeout_ms: 2_000,
+            max_call_depth: 1_000,
+            max_memory_mb: 256,
+        }
+    }
+}
@@ -1 +1,5 @@
+pub mod control_flow;
 pub mod evaluator;
+pub mod execution_limits;
+pub mod operations;
+pub mod runtime_error;
@@ -0,0 +1,200 @@
+use crate::{
+    binding::{
+        bound_binary_operator_kind::BoundBinaryOperatorKind,
+        bound_unary_operator_kind::BoundUnaryOperatorKind,
+    },
+    reports::text_span::TextSpan,
+    util::literals::{LiteralType, LiteralValue},
+};
+
+use super::runtime_error::RuntimeError;
+
+pub fn evaluate_unary_operation(
+    kind: &BoundUnaryOperatorKind,
+    operand: LiteralValue,
+    span: &TextSpan,
+) -> Result<LiteralValue, RuntimeError> {
+    match kind {
+        BoundUnaryOperatorKind::Identity => Ok(operand),
+        BoundUnaryOperatorKind::Negation => match operand {
+            LiteralValue::Float(value) => Ok(LiteralValue::Float(-value)),
+            _ => operand
+                .as_integer()
+                .unwrap()
+                .checked_neg()
+                .map(LiteralValue::Integer)
+                .ok_or_else(|| RuntimeError::IntegerOverflow(span.clone())),
+        },
+        BoundUnaryOperatorKind::LogicalNegation => {
+            Ok(LiteralValue::Boolean(!operand.as_boolean().unwrap()))
+        }
+        BoundUnaryOperatorKind::BitwiseNot => {
+            Ok(LiteralValue::Integer(!operand.as_integer().unwrap()))
+        }
+    }
+}
+
+fn promote(left: LiteralValue, right: LiteralValue) -> (LiteralValue, LiteralValue) {
+    match (&left, &right) {
+        (LiteralValue::Integer(_), LiteralValue::Float(_))
+        | (LiteralValue::Float(_), LiteralValue::Integer(_)) => (
+            LiteralValue::Float(left.as_float().unwrap()),
+            LiteralValue::Float(right.as_float().unwrap()),
+        ),
+        _ => (left, right),
+    }
+}
+
+fn check_overflow(result: Option<i32>, span: &TextSpan) -> Result<LiteralValue, RuntimeError> {
+    result
+        .map(LiteralValue::Integer)
+        .ok_or_else(|| RuntimeError::IntegerOverflow(span.clone()))
+}
+
+fn check_shift(shift: &LiteralValue, span: &TextSpan) -> Result<u32, RuntimeError> {
+    u32::try_from(shift.as_integer().unwrap())
+        .ok()
+        .filter(|shift| *shift < i32::BITS)
+        .ok_or_else(|| RuntimeError::InvalidShift(span.clone()))
+}
+
+// There is no indexing from the end, so a negative index is out of bounds as well.
+pub fn check_index(
+    index: &LiteralValue,
+    length: usize,
+    span: &TextSpan,
+) -> Result<usize, RuntimeError> {
+    let index = index.as_integer().unwrap();
+    usize::try_from(index)
+        .ok()
+        .filter(|index| *index < length)
+        .ok_or_else(|| RuntimeError::IndexOutOfBounds(index, length, span.clone()))
+}
+
+pub fn evaluate_binary_operation(
+    kind: &BoundBinaryOperatorKind,
+    left: LiteralValue,
+    right: LiteralValue,
+    span: &TextSpan,
+) -> Result<LiteralValue, RuntimeError> {
+    let (left, right) = promote(left, right);
+    let is_float = left.get_type() == LiteralType::Float;
+
+    match kind {
+        BoundBinaryOperatorKind::Addition => {
+            if is_float {
+                return Ok(LiteralValue::Float(
+                    left.as_float().unwrap() + right.as_float().unwrap(),
+                ));
+            }
+            check_overflow(
+                left.as_integer()
+                    .unwrap()
+                    .checked_add(right.as_integer().unwrap()),
+                span,
+            )
+        }
+        BoundBinaryOperatorKind::Subtraction => {
+            if is_float {
+                return Ok(LiteralValue::Float(
+                    left.as_float().unwrap() - right.as_float().unwrap(),
+                ));
+            }
+            check_overflow(
+                left.as_integer()
+                    .unwrap()
+                    .checked_sub(right.as_integer().unwrap()),
+                span,
+            )
+        }
+        BoundBinaryOperatorKind::Multiplication => {
+            if is_float {
+                return Ok(LiteralValue::Float(
+                    left.as_float().unwrap() * right.as_float().unwrap(),
+                ));
+            }
+            check_overflow(
+                left.as_integer()
+                    .unwrap()
+                    .checked_mul(right.as_integer().unwrap()),
+                span,
+            )
+        }
+        BoundBinaryOperatorKind::Division => {
+            if is_float {
+                return Ok(LiteralValue::Float(
+                    left.as_float().unwrap() / right.as_float().unwrap(),
+                ));
+            }
+            if right.as_integer().unwrap() == 0 {
+                return Err(RuntimeError::DivisionByZero(span.clone()));
+            }
+            check_overflow(
+                left.as_integer()
+                    .unwrap()
+                    .checked_div(right.as_integer().unwrap()),
+                span,
+            )
+        }
+        BoundBinaryOperatorKind::Modulo => {
+            if is_float {
+                return Ok(LiteralValue::Float(
+                    left.as_float().unwrap() % right.as_float().unwrap(),
+                ));
+            }
+            if right.as_integer().unwrap() == 0 {
+                return Err(RuntimeError::DivisionByZero(span.clone()));
+            }
+            check_overflow(
+                left.as_integer()
+                    .unwrap()
+                    .checked_rem(right.as_integer().unwrap()),
+                span,
+            )
+        }
+        BoundBinaryOperatorKind::Power => {
+            if is_float {
+                return Ok(LiteralValue::Float(
+                    left.as_float().unwrap().powf(right.as_float().unwrap()),
+                ));
+            }
+            let Ok(exponent) = u32::try_from(right.as_integer().unwrap()) else {
+                return Err(RuntimeError::NegativeExponent(span.clone()));
+            };
+            check_overflow(left.as_integer().unwrap().checked_pow(exponent), span)
+        }
+        BoundBinaryOperatorKind::BitwiseAnd => Ok(LiteralValue::Integer(
+            left.as_integer().unwrap() & right.as_integer().unwrap(),
+        )),
+        BoundBinaryOperatorKind::BitwiseOr => Ok(LiteralValue::Integer(
+            left.as_integer().unwrap() | right.as_integer().unwrap(),
+        )),
+        BoundBinaryOperatorKind::BitwiseXor => Ok(LiteralValue::Integer(
+            left.as_integer().unwrap() ^ right.as_integer().unwrap(),
+        )),
+        // Bits shifted out are dropped rather than reported as an overflow.
+        BoundBinaryOperatorKind::ShiftLeft => {
+            let shift = check_shift(&right, span)?;
+            Ok(LiteralValue::Integer(left.as_integer().unwrap() << shift))
+        }
+        BoundBinaryOperatorKind::ShiftRight => {
+            let shift = check_shift(&right, span)?;
+            Ok(LiteralValue::Integer(left.as_integer().unwrap() >> shift))
+        }
+        BoundBinaryOperatorKind::Concatenation => Ok(LiteralValue::String(
+            left.as_string().unwrap() + &right.as_string().unwrap(),
+        )),
+        BoundBinaryOperatorKind::LogicalAnd => Ok(LiteralValue::Boolean(
+            left.as_boolean().unwrap() && right.as_boolean().unwrap(),
+        )),
+        BoundBinaryOperatorKind::LogicalOr => Ok(LiteralValue::Boolean(
+            left.as_boolean().unwrap() || right.as_boolean().unwrap(),
+        )),
+        BoundBinaryOperatorKind::Equals => Ok(LiteralValue::Boolean(left == right)),
+        BoundBinaryOperatorKind::NotEquals => Ok(LiteralValue::Boolean(left != right)),
+        BoundBinaryOperatorKind::Less => Ok(LiteralValue::Boolean(left < right)),
+        BoundBinaryOperatorKind::LessOrEquals => Ok(LiteralValue::Boolean(left <= right)),
+        BoundBinaryOperatorKind::Greater => Ok(LiteralValue::Boolean(left > right)),
+        BoundBinaryOperatorKind::GreaterOrEquals => Ok(LiteralValue::Boolean(left >= right)),
+    }
+}
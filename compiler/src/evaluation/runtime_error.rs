@@ -0,0 +1,16 @@
+use crate::reports::text_span::TextSpan;
+
+#[derive(Debug, Clone)]
+pub enum RuntimeError {
+    DivisionByZero(TextSpan),
+    IntegerOverflow(TextSpan),
+    NegativeExponent(TextSpan),
+    InvalidShift(TextSpan),
+    IndexOutOfBounds(i32, usize, TextSpan),
+    UninitializedVariable(String, TextSpan),
+    MissingReturnValue(String, TextSpan),
+    StepLimitExceeded(u64),
+    TimeLimitExceeded(u64),
+    CallDepthExceeded(u64, TextSpan),
+    NativeExecutionFailed(String),
+}
@@ -0,0 +1,521 @@
+use crate::{
+    syntax_analyzer::{
+        array_literal_expression::ArrayLiteralExpressionSyntax, assignment::Assignment,
+        binary_expression::BinaryExpressionSyntax, call_expression::CallExpressionSyntax,
+        conditional_expression::ConditionalExpressionSyntax,
+        constant_declaration::ConstantDeclaration, else_statement::ElseStatement,
+        expression_statement::ExpressionStatement, field_assignment::FieldAssignment,
+        field_expression::FieldExpressionSyntax, for_statement::ForStatement,
+        function_declaration::FunctionDeclaration, if_statement::IfStatement,
+        increment_statement::IncrementStatement, index_assignment::IndexAssignment,
+        index_expression::IndexExpressionSyntax, literal_expression::LiteralExpressionSyntax,
+        name_expression::NameExpressionSyntax,
+        parenthesized_expression::ParenthesizedExpressionSyntax, return_statement::ReturnStatement,
+        statement_list::StatementList, struct_declaration::StructDeclaration,
+        struct_literal_expression::StructLiteralExpressionSyntax, type_clause::TypeClause,
+        unary_expression::UnaryExpressionSyntax, variable_declaration::VariableDeclaration,
+        while_statement::WhileStatement,
+    },
+    util::{
+        expression::Expression, statement::Statement, syntax_kind::SyntaxKind,
+        syntax_token::SyntaxToken, syntax_trivia::SyntaxTrivia,
+    },
+};
+
+const INDENTATION: &str = "    ";
+
+// Prints a tree from the recursive parser with canonical spacing and indentation. Blank
+// lines between statements are kept, several in a row become one. Comments stay on their
+// line, except those inside a statement, which are moved above it.
+pub struct Formatter {
+    output: String,
+    depth: usize,
+}
+
+impl Formatter {
+    pub fn new() -> Self {
+        Self {
+            output: String::new(),
+            depth: 0,
+        }
+    }
+
+    pub fn format(mut self, root: &dyn Statement) -> String {
+        self.format_line(root, false);
+        self.output
+    }
+
+    // Writes a statement with the comments before it and the ones that follow it on the
+    // same line.
+    fn format_line(&mut self, statement: &dyn Statement, blank_line_allowed: bool) {
+        let tokens = statement.get_tokens();
+        let (Some(first), Some(last)) = (tokens.first(), tokens.last()) else {
+            return;
+        };
+
+        if self.write_comment_lines(&first.leading_trivia(), 1, blank_line_allowed) {
+            self.output.push('\n');
+        }
+        for comment in inner_comments(statement, &tokens) {
+            self.write_indentation();
+            self.output.push_str(&comment.text());
+            self.output.push('\n');
+        }
+
+        self.write_indentation();
+        self.format_statement(statement);
+        let rest = self.write_same_line_comments(&last.trailing_trivia());
+        self.output.push('\n');
+        self.write_comment_lines(&rest, 0, true);
+    }
+
+    // Writes each comment on a line of its own, keeping a blank line where the source had
+    // one. Returns whether a blank line comes before whatever follows the trivia.
+    fn write_comment_lines(
+        &mut self,
+        trivia: &[SyntaxTrivia],
+        mut line_breaks: usize,
+        mut blank_line_allowed: bool,
+    ) -> bool {
+        for item in trivia {
+            if *item.kind() == SyntaxKind::WhiteSpace {
+                line_breaks += item.text().matches('\n').count();
+                continue;
+            }
+
+            if blank_line_allowed && line_breaks > 1 {
+                self.output.push('\n');
+            }
+            self.write_indentation();
+            self.output.push_str(&item.text());
+            self.output.push('\n');
+            line_breaks = 0;
+            blank_line_allowed = true;
+        }
+
+        blank_line_allowed && line_breaks > 1
+    }
+
+    // Writes the comments that share the line with what was just written and returns the
+    // trivia from the end of that line on.
+    fn write_same_line_comments(&mut self, trivia: &[SyntaxTrivia]) -> Vec<SyntaxTrivia> {
+        for (index, item) in trivia.iter().enumerate() {
+            match item.kind() {
+                SyntaxKind::WhiteSpace if item.text().contains('\n') => {
+                    return trivia[index..].to_vec()
+                }
+                SyntaxKind::WhiteSpace => {}
+                _ => {
+                    self.output.push(' ');
+                    self.output.push_str(&item.text());
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    fn write_indentation(&mut self) {
+        self.output.push_str(&INDENTATION.repeat(self.depth));
+    }
+
+    fn format_statement(&mut self, statement: &dyn Statement) {
+        let any = statement.as_any();
+
+        match statement.get_kind() {
+            SyntaxKind::StatementList => {
+                self.format_statement_list(any.downcast_ref::<StatementList>().unwrap())
+            }
+            SyntaxKind::IfStatement => {
+                let if_statement = any.downcast_ref::<IfStatement>().unwrap();
+                let condition = format_expression(if_statement.get_condition().as_ref());
+
+                self.output.push_str(&format!("if ({}) ", condition));
+                self.format_statement(if_statement.get_then_statement().as_ref());
+                if let Some(else_clause) = if_statement.get_else_clause() {
+                    self.output.push(' ');
+                    self.format_statement(else_clause.as_ref());
+                }
+            }
+            SyntaxKind::ElseStatement => {
+                let else_statement = any.downcast_ref::<ElseStatement>().unwrap();
+
+                self.output.push_str("else ");
+                self.format_statement(else_statement.get_else_statement().as_ref());
+            }
+            SyntaxKind::ForStatement => {
+                let for_statement = any.downcast_ref::<ForStatement>().unwrap();
+
+                self.output.push_str(&format!(
+                    "for ({} = {} to {}) ",
+                    for_statement.get_identifier().name(),
+                    format_expression(for_statement.get_lower_bound().as_ref()),
+                    format_expression(for_statement.get_upper_bound().as_ref())
+                ));
+                self.format_statement(for_statement.get_body().as_ref());
+            }
+            SyntaxKind::WhileStatement => {
+                let while_statement = any.downcast_ref::<WhileStatement>().unwrap();
+                let condition = format_expression(while_statement.get_condition().as_ref());
+
+                self.output.push_str(&format!("while ({}) ", condition));
+                self.format_statement(while_statement.get_body().as_ref());
+            }
+            SyntaxKind::FunctionDeclaration => {
+                let function = any.downcast_ref::<FunctionDeclaration>().unwrap();
+                let parameters: Vec<String> = function
+                    .get_parameters()
+                    .iter()
+                    .map(|parameter| match parameter.get_type_clause() {
+                        Some(type_clause) => format!(
+                            "{}{}",
+                            parameter.get_identifier().name(),
+                            format_type_clause(type_clause)
+                        ),
+                        None => parameter.get_identifier().name(),
+                    })
+                    .collect();
+                let return_type = match function.get_return_type() {
+                    Some(type_clause) => format_type_clause(type_clause),
+                    None => String::new(),
+                };
+
+                self.output.push_str(&format!(
+                    "fn {}({}){} ",
+                    function.get_identifier().name(),
+                    parameters.join(", "),
+                    return_type
+                ));
+                self.format_statement(function.get_body().as_ref());
+            }
+            SyntaxKind::StructDeclaration => {
+                let declaration = any.downcast_ref::<StructDeclaration>().unwrap();
+                let fields: Vec<String> = declaration
+                    .get_fields()
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}{}",
+                            field.get_identifier().name(),
+                            format_type_clause(field.get_type_clause())
+                        )
+                    })
+                    .collect();
+
+                self.output.push_str(&format!(
+                    "struct {} {}",
+                    declaration.get_identifier().name(),
+                    format_fields(&fields)
+                ));
+            }
+            SyntaxKind::ReturnStatement => {
+                let return_statement = any.downcast_ref::<ReturnStatement>().unwrap();
+                let expression = format_expression(return_statement.get_expression().as_ref());
+
+                self.output.push_str(&format!("return {};", expression));
+            }
+            SyntaxKind::BreakStatement => self.output.push_str("break;"),
+            SyntaxKind::ContinueStatement => self.output.push_str("continue;"),
+            SyntaxKind::VariableDeclaration => {
+                let declaration = any.downcast_ref::<VariableDeclaration>().unwrap();
+
+                self.output.push_str(&format!(
+                    "let {} = {};",
+                    declaration.get_variable().name(),
+                    format_expression(declaration.get_expression().as_ref())
+                ));
+            }
+            SyntaxKind::ConstantDeclaration => {
+                let declaration = any.downcast_ref::<ConstantDeclaration>().unwrap();
+
+                self.output.push_str(&format!(
+                    "const {} = {};",
+                    declaration.get_variable().name(),
+                    format_expression(declaration.get_expression().as_ref())
+                ));
+            }
+            SyntaxKind::Assignment => {
+                let assignment = any.downcast_ref::<Assignment>().unwrap();
+
+                self.output.push_str(&format!(
+                    "{} {} {};",
+                    assignment.get_variable().name(),
+                    assignment.get_equals().name(),
+                    format_expression(assignment.get_expression().as_ref())
+                ));
+            }
+            SyntaxKind::IndexAssignment => {
+                let assignment = any.downcast_ref::<IndexAssignment>().unwrap();
+
+                self.output.push_str(&format!(
+                    "{}[{}] {} {};",
+                    assignment.get_variable().name(),
+                    format_expression(assignment.get_index().as_ref()),
+                    assignment.get_equals().name(),
+                    format_expression(assignment.get_expression().as_ref())
+                ));
+            }
+            SyntaxKind::FieldAssignment => {
+                let assignment = any.downcast_ref::<FieldAssignment>().unwrap();
+
+                self.output.push_str(&format!(
+                    "{}.{} {} {};",
+                    assignment.get_variable().name(),
+                    assignment.get_field().name(),
+                    assignment.get_equals().name(),
+                    format_expression(assignment.get_expression().as_ref())
+                ));
+            }
+            SyntaxKind::IncrementStatement => {
+                let increment = any.downcast_ref::<IncrementStatement>().unwrap();
+
+                self.output.push_str(&format!(
+                    "{}{};",
+                    increment.get_variable().name(),
+                    increment.get_operator().name()
+                ));
+            }
+            SyntaxKind::ExpressionStatement => {
+                let statement = any.downcast_ref::<ExpressionStatement>().unwrap();
+                let expression = format_expression(statement.get_expression().as_ref());
+
+                self.output.push_str(&format!("{};", expression));
+            }
+            // Anything that did not parse is written back the way it was.
+            _ => self.output.push_str(statement.to_source().trim()),
+        }
+    }
+
+    fn format_statement_list(&mut self, statement_list: &StatementList) {
+        let statements = statement_list.get_statements();
+        let tokens = statement_list.get_tokens();
+        let open_brace = tokens[0].trailing_trivia();
+        let close_brace = tokens[tokens.len() - 1].leading_trivia();
+
+        if statements.is_empty() && !has_comments(&open_brace) && !has_comments(&close_brace) {
+            self.output.push_str("{}");
+            return;
+        }
+
+        self.output.push('{');
+        self.write_same_line_comments(&open_brace);
+        self.output.push('\n');
+
+        self.depth += 1;
+        for (index, statement) in statements.iter().enumerate() {
+            self.format_line(statement.as_ref(), index > 0);
+        }
+        self.write_comment_lines(&close_brace, 1, !statements.is_empty());
+        self.depth -= 1;
+
+        self.write_indentation();
+        self.output.push('}');
+    }
+}
+
+fn format_expression(expression: &dyn Expression) -> String {
+    let any = expression.as_any();
+
+    match expression.get_kind() {
+        SyntaxKind::LiteralExpression => any
+            .downcast_ref::<LiteralExpressionSyntax>()
+            .unwrap()
+            .get_token()
+            .name(),
+        SyntaxKind::NameExpression => any
+            .downcast_ref::<NameExpressionSyntax>()
+            .unwrap()
+            .get_token()
+            .name(),
+        SyntaxKind::BinaryExpression => {
+            let binary = any.downcast_ref::<BinaryExpressionSyntax>().unwrap();
+            format!(
+                "{} {} {}",
+                format_expression(binary.get_left().as_ref()),
+                binary.get_operator().name(),
+                format_expression(binary.get_right().as_ref())
+            )
+        }
+        SyntaxKind::UnaryExpression => {
+            let unary = any.downcast_ref::<UnaryExpressionSyntax>().unwrap();
+            let operand = format_expression(unary.operand().as_ref());
+            // `- -a` written without the space would be read back as a decrement.
+            let separator = if operand.starts_with('-') { " " } else { "" };
+            format!("{}{}{}", unary.operator().name(), separator, operand)
+        }
+        SyntaxKind::ParenthesizedExpression => {
+            let parenthesized = any.downcast_ref::<ParenthesizedExpressionSyntax>().unwrap();
+            format!(
+                "({})",
+                format_expression(parenthesized.get_expression().as_ref())
+            )
+        }
+        SyntaxKind::ConditionalExpression => {
+            let conditional = any.downcast_ref::<ConditionalExpressionSyntax>().unwrap();
+            format!(
+                "if ({}) {} else {}",
+                format_expression(conditional.get_condition().as_ref()),
+                format_expression(conditional.get_when_true().as_ref()),
+                format_expression(conditional.get_when_false().as_ref())
+            )
+        }
+        SyntaxKind::ArrayLiteralExpression => {
+            let array = any.downcast_ref::<ArrayLiteralExpressionSyntax>().unwrap();
+            let elements: Vec<String> = array
+                .get_elements()
+                .iter()
+                .map(|element| format_expression(element.as_ref()))
+                .collect();
+            format!("[{}]", elements.join(", "))
+        }
+        SyntaxKind::IndexExpression => {
+            let index = any.downcast_ref::<IndexExpressionSyntax>().unwrap();
+            format!(
+                "{}[{}]",
+                format_expression(index.get_array().as_ref()),
+                format_expression(index.get_index().as_ref())
+            )
+        }
+        SyntaxKind::StructLiteralExpression => {
+            let literal = any.downcast_ref::<StructLiteralExpressionSyntax>().unwrap();
+            let fields: Vec<String> = literal
+                .get_fields()
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}: {}",
+                        field.get_identifier().name(),
+                        format_expression(field.get_expression().as_ref())
+                    )
+                })
+                .collect();
+            format!(
+                "{} {}",
+                literal.get_identifier().name(),
+                format_fields(&fields)
+            )
+        }
+        SyntaxKind::FieldExpression => {
+            let field = any.downcast_ref::<FieldExpressionSyntax>().unwrap();
+            format!(
+                "{}.{}",
+                format_expression(field.get_structure().as_ref()),
+                field.get_field().name()
+            )
+        }
+        SyntaxKind::CallExpression => {
+            let call = any.downcast_ref::<CallExpressionSyntax>().unwrap();
+            let arguments: Vec<String> = call
+                .get_arguments()
+                .iter()
+                .map(|argument| format_expression(argument.as_ref()))
+                .collect();
+            format!("{}({})", call.get_identifier().name(), arguments.join(", "))
+        }
+        _ => expression
+            .get_tokens()
+            .iter()
+            .map(|token| token.text())
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+fn format_type_clause(type_clause: &TypeClause) -> String {
+    format!(
+        ": {}{}{}",
+        "[".repeat(type_clause.get_depth()),
+        type_clause.get_type_name().name(),
+        "]".repeat(type_clause.get_depth())
+    )
+}
+
+// Struct declarations and literals are kept on one line, `{}` when there are no fields.
+fn format_fields(fields: &[String]) -> String {
+    if fields.is_empty() {
+        String::from("{}")
+    } else {
+        format!("{{ {} }}", fields.join(", "))
+    }
+}
+
+fn has_comments(trivia: &[SyntaxTrivia]) -> bool {
+    trivia
+        .iter()
+        .any(|item| *item.kind() != SyntaxKind::WhiteSpace)
+}
+
+// Comments between the tokens of a statement have no place of their own in the output, so
+// they are moved above it. Nested blocks place the comments inside them themselves.
+fn inner_comments(statement: &dyn Statement, tokens: &[SyntaxToken]) -> Vec<SyntaxTrivia> {
+    let blocks = block_ranges(statement, tokens.len());
+    let last = tokens.len() - 1;
+    let mut comments = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if blocks
+            .iter()
+            .any(|(start, end)| *start < index && index + 1 < *end)
+        {
+            continue;
+        }
+
+        let block_start = blocks.iter().any(|(start, _)| *start == index);
+        let block_end = blocks.iter().any(|(_, end)| *end == index + 1);
+        if index > 0 && !block_end {
+            comments.extend(token.leading_trivia());
+        }
+        if index < last && !block_start {
+            comments.extend(token.trailing_trivia());
+        }
+    }
+
+    comments.retain(|trivia| *trivia.kind() != SyntaxKind::WhiteSpace);
+    comments
+}
+
+// Where the blocks nested directly in a statement start and end among its tokens.
+fn block_ranges(statement: &dyn Statement, length: usize) -> Vec<(usize, usize)> {
+    let any = statement.as_any();
+    let body_length = |body: Box<dyn Statement>| body.get_tokens().len();
+
+    let body = match statement.get_kind() {
+        SyntaxKind::StatementList => return vec![(0, length)],
+        SyntaxKind::IfStatement => {
+            let if_statement = any.downcast_ref::<IfStatement>().unwrap();
+            let else_length = if_statement.get_else_clause().map_or(0, body_length);
+            let then_end = length - else_length;
+            let then_start = then_end - body_length(if_statement.get_then_statement());
+
+            let mut ranges = vec![(then_start, then_end)];
+            if let Some(else_clause) = if_statement.get_else_clause() {
+                // The else keyword comes before its block, or before the if statement of
+                // an else-if, whose blocks are found the same way.
+                let else_statement = else_clause
+                    .as_any()
+                    .downcast_ref::<ElseStatement>()
+                    .unwrap()
+                    .get_else_statement();
+                if *else_statement.get_kind() == SyntaxKind::IfStatement {
+                    let nested = block_ranges(else_statement.as_ref(), else_length - 1);
+                    ranges.extend(
+                        nested
+                            .into_iter()
+                            .map(|(start, end)| (then_end + 1 + start, then_end + 1 + end)),
+                    );
+                } else {
+                    ranges.push((then_end + 1, length));
+                }
+            }
+            return ranges;
+        }
+        SyntaxKind::WhileStatement => any.downcast_ref::<WhileStatement>().unwrap().get_body(),
+        SyntaxKind::ForStatement => any.downcast_ref::<ForStatement>().unwrap().get_body(),
+        SyntaxKind::FunctionDeclaration => any
+            .downcast_ref::<FunctionDeclaration>()
+            .unwrap()
+            .get_body(),
+        _ => return Vec::new(),
+    };
+
+    vec![(length - body_length(body), length)]
+}
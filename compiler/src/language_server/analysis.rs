@@ -0,0 +1,423 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    binding::{binder::Binder, bound_function::BoundFunction, symbol_reference::ReferencedSymbol},
+    environment::Environment,
+    lexical_analyzer::lexer::Lexer,
+    parse_program,
+    reports::{
+        diagnostic::Diagnostic, diagnostics::Diagnostics, source_map::SourceMap,
+        text_span::TextSpan, text_type::TextType,
+    },
+    syntax_analyzer::{
+        constant_declaration::ConstantDeclaration, else_statement::ElseStatement,
+        for_statement::ForStatement, function_declaration::FunctionDeclaration,
+        if_statement::IfStatement, statement_list::StatementList,
+        variable_declaration::VariableDeclaration, while_statement::WhileStatement,
+    },
+    util::{
+        function_symbol::FunctionSymbol, parser_type::ParserType, statement::Statement,
+        syntax_kind::SyntaxKind, syntax_token::SyntaxToken, variable_symbol::VariableSymbol,
+    },
+};
+
+pub const TOKEN_TYPES: [&str; 7] = [
+    "keyword", "variable", "function", "number", "string", "operator", "comment",
+];
+pub const TOKEN_MODIFIERS: [&str; 2] = ["declaration", "readonly"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Variable(VariableSymbol),
+    // The function and its signature.
+    Function(FunctionSymbol, String),
+}
+
+// A name in the source together with what it refers to.
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+    pub span: TextSpan,
+    pub target: Target,
+    pub is_declaration: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolKind {
+    Function = 12,
+    Variable = 13,
+    Constant = 14,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub span: TextSpan,
+    pub selection: TextSpan,
+    pub detail: Option<String>,
+    pub children: Vec<DocumentSymbol>,
+}
+
+// Everything the language server knows about one version of a document. Symbols come from
+// the syntax tree, references to variables and functions from the binder, which only runs
+// when the program parses.
+pub struct Analysis {
+    code: String,
+    source_map: SourceMap,
+    diagnostics: Vec<Diagnostic>,
+    tokens: Vec<SyntaxToken>,
+    occurrences: Vec<Occurrence>,
+    symbols: Vec<DocumentSymbol>,
+}
+
+impl Analysis {
+    pub fn new(code: &str) -> Self {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let tokens = lex(code);
+        let mut occurrences = Vec::new();
+        let mut symbols = Vec::new();
+
+        if let Some(root) = parse_program(Rc::clone(&diagnostics), code, &ParserType::Recursive) {
+            collect_symbols(root.as_ref(), &mut symbols);
+
+            let environment = Rc::new(RefCell::new(Environment::new()));
+            let mut binder = Binder::new(Rc::clone(&diagnostics), environment);
+            binder.bind_statement(root);
+            let functions = binder.get_functions();
+            occurrences.extend(binder.get_references().into_iter().map(|reference| {
+                let target = match reference.get_symbol() {
+                    ReferencedSymbol::Variable(symbol) => Target::Variable(symbol.clone()),
+                    ReferencedSymbol::Function(symbol) => Target::Function(
+                        symbol.clone(),
+                        signature(&symbol.id(), &functions[symbol.index()]),
+                    ),
+                };
+                Occurrence {
+                    span: reference.get_span().clone(),
+                    target,
+                    is_declaration: reference.is_declaration(),
+                }
+            }));
+        }
+
+        let diagnostics = diagnostics.borrow().filter_type(TextType::Error);
+        Self {
+            code: code.to_string(),
+            source_map: SourceMap::new(code),
+            diagnostics,
+            tokens,
+            occurrences,
+            symbols,
+        }
+    }
+
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
+    pub fn diagnostics(&self) -> &Vec<Diagnostic> {
+        &self.diagnostics
+    }
+
+    pub fn symbols(&self) -> &Vec<DocumentSymbol> {
+        &self.symbols
+    }
+
+    pub fn occurrence_at(&self, offset: usize) -> Option<&Occurrence> {
+        self.occurrences
+            .iter()
+            .find(|occurrence| occurrence.span.contains(offset))
+    }
+
+    pub fn definition(&self, offset: usize) -> Option<&Occurrence> {
+        let target = &self.occurrence_at(offset)?.target;
+        self.occurrences
+            .iter()
+            .find(|occurrence| occurrence.is_declaration && occurrence.target == *target)
+    }
+
+    pub fn references(&self, offset: usize, include_declaration: bool) -> Vec<&Occurrence> {
+        let Some(occurrence) = self.occurrence_at(offset) else {
+            return Vec::new();
+        };
+        let mut references: Vec<&Occurrence> = self
+            .occurrences
+            .iter()
+            .filter(|other| other.target == occurrence.target)
+            .filter(|other| include_declaration || !other.is_declaration)
+            .collect();
+        references.sort_by_key(|other| other.span.start());
+        references
+    }
+
+    pub fn hover(&self, offset: usize) -> Option<(TextSpan, String)> {
+        let occurrence = self.occurrence_at(offset)?;
+        let text = match &occurrence.target {
+            Target::Variable(symbol) => format!(
+                "{} {}: {:?}",
+                if symbol.is_read_only() {
+                    "const"
+                } else {
+                    "let"
+                },
+                symbol.id(),
+                symbol.get_type()
+            ),
+            Target::Function(_, signature) => signature.clone(),
+        };
+        Some((occurrence.span.clone(), text))
+    }
+
+    // Encodes the tokens as relative line, start, length, type and modifier quintuples.
+    // Comments that span several lines are split, since a token must stay on one line.
+    pub fn semantic_tokens(&self) -> Vec<usize> {
+        let mut data = Vec::new();
+        let (mut previous_line, mut previous_start) = (0, 0);
+
+        for (index, token) in self.tokens.iter().enumerate() {
+            let Some((token_type, modifiers)) = self.classify(index, token) else {
+                continue;
+            };
+
+            let mut offset = token.position();
+            for line in self.slice(token.position(), token.length()).split('\n') {
+                let (line_number, start) = self.source_map.utf16_position(offset);
+                let length: usize = line
+                    .trim_end_matches('\r')
+                    .chars()
+                    .map(char::len_utf16)
+                    .sum();
+                offset += line.chars().count() + 1;
+                if length == 0 {
+                    continue;
+                }
+
+                let delta_start = if line_number == previous_line {
+                    start - previous_start
+                } else {
+                    start
+                };
+                data.extend([
+                    line_number - previous_line,
+                    delta_start,
+                    length,
+                    token_type,
+                    modifiers,
+                ]);
+                (previous_line, previous_start) = (line_number, start);
+            }
+        }
+        data
+    }
+
+    fn classify(&self, index: usize, token: &SyntaxToken) -> Option<(usize, usize)> {
+        let token_type = match token.kind() {
+            SyntaxKind::Eof | SyntaxKind::BadToken | SyntaxKind::WhiteSpace => return None,
+            SyntaxKind::LineComment | SyntaxKind::BlockComment => 6,
+            SyntaxKind::Number => 3,
+            SyntaxKind::StringLiteral => 4,
+            SyntaxKind::True
+            | SyntaxKind::False
+            | SyntaxKind::If
+            | SyntaxKind::Else
+            | SyntaxKind::Let
+            | SyntaxKind::Const
+            | SyntaxKind::While
+            | SyntaxKind::For
+            | SyntaxKind::To
+            | SyntaxKind::Fn
+            | SyntaxKind::Return
+            | SyntaxKind::Break
+            | SyntaxKind::Continue
+            | SyntaxKind::Struct => 0,
+            SyntaxKind::IdentifierToken => match self.occurrence_at(token.position()) {
+                Some(occurrence) => match occurrence.target {
+                    Target::Function(..) => 2,
+                    Target::Variable(_) => 1,
+                },
+                None if is_function_name(&self.tokens, index) => 2,
+                None => 1,
+            },
+            SyntaxKind::OpenParenthesis
+            | SyntaxKind::CloseParenthesis
+            | SyntaxKind::OpenBrace
+            | SyntaxKind::CloseBrace
+            | SyntaxKind::OpenBracket
+            | SyntaxKind::CloseBracket
+            | SyntaxKind::Semicolon
+            | SyntaxKind::Comma
+            | SyntaxKind::Dot
+            | SyntaxKind::Colon => return None,
+            _ => 5,
+        };
+
+        let mut modifiers = 0;
+        if let Some(occurrence) = self
+            .occurrences
+            .iter()
+            .find(|occurrence| occurrence.span.start() == token.position())
+        {
+            if occurrence.is_declaration {
+                modifiers |= 1;
+            }
+            if matches!(&occurrence.target, Target::Variable(symbol) if symbol.is_read_only()) {
+                modifiers |= 2;
+            }
+        }
+        Some((token_type, modifiers))
+    }
+
+    fn slice(&self, start: usize, length: usize) -> String {
+        self.code.chars().skip(start).take(length).collect()
+    }
+}
+
+// The lexer is run on its own so that comments, which the parser keeps as trivia, are seen
+// as tokens. Lexical errors are already reported by the parser.
+fn lex(code: &str) -> Vec<SyntaxToken> {
+    let mut lexer = Lexer::in_memory_reader(code, Rc::new(RefCell::new(Diagnostics::new())));
+    let mut tokens = Vec::new();
+
+    loop {
+        let token = lexer.next_token();
+        let kind = *token.kind();
+        if kind == SyntaxKind::Eof || kind == SyntaxKind::BadToken {
+            break;
+        }
+        if kind != SyntaxKind::WhiteSpace {
+            tokens.push(token);
+        }
+    }
+    tokens
+}
+
+fn is_function_name(tokens: &[SyntaxToken], index: usize) -> bool {
+    *tokens[index].kind() == SyntaxKind::IdentifierToken
+        && tokens
+            .get(index + 1)
+            .is_some_and(|next| *next.kind() == SyntaxKind::OpenParenthesis)
+}
+
+fn signature(name: &str, function: &BoundFunction) -> String {
+    let parameters: Vec<String> = function
+        .get_parameters()
+        .iter()
+        .map(|parameter| format!("{}: {:?}", parameter.id(), parameter.get_type()))
+        .collect();
+    match function.get_return_type() {
+        Some(return_type) => format!("fn {}({}): {:?}", name, parameters.join(", "), return_type),
+        None => format!("fn {}({})", name, parameters.join(", ")),
+    }
+}
+
+fn token_span(token: &SyntaxToken) -> TextSpan {
+    TextSpan::new(token.position(), token.length())
+}
+
+fn statement_span(statement: &dyn Statement) -> TextSpan {
+    let tokens = statement.get_tokens();
+    let start = tokens.first().map_or(0, |token| token.position());
+    let end = tokens
+        .last()
+        .map_or(0, |token| token.position() + token.length());
+    TextSpan::new(start, end - start)
+}
+
+fn collect_symbols(statement: &dyn Statement, symbols: &mut Vec<DocumentSymbol>) {
+    let any = statement.as_any();
+
+    match *statement.get_kind() {
+        SyntaxKind::StatementList => {
+            for statement in any
+                .downcast_ref::<StatementList>()
+                .unwrap()
+                .get_statements()
+            {
+                collect_symbols(statement.as_ref(), symbols);
+            }
+        }
+        SyntaxKind::VariableDeclaration => {
+            let declaration = any.downcast_ref::<VariableDeclaration>().unwrap();
+            symbols.push(leaf_symbol(
+                declaration.get_variable(),
+                SymbolKind::Variable,
+                statement_span(statement),
+            ));
+        }
+        SyntaxKind::ConstantDeclaration => {
+            let declaration = any.downcast_ref::<ConstantDeclaration>().unwrap();
+            symbols.push(leaf_symbol(
+                declaration.get_variable(),
+                SymbolKind::Constant,
+                statement_span(statement),
+            ));
+        }
+        SyntaxKind::IfStatement => {
+            let if_statement = any.downcast_ref::<IfStatement>().unwrap();
+            collect_symbols(if_statement.get_then_statement().as_ref(), symbols);
+            if let Some(else_clause) = if_statement.get_else_clause() {
+                collect_symbols(else_clause.as_ref(), symbols);
+            }
+        }
+        SyntaxKind::ElseStatement => {
+            let else_statement = any.downcast_ref::<ElseStatement>().unwrap();
+            collect_symbols(else_statement.get_else_statement().as_ref(), symbols);
+        }
+        SyntaxKind::WhileStatement => {
+            let while_statement = any.downcast_ref::<WhileStatement>().unwrap();
+            collect_symbols(while_statement.get_body().as_ref(), symbols);
+        }
+        SyntaxKind::ForStatement => {
+            let for_statement = any.downcast_ref::<ForStatement>().unwrap();
+            let identifier = for_statement.get_identifier();
+            symbols.push(leaf_symbol(
+                identifier,
+                SymbolKind::Variable,
+                token_span(identifier),
+            ));
+            collect_symbols(for_statement.get_body().as_ref(), symbols);
+        }
+        SyntaxKind::FunctionDeclaration => {
+            let declaration = any.downcast_ref::<FunctionDeclaration>().unwrap();
+            let parameters = declaration.get_parameters();
+            let mut children: Vec<DocumentSymbol> = parameters
+                .iter()
+                .map(|parameter| {
+                    let identifier = parameter.get_identifier();
+                    leaf_symbol(identifier, SymbolKind::Variable, token_span(identifier))
+                })
+                .collect();
+            collect_symbols(declaration.get_body().as_ref(), &mut children);
+
+            let identifier = declaration.get_identifier();
+            let parameters: Vec<String> = parameters
+                .iter()
+                .map(|parameter| parameter.get_identifier().name())
+                .collect();
+            symbols.push(DocumentSymbol {
+                name: identifier.name(),
+                kind: SymbolKind::Function,
+                span: statement_span(statement),
+                selection: token_span(identifier),
+                detail: Some(format!(
+                    "fn {}({})",
+                    identifier.name(),
+                    parameters.join(", ")
+                )),
+                children,
+            });
+        }
+        _ => {}
+    }
+}
+
+fn leaf_symbol(token: &SyntaxToken, kind: SymbolKind, span: TextSpan) -> DocumentSymbol {
+    DocumentSymbol {
+        name: token.name(),
+        kind,
+        span,
+        selection: token_span(token),
+        detail: None,
+        children: Vec::new(),
+    }
+}
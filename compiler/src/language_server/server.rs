@@ -0,0 +1,255 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+};
+
+use rocket::serde::json::{serde_json::json, Value};
+
+use crate::reports::{source_map::SourceMap, text_span::TextSpan};
+
+use super::{
+    analysis::{Analysis, DocumentSymbol, Occurrence, TOKEN_MODIFIERS, TOKEN_TYPES},
+    transport::{read_message, write_message},
+};
+
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_REQUEST: i32 = -32600;
+
+// A language server for a single client. Documents are synchronized in full and analyzed
+// again on every change.
+pub struct LanguageServer {
+    documents: HashMap<String, Analysis>,
+    shutdown: bool,
+    exit_code: Option<i32>,
+}
+
+impl LanguageServer {
+    pub fn new() -> Self {
+        Self {
+            documents: HashMap::new(),
+            shutdown: false,
+            exit_code: None,
+        }
+    }
+
+    // Serves messages until the client sends `exit` or closes the input, and returns the
+    // process exit code.
+    pub fn run(&mut self, reader: &mut impl BufRead, writer: &mut impl Write) -> io::Result<i32> {
+        while let Some(message) = read_message(reader)? {
+            for response in self.handle(&message) {
+                write_message(writer, &response)?;
+            }
+            if let Some(exit_code) = self.exit_code {
+                return Ok(exit_code);
+            }
+        }
+        Ok(1)
+    }
+
+    // Returns the responses and notifications the message produces.
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+
+        let result = match method {
+            "initialize" => Ok(capabilities()),
+            "initialized" | "$/cancelRequest" | "$/setTrace" => return Vec::new(),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            "exit" => {
+                self.exit_code = Some(if self.shutdown { 0 } else { 1 });
+                return Vec::new();
+            }
+            _ if self.shutdown => Err((INVALID_REQUEST, "The server is shutting down.".into())),
+            "textDocument/didOpen" => {
+                let document = &params["textDocument"];
+                return self.update(document["uri"].as_str(), document["text"].as_str());
+            }
+            "textDocument/didChange" => {
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                return self.update(params["textDocument"]["uri"].as_str(), text);
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = params["textDocument"]["uri"].as_str() {
+                    self.documents.remove(uri);
+                }
+                return Vec::new();
+            }
+            "textDocument/hover" => Ok(self.hover(params)),
+            "textDocument/definition" => Ok(self.definition(params)),
+            "textDocument/references" => Ok(self.references(params)),
+            "textDocument/documentSymbol" => Ok(self.document_symbols(params)),
+            "textDocument/semanticTokens/full" => Ok(self.semantic_tokens(params)),
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method {}.", method))),
+        };
+
+        // Notifications have no id and never get a response.
+        let Some(id) = id else {
+            return Vec::new();
+        };
+        vec![match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message },
+            }),
+        }]
+    }
+
+    fn update(&mut self, uri: Option<&str>, text: Option<&str>) -> Vec<Value> {
+        let (Some(uri), Some(text)) = (uri, text) else {
+            return Vec::new();
+        };
+
+        let analysis = Analysis::new(text);
+        let diagnostics: Vec<Value> = analysis
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| {
+                json!({
+                    "range": range(analysis.source_map(), diagnostic.get_span()),
+                    "severity": 1,
+                    "source": "compiler",
+                    "message": diagnostic.get_message(),
+                })
+            })
+            .collect();
+        self.documents.insert(uri.to_string(), analysis);
+
+        vec![json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })]
+    }
+
+    // The document and the character offset of the position in the request.
+    fn document_at(&self, params: &Value) -> Option<(&Analysis, usize)> {
+        let analysis = self
+            .documents
+            .get(params["textDocument"]["uri"].as_str()?)?;
+        let position = &params["position"];
+        let offset = analysis.source_map().utf16_offset(
+            position["line"].as_u64()? as usize,
+            position["character"].as_u64()? as usize,
+        );
+        Some((analysis, offset))
+    }
+
+    fn hover(&self, params: &Value) -> Value {
+        let Some((analysis, offset)) = self.document_at(params) else {
+            return Value::Null;
+        };
+        match analysis.hover(offset) {
+            Some((span, text)) => json!({
+                "contents": { "kind": "markdown", "value": format!("```\n{}\n```", text) },
+                "range": range(analysis.source_map(), &span),
+            }),
+            None => Value::Null,
+        }
+    }
+
+    fn definition(&self, params: &Value) -> Value {
+        let Some((analysis, offset)) = self.document_at(params) else {
+            return Value::Null;
+        };
+        match analysis.definition(offset) {
+            Some(occurrence) => location(params, analysis, occurrence),
+            None => Value::Null,
+        }
+    }
+
+    fn references(&self, params: &Value) -> Value {
+        let Some((analysis, offset)) = self.document_at(params) else {
+            return Value::Null;
+        };
+        let include_declaration = params["context"]["includeDeclaration"]
+            .as_bool()
+            .unwrap_or(true);
+
+        analysis
+            .references(offset, include_declaration)
+            .into_iter()
+            .map(|occurrence| location(params, analysis, occurrence))
+            .collect()
+    }
+
+    fn document_symbols(&self, params: &Value) -> Value {
+        let Some(analysis) = params["textDocument"]["uri"]
+            .as_str()
+            .and_then(|uri| self.documents.get(uri))
+        else {
+            return Value::Null;
+        };
+        symbols(analysis.source_map(), analysis.symbols())
+    }
+
+    fn semantic_tokens(&self, params: &Value) -> Value {
+        let Some(analysis) = params["textDocument"]["uri"]
+            .as_str()
+            .and_then(|uri| self.documents.get(uri))
+        else {
+            return Value::Null;
+        };
+        json!({ "data": analysis.semantic_tokens() })
+    }
+}
+
+fn capabilities() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": 1,
+            "hoverProvider": true,
+            "definitionProvider": true,
+            "referencesProvider": true,
+            "documentSymbolProvider": true,
+            "semanticTokensProvider": {
+                "legend": { "tokenTypes": TOKEN_TYPES, "tokenModifiers": TOKEN_MODIFIERS },
+                "full": true,
+            },
+        },
+        "serverInfo": { "name": "compiler" },
+    })
+}
+
+fn range(source_map: &SourceMap, span: &TextSpan) -> Value {
+    let (start_line, start_character) = source_map.utf16_position(span.start());
+    let (end_line, end_character) = source_map.utf16_position(span.start() + span.length());
+    json!({
+        "start": { "line": start_line, "character": start_character },
+        "end": { "line": end_line, "character": end_character },
+    })
+}
+
+fn location(params: &Value, analysis: &Analysis, occurrence: &Occurrence) -> Value {
+    json!({
+        "uri": params["textDocument"]["uri"],
+        "range": range(analysis.source_map(), &occurrence.span),
+    })
+}
+
+fn symbols(source_map: &SourceMap, document_symbols: &[DocumentSymbol]) -> Value {
+    document_symbols
+        .iter()
+        .map(|symbol| {
+            let mut value = json!({
+                "name": symbol.name,
+                "kind": symbol.kind as i32,
+                "range": range(source_map, &symbol.span),
+                "selectionRange": range(source_map, &symbol.selection),
+                "children": symbols(source_map, &symbol.children),
+            });
+            if let Some(detail) = &symbol.detail {
+                value["detail"] = json!(detail);
+            }
+            value
+        })
+        .collect()
+}
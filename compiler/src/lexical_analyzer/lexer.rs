@@ -6,32 +6,30 @@ use crate::reports::text_place::TextPlace;
 use crate::reports::text_span::TextSpan;
 use crate::reports::text_type::TextType;
 use crate::util::literals::LiteralValue;
+use crate::util::utils::unescape;
 use crate::util::{syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
 
 pub struct Lexer {
-    text: String,
+    // Positions count characters, so the text is kept as characters to index them directly.
+    text: Vec<char>,
     position: usize,
-    size: usize,
     diagnostics: Rc<RefCell<Diagnostics>>,
 }
 
 impl Lexer {
     pub fn in_memory_reader(text: &str, diagnostics: Rc<RefCell<Diagnostics>>) -> Self {
         Self {
-            text: text.to_string(),
+            text: text.chars().collect(),
             position: 0,
-            size: text.chars().count(),
             diagnostics,
         }
     }
 
     fn peek(&self, offset: usize) -> char {
-        let index = self.position + offset;
-
-        if index >= self.size {
-            return '\0';
-        }
-        self.text.chars().nth(index).unwrap()
+        self.text
+            .get(self.position + offset)
+            .copied()
+            .unwrap_or('\0')
     }
 
     fn current(&self) -> char {
@@ -43,8 +41,17 @@ impl Lexer {
     }
 
     fn lookbehind(&self) -> char {
-        // potential out of bounds array error
-        self.text.chars().nth(self.position - 1).unwrap()
+        match self.position {
+            0 => '\0',
+            position => self.text[position - 1],
+        }
+    }
+
+    // An escape at the end of the input can move the position past the last character.
+    fn text_from(&self, start: usize) -> String {
+        self.text[start..self.position.min(self.text.len())]
+            .iter()
+            .collect()
     }
 
     fn next(&mut self) {
@@ -53,22 +60,56 @@ impl Lexer {
 
     fn create_operator(&mut self) -> SyntaxToken {
         let mut double_jump = false;
-        let kind: SyntaxKind = if self.current() == '+' {
+        let kind: SyntaxKind = if self.current() == '+' && self.lookahead() == '=' {
+            double_jump = true;
+            SyntaxKind::PlusEquals
+        } else if self.current() == '+' && self.lookahead() == '+' {
+            double_jump = true;
+            SyntaxKind::PlusPlus
+        } else if self.current() == '+' {
             SyntaxKind::Plus
+        } else if self.current() == '-' && self.lookahead() == '=' {
+            double_jump = true;
+            SyntaxKind::MinusEquals
+        } else if self.current() == '-' && self.lookahead() == '-' {
+            double_jump = true;
+            SyntaxKind::MinusMinus
         } else if self.current() == '-' {
             SyntaxKind::Minus
+        } else if self.current() == '*' && self.lookahead() == '=' {
+            double_jump = true;
+            SyntaxKind::MulEquals
+        } else if self.current() == '*' && self.lookahead() == '*' {
+            double_jump = true;
+            SyntaxKind::StarStar
         } else if self.current() == '*' {
             SyntaxKind::Mul
+        } else if self.current() == '/' && self.lookahead() == '=' {
+            double_jump = true;
+            SyntaxKind::DivEquals
         } else if self.current() == '/' {
             SyntaxKind::Div
+        } else if self.current() == '%' && self.lookahead() == '=' {
+            double_jump = true;
+            SyntaxKind::PercentEquals
+        } else if self.current() == '%' {
+            SyntaxKind::Percent
         } else if self.current() == '=' && self.lookahead() != '=' && self.lookbehind() != '=' {
             SyntaxKind::Equals
         } else if self.current() == '&' && self.lookahead() == '&' {
             double_jump = true;
             SyntaxKind::AmpersandAmpersand
+        } else if self.current() == '&' {
+            SyntaxKind::Ampersand
         } else if self.current() == '|' && self.lookahead() == '|' {
             double_jump = true;
             SyntaxKind::PipePipe
+        } else if self.current() == '|' {
+            SyntaxKind::Pipe
+        } else if self.current() == '^' {
+            SyntaxKind::Caret
+        } else if self.current() == '~' {
+            SyntaxKind::Tilde
         } else if self.current() == '=' && self.lookahead() == '=' {
             double_jump = true;
             SyntaxKind::EqualsEquals
@@ -77,11 +118,17 @@ impl Lexer {
             SyntaxKind::BangEquals
         } else if self.current() == '!' && self.lookahead() != '=' {
             SyntaxKind::Bang
+        } else if self.current() == '<' && self.lookahead() == '<' {
+            double_jump = true;
+            SyntaxKind::LessLess
         } else if self.current() == '<' && self.lookahead() == '=' {
             double_jump = true;
             SyntaxKind::LessOrEquals
         } else if self.current() == '<' && self.lookahead() != '=' {
             SyntaxKind::Less
+        } else if self.current() == '>' && self.lookahead() == '>' {
+            double_jump = true;
+            SyntaxKind::GreaterGreater
         } else if self.current() == '>' && self.lookahead() == '=' {
             double_jump = true;
             SyntaxKind::GreaterOrEquals
@@ -118,24 +165,32 @@ impl Lexer {
         while self.current().is_ascii_digit() {
             self.next();
         }
-        let value: String = self
-            .text
-            .chars()
-            .skip(start)
-            .take(self.position - start)
-            .collect();
 
-        let result: Result<i32, _> = value.parse();
+        let is_float = self.current() == '.' && self.lookahead().is_ascii_digit();
+        if is_float {
+            self.next();
+            while self.current().is_ascii_digit() {
+                self.next();
+            }
+        }
+
+        let value: String = self.text_from(start);
+
+        let result = if is_float {
+            value.parse().map(LiteralValue::Float).ok()
+        } else {
+            value.parse().map(LiteralValue::Integer).ok()
+        };
 
         match result {
-            Ok(number) => SyntaxToken::new(
+            Some(number) => SyntaxToken::new(
                 value.to_string(),
-                LiteralValue::Integer(number),
+                number,
                 start,
                 SyntaxKind::Number,
                 value.len(),
             ),
-            Err(_) => {
+            None => {
                 self.diagnostics.borrow_mut().report_invalid_number(
                     value.clone(),
                     TextSpan::new(start, value.len()),
@@ -153,6 +208,61 @@ impl Lexer {
         }
     }
 
+    fn create_string_token(&mut self) -> SyntaxToken {
+        let start = self.position;
+        self.next();
+
+        while self.current() != '"' && self.current() != '\0' {
+            if self.current() == '\\' {
+                self.next();
+            }
+            self.next();
+        }
+
+        let terminated = self.current() == '"';
+        if terminated {
+            self.next();
+        }
+
+        let text: String = self.text_from(start);
+        let content: String = text
+            .chars()
+            .skip(1)
+            .take(self.position - start - if terminated { 2 } else { 1 })
+            .collect();
+
+        if !terminated {
+            self.diagnostics.borrow_mut().report_unterminated_string(
+                TextSpan::new(start, self.position - start),
+                TextPlace::Lexical,
+                TextType::Error,
+            );
+        }
+
+        let value = match unescape(&content) {
+            Some(value) => value,
+            None => {
+                self.diagnostics
+                    .borrow_mut()
+                    .report_invalid_escape_sequence(
+                        text.clone(),
+                        TextSpan::new(start, self.position - start),
+                        TextPlace::Lexical,
+                        TextType::Error,
+                    );
+                content
+            }
+        };
+
+        SyntaxToken::new(
+            text,
+            LiteralValue::String(value),
+            start,
+            SyntaxKind::StringLiteral,
+            self.position - start,
+        )
+    }
+
     fn create_white_space_token(&mut self) -> SyntaxToken {
         let start: usize = self.position;
 
@@ -160,28 +270,77 @@ impl Lexer {
             self.next();
         }
 
+        let value: String = self.text_from(start);
+
         SyntaxToken::new(
-            "WhiteSpace".to_string(),
-            LiteralValue::String("WhiteSpace".to_string()),
+            value.to_string(),
+            LiteralValue::String(value.to_string()),
             start,
             SyntaxKind::WhiteSpace,
-            1,
+            self.position - start,
+        )
+    }
+
+    fn create_line_comment(&mut self) -> SyntaxToken {
+        let start: usize = self.position;
+
+        while self.current() != '\n' && self.current() != '\0' {
+            self.next();
+        }
+
+        self.create_comment_token(start, SyntaxKind::LineComment)
+    }
+
+    // Block comments nest, so `/* a /* b */ c */` is a single comment.
+    fn create_block_comment(&mut self) -> SyntaxToken {
+        let start: usize = self.position;
+        let mut depth = 0;
+
+        loop {
+            if self.current() == '/' && self.lookahead() == '*' {
+                depth += 1;
+                self.next();
+            } else if self.current() == '*' && self.lookahead() == '/' {
+                depth -= 1;
+                self.next();
+            } else if self.current() == '\0' {
+                self.diagnostics.borrow_mut().report_unterminated_comment(
+                    TextSpan::new(start, self.position - start),
+                    TextPlace::Lexical,
+                    TextType::Error,
+                );
+                break;
+            }
+            self.next();
+
+            if depth == 0 {
+                break;
+            }
+        }
+
+        self.create_comment_token(start, SyntaxKind::BlockComment)
+    }
+
+    fn create_comment_token(&mut self, start: usize, kind: SyntaxKind) -> SyntaxToken {
+        let value: String = self.text_from(start);
+
+        SyntaxToken::new(
+            value.to_string(),
+            LiteralValue::String(value.to_string()),
+            start,
+            kind,
+            self.position - start,
         )
     }
 
     fn create_keyword_identifier(&mut self) -> SyntaxToken {
         let start: usize = self.position;
 
-        while self.current().is_alphabetic() {
+        while self.current().is_alphanumeric() || self.current() == '_' {
             self.next();
         }
 
-        let value: String = self
-            .text
-            .chars()
-            .skip(start)
-            .take(self.position - start)
-            .collect();
+        let value: String = self.text_from(start);
 
         let kind = SyntaxKind::get_keyword_kind(&value);
         if kind == SyntaxKind::True || kind == SyntaxKind::False {
@@ -236,6 +395,18 @@ impl Lexer {
             return self.create_white_space_token();
         }
 
+        if self.current() == '"' {
+            return self.create_string_token();
+        }
+
+        if self.current() == '/' && self.lookahead() == '/' {
+            return self.create_line_comment();
+        }
+
+        if self.current() == '/' && self.lookahead() == '*' {
+            return self.create_block_comment();
+        }
+
         if self.current() == '(' || self.current() == ')' {
             return self.create_token(if self.current() == '(' {
                 SyntaxKind::OpenParenthesis
@@ -252,11 +423,31 @@ impl Lexer {
             });
         }
 
+        if self.current() == '[' || self.current() == ']' {
+            return self.create_token(if self.current() == '[' {
+                SyntaxKind::OpenBracket
+            } else {
+                SyntaxKind::CloseBracket
+            });
+        }
+
         if self.current() == ';' {
             return self.create_token(SyntaxKind::Semicolon);
         }
 
-        if self.current().is_alphabetic() {
+        if self.current() == ',' {
+            return self.create_token(SyntaxKind::Comma);
+        }
+
+        if self.current() == '.' {
+            return self.create_token(SyntaxKind::Dot);
+        }
+
+        if self.current() == ':' {
+            return self.create_token(SyntaxKind::Colon);
+        }
+
+        if self.current().is_alphabetic() || self.current() == '_' {
             return self.create_keyword_identifier();
         }
 
@@ -1,26 +1,46 @@
 #[macro_use]
 extern crate rocket;
+mod assembly;
 mod binding;
+mod bytecode;
+mod cli;
+mod environment;
 mod evaluation;
-pub mod global_state;
+mod formatting;
+mod language_server;
 mod lexical_analyzer;
+mod repl;
 mod reports;
 mod syntax_analyzer;
 mod syntax_tree;
 mod tests;
 mod util;
+use assembly::assembly_generator::AssemblyGenerator;
 use binding::binder::Binder;
+use binding::bound_function::BoundFunction;
+use binding::bound_statement::BoundStatement;
+use binding::type_table::TypeTable;
+use bytecode::code_generator::CodeGenerator;
+use bytecode::virtual_machine::VirtualMachine;
+use environment::Environment;
 use evaluation::evaluator::Evaluator;
-use global_state::SYMBOL_TABLE;
+use evaluation::execution_limits::ExecutionLimits;
+use formatting::formatter::Formatter;
 use reports::diagnostic::Diagnostic;
 use reports::diagnostics::Diagnostics;
+use reports::parse_error::report_parse_error;
+use reports::text_place::TextPlace;
+use reports::text_span::TextSpan;
 use reports::text_type::TextType;
-use rocket::launch;
+use rocket::fairing::AdHoc;
 use rocket::serde::Deserialize;
 use rocket::serde::{json::Json, Serialize};
-use util::literals::LiteralType;
+use rocket::State;
+use rocket::{Build, Rocket};
+use util::backend_type::BackendType;
 use util::parser_type::ParserType;
 use util::statement::Statement;
+use util::syntax_kind::SyntaxKind;
 
 use crate::compiler::CompilerParser;
 use rustemo::Parser;
@@ -42,6 +62,8 @@ use std::time::Instant;
 pub struct Program {
     pub code: String,
     pub parser: ParserType,
+    #[serde(default)]
+    pub backend: BackendType,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -59,6 +81,13 @@ pub struct Report {
     pub seconds: f64,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct FormatReport {
+    pub code: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
 impl Report {
     pub fn new(diagnostics: Vec<Diagnostic>) -> Self {
         Self {
@@ -68,81 +97,233 @@ impl Report {
         }
     }
 
-    pub fn report_symbol_table(&mut self) {
-        for (key, value) in SYMBOL_TABLE.lock().unwrap().iter() {
-            let value = value.clone();
+    pub fn report_symbol_table(&mut self, environment: &Environment) {
+        for (key, value) in environment.get_variables() {
             self.symbol_table.push(Pair {
                 id: key.id(),
-                value: if value.is_some() {
-                    let value_clone = value.unwrap();
-                    if *value_clone.get_type() == LiteralType::Integer {
-                        value_clone.as_integer().unwrap().to_string()
-                    } else {
-                        value_clone.as_boolean().unwrap().to_string()
-                    }
-                } else {
-                    "None".to_string()
+                value: match value {
+                    Some(value) => value.to_string(),
+                    None => "None".to_string(),
                 },
             });
         }
     }
 }
 
+// The tree-walker recurses on the native stack for every call, the default thread stacks
+// are too small for the call depth the limits allow.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+fn with_large_stack<T: Send + 'static>(run: impl FnOnce() -> T + Send + 'static) -> T {
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(run)
+        .unwrap()
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
 #[post("/generate", format = "json", data = "<data>")]
-pub fn generate(data: Json<Program>) -> Json<Report> {
+pub fn generate(data: Json<Program>, limits: &State<ExecutionLimits>) -> Json<Report> {
+    let data = data.into_inner();
+    let limits = limits.inner().clone();
+    Json(with_large_stack(move || generate_report(&data, &limits)))
+}
+
+fn generate_report(data: &Program, limits: &ExecutionLimits) -> Report {
     let start = Instant::now();
 
     let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+    let environment = Rc::new(RefCell::new(Environment::new()));
 
-    compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+    compile_program(
+        Rc::clone(&diagnostics),
+        Rc::clone(&environment),
+        &data.code,
+        &data.parser,
+        &data.backend,
+        limits,
+    );
 
     diagnostics.borrow_mut().print();
+    println!("{:?}", environment.borrow().get_variables());
 
     let mut report = Report::new(diagnostics.borrow().get_diagnostics());
-    report.report_symbol_table();
+    report.report_symbol_table(&environment.borrow());
 
     let end = Instant::now();
     let duration = end.duration_since(start);
     report.seconds = duration.as_secs_f64();
 
-    Json(report)
+    report
 }
 
-fn compile_program(diagnostics: Rc<RefCell<Diagnostics>>, code: &str, parser_type: &ParserType) {
-    SYMBOL_TABLE.lock().unwrap().clear();
+// Programs with errors are returned unchanged, together with the diagnostics.
+#[post("/format", format = "json", data = "<data>")]
+pub fn format(data: Json<Program>) -> Json<FormatReport> {
+    let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+    let code = format_program(Rc::clone(&diagnostics), &data.code);
+
+    let diagnostics = diagnostics.borrow().filter_type(TextType::Error);
+    Json(FormatReport {
+        code: code.unwrap_or_else(|| data.code.clone()),
+        diagnostics,
+    })
+}
+
+fn parse_program(
+    diagnostics: Rc<RefCell<Diagnostics>>,
+    code: &str,
+    parser_type: &ParserType,
+) -> Option<Box<dyn Statement>> {
+    diagnostics.borrow_mut().set_source(code);
+
+    if *parser_type == ParserType::Lr {
+        return parse_lr_program(diagnostics, code);
+    }
 
     let mut lexer = Lexer::in_memory_reader(code, Rc::clone(&diagnostics));
+    let mut parser: CustomParser = CustomParser::new(Rc::clone(&diagnostics));
+    parser.create(&mut lexer);
 
-    if diagnostics.borrow().filter_type(TextType::Error).len() == 0 {
-        let res: Option<Box<dyn Statement>>;
+    if !diagnostics.borrow().filter_type(TextType::Error).is_empty() {
+        return None;
+    }
 
-        if *parser_type == ParserType::Recursive {
-            let mut parser: CustomParser = CustomParser::new(Rc::clone(&diagnostics));
-            parser.create(&mut lexer);
-            res = Some(parser.parse());
-        } else {
-            res = Some(CompilerParser::new().parse(code).unwrap());
-        }
+    let root = parser.parse();
+
+    if diagnostics.borrow().filter_type(TextType::Error).is_empty() {
+        Some(root)
+    } else {
+        None
+    }
+}
 
-        if diagnostics.borrow().filter_type(TextType::Error).len() == 0 {
-            let mut binder = Binder::new(Rc::clone(&diagnostics));
-            let root = binder.bind_statement(res.unwrap());
+// Rustemo can only report the tokens it expected, so the source is first run through the
+// lexer of the recursive parser, which reports invalid numbers, characters, strings and
+// comments the same way for both parsers.
+fn parse_lr_program(
+    diagnostics: Rc<RefCell<Diagnostics>>,
+    code: &str,
+) -> Option<Box<dyn Statement>> {
+    let mut lexer = Lexer::in_memory_reader(code, Rc::clone(&diagnostics));
+    while !matches!(
+        lexer.next_token().kind(),
+        SyntaxKind::Eof | SyntaxKind::BadToken
+    ) {}
 
-            if diagnostics.borrow().filter_type(TextType::Error).len() == 0 {
-                let evaluator = Evaluator::new(root);
-                evaluator.evaluate();
+    if !diagnostics.borrow().filter_type(TextType::Error).is_empty() {
+        return None;
+    }
+
+    compiler_actions::set_source(code);
+    match CompilerParser::new().parse(code) {
+        Ok(root) => {
+            // The generated lexer only tries the tokens the parser expects next, so it reads
+            // a keyword in the place of a name as an identifier.
+            let keywords: Vec<_> = root
+                .get_tokens()
+                .into_iter()
+                .filter(|token| {
+                    *token.kind() == SyntaxKind::IdentifierToken
+                        && SyntaxKind::get_keyword_kind(&token.name())
+                            != SyntaxKind::IdentifierToken
+                })
+                .collect();
+            for keyword in &keywords {
+                diagnostics.borrow_mut().report_expected_tokens(
+                    keyword.name(),
+                    vec!["Identifier".to_string()],
+                    TextSpan::new(keyword.position(), keyword.length()),
+                    TextPlace::Syntax,
+                    TextType::Error,
+                );
             }
+            keywords.is_empty().then_some(root)
+        }
+        Err(error) => {
+            report_parse_error(&mut diagnostics.borrow_mut(), code, error);
+            None
         }
     }
+}
 
-    SYMBOL_TABLE
-        .lock()
-        .unwrap()
-        .retain(|key, _| key.is_global());
-    println!("{:?}", *SYMBOL_TABLE.lock().unwrap());
+// Formatting needs the whitespace kept by the recursive parser, so the LR parser is not used.
+fn format_program(diagnostics: Rc<RefCell<Diagnostics>>, code: &str) -> Option<String> {
+    let root = parse_program(diagnostics, code, &ParserType::Recursive)?;
+    Some(Formatter::new().format(root.as_ref()))
+}
+
+fn bind_program(
+    diagnostics: Rc<RefCell<Diagnostics>>,
+    environment: Rc<RefCell<Environment>>,
+    code: &str,
+    parser_type: &ParserType,
+) -> Option<(Box<dyn BoundStatement>, Vec<BoundFunction>, TypeTable)> {
+    let root = parse_program(Rc::clone(&diagnostics), code, parser_type)?;
+
+    let mut binder = Binder::new(Rc::clone(&diagnostics), environment);
+    let root = binder.bind_statement(root);
+
+    if diagnostics.borrow().filter_type(TextType::Error).is_empty() {
+        Some((root, binder.get_functions(), binder.get_types()))
+    } else {
+        None
+    }
 }
 
-#[launch]
-fn rocket() -> _ {
-    rocket::build().mount("/", routes![generate])
+fn compile_program(
+    diagnostics: Rc<RefCell<Diagnostics>>,
+    environment: Rc<RefCell<Environment>>,
+    code: &str,
+    parser_type: &ParserType,
+    backend: &BackendType,
+    limits: &ExecutionLimits,
+) {
+    let Some((root, functions, types)) = bind_program(
+        Rc::clone(&diagnostics),
+        Rc::clone(&environment),
+        code,
+        parser_type,
+    ) else {
+        return;
+    };
+
+    let result = match backend {
+        BackendType::TreeWalker => {
+            Evaluator::new(root, functions, limits.clone(), Rc::clone(&environment)).evaluate()
+        }
+        BackendType::Bytecode => {
+            let module = CodeGenerator::new().generate(root, &functions);
+            VirtualMachine::new(module, limits.clone(), Rc::clone(&environment)).run()
+        }
+        BackendType::Assembly => {
+            let program = AssemblyGenerator::new().generate(root, &functions, &types);
+            assembly::driver::execute(&program, limits, Rc::clone(&environment))
+        }
+    };
+    if let Err(error) = result {
+        diagnostics.borrow_mut().report_runtime_error(error);
+    }
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![generate, format])
+        .attach(AdHoc::config::<ExecutionLimits>())
+}
+
+// Without arguments, or with `serve`, the web server is started as before.
+fn main() {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+
+    if arguments.first().is_none_or(|command| command == "serve") {
+        if let Err(error) = rocket::execute(rocket().launch()) {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    std::process::exit(with_large_stack(move || cli::run(&arguments)));
 }
@@ -0,0 +1,279 @@
+use std::{
+    cell::RefCell,
+    io::{self, BufRead, Write},
+    rc::Rc,
+};
+
+use crate::{
+    binding::{binder::Binder, bound_statement_list::BoundStatementList},
+    cli::{print_symbol_table, report_errors},
+    environment::Environment,
+    evaluation::{evaluator::Evaluator, execution_limits::ExecutionLimits},
+    lexical_analyzer::lexer::Lexer,
+    parse_lr_program,
+    reports::{diagnostic::Diagnostic, diagnostics::Diagnostics, text_type::TextType},
+    syntax_analyzer::{parser::Parser as CustomParser, statement_list::StatementList},
+    util::{
+        expression::Expression, literals::LiteralValue, parser_type::ParserType,
+        statement::Statement, syntax_kind::SyntaxKind,
+    },
+};
+
+const HELP: &str = "Enter statements (ending with ; or }) or an expression to print its value.
+Blocks can span several lines while braces are open.
+
+Commands:
+    :vars                    Print the symbol table
+    :ast                     Toggle printing the syntax tree of each input
+    :parser recursive|lr     Parser used for statements (expressions always use recursive)
+    :help                    Print this message
+    :quit                    Exit";
+
+// The LR parser reads statements as the body of a block, so its spans are ahead of the
+// input by the opening brace.
+const LR_PREFIX: &str = "{";
+
+// Keeps the binder and the environment alive between inputs, so that declarations made
+// by one input are visible to the next.
+pub struct Repl {
+    parser_type: ParserType,
+    show_ast: bool,
+    diagnostics: Rc<RefCell<Diagnostics>>,
+    environment: Rc<RefCell<Environment>>,
+    binder: Binder,
+    limits: ExecutionLimits,
+    source: String,
+}
+
+impl Repl {
+    pub fn new(parser_type: ParserType) -> Self {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+        let binder = Binder::new(Rc::clone(&diagnostics), Rc::clone(&environment));
+
+        Self {
+            parser_type,
+            show_ast: false,
+            diagnostics,
+            environment,
+            binder,
+            limits: ExecutionLimits::default(),
+            source: String::new(),
+        }
+    }
+
+    pub fn errors(&self) -> Vec<Diagnostic> {
+        self.diagnostics.borrow().filter_type(TextType::Error)
+    }
+
+    // Runs one input and returns the value when the input is an expression. Errors are
+    // available through `errors`; an input that fails to bind leaves no declarations behind.
+    pub fn submit(&mut self, input: &str) -> Option<LiteralValue> {
+        *self.diagnostics.borrow_mut() = Diagnostics::new();
+
+        if matches!(
+            token_kinds(input).last(),
+            Some(SyntaxKind::Semicolon | SyntaxKind::CloseBrace)
+        ) {
+            self.submit_statements(input);
+            if self.parser_type == ParserType::Lr {
+                self.diagnostics.borrow_mut().shift(LR_PREFIX.len());
+            }
+            None
+        } else {
+            self.submit_expression(input)
+        }
+    }
+
+    fn submit_statements(&mut self, input: &str) {
+        let Some(statements) = self.parse_statements(input) else {
+            return;
+        };
+        if self.show_ast {
+            println!("{:#?}", statements);
+        }
+
+        let snapshot = (self.binder.clone(), self.environment.borrow().clone());
+        let root = self.binder.bind_global_statements(statements);
+        if self.has_errors() {
+            self.restore(snapshot);
+            return;
+        }
+
+        let result = Evaluator::new(
+            root,
+            self.binder.get_functions(),
+            self.limits.clone(),
+            Rc::clone(&self.environment),
+        )
+        .evaluate();
+        if let Err(error) = result {
+            self.diagnostics.borrow_mut().report_runtime_error(error);
+        }
+    }
+
+    fn submit_expression(&mut self, input: &str) -> Option<LiteralValue> {
+        let expression = self.parse_expression(input)?;
+        if self.show_ast {
+            println!("{:#?}", expression);
+        }
+
+        let snapshot = (self.binder.clone(), self.environment.borrow().clone());
+        let expression = self.binder.bind_global_expression(expression);
+        if self.has_errors() {
+            self.restore(snapshot);
+            return None;
+        }
+
+        let result = Evaluator::new(
+            Box::new(BoundStatementList::new(Vec::new())),
+            self.binder.get_functions(),
+            self.limits.clone(),
+            Rc::clone(&self.environment),
+        )
+        .evaluate_value(expression);
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.diagnostics.borrow_mut().report_runtime_error(error);
+                None
+            }
+        }
+    }
+
+    fn parse_statements(&mut self, input: &str) -> Option<Vec<Box<dyn Statement>>> {
+        if self.parser_type == ParserType::Lr {
+            // The generated parser only accepts whole programs. The line break keeps a line
+            // comment at the end of the input from swallowing the closing brace.
+            self.set_source(input);
+            let program = format!("{}{}\n}}", LR_PREFIX, input);
+
+            let root = parse_lr_program(Rc::clone(&self.diagnostics), &program)?;
+            return Some(
+                root.as_any()
+                    .downcast_ref::<StatementList>()
+                    .unwrap()
+                    .get_statements(),
+            );
+        }
+
+        let mut parser = self.create_parser(input)?;
+        let statements = parser.parse_statements();
+        (!self.has_errors()).then_some(statements)
+    }
+
+    fn parse_expression(&mut self, input: &str) -> Option<Box<dyn Expression>> {
+        let mut parser = self.create_parser(input)?;
+        let expression = parser.parse_expression_input();
+        (!self.has_errors()).then_some(expression)
+    }
+
+    fn create_parser(&mut self, input: &str) -> Option<CustomParser> {
+        self.set_source(input);
+
+        let mut lexer = Lexer::in_memory_reader(input, Rc::clone(&self.diagnostics));
+        let mut parser = CustomParser::new(Rc::clone(&self.diagnostics));
+        parser.create(&mut lexer);
+
+        (!self.has_errors()).then_some(parser)
+    }
+
+    fn set_source(&mut self, source: &str) {
+        self.source = source.to_string();
+        self.diagnostics.borrow_mut().set_source(source);
+    }
+
+    fn has_errors(&self) -> bool {
+        !self.errors().is_empty()
+    }
+
+    fn restore(&mut self, (binder, environment): (Binder, Environment)) {
+        self.binder = binder;
+        *self.environment.borrow_mut() = environment;
+    }
+
+    // Handles a `:` command and returns false when the REPL should exit.
+    fn command(&mut self, command: &str) -> bool {
+        let arguments: Vec<&str> = command.split_whitespace().collect();
+
+        match arguments.as_slice() {
+            [":quit"] | [":q"] => return false,
+            [":vars"] => print_symbol_table(&self.environment.borrow()),
+            [":ast"] => {
+                self.show_ast = !self.show_ast;
+                println!("Syntax trees {}.", if self.show_ast { "on" } else { "off" });
+            }
+            [":parser", "recursive"] => self.parser_type = ParserType::Recursive,
+            [":parser", "lr"] => self.parser_type = ParserType::Lr,
+            [":parser"] => println!("{:?}", self.parser_type),
+            [":help"] => println!("{}", HELP),
+            _ => eprintln!("Unknown command {}. Type :help for a list.", command),
+        }
+        true
+    }
+
+    pub fn run(&mut self) {
+        let stdin = io::stdin();
+        let mut lines = stdin.lock().lines();
+        let mut input = String::new();
+
+        loop {
+            print!("{}", if input.is_empty() { "> " } else { "... " });
+            let _ = io::stdout().flush();
+
+            let Some(Ok(line)) = lines.next() else {
+                break;
+            };
+
+            if input.is_empty() && line.trim_start().starts_with(':') {
+                if !self.command(line.trim()) {
+                    break;
+                }
+                continue;
+            }
+
+            input.push_str(&line);
+            input.push('\n');
+            if open_braces(&input) > 0 {
+                continue;
+            }
+
+            let code = std::mem::take(&mut input);
+            if code.trim().is_empty() {
+                continue;
+            }
+            if let Some(value) = self.submit(&code) {
+                println!("{}", value);
+            }
+            report_errors(&self.diagnostics.borrow(), &self.source);
+        }
+    }
+}
+
+// Counts braces that are still open, ignoring the ones inside strings and comments.
+fn open_braces(text: &str) -> i32 {
+    token_kinds(text)
+        .iter()
+        .map(|kind| match kind {
+            SyntaxKind::OpenBrace => 1,
+            SyntaxKind::CloseBrace => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+// The kinds of the tokens in the text, leaving out whitespace and comments. Lexical errors
+// are reported when the input is submitted.
+fn token_kinds(text: &str) -> Vec<SyntaxKind> {
+    let mut lexer = Lexer::in_memory_reader(text, Rc::new(RefCell::new(Diagnostics::new())));
+    let mut kinds = Vec::new();
+
+    loop {
+        match *lexer.next_token().kind() {
+            SyntaxKind::Eof | SyntaxKind::BadToken => break,
+            SyntaxKind::WhiteSpace | SyntaxKind::LineComment | SyntaxKind::BlockComment => {}
+            kind => kinds.push(kind),
+        }
+    }
+    kinds
+}
@@ -11,18 +11,40 @@ pub struct Diagnostic {
     span: TextSpan,
     place: TextPlace,
     kind: TextType,
+    line: usize,
+    column: usize,
 }
 
 impl Diagnostic {
-    pub fn new(message: String, span: TextSpan, place: TextPlace, kind: TextType) -> Self {
+    pub fn new(
+        message: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+        (line, column): (usize, usize),
+    ) -> Self {
         Self {
             message,
             span,
             place,
             kind,
+            line,
+            column,
         }
     }
 
+    pub fn get_span(&self) -> &TextSpan {
+        &self.span
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
     pub fn get_type(&self) -> &TextType {
         &self.kind
     }
@@ -40,8 +62,8 @@ impl Debug for Diagnostic {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
             f,
-            "{:?}-{:?}-Position:{:?}: {:?}",
-            self.kind, self.place, self.span, self.message
+            "{:?}-{:?}-Position:{}:{}: {:?}",
+            self.kind, self.place, self.line, self.column, self.message
         )
     }
 }
@@ -1,37 +1,76 @@
 use rocket::serde::Serialize;
 
-use crate::util::{literals::LiteralType, syntax_kind::SyntaxKind};
+use crate::{
+    evaluation::runtime_error::RuntimeError,
+    util::{literals::LiteralType, syntax_kind::SyntaxKind},
+};
 
 use super::{
-    diagnostic::Diagnostic, text_place::TextPlace, text_span::TextSpan, text_type::TextType,
+    diagnostic::Diagnostic, renderer::render, source_map::SourceMap, text_place::TextPlace,
+    text_span::TextSpan, text_type::TextType,
 };
 
 #[derive(Serialize, Clone)]
 #[serde(crate = "rocket::serde")]
 pub struct Diagnostics {
     pub diagnostics: Vec<Diagnostic>,
+    #[serde(skip)]
+    source_map: Option<SourceMap>,
 }
 
 impl Diagnostics {
     pub fn new() -> Self {
         Self {
             diagnostics: Vec::new(),
+            source_map: None,
         }
     }
 
+    pub fn set_source(&mut self, text: &str) {
+        self.source_map = Some(SourceMap::new(text));
+    }
+
     pub fn get_diagnostics(&self) -> Vec<Diagnostic> {
         self.diagnostics.clone()
     }
 
     pub fn print(&self) {
         for diagnostic in &self.diagnostics {
-            println!("{:?}", diagnostic)
+            match &self.source_map {
+                Some(source_map) if *diagnostic.get_type() == TextType::Error => {
+                    println!("{}\n", render(diagnostic, source_map))
+                }
+                _ => println!("{:?}", diagnostic),
+            }
         }
     }
 
     fn report(&mut self, message: String, span: TextSpan, place: TextPlace, kind: TextType) {
+        let location = match &self.source_map {
+            Some(source_map) => source_map.location(span.start()),
+            None => (1, span.start() + 1),
+        };
         self.diagnostics
-            .push(Diagnostic::new(message, span, place, kind))
+            .push(Diagnostic::new(message, span, place, kind, location))
+    }
+
+    // Moves every diagnostic back by the length of a prefix that was parsed in front of the
+    // source, and locates it again in the source.
+    pub fn shift(&mut self, prefix: usize) {
+        for diagnostic in self.diagnostics.iter_mut() {
+            let start = diagnostic.get_span().start().saturating_sub(prefix);
+            let location = match &self.source_map {
+                Some(source_map) => source_map.location(start),
+                None => (1, start + 1),
+            };
+            *diagnostic = Diagnostic::new(
+                diagnostic.get_message().to_string(),
+                TextSpan::new(start, diagnostic.get_span().length()),
+                diagnostic.get_place().clone(),
+                diagnostic.get_type().clone(),
+                location,
+            );
+        }
     }
 
     pub fn filter_type(&self, text_type: TextType) -> Vec<Diagnostic> {
@@ -153,6 +192,26 @@ impl Diagnostics {
         );
     }
 
+    pub fn report_expected_tokens(
+        &mut self,
+        value: String,
+        expected: Vec<String>,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        let expected = match expected.len() {
+            1 => expected[0].clone(),
+            _ => format!("one of {}", expected.join(", ")),
+        };
+        self.report(
+            format!("Unexpected token {:?}, expected {}.", value, expected),
+            span,
+            place,
+            kind,
+        );
+    }
+
     pub fn report_undefined_binary_operator(
         &mut self,
         operator: SyntaxKind,
@@ -173,6 +232,173 @@ impl Diagnostics {
         );
     }
 
+    pub fn report_mismatched_branch_types(
+        &mut self,
+        when_true: LiteralType,
+        when_false: LiteralType,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!(
+                "Branches of the conditional expression have different types {:?} and {:?}.",
+                when_true, when_false
+            ),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_mismatched_element_types(
+        &mut self,
+        first: LiteralType,
+        element: LiteralType,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!(
+                "Elements of the array literal have different types {:?} and {:?}.",
+                first, element
+            ),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_empty_array(&mut self, span: TextSpan, place: TextPlace, kind: TextType) {
+        self.report(
+            "Array literal must have at least one element.".to_string(),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_not_an_array(
+        &mut self,
+        literal_type: LiteralType,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Type {:?} is not an array.", literal_type),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_not_a_struct(
+        &mut self,
+        literal_type: LiteralType,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Type {:?} is not a struct.", literal_type),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_undefined_type(
+        &mut self,
+        name: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(format!("Type {} is undefined.", name), span, place, kind);
+    }
+
+    pub fn report_struct_already_declared(
+        &mut self,
+        name: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Struct {} is already declared.", name),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_field_already_declared(
+        &mut self,
+        name: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Field {} is already declared.", name),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_undefined_field(
+        &mut self,
+        struct_name: String,
+        field: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Struct {} has no field {}.", struct_name, field),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_missing_field(
+        &mut self,
+        struct_name: String,
+        field: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!(
+                "Field {} of struct {} is not initialized.",
+                field, struct_name
+            ),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_field_initialized_twice(
+        &mut self,
+        field: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Field {} is initialized more than once.", field),
+            span,
+            place,
+            kind,
+        );
+    }
+
     pub fn report_undefined_unary_operator(
         &mut self,
         operator: SyntaxKind,
@@ -210,4 +436,203 @@ impl Diagnostics {
             kind,
         )
     }
+
+    pub fn report_undefined_function(
+        &mut self,
+        text: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Function {} is undefined.", text),
+            span,
+            place,
+            kind,
+        );
+    }
+
+    pub fn report_wrong_argument_count(
+        &mut self,
+        text: String,
+        expected: usize,
+        actual: usize,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!(
+                "Function {} requires {} arguments but was given {}.",
+                text, expected, actual
+            ),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_captured_variable(
+        &mut self,
+        text: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!(
+                "Local variable {} of an enclosing scope can't be used inside a function.",
+                text
+            ),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_return_outside_function(
+        &mut self,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            "Return statement is only allowed inside a function.".to_string(),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_outside_loop(
+        &mut self,
+        statement: &str,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("{} statement is only allowed inside a loop.", statement),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_function_without_return_value(
+        &mut self,
+        text: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("Function {} doesn't return a value.", text),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_mismatched_return_type(
+        &mut self,
+        text: String,
+        return_type: LiteralType,
+        actual_type: LiteralType,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!(
+                "Function {} returns {:?} but this expression is {:?}.",
+                text, return_type, actual_type
+            ),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_unterminated_string(&mut self, span: TextSpan, place: TextPlace, kind: TextType) {
+        self.report(
+            "Unterminated string literal.".to_string(),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_unterminated_comment(
+        &mut self,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report("Unterminated block comment.".to_string(), span, place, kind)
+    }
+
+    pub fn report_invalid_escape_sequence(
+        &mut self,
+        text: String,
+        span: TextSpan,
+        place: TextPlace,
+        kind: TextType,
+    ) {
+        self.report(
+            format!("The string {} contains an invalid escape sequence.", text),
+            span,
+            place,
+            kind,
+        )
+    }
+
+    pub fn report_runtime_error(&mut self, error: RuntimeError) {
+        let (message, span) = match error {
+            RuntimeError::DivisionByZero(span) => ("Division by zero.".to_string(), span),
+            RuntimeError::IntegerOverflow(span) => ("Integer overflow.".to_string(), span),
+            RuntimeError::NegativeExponent(span) => {
+                ("Negative exponent in an integer power.".to_string(), span)
+            }
+            RuntimeError::InvalidShift(span) => (
+                "Shift amount is outside of the range 0 to 31.".to_string(),
+                span,
+            ),
+            RuntimeError::IndexOutOfBounds(index, length, span) => (
+                format!(
+                    "Index {} is out of bounds for an array of length {}.",
+                    index, length
+                ),
+                span,
+            ),
+            RuntimeError::UninitializedVariable(name, span) => (
+                format!("Variable {} is used before it is initialized.", name),
+                span,
+            ),
+            RuntimeError::MissingReturnValue(name, span) => (
+                format!("Function {} finished without returning a value.", name),
+                span,
+            ),
+            RuntimeError::StepLimitExceeded(steps) => (
+                format!("Execution was aborted after exceeding {} steps.", steps),
+                TextSpan::new(0, 0),
+            ),
+            RuntimeError::TimeLimitExceeded(milliseconds) => (
+                format!("Execution was aborted after exceeding {} ms.", milliseconds),
+                TextSpan::new(0, 0),
+            ),
+            RuntimeError::CallDepthExceeded(depth, span) => (
+                format!(
+                    "Execution was aborted after exceeding a call depth of {}.",
+                    depth
+                ),
+                span,
+            ),
+            RuntimeError::NativeExecutionFailed(reason) => (
+                format!("Native execution failed: {}.", reason),
+                TextSpan::new(0, 0),
+            ),
+        };
+        self.report(message, span, TextPlace::Runtime, TextType::Error);
+    }
 }
@@ -1,5 +1,8 @@
 pub mod diagnostic;
 pub mod diagnostics;
+pub mod parse_error;
+pub mod renderer;
+pub mod source_map;
 pub mod text_place;
 pub mod text_span;
 pub mod text_type;
@@ -0,0 +1,62 @@
+use rustemo::{Error, Position};
+
+use super::{
+    diagnostics::Diagnostics, source_map::SourceMap, text_place::TextPlace, text_span::TextSpan,
+    text_type::TextType,
+};
+
+// Turns an error from the Rustemo parser into a syntax diagnostic. Rustemo only gives a
+// line-based position and a message ending with "Expected A." or "Expected one of A, B.".
+pub fn report_parse_error(diagnostics: &mut Diagnostics, code: &str, error: Error) {
+    let (message, location) = match error {
+        Error::Error {
+            message, location, ..
+        } => (message, location),
+        Error::IOError(error) => (error.to_string(), None),
+    };
+
+    let source_map = SourceMap::new(code);
+    let offset = match location.map(|location| location.start) {
+        Some(Position::LineBased(position)) => source_map.offset(position.line, position.column),
+        Some(Position::Position(position)) => code[..position.min(code.len())].chars().count(),
+        None => 0,
+    };
+
+    let found = found_token(code, offset);
+    let length = if found == "EOF" {
+        0
+    } else {
+        found.chars().count()
+    };
+    diagnostics.report_expected_tokens(
+        found,
+        expected_tokens(&message),
+        TextSpan::new(offset, length),
+        TextPlace::Syntax,
+        TextType::Error,
+    );
+}
+
+fn expected_tokens(message: &str) -> Vec<String> {
+    let expected = match message.rsplit_once("Expected ") {
+        Some((_, expected)) => expected.trim_end_matches('.'),
+        None => return Vec::new(),
+    };
+    expected
+        .trim_start_matches("one of ")
+        .split(", ")
+        // Keyword terminals can't share the name of a Rust keyword, e.g. IfKeyword.
+        .map(|token| token.trim_end_matches("Keyword").to_string())
+        .collect()
+}
+
+fn found_token(code: &str, offset: usize) -> String {
+    let mut rest = code.chars().skip(offset).peekable();
+    match rest.peek() {
+        None => "EOF".to_string(),
+        Some(first) if first.is_alphanumeric() || *first == '_' => rest
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect(),
+        Some(first) => first.to_string(),
+    }
+}
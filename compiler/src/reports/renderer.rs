@@ -0,0 +1,42 @@
+use colored::*;
+
+use super::{diagnostic::Diagnostic, source_map::SourceMap, text_type::TextType};
+
+// Renders a diagnostic the way rustc does: a header, the offending line and carets
+// under the span.
+pub fn render(diagnostic: &Diagnostic, source_map: &SourceMap) -> String {
+    let (line, column) = (diagnostic.get_line(), diagnostic.get_column());
+    let text = source_map.line(line);
+    let gutter = " ".repeat(line.to_string().len());
+
+    let available = text.chars().count().saturating_sub(column - 1);
+    let width = diagnostic.get_span().length().min(available).max(1);
+
+    let header = match diagnostic.get_type() {
+        TextType::Error => format!("error[{:?}]", diagnostic.get_place()).red().bold(),
+        TextType::Info => format!("info[{:?}]", diagnostic.get_place()).cyan().bold(),
+    };
+    let carets = "^".repeat(width);
+
+    format!(
+        "{}: {}\n{}{} {}:{}\n{} {}\n{} {} {}\n{} {} {}{}",
+        header,
+        diagnostic.get_message().bold(),
+        gutter,
+        "-->".blue().bold(),
+        line,
+        column,
+        gutter,
+        "|".blue().bold(),
+        line.to_string().blue().bold(),
+        "|".blue().bold(),
+        text,
+        gutter,
+        "|".blue().bold(),
+        " ".repeat(column - 1),
+        match diagnostic.get_type() {
+            TextType::Error => carets.red().bold(),
+            TextType::Info => carets.cyan().bold(),
+        }
+    )
+}
@@ -0,0 +1,69 @@
+// Converts character offsets from a `TextSpan` into 1-based lines and columns.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    lines: Vec<String>,
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    pub fn new(text: &str) -> Self {
+        let mut lines = Vec::new();
+        let mut line_starts = Vec::new();
+        let mut start = 0;
+
+        for line in text.split('\n') {
+            line_starts.push(start);
+            start += line.chars().count() + 1;
+            lines.push(line.trim_end_matches('\r').to_string());
+        }
+
+        Self { lines, line_starts }
+    }
+
+    pub fn location(&self, offset: usize) -> (usize, usize) {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        (line + 1, offset - self.line_starts[line] + 1)
+    }
+
+    pub fn line(&self, line: usize) -> &str {
+        &self.lines[line - 1]
+    }
+
+    // Converts a 1-based line and a byte column within it back into a character offset.
+    pub fn offset(&self, line: usize, byte_column: usize) -> usize {
+        let index = line.clamp(1, self.lines.len()) - 1;
+        let text = &self.lines[index];
+        let column = text
+            .char_indices()
+            .take_while(|(byte, _)| *byte < byte_column)
+            .count();
+        self.line_starts[index] + column
+    }
+
+    // Language server positions use 0-based lines and UTF-16 code units within the line.
+    pub fn utf16_position(&self, offset: usize) -> (usize, usize) {
+        let (line, column) = self.location(offset);
+        let character = self.lines[line - 1]
+            .chars()
+            .take(column - 1)
+            .map(char::len_utf16)
+            .sum();
+        (line - 1, character)
+    }
+
+    pub fn utf16_offset(&self, line: usize, character: usize) -> usize {
+        let index = line.min(self.lines.len() - 1);
+        let mut units = 0;
+        let column = self.lines[index]
+            .chars()
+            .take_while(|c| {
+                units += c.len_utf16();
+                units <= character
+            })
+            .count();
+        self.line_starts[index] + column
+    }
+}
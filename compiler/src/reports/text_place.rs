@@ -6,4 +6,5 @@ pub enum TextPlace {
     Syntax,
     Lexical,
     Semantic,
+    Runtime,
 }
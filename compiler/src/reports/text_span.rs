@@ -14,9 +14,22 @@ impl TextSpan {
         Self { start, length }
     }
 
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
     fn end(&self) -> usize {
         self.start + self.length
     }
+
+    // The end is included, so a cursor placed right after a name still points at it.
+    pub fn contains(&self, offset: usize) -> bool {
+        self.start <= offset && offset <= self.end()
+    }
 }
 
 impl Debug for TextSpan {
@@ -0,0 +1,86 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression,
+    literals::{LiteralType, LiteralValue},
+    syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `[element, element, ...]`
+#[derive(Debug)]
+pub struct ArrayLiteralExpressionSyntax {
+    open_bracket: SyntaxToken,
+    elements: Vec<Box<dyn Expression>>,
+    commas: Vec<SyntaxToken>,
+    close_bracket: SyntaxToken,
+}
+
+impl Clone for ArrayLiteralExpressionSyntax {
+    fn clone(&self) -> Self {
+        ArrayLiteralExpressionSyntax {
+            open_bracket: self.open_bracket.clone(),
+            elements: self.elements.clone(),
+            commas: self.commas.clone(),
+            close_bracket: self.close_bracket.clone(),
+        }
+    }
+}
+
+impl ArrayLiteralExpressionSyntax {
+    pub fn new(
+        open_bracket: SyntaxToken,
+        elements: Vec<Box<dyn Expression>>,
+        commas: Vec<SyntaxToken>,
+        close_bracket: SyntaxToken,
+    ) -> Self {
+        Self {
+            open_bracket,
+            elements,
+            commas,
+            close_bracket,
+        }
+    }
+
+    pub fn get_open_bracket(&self) -> &SyntaxToken {
+        &self.open_bracket
+    }
+
+    pub fn get_elements(&self) -> Vec<Box<dyn Expression>> {
+        self.elements.clone()
+    }
+}
+
+impl Expression for ArrayLiteralExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ArrayLiteralExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        self.elements.clone()
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        todo!()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        todo!()
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.open_bracket.clone()];
+        for (index, element) in self.elements.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.commas[index - 1].clone());
+            }
+            tokens.extend(element.get_tokens());
+        }
+        tokens.push(self.close_bracket.clone());
+        tokens
+    }
+}
@@ -43,6 +43,11 @@ impl Assignment {
         &self.variable
     }
 
+    // `=` or the operator of a compound assignment such as `+=`.
+    pub fn get_equals(&self) -> &SyntaxToken {
+        &self.equals
+    }
+
     pub fn get_expression(&self) -> Box<dyn Expression> {
         self.expression.clone()
     }
@@ -56,4 +61,11 @@ impl Statement for Assignment {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::Assignment
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.variable.clone(), self.equals.clone()];
+        tokens.extend(self.expression.get_tokens());
+        tokens.push(self.semi_colon.clone());
+        tokens
+    }
 }
@@ -71,7 +71,14 @@ impl Expression for BinaryExpressionSyntax {
         todo!()
     }
 
-    fn get_type(&self) -> &LiteralType {
+    fn get_type(&self) -> LiteralType {
         todo!()
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = self.left.get_tokens();
+        tokens.push(self.operator.clone());
+        tokens.extend(self.right.get_tokens());
+        tokens
+    }
 }
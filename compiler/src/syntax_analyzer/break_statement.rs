@@ -0,0 +1,36 @@
+use std::any::Any;
+
+use crate::util::{statement::Statement, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone)]
+pub struct BreakStatement {
+    break_token: SyntaxToken,
+    semi_colon: SyntaxToken,
+}
+
+impl BreakStatement {
+    pub fn new(break_token: SyntaxToken, semi_colon: SyntaxToken) -> Self {
+        Self {
+            break_token,
+            semi_colon,
+        }
+    }
+
+    pub fn get_break_token(&self) -> &SyntaxToken {
+        &self.break_token
+    }
+}
+
+impl Statement for BreakStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::BreakStatement
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        vec![self.break_token.clone(), self.semi_colon.clone()]
+    }
+}
@@ -0,0 +1,91 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression,
+    literals::{LiteralType, LiteralValue},
+    syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug)]
+pub struct CallExpressionSyntax {
+    identifier: SyntaxToken,
+    open_parenthesis: SyntaxToken,
+    arguments: Vec<Box<dyn Expression>>,
+    commas: Vec<SyntaxToken>,
+    close_parenthesis: SyntaxToken,
+}
+
+impl Clone for CallExpressionSyntax {
+    fn clone(&self) -> Self {
+        CallExpressionSyntax {
+            identifier: self.identifier.clone(),
+            open_parenthesis: self.open_parenthesis.clone(),
+            arguments: self.arguments.clone(),
+            commas: self.commas.clone(),
+            close_parenthesis: self.close_parenthesis.clone(),
+        }
+    }
+}
+
+impl CallExpressionSyntax {
+    pub fn new(
+        identifier: SyntaxToken,
+        open_parenthesis: SyntaxToken,
+        arguments: Vec<Box<dyn Expression>>,
+        commas: Vec<SyntaxToken>,
+        close_parenthesis: SyntaxToken,
+    ) -> Self {
+        Self {
+            identifier,
+            open_parenthesis,
+            arguments,
+            commas,
+            close_parenthesis,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_arguments(&self) -> Vec<Box<dyn Expression>> {
+        self.arguments.clone()
+    }
+}
+
+impl Expression for CallExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::CallExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        let mut children = vec![Box::new(self.identifier.clone()) as Box<dyn Expression>];
+        children.extend(self.arguments.clone());
+        children
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        todo!()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        todo!()
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.identifier.clone(), self.open_parenthesis.clone()];
+        for (index, argument) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.commas[index - 1].clone());
+            }
+            tokens.extend(argument.get_tokens());
+        }
+        tokens.push(self.close_parenthesis.clone());
+        tokens
+    }
+}
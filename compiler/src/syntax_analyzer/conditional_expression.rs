@@ -0,0 +1,110 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression,
+    literals::{LiteralType, LiteralValue},
+    syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `if (condition) when_true else when_false` used as an expression.
+#[derive(Debug)]
+pub struct ConditionalExpressionSyntax {
+    if_token: SyntaxToken,
+    open_parenthesis: SyntaxToken,
+    condition: Box<dyn Expression>,
+    close_parenthesis: SyntaxToken,
+    when_true: Box<dyn Expression>,
+    else_token: SyntaxToken,
+    when_false: Box<dyn Expression>,
+}
+
+impl Clone for ConditionalExpressionSyntax {
+    fn clone(&self) -> Self {
+        ConditionalExpressionSyntax {
+            if_token: self.if_token.clone(),
+            open_parenthesis: self.open_parenthesis.clone(),
+            condition: self.condition.clone(),
+            close_parenthesis: self.close_parenthesis.clone(),
+            when_true: self.when_true.clone(),
+            else_token: self.else_token.clone(),
+            when_false: self.when_false.clone(),
+        }
+    }
+}
+
+impl ConditionalExpressionSyntax {
+    pub fn new(
+        if_token: SyntaxToken,
+        open_parenthesis: SyntaxToken,
+        condition: Box<dyn Expression>,
+        close_parenthesis: SyntaxToken,
+        when_true: Box<dyn Expression>,
+        else_token: SyntaxToken,
+        when_false: Box<dyn Expression>,
+    ) -> Self {
+        Self {
+            if_token,
+            open_parenthesis,
+            condition,
+            close_parenthesis,
+            when_true,
+            else_token,
+            when_false,
+        }
+    }
+
+    pub fn get_if_token(&self) -> &SyntaxToken {
+        &self.if_token
+    }
+
+    pub fn get_condition(&self) -> Box<dyn Expression> {
+        self.condition.clone()
+    }
+
+    pub fn get_when_true(&self) -> Box<dyn Expression> {
+        self.when_true.clone()
+    }
+
+    pub fn get_when_false(&self) -> Box<dyn Expression> {
+        self.when_false.clone()
+    }
+}
+
+impl Expression for ConditionalExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ConditionalExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        vec![
+            Box::new(self.if_token.clone()) as Box<dyn Expression>,
+            self.condition.clone(),
+            self.when_true.clone(),
+            Box::new(self.else_token.clone()) as Box<dyn Expression>,
+            self.when_false.clone(),
+        ]
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        todo!()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        todo!()
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.if_token.clone(), self.open_parenthesis.clone()];
+        tokens.extend(self.condition.get_tokens());
+        tokens.push(self.close_parenthesis.clone());
+        tokens.extend(self.when_true.get_tokens());
+        tokens.push(self.else_token.clone());
+        tokens.extend(self.when_false.get_tokens());
+        tokens
+    }
+}
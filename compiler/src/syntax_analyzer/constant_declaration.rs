@@ -60,4 +60,15 @@ impl Statement for ConstantDeclaration {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::ConstantDeclaration
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![
+            self.const_token.clone(),
+            self.variable.clone(),
+            self.equals.clone(),
+        ];
+        tokens.extend(self.expression.get_tokens());
+        tokens.push(self.semi_colon.clone());
+        tokens
+    }
 }
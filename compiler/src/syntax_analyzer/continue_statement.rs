@@ -0,0 +1,36 @@
+use std::any::Any;
+
+use crate::util::{statement::Statement, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
+
+#[derive(Debug, Clone)]
+pub struct ContinueStatement {
+    continue_token: SyntaxToken,
+    semi_colon: SyntaxToken,
+}
+
+impl ContinueStatement {
+    pub fn new(continue_token: SyntaxToken, semi_colon: SyntaxToken) -> Self {
+        Self {
+            continue_token,
+            semi_colon,
+        }
+    }
+
+    pub fn get_continue_token(&self) -> &SyntaxToken {
+        &self.continue_token
+    }
+}
+
+impl Statement for ContinueStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ContinueStatement
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        vec![self.continue_token.clone(), self.semi_colon.clone()]
+    }
+}
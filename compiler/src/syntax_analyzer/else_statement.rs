@@ -38,4 +38,10 @@ impl Statement for ElseStatement {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::ElseStatement
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.else_token.clone()];
+        tokens.extend(self.else_statement.get_tokens());
+        tokens
+    }
 }
@@ -0,0 +1,53 @@
+use std::any::Any;
+
+use crate::util::expression::Expression;
+use crate::util::literals::{LiteralType, LiteralValue};
+use crate::util::syntax_kind::SyntaxKind;
+use crate::util::syntax_token::SyntaxToken;
+
+// Stands in for an expression that could not be parsed, the token is where it was expected.
+#[derive(Debug)]
+pub struct ErrorExpressionSyntax {
+    token: SyntaxToken,
+}
+
+impl ErrorExpressionSyntax {
+    pub fn new(token: SyntaxToken) -> Self {
+        Self { token }
+    }
+}
+
+impl Clone for ErrorExpressionSyntax {
+    fn clone(&self) -> Self {
+        ErrorExpressionSyntax {
+            token: self.token.clone(),
+        }
+    }
+}
+
+impl Expression for ErrorExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ErrorExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        vec![Box::new(self.token.clone()) as Box<dyn Expression>]
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        self.token.get_value().clone()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        self.token.get_type()
+    }
+
+    // The token is not consumed, it belongs to whatever the parser reads next.
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,37 @@
+use std::any::Any;
+
+use crate::util::{statement::Statement, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
+
+// Tokens skipped by the parser while recovering from a syntax error.
+#[derive(Debug)]
+pub struct ErrorStatement {
+    tokens: Vec<SyntaxToken>,
+}
+
+impl Clone for ErrorStatement {
+    fn clone(&self) -> Self {
+        ErrorStatement {
+            tokens: self.tokens.clone(),
+        }
+    }
+}
+
+impl ErrorStatement {
+    pub fn new(tokens: Vec<SyntaxToken>) -> Self {
+        Self { tokens }
+    }
+}
+
+impl Statement for ErrorStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ErrorStatement
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        self.tokens.clone()
+    }
+}
@@ -0,0 +1,50 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression, statement::Statement, syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug)]
+pub struct ExpressionStatement {
+    expression: Box<dyn Expression>,
+    semi_colon: SyntaxToken,
+}
+
+impl Clone for ExpressionStatement {
+    fn clone(&self) -> Self {
+        ExpressionStatement {
+            expression: self.expression.clone(),
+            semi_colon: self.semi_colon.clone(),
+        }
+    }
+}
+
+impl ExpressionStatement {
+    pub fn new(expression: Box<dyn Expression>, semi_colon: SyntaxToken) -> Self {
+        Self {
+            expression,
+            semi_colon,
+        }
+    }
+
+    pub fn get_expression(&self) -> Box<dyn Expression> {
+        self.expression.clone()
+    }
+}
+
+impl Statement for ExpressionStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ExpressionStatement
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = self.expression.get_tokens();
+        tokens.push(self.semi_colon.clone());
+        tokens
+    }
+}
@@ -0,0 +1,88 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression, statement::Statement, syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `s.field = value;` or a compound assignment such as `s.field += value;`.
+#[derive(Debug)]
+pub struct FieldAssignment {
+    variable: SyntaxToken,
+    dot: SyntaxToken,
+    field: SyntaxToken,
+    equals: SyntaxToken,
+    expression: Box<dyn Expression>,
+    semi_colon: SyntaxToken,
+}
+
+impl Clone for FieldAssignment {
+    fn clone(&self) -> Self {
+        FieldAssignment {
+            variable: self.variable.clone(),
+            dot: self.dot.clone(),
+            field: self.field.clone(),
+            equals: self.equals.clone(),
+            expression: self.expression.clone(),
+            semi_colon: self.semi_colon.clone(),
+        }
+    }
+}
+
+impl FieldAssignment {
+    pub fn new(
+        variable: SyntaxToken,
+        dot: SyntaxToken,
+        field: SyntaxToken,
+        equals: SyntaxToken,
+        expression: Box<dyn Expression>,
+        semi_colon: SyntaxToken,
+    ) -> Self {
+        Self {
+            variable,
+            dot,
+            field,
+            equals,
+            expression,
+            semi_colon,
+        }
+    }
+
+    pub fn get_variable(&self) -> &SyntaxToken {
+        &self.variable
+    }
+
+    pub fn get_field(&self) -> &SyntaxToken {
+        &self.field
+    }
+
+    pub fn get_equals(&self) -> &SyntaxToken {
+        &self.equals
+    }
+
+    pub fn get_expression(&self) -> Box<dyn Expression> {
+        self.expression.clone()
+    }
+}
+
+impl Statement for FieldAssignment {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::FieldAssignment
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![
+            self.variable.clone(),
+            self.dot.clone(),
+            self.field.clone(),
+            self.equals.clone(),
+        ];
+        tokens.extend(self.expression.get_tokens());
+        tokens.push(self.semi_colon.clone());
+        tokens
+    }
+}
@@ -0,0 +1,73 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression,
+    literals::{LiteralType, LiteralValue},
+    syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `structure.field`
+#[derive(Debug)]
+pub struct FieldExpressionSyntax {
+    structure: Box<dyn Expression>,
+    dot: SyntaxToken,
+    field: SyntaxToken,
+}
+
+impl Clone for FieldExpressionSyntax {
+    fn clone(&self) -> Self {
+        FieldExpressionSyntax {
+            structure: self.structure.clone(),
+            dot: self.dot.clone(),
+            field: self.field.clone(),
+        }
+    }
+}
+
+impl FieldExpressionSyntax {
+    pub fn new(structure: Box<dyn Expression>, dot: SyntaxToken, field: SyntaxToken) -> Self {
+        Self {
+            structure,
+            dot,
+            field,
+        }
+    }
+
+    pub fn get_structure(&self) -> Box<dyn Expression> {
+        self.structure.clone()
+    }
+
+    pub fn get_field(&self) -> &SyntaxToken {
+        &self.field
+    }
+}
+
+impl Expression for FieldExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::FieldExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        vec![self.structure.clone()]
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        todo!()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        todo!()
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = self.structure.get_tokens();
+        tokens.push(self.dot.clone());
+        tokens.push(self.field.clone());
+        tokens
+    }
+}
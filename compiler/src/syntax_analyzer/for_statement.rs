@@ -84,4 +84,19 @@ impl Statement for ForStatement {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::ForStatement
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![
+            self.for_token.clone(),
+            self.open_parenthesis.clone(),
+            self.identifier.clone(),
+            self.equals.clone(),
+        ];
+        tokens.extend(self.lower_bound.get_tokens());
+        tokens.push(self.to_token.clone());
+        tokens.extend(self.upper_bound.get_tokens());
+        tokens.push(self.close_parenthesis.clone());
+        tokens.extend(self.body.get_tokens());
+        tokens
+    }
 }
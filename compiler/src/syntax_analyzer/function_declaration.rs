@@ -0,0 +1,137 @@
+use std::any::Any;
+
+use crate::util::{statement::Statement, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
+
+use super::type_clause::TypeClause;
+
+// `name: type`, or just `name` for an `int` parameter
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    identifier: SyntaxToken,
+    type_clause: Option<TypeClause>,
+}
+
+impl Parameter {
+    pub fn new(identifier: SyntaxToken, type_clause: Option<TypeClause>) -> Self {
+        Self {
+            identifier,
+            type_clause,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_type_clause(&self) -> &Option<TypeClause> {
+        &self.type_clause
+    }
+
+    pub fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.identifier.clone()];
+        if let Some(type_clause) = &self.type_clause {
+            tokens.extend(type_clause.get_tokens());
+        }
+        tokens
+    }
+}
+
+// `fn name(parameter: type, ...): type { ... }`, a function without a return type returns an
+// `int` when its body has a return statement and nothing otherwise.
+#[derive(Debug)]
+pub struct FunctionDeclaration {
+    fn_token: SyntaxToken,
+    identifier: SyntaxToken,
+    open_parenthesis: SyntaxToken,
+    parameters: Vec<Parameter>,
+    commas: Vec<SyntaxToken>,
+    close_parenthesis: SyntaxToken,
+    return_type: Option<TypeClause>,
+    body: Box<dyn Statement>,
+}
+
+impl Clone for FunctionDeclaration {
+    fn clone(&self) -> Self {
+        FunctionDeclaration {
+            fn_token: self.fn_token.clone(),
+            identifier: self.identifier.clone(),
+            open_parenthesis: self.open_parenthesis.clone(),
+            parameters: self.parameters.clone(),
+            commas: self.commas.clone(),
+            close_parenthesis: self.close_parenthesis.clone(),
+            return_type: self.return_type.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+
+impl FunctionDeclaration {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fn_token: SyntaxToken,
+        identifier: SyntaxToken,
+        open_parenthesis: SyntaxToken,
+        parameters: Vec<Parameter>,
+        commas: Vec<SyntaxToken>,
+        close_parenthesis: SyntaxToken,
+        return_type: Option<TypeClause>,
+        body: Box<dyn Statement>,
+    ) -> Self {
+        Self {
+            fn_token,
+            identifier,
+            open_parenthesis,
+            parameters,
+            commas,
+            close_parenthesis,
+            return_type,
+            body,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_parameters(&self) -> &Vec<Parameter> {
+        &self.parameters
+    }
+
+    pub fn get_return_type(&self) -> &Option<TypeClause> {
+        &self.return_type
+    }
+
+    pub fn get_body(&self) -> Box<dyn Statement> {
+        self.body.clone()
+    }
+}
+
+impl Statement for FunctionDeclaration {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::FunctionDeclaration
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![
+            self.fn_token.clone(),
+            self.identifier.clone(),
+            self.open_parenthesis.clone(),
+        ];
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.commas[index - 1].clone());
+            }
+            tokens.extend(parameter.get_tokens());
+        }
+        tokens.push(self.close_parenthesis.clone());
+        if let Some(return_type) = &self.return_type {
+            tokens.extend(return_type.get_tokens());
+        }
+        tokens.extend(self.body.get_tokens());
+        tokens
+    }
+}
@@ -68,4 +68,15 @@ impl Statement for IfStatement {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::IfStatement
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.if_token.clone(), self.open_parenthesis.clone()];
+        tokens.extend(self.condition.get_tokens());
+        tokens.push(self.close_parenthesis.clone());
+        tokens.extend(self.then_statement.get_tokens());
+        if let Some(else_clause) = &self.else_clause {
+            tokens.extend(else_clause.get_tokens());
+        }
+        tokens
+    }
 }
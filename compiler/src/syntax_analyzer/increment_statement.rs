@@ -0,0 +1,47 @@
+use std::any::Any;
+
+use crate::util::{statement::Statement, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
+
+// `x++;` or `x--;`.
+#[derive(Debug, Clone)]
+pub struct IncrementStatement {
+    variable: SyntaxToken,
+    operator: SyntaxToken,
+    semi_colon: SyntaxToken,
+}
+
+impl IncrementStatement {
+    pub fn new(variable: SyntaxToken, operator: SyntaxToken, semi_colon: SyntaxToken) -> Self {
+        Self {
+            variable,
+            operator,
+            semi_colon,
+        }
+    }
+
+    pub fn get_variable(&self) -> &SyntaxToken {
+        &self.variable
+    }
+
+    pub fn get_operator(&self) -> &SyntaxToken {
+        &self.operator
+    }
+}
+
+impl Statement for IncrementStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::IncrementStatement
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        vec![
+            self.variable.clone(),
+            self.operator.clone(),
+            self.semi_colon.clone(),
+        ]
+    }
+}
@@ -0,0 +1,94 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression, statement::Statement, syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `a[i] = value;` or a compound assignment such as `a[i] += value;`.
+#[derive(Debug)]
+pub struct IndexAssignment {
+    variable: SyntaxToken,
+    open_bracket: SyntaxToken,
+    index: Box<dyn Expression>,
+    close_bracket: SyntaxToken,
+    equals: SyntaxToken,
+    expression: Box<dyn Expression>,
+    semi_colon: SyntaxToken,
+}
+
+impl Clone for IndexAssignment {
+    fn clone(&self) -> Self {
+        IndexAssignment {
+            variable: self.variable.clone(),
+            open_bracket: self.open_bracket.clone(),
+            index: self.index.clone(),
+            close_bracket: self.close_bracket.clone(),
+            equals: self.equals.clone(),
+            expression: self.expression.clone(),
+            semi_colon: self.semi_colon.clone(),
+        }
+    }
+}
+
+impl IndexAssignment {
+    pub fn new(
+        variable: SyntaxToken,
+        open_bracket: SyntaxToken,
+        index: Box<dyn Expression>,
+        close_bracket: SyntaxToken,
+        equals: SyntaxToken,
+        expression: Box<dyn Expression>,
+        semi_colon: SyntaxToken,
+    ) -> Self {
+        Self {
+            variable,
+            open_bracket,
+            index,
+            close_bracket,
+            equals,
+            expression,
+            semi_colon,
+        }
+    }
+
+    pub fn get_variable(&self) -> &SyntaxToken {
+        &self.variable
+    }
+
+    pub fn get_open_bracket(&self) -> &SyntaxToken {
+        &self.open_bracket
+    }
+
+    pub fn get_index(&self) -> Box<dyn Expression> {
+        self.index.clone()
+    }
+
+    pub fn get_equals(&self) -> &SyntaxToken {
+        &self.equals
+    }
+
+    pub fn get_expression(&self) -> Box<dyn Expression> {
+        self.expression.clone()
+    }
+}
+
+impl Statement for IndexAssignment {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::IndexAssignment
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.variable.clone(), self.open_bracket.clone()];
+        tokens.extend(self.index.get_tokens());
+        tokens.push(self.close_bracket.clone());
+        tokens.push(self.equals.clone());
+        tokens.extend(self.expression.get_tokens());
+        tokens.push(self.semi_colon.clone());
+        tokens
+    }
+}
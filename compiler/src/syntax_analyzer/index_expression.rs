@@ -0,0 +1,86 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression,
+    literals::{LiteralType, LiteralValue},
+    syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `array[index]`
+#[derive(Debug)]
+pub struct IndexExpressionSyntax {
+    array: Box<dyn Expression>,
+    open_bracket: SyntaxToken,
+    index: Box<dyn Expression>,
+    close_bracket: SyntaxToken,
+}
+
+impl Clone for IndexExpressionSyntax {
+    fn clone(&self) -> Self {
+        IndexExpressionSyntax {
+            array: self.array.clone(),
+            open_bracket: self.open_bracket.clone(),
+            index: self.index.clone(),
+            close_bracket: self.close_bracket.clone(),
+        }
+    }
+}
+
+impl IndexExpressionSyntax {
+    pub fn new(
+        array: Box<dyn Expression>,
+        open_bracket: SyntaxToken,
+        index: Box<dyn Expression>,
+        close_bracket: SyntaxToken,
+    ) -> Self {
+        Self {
+            array,
+            open_bracket,
+            index,
+            close_bracket,
+        }
+    }
+
+    pub fn get_array(&self) -> Box<dyn Expression> {
+        self.array.clone()
+    }
+
+    pub fn get_open_bracket(&self) -> &SyntaxToken {
+        &self.open_bracket
+    }
+
+    pub fn get_index(&self) -> Box<dyn Expression> {
+        self.index.clone()
+    }
+}
+
+impl Expression for IndexExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::IndexExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        vec![self.array.clone(), self.index.clone()]
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        todo!()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        todo!()
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = self.array.get_tokens();
+        tokens.push(self.open_bracket.clone());
+        tokens.extend(self.index.get_tokens());
+        tokens.push(self.close_bracket.clone());
+        tokens
+    }
+}
@@ -47,7 +47,11 @@ impl Expression for LiteralExpressionSyntax {
         self.literal_token.get_value().clone()
     }
 
-    fn get_type(&self) -> &LiteralType {
+    fn get_type(&self) -> LiteralType {
         self.literal_token.get_type()
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        vec![self.literal_token.clone()]
+    }
 }
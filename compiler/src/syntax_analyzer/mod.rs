@@ -1,14 +1,32 @@
+pub mod array_literal_expression;
 pub mod assignment;
 pub mod binary_expression;
+pub mod break_statement;
+pub mod call_expression;
+pub mod conditional_expression;
 pub mod constant_declaration;
+pub mod continue_statement;
 pub mod else_statement;
+pub mod error_expression;
+pub mod error_statement;
+pub mod expression_statement;
+pub mod field_assignment;
+pub mod field_expression;
 pub mod for_statement;
+pub mod function_declaration;
 pub mod if_statement;
+pub mod increment_statement;
+pub mod index_assignment;
+pub mod index_expression;
 pub mod literal_expression;
 pub mod name_expression;
 pub mod parenthesized_expression;
 pub mod parser;
+pub mod return_statement;
 pub mod statement_list;
+pub mod struct_declaration;
+pub mod struct_literal_expression;
+pub mod type_clause;
 pub mod unary_expression;
 pub mod variable_declaration;
 pub mod while_statement;
@@ -45,7 +45,11 @@ impl Expression for NameExpressionSyntax {
         self.token.get_value().clone()
     }
 
-    fn get_type(&self) -> &LiteralType {
+    fn get_type(&self) -> LiteralType {
         self.token.get_type()
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        vec![self.token.clone()]
+    }
 }
@@ -63,7 +63,14 @@ impl Expression for ParenthesizedExpressionSyntax {
         todo!()
     }
 
-    fn get_type(&self) -> &LiteralType {
+    fn get_type(&self) -> LiteralType {
         todo!()
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.open_parenthesis_token.clone()];
+        tokens.extend(self.expression.get_tokens());
+        tokens.push(self.close_parenthesis_token.clone());
+        tokens
+    }
 }
@@ -1,16 +1,34 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use super::array_literal_expression::ArrayLiteralExpressionSyntax;
 use super::assignment::Assignment;
 use super::binary_expression::BinaryExpressionSyntax;
+use super::break_statement::BreakStatement;
+use super::call_expression::CallExpressionSyntax;
+use super::conditional_expression::ConditionalExpressionSyntax;
 use super::constant_declaration::ConstantDeclaration;
+use super::continue_statement::ContinueStatement;
 use super::else_statement::ElseStatement;
+use super::error_expression::ErrorExpressionSyntax;
+use super::error_statement::ErrorStatement;
+use super::expression_statement::ExpressionStatement;
+use super::field_assignment::FieldAssignment;
+use super::field_expression::FieldExpressionSyntax;
 use super::for_statement::ForStatement;
+use super::function_declaration::{FunctionDeclaration, Parameter};
 use super::if_statement::IfStatement;
+use super::increment_statement::IncrementStatement;
+use super::index_assignment::IndexAssignment;
+use super::index_expression::IndexExpressionSyntax;
 use super::literal_expression::LiteralExpressionSyntax;
 use super::name_expression::NameExpressionSyntax;
 use super::parenthesized_expression::ParenthesizedExpressionSyntax;
+use super::return_statement::ReturnStatement;
 use super::statement_list::StatementList;
+use super::struct_declaration::{FieldDeclaration, StructDeclaration};
+use super::struct_literal_expression::{FieldInitializer, StructLiteralExpressionSyntax};
+use super::type_clause::TypeClause;
 use super::unary_expression::UnaryExpressionSyntax;
 use super::variable_declaration::VariableDeclaration;
 use super::while_statement::WhileStatement;
@@ -24,11 +42,13 @@ use crate::util::literals::LiteralValue;
 use crate::util::statement::Statement;
 use crate::util::syntax_kind::SyntaxKind;
 use crate::util::syntax_token::SyntaxToken;
+use crate::util::syntax_trivia::SyntaxTrivia;
 
 pub struct Parser {
     tokens: Vec<SyntaxToken>,
     position: usize,
     diagnostics: Rc<RefCell<Diagnostics>>,
+    recovering: bool,
 }
 
 impl Parser {
@@ -37,12 +57,15 @@ impl Parser {
             tokens: Vec::new(),
             position: 0,
             diagnostics,
+            recovering: false,
         }
     }
 
     pub fn create(&mut self, lexer: &mut Lexer) {
+        let mut leading_trivia = Vec::new();
+
         loop {
-            let token = lexer.next_token();
+            let mut token = lexer.next_token();
 
             self.diagnostics.borrow_mut().info_message(
                 format!(
@@ -57,33 +80,117 @@ impl Parser {
                 TextType::Info,
             );
 
-            if *token.kind() != SyntaxKind::BadToken && *token.kind() != SyntaxKind::WhiteSpace {
-                self.tokens.push(token.clone());
+            match *token.kind() {
+                SyntaxKind::WhiteSpace | SyntaxKind::LineComment | SyntaxKind::BlockComment => self
+                    .add_trivia(
+                        SyntaxTrivia::new(*token.kind(), token.name(), token.position()),
+                        &mut leading_trivia,
+                    ),
+                SyntaxKind::BadToken => break,
+                SyntaxKind::Eof => {
+                    self.add_end_of_file(token, leading_trivia);
+                    break;
+                }
+                _ => {
+                    token.set_leading_trivia(std::mem::take(&mut leading_trivia));
+                    self.tokens.push(token);
+                }
             }
-            if *token.kind() == SyntaxKind::Eof {
-                break;
+        }
+    }
+
+    // Trivia on the same line as the previous token, up to and including the line break,
+    // trails that token. Everything after it leads the next token.
+    fn add_trivia(&mut self, trivia: SyntaxTrivia, leading_trivia: &mut Vec<SyntaxTrivia>) {
+        let Some(previous) = self.tokens.last_mut() else {
+            leading_trivia.push(trivia);
+            return;
+        };
+        let mut trailing_trivia = previous.trailing_trivia();
+        let line_ended = trailing_trivia
+            .last()
+            .is_some_and(|last| last.text().ends_with('\n'));
+        if !leading_trivia.is_empty() || line_ended {
+            leading_trivia.push(trivia);
+            return;
+        }
+
+        let text = trivia.text();
+        match text.find('\n') {
+            Some(index) if *trivia.kind() == SyntaxKind::WhiteSpace && index + 1 < text.len() => {
+                let (line, rest) = text.split_at(index + 1);
+                trailing_trivia.push(SyntaxTrivia::new(
+                    *trivia.kind(),
+                    line.to_string(),
+                    trivia.position(),
+                ));
+                leading_trivia.push(SyntaxTrivia::new(
+                    *trivia.kind(),
+                    rest.to_string(),
+                    trivia.position() + line.chars().count(),
+                ));
             }
-            if *token.kind() == SyntaxKind::BadToken {
-                break;
+            _ => trailing_trivia.push(trivia),
+        }
+        previous.set_trailing_trivia(trailing_trivia);
+    }
+
+    // Trivia at the end of the file trails the last token, so it is part of the tree.
+    fn add_end_of_file(&mut self, mut eof: SyntaxToken, leading_trivia: Vec<SyntaxTrivia>) {
+        match self.tokens.last_mut() {
+            Some(previous) => {
+                let mut trailing_trivia = previous.trailing_trivia();
+                trailing_trivia.extend(leading_trivia);
+                previous.set_trailing_trivia(trailing_trivia);
             }
+            None => eof.set_leading_trivia(leading_trivia),
         }
+        self.tokens.push(eof);
     }
 
     fn current(&self) -> SyntaxToken {
         self.tokens[self.position].clone()
     }
 
+    fn peek(&self, offset: usize) -> SyntaxToken {
+        let index = self.position + offset;
+
+        if index >= self.tokens.len() {
+            return self.tokens[self.tokens.len() - 1].clone();
+        }
+        self.tokens[index].clone()
+    }
+
     fn next_token(&mut self) -> SyntaxToken {
         let current = self.current();
         self.position += 1;
         current
     }
 
+    // On a mismatch the expected token is inserted, so the caller can carry on as if it
+    // was there. Only the first error is reported until the parser synchronizes again.
     fn equals(&mut self, kinds: &[SyntaxKind]) -> SyntaxToken {
         if kinds.contains(self.current().kind()) {
             return self.next_token();
         }
 
+        self.report_unexpected_token();
+
+        SyntaxToken::new(
+            String::new(),
+            LiteralValue::String(String::new()),
+            self.current().position(),
+            kinds[0],
+            0,
+        )
+    }
+
+    fn report_unexpected_token(&mut self) {
+        if self.recovering {
+            return;
+        }
+        self.recovering = true;
+
         self.diagnostics.borrow_mut().report_unexpected_token(
             self.current().name(),
             TextSpan::new(self.current().position(), self.current().length()),
@@ -91,14 +198,38 @@ impl Parser {
             TextPlace::Syntax,
             TextType::Error,
         );
+    }
 
-        SyntaxToken::new(
-            "Error".to_string(),
-            LiteralValue::String("Error".to_string()),
-            self.current().position(),
-            SyntaxKind::BadToken,
-            1,
-        )
+    // Skips tokens up to and including the next semicolon, or up to a closing brace or the
+    // start of a statement.
+    fn synchronize(&mut self) -> Vec<SyntaxToken> {
+        let mut skipped = Vec::new();
+
+        loop {
+            match *self.current().kind() {
+                SyntaxKind::Semicolon => {
+                    skipped.push(self.next_token());
+                    break;
+                }
+                SyntaxKind::CloseBrace
+                | SyntaxKind::Eof
+                | SyntaxKind::OpenBrace
+                | SyntaxKind::If
+                | SyntaxKind::Let
+                | SyntaxKind::Const
+                | SyntaxKind::While
+                | SyntaxKind::For
+                | SyntaxKind::Fn
+                | SyntaxKind::Struct
+                | SyntaxKind::Return
+                | SyntaxKind::Break
+                | SyntaxKind::Continue => break,
+                _ => skipped.push(self.next_token()),
+            }
+        }
+
+        self.recovering = false;
+        skipped
     }
 
     pub fn parse(&mut self) -> Box<dyn Statement> {
@@ -116,8 +247,168 @@ impl Parser {
             SyntaxKind::Const => self.parse_constant_declaration(),
             SyntaxKind::While => self.parse_while_statement(),
             SyntaxKind::For => self.parse_for_statement(),
-            _ => self.parse_assignment(),
+            SyntaxKind::Fn => self.parse_function_declaration(),
+            SyntaxKind::Struct => self.parse_struct_declaration(),
+            SyntaxKind::Return => self.parse_return_statement(),
+            SyntaxKind::Break => self.parse_break_statement(),
+            SyntaxKind::Continue => self.parse_continue_statement(),
+            SyntaxKind::IdentifierToken
+                if *self.peek(1).get_kind() == SyntaxKind::OpenParenthesis =>
+            {
+                self.parse_expression_statement()
+            }
+            SyntaxKind::IdentifierToken
+                if matches!(
+                    *self.peek(1).get_kind(),
+                    SyntaxKind::PlusPlus | SyntaxKind::MinusMinus
+                ) =>
+            {
+                self.parse_increment_statement()
+            }
+            SyntaxKind::IdentifierToken if *self.peek(1).get_kind() == SyntaxKind::OpenBracket => {
+                self.parse_index_assignment()
+            }
+            SyntaxKind::IdentifierToken if *self.peek(1).get_kind() == SyntaxKind::Dot => {
+                self.parse_field_assignment()
+            }
+            SyntaxKind::IdentifierToken => self.parse_assignment(),
+            _ => self.parse_error_statement(),
+        }
+    }
+
+    fn parse_error_statement(&mut self) -> Box<dyn Statement> {
+        self.report_unexpected_token();
+        let mut tokens = self.synchronize();
+
+        if tokens.is_empty() {
+            tokens.push(self.next_token());
+        }
+        Box::new(ErrorStatement::new(tokens)) as Box<dyn Statement>
+    }
+
+    fn parse_function_declaration(&mut self) -> Box<dyn Statement> {
+        let fn_token = self.equals(&[SyntaxKind::Fn]);
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let open_parenthesis = self.equals(&[SyntaxKind::OpenParenthesis]);
+
+        let mut parameters = Vec::new();
+        let mut commas = Vec::new();
+        if *self.current().get_kind() != SyntaxKind::CloseParenthesis {
+            parameters.push(self.parse_parameter());
+            while *self.current().get_kind() == SyntaxKind::Comma {
+                commas.push(self.next_token());
+                parameters.push(self.parse_parameter());
+            }
+        }
+
+        let close_parenthesis = self.equals(&[SyntaxKind::CloseParenthesis]);
+        let return_type = if *self.current().get_kind() == SyntaxKind::Colon {
+            Some(self.parse_type_clause())
+        } else {
+            None
+        };
+        let body = self.parse_statement_list();
+
+        Box::new(FunctionDeclaration::new(
+            fn_token,
+            identifier,
+            open_parenthesis,
+            parameters,
+            commas,
+            close_parenthesis,
+            return_type,
+            body,
+        )) as Box<dyn Statement>
+    }
+
+    fn parse_parameter(&mut self) -> Parameter {
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let type_clause = if *self.current().get_kind() == SyntaxKind::Colon {
+            Some(self.parse_type_clause())
+        } else {
+            None
+        };
+
+        Parameter::new(identifier, type_clause)
+    }
+
+    fn parse_struct_declaration(&mut self) -> Box<dyn Statement> {
+        let struct_token = self.equals(&[SyntaxKind::Struct]);
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let open_brace = self.equals(&[SyntaxKind::OpenBrace]);
+
+        let mut fields = Vec::new();
+        let mut commas = Vec::new();
+        if *self.current().get_kind() != SyntaxKind::CloseBrace {
+            fields.push(self.parse_field_declaration());
+            while *self.current().get_kind() == SyntaxKind::Comma {
+                commas.push(self.next_token());
+                fields.push(self.parse_field_declaration());
+            }
+        }
+
+        let close_brace = self.equals(&[SyntaxKind::CloseBrace]);
+
+        Box::new(StructDeclaration::new(
+            struct_token,
+            identifier,
+            open_brace,
+            fields,
+            commas,
+            close_brace,
+        )) as Box<dyn Statement>
+    }
+
+    fn parse_field_declaration(&mut self) -> FieldDeclaration {
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let type_clause = self.parse_type_clause();
+
+        FieldDeclaration::new(identifier, type_clause)
+    }
+
+    fn parse_type_clause(&mut self) -> TypeClause {
+        let colon = self.equals(&[SyntaxKind::Colon]);
+
+        let mut open_brackets = Vec::new();
+        while *self.current().get_kind() == SyntaxKind::OpenBracket {
+            open_brackets.push(self.next_token());
         }
+        let type_name = self.equals(&[SyntaxKind::IdentifierToken]);
+        let close_brackets = open_brackets
+            .iter()
+            .map(|_| self.equals(&[SyntaxKind::CloseBracket]))
+            .collect();
+
+        TypeClause::new(colon, open_brackets, type_name, close_brackets)
+    }
+
+    fn parse_return_statement(&mut self) -> Box<dyn Statement> {
+        let return_token = self.equals(&[SyntaxKind::Return]);
+        let expression = self.parse_expression();
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+
+        Box::new(ReturnStatement::new(return_token, expression, semi_colon)) as Box<dyn Statement>
+    }
+
+    fn parse_break_statement(&mut self) -> Box<dyn Statement> {
+        let break_token = self.equals(&[SyntaxKind::Break]);
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+
+        Box::new(BreakStatement::new(break_token, semi_colon)) as Box<dyn Statement>
+    }
+
+    fn parse_continue_statement(&mut self) -> Box<dyn Statement> {
+        let continue_token = self.equals(&[SyntaxKind::Continue]);
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+
+        Box::new(ContinueStatement::new(continue_token, semi_colon)) as Box<dyn Statement>
+    }
+
+    fn parse_expression_statement(&mut self) -> Box<dyn Statement> {
+        let expression = self.parse_call_expression();
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+
+        Box::new(ExpressionStatement::new(expression, semi_colon)) as Box<dyn Statement>
     }
 
     fn parse_for_statement(&mut self) -> Box<dyn Statement> {
@@ -181,35 +472,131 @@ impl Parser {
     fn parse_else_statement(&mut self) -> Option<Box<dyn Statement>> {
         if *self.current().get_kind() == SyntaxKind::Else {
             let else_token = self.equals(&[SyntaxKind::Else]);
-            let statement_list = self.parse_statement_list();
-            return Some(
-                Box::new(ElseStatement::new(else_token, statement_list)) as Box<dyn Statement>
-            );
+            let statement = if *self.current().get_kind() == SyntaxKind::If {
+                self.parse_if_statement()
+            } else {
+                self.parse_statement_list()
+            };
+            return Some(Box::new(ElseStatement::new(else_token, statement)) as Box<dyn Statement>);
         }
 
         None
     }
 
+    // Parses REPL input: statements without the braces around a program.
+    pub fn parse_statements(&mut self) -> Vec<Box<dyn Statement>> {
+        let statements = self.parse_statement_sequence();
+        self.equals(&[SyntaxKind::Eof]);
+        statements
+    }
+
+    // Parses REPL input that consists of a single expression.
+    pub fn parse_expression_input(&mut self) -> Box<dyn Expression> {
+        let expression = self.parse_expression();
+        self.equals(&[SyntaxKind::Eof]);
+        expression
+    }
+
     fn parse_statement_list(&mut self) -> Box<dyn Statement> {
+        let open_brace = self.equals(&[SyntaxKind::OpenBrace]);
+        let statements = self.parse_statement_sequence();
+        let close_brace = self.equals(&[SyntaxKind::CloseBrace]);
+
+        Box::new(StatementList::new(open_brace, statements, close_brace)) as Box<dyn Statement>
+    }
+
+    fn parse_statement_sequence(&mut self) -> Vec<Box<dyn Statement>> {
         let mut statements = Vec::new();
 
-        let open_brace = self.equals(&[SyntaxKind::OpenBrace]);
-        while *self.current().get_kind() != SyntaxKind::CloseBrace {
+        while *self.current().get_kind() != SyntaxKind::CloseBrace
+            && *self.current().get_kind() != SyntaxKind::Eof
+        {
+            let start = self.position;
+            self.recovering = false;
             statements.push(self.parse_statement());
+
+            if self.recovering {
+                let skipped = self.synchronize();
+                if !skipped.is_empty() {
+                    statements.push(Box::new(ErrorStatement::new(skipped)) as Box<dyn Statement>);
+                }
+            }
+            if self.position == start {
+                let token = self.next_token();
+                statements.push(Box::new(ErrorStatement::new(vec![token])) as Box<dyn Statement>);
+            }
         }
-        let close_brace = self.equals(&[SyntaxKind::CloseBrace]);
 
-        Box::new(StatementList::new(open_brace, statements, close_brace)) as Box<dyn Statement>
+        statements
     }
 
     fn parse_assignment(&mut self) -> Box<dyn Statement> {
         let variable = self.equals(&[SyntaxKind::IdentifierToken]);
-        let equals = self.equals(&[SyntaxKind::Equals]);
+        let equals = self.equals(&[
+            SyntaxKind::Equals,
+            SyntaxKind::PlusEquals,
+            SyntaxKind::MinusEquals,
+            SyntaxKind::MulEquals,
+            SyntaxKind::DivEquals,
+            SyntaxKind::PercentEquals,
+        ]);
         let expression = self.parse_expression();
         let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
         Box::new(Assignment::new(variable, equals, expression, semi_colon)) as Box<dyn Statement>
     }
 
+    fn parse_index_assignment(&mut self) -> Box<dyn Statement> {
+        let variable = self.equals(&[SyntaxKind::IdentifierToken]);
+        let open_bracket = self.equals(&[SyntaxKind::OpenBracket]);
+        let index = self.parse_expression();
+        let close_bracket = self.equals(&[SyntaxKind::CloseBracket]);
+        let equals = self.equals(&[
+            SyntaxKind::Equals,
+            SyntaxKind::PlusEquals,
+            SyntaxKind::MinusEquals,
+            SyntaxKind::MulEquals,
+            SyntaxKind::DivEquals,
+            SyntaxKind::PercentEquals,
+        ]);
+        let expression = self.parse_expression();
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+        Box::new(IndexAssignment::new(
+            variable,
+            open_bracket,
+            index,
+            close_bracket,
+            equals,
+            expression,
+            semi_colon,
+        )) as Box<dyn Statement>
+    }
+
+    fn parse_field_assignment(&mut self) -> Box<dyn Statement> {
+        let variable = self.equals(&[SyntaxKind::IdentifierToken]);
+        let dot = self.equals(&[SyntaxKind::Dot]);
+        let field = self.equals(&[SyntaxKind::IdentifierToken]);
+        let equals = self.equals(&[
+            SyntaxKind::Equals,
+            SyntaxKind::PlusEquals,
+            SyntaxKind::MinusEquals,
+            SyntaxKind::MulEquals,
+            SyntaxKind::DivEquals,
+            SyntaxKind::PercentEquals,
+        ]);
+        let expression = self.parse_expression();
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+        Box::new(FieldAssignment::new(
+            variable, dot, field, equals, expression, semi_colon,
+        )) as Box<dyn Statement>
+    }
+
+    fn parse_increment_statement(&mut self) -> Box<dyn Statement> {
+        let variable = self.equals(&[SyntaxKind::IdentifierToken]);
+        let operator = self.equals(&[SyntaxKind::PlusPlus, SyntaxKind::MinusMinus]);
+        let semi_colon = self.equals(&[SyntaxKind::Semicolon]);
+        Box::new(IncrementStatement::new(variable, operator, semi_colon)) as Box<dyn Statement>
+    }
+
     fn parse_variable_declaration(&mut self) -> Box<dyn Statement> {
         let let_token = self.equals(&[SyntaxKind::Let]);
         let variable = self.equals(&[SyntaxKind::IdentifierToken]);
@@ -242,9 +629,63 @@ impl Parser {
     }
 
     fn parse_logical_expression(&mut self) -> Box<dyn Expression> {
-        let mut expr = self.parse_relational_expression();
+        let mut expr = self.parse_and_expression();
 
         while *self.current().get_kind() == SyntaxKind::PipePipe {
+            let operator = self.next_token();
+            let right = self.parse_and_expression();
+            expr =
+                Box::new(BinaryExpressionSyntax::new(expr, operator, right)) as Box<dyn Expression>;
+        }
+
+        expr
+    }
+
+    fn parse_and_expression(&mut self) -> Box<dyn Expression> {
+        let mut expr = self.parse_bitwise_or_expression();
+
+        while *self.current().get_kind() == SyntaxKind::AmpersandAmpersand {
+            let operator = self.next_token();
+            let right = self.parse_bitwise_or_expression();
+            expr =
+                Box::new(BinaryExpressionSyntax::new(expr, operator, right)) as Box<dyn Expression>;
+        }
+
+        expr
+    }
+
+    // As in C, the bitwise operators bind looser than comparisons, so `a & 1 == 1`
+    // is `a & (1 == 1)`.
+    fn parse_bitwise_or_expression(&mut self) -> Box<dyn Expression> {
+        let mut expr = self.parse_bitwise_xor_expression();
+
+        while *self.current().get_kind() == SyntaxKind::Pipe {
+            let operator = self.next_token();
+            let right = self.parse_bitwise_xor_expression();
+            expr =
+                Box::new(BinaryExpressionSyntax::new(expr, operator, right)) as Box<dyn Expression>;
+        }
+
+        expr
+    }
+
+    fn parse_bitwise_xor_expression(&mut self) -> Box<dyn Expression> {
+        let mut expr = self.parse_bitwise_and_expression();
+
+        while *self.current().get_kind() == SyntaxKind::Caret {
+            let operator = self.next_token();
+            let right = self.parse_bitwise_and_expression();
+            expr =
+                Box::new(BinaryExpressionSyntax::new(expr, operator, right)) as Box<dyn Expression>;
+        }
+
+        expr
+    }
+
+    fn parse_bitwise_and_expression(&mut self) -> Box<dyn Expression> {
+        let mut expr = self.parse_relational_expression();
+
+        while *self.current().get_kind() == SyntaxKind::Ampersand {
             let operator = self.next_token();
             let right = self.parse_relational_expression();
             expr =
@@ -255,15 +696,29 @@ impl Parser {
     }
 
     fn parse_relational_expression(&mut self) -> Box<dyn Expression> {
-        let mut expr = self.parse_arhithmetic_expression();
+        let mut expr = self.parse_shift_expression();
 
-        while *self.current().get_kind() == SyntaxKind::AmpersandAmpersand
-            || *self.current().get_kind() == SyntaxKind::EqualsEquals
+        while *self.current().get_kind() == SyntaxKind::EqualsEquals
             || *self.current().get_kind() == SyntaxKind::BangEquals
             || *self.current().get_kind() == SyntaxKind::Less
             || *self.current().get_kind() == SyntaxKind::LessOrEquals
             || *self.current().get_kind() == SyntaxKind::Greater
             || *self.current().get_kind() == SyntaxKind::GreaterOrEquals
+        {
+            let operator = self.next_token();
+            let right = self.parse_shift_expression();
+            expr =
+                Box::new(BinaryExpressionSyntax::new(expr, operator, right)) as Box<dyn Expression>;
+        }
+
+        expr
+    }
+
+    fn parse_shift_expression(&mut self) -> Box<dyn Expression> {
+        let mut expr = self.parse_arhithmetic_expression();
+
+        while *self.current().get_kind() == SyntaxKind::LessLess
+            || *self.current().get_kind() == SyntaxKind::GreaterGreater
         {
             let operator = self.next_token();
             let right = self.parse_arhithmetic_expression();
@@ -290,13 +745,14 @@ impl Parser {
     }
 
     fn parse_term(&mut self) -> Box<dyn Expression> {
-        let mut expr = self.parse_factor();
+        let mut expr = self.parse_power();
 
         while *self.current().get_kind() == SyntaxKind::Mul
             || *self.current().get_kind() == SyntaxKind::Div
+            || *self.current().get_kind() == SyntaxKind::Percent
         {
             let operator = self.next_token();
-            let right = self.parse_factor();
+            let right = self.parse_power();
             expr =
                 Box::new(BinaryExpressionSyntax::new(expr, operator, right)) as Box<dyn Expression>;
         }
@@ -304,15 +760,56 @@ impl Parser {
         expr
     }
 
+    // `**` is right associative and a unary operator binds tighter, so `-2 ** 2` is 4.
+    fn parse_power(&mut self) -> Box<dyn Expression> {
+        let base = self.parse_factor();
+
+        if *self.current().get_kind() == SyntaxKind::StarStar {
+            let operator = self.next_token();
+            let exponent = self.parse_power();
+            return Box::new(BinaryExpressionSyntax::new(base, operator, exponent))
+                as Box<dyn Expression>;
+        }
+
+        base
+    }
+
     fn parse_factor(&mut self) -> Box<dyn Expression> {
         if *self.current().get_kind() == SyntaxKind::Bang
             || *self.current().get_kind() == SyntaxKind::Minus
+            || *self.current().get_kind() == SyntaxKind::Tilde
         {
             let operator = self.next_token();
             let operand: Box<dyn Expression> = self.parse_factor();
             return Box::new(UnaryExpressionSyntax::new(operator, operand)) as Box<dyn Expression>;
         }
 
+        let mut expr = self.parse_primary();
+        loop {
+            match *self.current().get_kind() {
+                SyntaxKind::OpenBracket => {
+                    let open_bracket = self.next_token();
+                    let index = self.parse_expression();
+                    let close_bracket = self.equals(&[SyntaxKind::CloseBracket]);
+                    expr = Box::new(IndexExpressionSyntax::new(
+                        expr,
+                        open_bracket,
+                        index,
+                        close_bracket,
+                    )) as Box<dyn Expression>;
+                }
+                SyntaxKind::Dot => {
+                    let dot = self.next_token();
+                    let field = self.equals(&[SyntaxKind::IdentifierToken]);
+                    expr = Box::new(FieldExpressionSyntax::new(expr, dot, field))
+                        as Box<dyn Expression>;
+                }
+                _ => return expr,
+            }
+        }
+    }
+
+    fn parse_primary(&mut self) -> Box<dyn Expression> {
         if *self.current().get_kind() == SyntaxKind::OpenParenthesis {
             let open_parenthesis_token = self.next_token();
             let expr = self.parse_expression();
@@ -325,16 +822,146 @@ impl Parser {
             )) as Box<dyn Expression>;
         }
 
+        if *self.current().get_kind() == SyntaxKind::If {
+            return self.parse_conditional_expression();
+        }
+
+        if *self.current().get_kind() == SyntaxKind::OpenBracket {
+            return self.parse_array_literal_expression();
+        }
+
         self.parse_literal()
     }
 
+    fn parse_array_literal_expression(&mut self) -> Box<dyn Expression> {
+        let open_bracket = self.equals(&[SyntaxKind::OpenBracket]);
+
+        let mut elements = Vec::new();
+        let mut commas = Vec::new();
+        if *self.current().get_kind() != SyntaxKind::CloseBracket {
+            elements.push(self.parse_expression());
+            while *self.current().get_kind() == SyntaxKind::Comma {
+                commas.push(self.next_token());
+                elements.push(self.parse_expression());
+            }
+        }
+
+        let close_bracket = self.equals(&[SyntaxKind::CloseBracket]);
+
+        Box::new(ArrayLiteralExpressionSyntax::new(
+            open_bracket,
+            elements,
+            commas,
+            close_bracket,
+        )) as Box<dyn Expression>
+    }
+
+    fn parse_struct_literal_expression(&mut self) -> Box<dyn Expression> {
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let open_brace = self.equals(&[SyntaxKind::OpenBrace]);
+
+        let mut fields = Vec::new();
+        let mut commas = Vec::new();
+        if *self.current().get_kind() != SyntaxKind::CloseBrace {
+            fields.push(self.parse_field_initializer());
+            while *self.current().get_kind() == SyntaxKind::Comma {
+                commas.push(self.next_token());
+                fields.push(self.parse_field_initializer());
+            }
+        }
+
+        let close_brace = self.equals(&[SyntaxKind::CloseBrace]);
+
+        Box::new(StructLiteralExpressionSyntax::new(
+            identifier,
+            open_brace,
+            fields,
+            commas,
+            close_brace,
+        )) as Box<dyn Expression>
+    }
+
+    fn parse_field_initializer(&mut self) -> FieldInitializer {
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let colon = self.equals(&[SyntaxKind::Colon]);
+        let expression = self.parse_expression();
+
+        FieldInitializer::new(identifier, colon, expression)
+    }
+
+    // The else branch extends as far to the right as possible, so in
+    // `if (c) a else b + 1` the addition belongs to the else branch.
+    fn parse_conditional_expression(&mut self) -> Box<dyn Expression> {
+        let if_token = self.equals(&[SyntaxKind::If]);
+        let open_parenthesis = self.equals(&[SyntaxKind::OpenParenthesis]);
+        let condition = self.parse_expression();
+        let close_parenthesis = self.equals(&[SyntaxKind::CloseParenthesis]);
+        let when_true = self.parse_expression();
+        let else_token = self.equals(&[SyntaxKind::Else]);
+        let when_false = self.parse_expression();
+
+        Box::new(ConditionalExpressionSyntax::new(
+            if_token,
+            open_parenthesis,
+            condition,
+            close_parenthesis,
+            when_true,
+            else_token,
+            when_false,
+        )) as Box<dyn Expression>
+    }
+
+    fn parse_call_expression(&mut self) -> Box<dyn Expression> {
+        let identifier = self.equals(&[SyntaxKind::IdentifierToken]);
+        let open_parenthesis = self.equals(&[SyntaxKind::OpenParenthesis]);
+
+        let mut arguments = Vec::new();
+        let mut commas = Vec::new();
+        if *self.current().get_kind() != SyntaxKind::CloseParenthesis {
+            arguments.push(self.parse_expression());
+            while *self.current().get_kind() == SyntaxKind::Comma {
+                commas.push(self.next_token());
+                arguments.push(self.parse_expression());
+            }
+        }
+
+        let close_parenthesis = self.equals(&[SyntaxKind::CloseParenthesis]);
+
+        Box::new(CallExpressionSyntax::new(
+            identifier,
+            open_parenthesis,
+            arguments,
+            commas,
+            close_parenthesis,
+        )) as Box<dyn Expression>
+    }
+
     fn parse_literal(&mut self) -> Box<dyn Expression> {
-        let literal_token = self.equals(&[
+        if *self.current().get_kind() == SyntaxKind::IdentifierToken
+            && *self.peek(1).get_kind() == SyntaxKind::OpenParenthesis
+        {
+            return self.parse_call_expression();
+        }
+
+        if *self.current().get_kind() == SyntaxKind::IdentifierToken
+            && *self.peek(1).get_kind() == SyntaxKind::OpenBrace
+        {
+            return self.parse_struct_literal_expression();
+        }
+
+        let literal_kinds = [
             SyntaxKind::True,
             SyntaxKind::False,
             SyntaxKind::Number,
+            SyntaxKind::StringLiteral,
             SyntaxKind::IdentifierToken,
-        ]);
+        ];
+        if !literal_kinds.contains(self.current().get_kind()) {
+            self.report_unexpected_token();
+            return Box::new(ErrorExpressionSyntax::new(self.current())) as Box<dyn Expression>;
+        }
+
+        let literal_token = self.next_token();
         if *literal_token.get_kind() == SyntaxKind::IdentifierToken {
             return Box::new(NameExpressionSyntax::new(literal_token)) as Box<dyn Expression>;
         }
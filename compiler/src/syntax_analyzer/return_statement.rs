@@ -0,0 +1,62 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression, statement::Statement, syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+#[derive(Debug)]
+pub struct ReturnStatement {
+    return_token: SyntaxToken,
+    expression: Box<dyn Expression>,
+    semi_colon: SyntaxToken,
+}
+
+impl Clone for ReturnStatement {
+    fn clone(&self) -> Self {
+        ReturnStatement {
+            return_token: self.return_token.clone(),
+            expression: self.expression.clone(),
+            semi_colon: self.semi_colon.clone(),
+        }
+    }
+}
+
+impl ReturnStatement {
+    pub fn new(
+        return_token: SyntaxToken,
+        expression: Box<dyn Expression>,
+        semi_colon: SyntaxToken,
+    ) -> Self {
+        Self {
+            return_token,
+            expression,
+            semi_colon,
+        }
+    }
+
+    pub fn get_return_token(&self) -> &SyntaxToken {
+        &self.return_token
+    }
+
+    pub fn get_expression(&self) -> Box<dyn Expression> {
+        self.expression.clone()
+    }
+}
+
+impl Statement for ReturnStatement {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::ReturnStatement
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.return_token.clone()];
+        tokens.extend(self.expression.get_tokens());
+        tokens.push(self.semi_colon.clone());
+        tokens
+    }
+}
@@ -44,4 +44,13 @@ impl Statement for StatementList {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::StatementList
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.open_brace.clone()];
+        for statement in &self.statements {
+            tokens.extend(statement.get_tokens());
+        }
+        tokens.push(self.close_brace.clone());
+        tokens
+    }
 }
@@ -0,0 +1,100 @@
+use std::any::Any;
+
+use crate::util::{statement::Statement, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
+
+use super::type_clause::TypeClause;
+
+// `name: type`
+#[derive(Debug, Clone)]
+pub struct FieldDeclaration {
+    identifier: SyntaxToken,
+    type_clause: TypeClause,
+}
+
+impl FieldDeclaration {
+    pub fn new(identifier: SyntaxToken, type_clause: TypeClause) -> Self {
+        Self {
+            identifier,
+            type_clause,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_type_clause(&self) -> &TypeClause {
+        &self.type_clause
+    }
+
+    pub fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.identifier.clone()];
+        tokens.extend(self.type_clause.get_tokens());
+        tokens
+    }
+}
+
+// `struct Name { field: type, ... }`
+#[derive(Debug, Clone)]
+pub struct StructDeclaration {
+    struct_token: SyntaxToken,
+    identifier: SyntaxToken,
+    open_brace: SyntaxToken,
+    fields: Vec<FieldDeclaration>,
+    commas: Vec<SyntaxToken>,
+    close_brace: SyntaxToken,
+}
+
+impl StructDeclaration {
+    pub fn new(
+        struct_token: SyntaxToken,
+        identifier: SyntaxToken,
+        open_brace: SyntaxToken,
+        fields: Vec<FieldDeclaration>,
+        commas: Vec<SyntaxToken>,
+        close_brace: SyntaxToken,
+    ) -> Self {
+        Self {
+            struct_token,
+            identifier,
+            open_brace,
+            fields,
+            commas,
+            close_brace,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_fields(&self) -> &Vec<FieldDeclaration> {
+        &self.fields
+    }
+}
+
+impl Statement for StructDeclaration {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::StructDeclaration
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![
+            self.struct_token.clone(),
+            self.identifier.clone(),
+            self.open_brace.clone(),
+        ];
+        for (index, field) in self.fields.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.commas[index - 1].clone());
+            }
+            tokens.extend(field.get_tokens());
+        }
+        tokens.push(self.close_brace.clone());
+        tokens
+    }
+}
@@ -0,0 +1,139 @@
+use std::any::Any;
+
+use crate::util::{
+    expression::Expression,
+    literals::{LiteralType, LiteralValue},
+    syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
+};
+
+// `field: value` inside a struct literal.
+#[derive(Debug)]
+pub struct FieldInitializer {
+    identifier: SyntaxToken,
+    colon: SyntaxToken,
+    expression: Box<dyn Expression>,
+}
+
+impl Clone for FieldInitializer {
+    fn clone(&self) -> Self {
+        FieldInitializer {
+            identifier: self.identifier.clone(),
+            colon: self.colon.clone(),
+            expression: self.expression.clone(),
+        }
+    }
+}
+
+impl FieldInitializer {
+    pub fn new(
+        identifier: SyntaxToken,
+        colon: SyntaxToken,
+        expression: Box<dyn Expression>,
+    ) -> Self {
+        Self {
+            identifier,
+            colon,
+            expression,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_expression(&self) -> Box<dyn Expression> {
+        self.expression.clone()
+    }
+
+    pub fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.identifier.clone(), self.colon.clone()];
+        tokens.extend(self.expression.get_tokens());
+        tokens
+    }
+}
+
+// `Name { field: value, ... }`
+#[derive(Debug)]
+pub struct StructLiteralExpressionSyntax {
+    identifier: SyntaxToken,
+    open_brace: SyntaxToken,
+    fields: Vec<FieldInitializer>,
+    commas: Vec<SyntaxToken>,
+    close_brace: SyntaxToken,
+}
+
+impl Clone for StructLiteralExpressionSyntax {
+    fn clone(&self) -> Self {
+        StructLiteralExpressionSyntax {
+            identifier: self.identifier.clone(),
+            open_brace: self.open_brace.clone(),
+            fields: self.fields.clone(),
+            commas: self.commas.clone(),
+            close_brace: self.close_brace.clone(),
+        }
+    }
+}
+
+impl StructLiteralExpressionSyntax {
+    pub fn new(
+        identifier: SyntaxToken,
+        open_brace: SyntaxToken,
+        fields: Vec<FieldInitializer>,
+        commas: Vec<SyntaxToken>,
+        close_brace: SyntaxToken,
+    ) -> Self {
+        Self {
+            identifier,
+            open_brace,
+            fields,
+            commas,
+            close_brace,
+        }
+    }
+
+    pub fn get_identifier(&self) -> &SyntaxToken {
+        &self.identifier
+    }
+
+    pub fn get_fields(&self) -> Vec<FieldInitializer> {
+        self.fields.clone()
+    }
+}
+
+impl Expression for StructLiteralExpressionSyntax {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_kind(&self) -> &SyntaxKind {
+        &SyntaxKind::StructLiteralExpression
+    }
+
+    fn get_children(&self) -> Vec<Box<dyn Expression>> {
+        self.fields
+            .iter()
+            .map(|field| field.get_expression())
+            .collect()
+    }
+
+    fn get_value(&self) -> LiteralValue {
+        todo!()
+    }
+
+    fn get_type(&self) -> LiteralType {
+        todo!()
+    }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.identifier.clone(), self.open_brace.clone()];
+        for (index, field) in self.fields.iter().enumerate() {
+            if index > 0 {
+                tokens.push(self.commas[index - 1].clone());
+            }
+            tokens.extend(field.get_tokens());
+        }
+        tokens.push(self.close_brace.clone());
+        tokens
+    }
+}
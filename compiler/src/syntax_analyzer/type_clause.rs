@@ -0,0 +1,43 @@
+use crate::util::syntax_token::SyntaxToken;
+
+// `: type`, where the type is a name inside any number of brackets, such as `: [[int]]`.
+#[derive(Debug, Clone)]
+pub struct TypeClause {
+    colon: SyntaxToken,
+    open_brackets: Vec<SyntaxToken>,
+    type_name: SyntaxToken,
+    close_brackets: Vec<SyntaxToken>,
+}
+
+impl TypeClause {
+    pub fn new(
+        colon: SyntaxToken,
+        open_brackets: Vec<SyntaxToken>,
+        type_name: SyntaxToken,
+        close_brackets: Vec<SyntaxToken>,
+    ) -> Self {
+        Self {
+            colon,
+            open_brackets,
+            type_name,
+            close_brackets,
+        }
+    }
+
+    pub fn get_type_name(&self) -> &SyntaxToken {
+        &self.type_name
+    }
+
+    // The number of array levels around the named type.
+    pub fn get_depth(&self) -> usize {
+        self.open_brackets.len()
+    }
+
+    pub fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.colon.clone()];
+        tokens.extend(self.open_brackets.clone());
+        tokens.push(self.type_name.clone());
+        tokens.extend(self.close_brackets.clone());
+        tokens
+    }
+}
@@ -56,7 +56,13 @@ impl Expression for UnaryExpressionSyntax {
         todo!()
     }
 
-    fn get_type(&self) -> &LiteralType {
+    fn get_type(&self) -> LiteralType {
         todo!()
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.operator.clone()];
+        tokens.extend(self.operand.get_tokens());
+        tokens
+    }
 }
@@ -60,4 +60,12 @@ impl Statement for WhileStatement {
     fn get_kind(&self) -> &SyntaxKind {
         &SyntaxKind::WhileStatement
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = vec![self.while_token.clone(), self.open_parenthesis.clone()];
+        tokens.extend(self.condition.get_tokens());
+        tokens.push(self.close_parenthesis.clone());
+        tokens.extend(self.body.get_tokens());
+        tokens
+    }
 }
@@ -0,0 +1,1209 @@
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        compile_program,
+        environment::Environment,
+        evaluation::execution_limits::ExecutionLimits,
+        lexical_analyzer::lexer::Lexer,
+        parse_lr_program,
+        reports::{diagnostics::Diagnostics, text_type::TextType},
+        syntax_analyzer::{
+            array_literal_expression::ArrayLiteralExpressionSyntax, assignment::Assignment,
+            binary_expression::BinaryExpressionSyntax, call_expression::CallExpressionSyntax,
+            conditional_expression::ConditionalExpressionSyntax,
+            constant_declaration::ConstantDeclaration, else_statement::ElseStatement,
+            expression_statement::ExpressionStatement, field_assignment::FieldAssignment,
+            field_expression::FieldExpressionSyntax, for_statement::ForStatement,
+            function_declaration::FunctionDeclaration, if_statement::IfStatement,
+            increment_statement::IncrementStatement, index_assignment::IndexAssignment,
+            index_expression::IndexExpressionSyntax, literal_expression::LiteralExpressionSyntax,
+            name_expression::NameExpressionSyntax,
+            parenthesized_expression::ParenthesizedExpressionSyntax,
+            parser::Parser as CustomParser, return_statement::ReturnStatement,
+            statement_list::StatementList, struct_declaration::StructDeclaration,
+            struct_literal_expression::StructLiteralExpressionSyntax, type_clause::TypeClause,
+            unary_expression::UnaryExpressionSyntax, variable_declaration::VariableDeclaration,
+            while_statement::WhileStatement,
+        },
+        util::{
+            backend_type::BackendType, expression::Expression, parser_type::ParserType,
+            statement::Statement, syntax_kind::SyntaxKind,
+        },
+    };
+
+    const PROGRAMS: u64 = 150;
+
+    #[test]
+    fn test_parsers_agree() {
+        for seed in 1..=PROGRAMS {
+            let program = Generator::new(seed).program();
+
+            if let Some(mismatch) = find_mismatch(&program) {
+                let program = minimize(program);
+                panic!(
+                    "Parsers disagree on program generated from seed {}.\n{}\nMinimized reproducer:\n{}",
+                    seed,
+                    find_mismatch(&program).unwrap_or(mismatch),
+                    render_program(&program)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_structural_comparison() {
+        assert_eq!(
+            parse_recursive("{ let a = 1 + 2 * 3; }"),
+            parse_lr("{\n  let a =\n    1 + 2 * 3;\n}")
+        );
+        assert_ne!(
+            parse_recursive("{ let a = (1 + 2) * 3; }"),
+            parse_recursive("{ let a = 1 + 2 * 3; }")
+        );
+        assert_eq!(
+            parse_recursive("{ let a = x || y && z; }"),
+            Ok("{(let a (x || (y && z)))}".to_string())
+        );
+        assert_eq!(
+            parse_recursive("{ let a = if (x) y else z || w; }"),
+            Ok("{(let a (if x y (z || w)))}".to_string())
+        );
+        assert_eq!(
+            parse_recursive("{ let a = 1 + if (x) 2 else 3 * 4; }"),
+            parse_lr("{ let a = 1 + if (x) 2 else 3 * 4; }")
+        );
+        assert_eq!(
+            parse_recursive("{ let a = x | y ^ z & w == 1 << 2 + 3; }"),
+            Ok(
+                "{(let a (x | (y ^ (z & (w == (Integer(1) << (Integer(2) + Integer(3))))))))}"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            parse_recursive("{ let a = ~x | y ^ z & w == 1 << 2 + 3 || b; }"),
+            parse_lr("{ let a = ~x | y ^ z & w == 1 << 2 + 3 || b; }")
+        );
+        assert!(parse_lr("{ let a = ; }").is_err());
+        for code in [
+            "{ let let = 1; }",
+            "{ let while = 1; }",
+            "{ fn f(true: int): int { return 1; } }",
+            "{ let a = 1; a = to; }",
+        ] {
+            assert!(parse_recursive(code).is_err());
+            assert!(parse_lr(code).is_err());
+        }
+        for code in [
+            "{ let a = [1, 2]; if (a[0] == 1) { a[1] = 3; } else { a[1] += 4; } }",
+            "{ for (i = 0 to 3) { while (false) { break; } continue; } }",
+            "{ struct P { x: int, y: [int] } let p = P { x: 1, y: [2] }; p.x = 2; }",
+            "{ fn f(a: int, b: [string]): int { return if (true) a else 0; } f(1, [\"\"]); }",
+            "{ let s = \"héllo → wörld\"; /* ünïcode */ let t = s + \"✓\"; // ✓\n const u = t; }",
+            "{ let iffy = 1; iffy = 2; let fortune = iffy; }",
+            "{ }",
+            "{ fn f() { } f(); while (false) { } if (true) { } else { { } } }",
+        ] {
+            assert!(parse_lr(code).is_ok());
+            assert_eq!(
+                token_spans(code, &ParserType::Recursive),
+                token_spans(code, &ParserType::Lr)
+            );
+        }
+        for code in [
+            "{ let a = 1; /* a **/ let b = 2; }",
+            "{ let a = 1; /* x // y */ }",
+            "{ let a = 1 /* a * b / c ** d *x/ /***/ */; /* **/ }",
+        ] {
+            assert!(parse_recursive(code).is_ok());
+            assert_eq!(parse_recursive(code), parse_lr(code));
+        }
+        assert_eq!(
+            parse_recursive("{ let a = 1; /* never closed }"),
+            Err("Unterminated block comment.".to_string())
+        );
+        assert_eq!(
+            parse_recursive("{ let a = 1; /* never closed }"),
+            parse_lr("{ let a = 1; /* never closed }")
+        );
+        assert_eq!(
+            parse_recursive("{ let a = 99999999999; }"),
+            Err("The number 99999999999 isn't valid.".to_string())
+        );
+        assert_eq!(
+            parse_recursive("{ let a = 99999999999; }"),
+            parse_lr("{ let a = 99999999999; }")
+        );
+        assert_eq!(
+            parse_recursive("{ // a\n let a = 1 /* b /* c */ */ + 2; }"),
+            parse_lr("{ // a\n let a = 1 /* b /* c */ */ + 2; }")
+        );
+        assert_eq!(
+            parse_recursive("{ let a = -b[1][i + 1] * [1, 2][0]; }"),
+            Ok("{(let a ((- (index (index b Integer(1)) (i + Integer(1)))) * (index (array Integer(1) Integer(2)) Integer(0))))}".to_string())
+        );
+        assert_eq!(
+            parse_recursive("{ let a = -b[1][i + 1] * [1, 2][0]; a[0] += len(a); }"),
+            parse_lr("{ let a = -b[1][i + 1] * [1, 2][0]; a[0] += len(a); }")
+        );
+        assert_eq!(
+            parse_recursive("{ struct P { x: int, y: [[P]] } let a = -P { x: 1, y: [] }.y[0].x; a.x *= b.c; }"),
+            Ok("{(struct P (x 0 int) (y 2 P)) (let a (- (field (index (field (new P (x Integer(1)) (y (array ))) y) Integer(0)) x))) (*= (field a x) (field b c))}".to_string())
+        );
+        assert_eq!(
+            parse_recursive(
+                "{ struct P { x: int, y: [[P]] } let a = -P { x: 1, y: [] }.y[0].x; a.x *= b.c; }"
+            ),
+            parse_lr(
+                "{ struct P { x: int, y: [[P]] } let a = -P { x: 1, y: [] }.y[0].x; a.x *= b.c; }"
+            )
+        );
+    }
+
+    // Returns a description of the first difference between the two parsers on the
+    // program, comparing trees, token spans, diagnostics and the final symbol tables.
+    fn find_mismatch(program: &[Node]) -> Option<String> {
+        let code = render_program(program);
+
+        // The parsers word their syntax errors differently, so only rejecting the same
+        // programs is required of them.
+        match (parse_recursive(&code), parse_lr(&code)) {
+            (Ok(recursive), Ok(lr)) if recursive != lr => {
+                return Some(format!("Recursive: {}\nLr:        {}", recursive, lr))
+            }
+            (Err(_), Err(_)) => return None,
+            (Ok(_), Ok(_)) => {}
+            (recursive, lr) => {
+                return Some(format!("Recursive: {:?}\nLr:        {:?}", recursive, lr))
+            }
+        }
+
+        let recursive = token_spans(&code, &ParserType::Recursive);
+        let lr = token_spans(&code, &ParserType::Lr);
+        if recursive != lr {
+            return Some(format!(
+                "Recursive spans: {:?}\nLr spans:        {:?}",
+                recursive, lr
+            ));
+        }
+
+        let recursive = run(&code, &ParserType::Recursive);
+        let lr = run(&code, &ParserType::Lr);
+        if recursive != lr {
+            return Some(format!("Recursive: {:?}\nLr:        {:?}", recursive, lr));
+        }
+
+        None
+    }
+
+    fn parse_recursive(code: &str) -> Result<String, String> {
+        parse_recursive_tree(code).map(|root| describe_statement(root.as_ref()))
+    }
+
+    fn parse_lr(code: &str) -> Result<String, String> {
+        parse_lr_tree(code).map(|root| describe_statement(root.as_ref()))
+    }
+
+    // The position and the length of every token, in the order of the tree.
+    fn token_spans(code: &str, parser: &ParserType) -> Result<Vec<(usize, usize)>, String> {
+        let root = match parser {
+            ParserType::Recursive => parse_recursive_tree(code)?,
+            ParserType::Lr => parse_lr_tree(code)?,
+        };
+        Ok(root
+            .get_tokens()
+            .iter()
+            .map(|token| (token.position(), token.length()))
+            .collect())
+    }
+
+    fn parse_recursive_tree(code: &str) -> Result<Box<dyn Statement>, String> {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let mut lexer = Lexer::in_memory_reader(code, Rc::clone(&diagnostics));
+        let mut parser = CustomParser::new(Rc::clone(&diagnostics));
+        parser.create(&mut lexer);
+
+        let errors = diagnostics.borrow().filter_type(TextType::Error);
+        if let Some(diagnostic) = errors.first() {
+            return Err(diagnostic.get_message().to_string());
+        }
+
+        let root = parser.parse();
+        let errors = diagnostics.borrow().filter_type(TextType::Error);
+        match errors.first() {
+            Some(diagnostic) => Err(diagnostic.get_message().to_string()),
+            None => Ok(root),
+        }
+    }
+
+    fn parse_lr_tree(code: &str) -> Result<Box<dyn Statement>, String> {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        match parse_lr_program(Rc::clone(&diagnostics), code) {
+            Some(root) => Ok(root),
+            None => Err(diagnostics.borrow().filter_type(TextType::Error)[0]
+                .get_message()
+                .to_string()),
+        }
+    }
+
+    fn run(code: &str, parser: &ParserType) -> (Vec<(String, String)>, Vec<String>) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            code,
+            parser,
+            &BackendType::TreeWalker,
+            &ExecutionLimits {
+                max_steps: 10_000,
+                timeout_ms: 1_000,
+                max_call_depth: 100,
+                max_memory_mb: 256,
+            },
+        );
+
+        let mut symbol_table: Vec<(String, String)> = environment
+            .borrow()
+            .get_variables()
+            .iter()
+            .map(|(symbol, value)| (symbol.id(), format!("{:?}", value)))
+            .collect();
+        symbol_table.sort();
+
+        let messages = diagnostics
+            .borrow()
+            .filter_type(TextType::Error)
+            .iter()
+            .map(|diagnostic| diagnostic.get_message().to_string())
+            .collect();
+
+        (symbol_table, messages)
+    }
+
+    // Prints the tree as an s-expression without positions, so that trees from both
+    // parsers can be compared as strings.
+    fn describe_statement(statement: &dyn Statement) -> String {
+        let any = statement.as_any();
+        match statement.get_kind() {
+            SyntaxKind::StatementList => {
+                let list = any.downcast_ref::<StatementList>().unwrap();
+                let statements: Vec<String> = list
+                    .get_statements()
+                    .iter()
+                    .map(|statement| describe_statement(statement.as_ref()))
+                    .collect();
+                format!("{{{}}}", statements.join(" "))
+            }
+            SyntaxKind::Assignment => {
+                let assignment = any.downcast_ref::<Assignment>().unwrap();
+                format!(
+                    "({} {} {})",
+                    assignment.get_equals().name(),
+                    assignment.get_variable().name(),
+                    describe_expression(assignment.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::IndexAssignment => {
+                let assignment = any.downcast_ref::<IndexAssignment>().unwrap();
+                format!(
+                    "({} (index {} {}) {})",
+                    assignment.get_equals().name(),
+                    assignment.get_variable().name(),
+                    describe_expression(assignment.get_index().as_ref()),
+                    describe_expression(assignment.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::FieldAssignment => {
+                let assignment = any.downcast_ref::<FieldAssignment>().unwrap();
+                format!(
+                    "({} (field {} {}) {})",
+                    assignment.get_equals().name(),
+                    assignment.get_variable().name(),
+                    assignment.get_field().name(),
+                    describe_expression(assignment.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::StructDeclaration => {
+                let declaration = any.downcast_ref::<StructDeclaration>().unwrap();
+                let fields: Vec<String> = declaration
+                    .get_fields()
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "({} {})",
+                            field.get_identifier().name(),
+                            describe_type_clause(field.get_type_clause())
+                        )
+                    })
+                    .collect();
+                format!(
+                    "(struct {} {})",
+                    declaration.get_identifier().name(),
+                    fields.join(" ")
+                )
+            }
+            SyntaxKind::IncrementStatement => {
+                let increment = any.downcast_ref::<IncrementStatement>().unwrap();
+                format!(
+                    "({} {})",
+                    increment.get_operator().name(),
+                    increment.get_variable().name()
+                )
+            }
+            SyntaxKind::VariableDeclaration => {
+                let declaration = any.downcast_ref::<VariableDeclaration>().unwrap();
+                format!(
+                    "(let {} {})",
+                    declaration.get_variable().name(),
+                    describe_expression(declaration.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::ConstantDeclaration => {
+                let declaration = any.downcast_ref::<ConstantDeclaration>().unwrap();
+                format!(
+                    "(const {} {})",
+                    declaration.get_variable().name(),
+                    describe_expression(declaration.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::IfStatement => {
+                let if_statement = any.downcast_ref::<IfStatement>().unwrap();
+                let else_clause = match if_statement.get_else_clause() {
+                    Some(else_clause) => format!(" {}", describe_statement(else_clause.as_ref())),
+                    None => String::new(),
+                };
+                format!(
+                    "(if {} {}{})",
+                    describe_expression(if_statement.get_condition().as_ref()),
+                    describe_statement(if_statement.get_then_statement().as_ref()),
+                    else_clause
+                )
+            }
+            SyntaxKind::ElseStatement => {
+                let else_statement = any.downcast_ref::<ElseStatement>().unwrap();
+                format!(
+                    "(else {})",
+                    describe_statement(else_statement.get_else_statement().as_ref())
+                )
+            }
+            SyntaxKind::WhileStatement => {
+                let while_statement = any.downcast_ref::<WhileStatement>().unwrap();
+                format!(
+                    "(while {} {})",
+                    describe_expression(while_statement.get_condition().as_ref()),
+                    describe_statement(while_statement.get_body().as_ref())
+                )
+            }
+            SyntaxKind::ForStatement => {
+                let for_statement = any.downcast_ref::<ForStatement>().unwrap();
+                format!(
+                    "(for {} {} {} {})",
+                    for_statement.get_identifier().name(),
+                    describe_expression(for_statement.get_lower_bound().as_ref()),
+                    describe_expression(for_statement.get_upper_bound().as_ref()),
+                    describe_statement(for_statement.get_body().as_ref())
+                )
+            }
+            SyntaxKind::FunctionDeclaration => {
+                let function = any.downcast_ref::<FunctionDeclaration>().unwrap();
+                let parameters: Vec<String> = function
+                    .get_parameters()
+                    .iter()
+                    .map(|parameter| {
+                        let parameter_type = match parameter.get_type_clause() {
+                            Some(type_clause) => describe_type_clause(type_clause),
+                            None => String::from("none"),
+                        };
+                        format!("({} {})", parameter.get_identifier().name(), parameter_type)
+                    })
+                    .collect();
+                let return_type = match function.get_return_type() {
+                    Some(type_clause) => describe_type_clause(type_clause),
+                    None => String::from("none"),
+                };
+                format!(
+                    "(fn {} ({}) {} {})",
+                    function.get_identifier().name(),
+                    parameters.join(" "),
+                    return_type,
+                    describe_statement(function.get_body().as_ref())
+                )
+            }
+            SyntaxKind::ReturnStatement => {
+                let return_statement = any.downcast_ref::<ReturnStatement>().unwrap();
+                format!(
+                    "(return {})",
+                    describe_expression(return_statement.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::BreakStatement => "(break)".to_string(),
+            SyntaxKind::ContinueStatement => "(continue)".to_string(),
+            SyntaxKind::ExpressionStatement => {
+                let expression_statement = any.downcast_ref::<ExpressionStatement>().unwrap();
+                format!(
+                    "({};)",
+                    describe_expression(expression_statement.get_expression().as_ref())
+                )
+            }
+            kind => format!("<{:?}>", kind),
+        }
+    }
+
+    fn describe_type_clause(type_clause: &TypeClause) -> String {
+        format!(
+            "{} {}",
+            type_clause.get_depth(),
+            type_clause.get_type_name().name()
+        )
+    }
+
+    fn describe_expression(expression: &dyn Expression) -> String {
+        let any = expression.as_any();
+        match expression.get_kind() {
+            SyntaxKind::LiteralExpression => {
+                let literal = any.downcast_ref::<LiteralExpressionSyntax>().unwrap();
+                format!("{:?}", literal.get_token().value())
+            }
+            SyntaxKind::NameExpression => {
+                let name = any.downcast_ref::<NameExpressionSyntax>().unwrap();
+                name.get_token().name()
+            }
+            SyntaxKind::BinaryExpression => {
+                let binary = any.downcast_ref::<BinaryExpressionSyntax>().unwrap();
+                format!(
+                    "({} {} {})",
+                    describe_expression(binary.get_left().as_ref()),
+                    binary.get_operator().name(),
+                    describe_expression(binary.get_right().as_ref())
+                )
+            }
+            SyntaxKind::UnaryExpression => {
+                let unary = any.downcast_ref::<UnaryExpressionSyntax>().unwrap();
+                format!(
+                    "({} {})",
+                    unary.operator().name(),
+                    describe_expression(unary.operand().as_ref())
+                )
+            }
+            SyntaxKind::ParenthesizedExpression => {
+                let parenthesized = any.downcast_ref::<ParenthesizedExpressionSyntax>().unwrap();
+                format!(
+                    "[{}]",
+                    describe_expression(parenthesized.get_expression().as_ref())
+                )
+            }
+            SyntaxKind::CallExpression => {
+                let call = any.downcast_ref::<CallExpressionSyntax>().unwrap();
+                let arguments: Vec<String> = call
+                    .get_arguments()
+                    .iter()
+                    .map(|argument| describe_expression(argument.as_ref()))
+                    .collect();
+                format!("({} {})", call.get_identifier().name(), arguments.join(" "))
+            }
+            SyntaxKind::ConditionalExpression => {
+                let conditional = any.downcast_ref::<ConditionalExpressionSyntax>().unwrap();
+                format!(
+                    "(if {} {} {})",
+                    describe_expression(conditional.get_condition().as_ref()),
+                    describe_expression(conditional.get_when_true().as_ref()),
+                    describe_expression(conditional.get_when_false().as_ref())
+                )
+            }
+            SyntaxKind::ArrayLiteralExpression => {
+                let array = any.downcast_ref::<ArrayLiteralExpressionSyntax>().unwrap();
+                let elements: Vec<String> = array
+                    .get_elements()
+                    .iter()
+                    .map(|element| describe_expression(element.as_ref()))
+                    .collect();
+                format!("(array {})", elements.join(" "))
+            }
+            SyntaxKind::IndexExpression => {
+                let index = any.downcast_ref::<IndexExpressionSyntax>().unwrap();
+                format!(
+                    "(index {} {})",
+                    describe_expression(index.get_array().as_ref()),
+                    describe_expression(index.get_index().as_ref())
+                )
+            }
+            SyntaxKind::StructLiteralExpression => {
+                let literal = any.downcast_ref::<StructLiteralExpressionSyntax>().unwrap();
+                let fields: Vec<String> = literal
+                    .get_fields()
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "({} {})",
+                            field.get_identifier().name(),
+                            describe_expression(field.get_expression().as_ref())
+                        )
+                    })
+                    .collect();
+                format!(
+                    "(new {} {})",
+                    literal.get_identifier().name(),
+                    fields.join(" ")
+                )
+            }
+            SyntaxKind::FieldExpression => {
+                let field = any.downcast_ref::<FieldExpressionSyntax>().unwrap();
+                format!(
+                    "(field {} {})",
+                    describe_expression(field.get_structure().as_ref()),
+                    field.get_field().name()
+                )
+            }
+            kind => format!("<{:?}>", kind),
+        }
+    }
+
+    // A small copy of the grammar used to generate programs, so that failing programs
+    // can be shrunk before they are rendered back to source.
+    #[derive(Clone, Debug)]
+    enum Node {
+        Literal(String),
+        Name(String),
+        Unary(&'static str, Box<Node>),
+        Binary(Box<Node>, &'static str, Box<Node>),
+        Parenthesized(Box<Node>),
+        Call(String, Vec<Node>),
+        Conditional(Box<Node>, Box<Node>, Box<Node>),
+        Let(String, Box<Node>),
+        Const(String, Box<Node>),
+        // `=` or a compound operator such as `+=`.
+        Assign(String, &'static str, Box<Node>),
+        Increment(String, &'static str),
+        // The else branch is a block or, for an else-if, another if.
+        If(Box<Node>, Vec<Node>, Option<Box<Node>>),
+        While(Box<Node>, Vec<Node>),
+        For(String, Box<Node>, Box<Node>, Vec<Node>),
+        // Parameters and the return type are written with their `int` type or left out.
+        Function(String, Vec<(String, bool)>, bool, Vec<Node>),
+        Return(Box<Node>),
+        Break,
+        Continue,
+        CallStatement(String, Vec<Node>),
+        Block(Vec<Node>),
+        Comment(&'static str),
+        // A block comment in front of an expression.
+        Commented(&'static str, Box<Node>),
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Type {
+        Integer,
+        Float,
+        Boolean,
+        String,
+    }
+
+    fn render_program(program: &[Node]) -> String {
+        let mut output = String::new();
+        render_block(program, 0, &mut output);
+        output
+    }
+
+    fn render_block(statements: &[Node], indent: usize, output: &mut String) {
+        output.push_str("{\n");
+        for statement in statements {
+            output.push_str(&"    ".repeat(indent + 1));
+            render_statement(statement, indent + 1, output);
+            output.push('\n');
+        }
+        output.push_str(&"    ".repeat(indent));
+        output.push('}');
+    }
+
+    fn render_statement(node: &Node, indent: usize, output: &mut String) {
+        match node {
+            Node::Let(name, value) => {
+                output.push_str(&format!("let {} = {};", name, render_expression(value)))
+            }
+            Node::Const(name, value) => {
+                output.push_str(&format!("const {} = {};", name, render_expression(value)))
+            }
+            Node::Assign(name, operator, value) => output.push_str(&format!(
+                "{} {} {};",
+                name,
+                operator,
+                render_expression(value)
+            )),
+            Node::Increment(name, operator) => output.push_str(&format!("{}{};", name, operator)),
+            Node::If(condition, then_block, else_branch) => {
+                output.push_str(&format!("if ({}) ", render_expression(condition)));
+                render_block(then_block, indent, output);
+                if let Some(else_branch) = else_branch {
+                    output.push_str(" else ");
+                    render_statement(else_branch, indent, output);
+                }
+            }
+            Node::While(condition, body) => {
+                output.push_str(&format!("while ({}) ", render_expression(condition)));
+                render_block(body, indent, output);
+            }
+            Node::For(name, lower, upper, body) => {
+                output.push_str(&format!(
+                    "for ({} = {} to {}) ",
+                    name,
+                    render_expression(lower),
+                    render_expression(upper)
+                ));
+                render_block(body, indent, output);
+            }
+            Node::Function(name, parameters, typed, body) => {
+                let parameters: Vec<String> = parameters
+                    .iter()
+                    .map(|(parameter, typed)| match typed {
+                        true => format!("{}: int", parameter),
+                        false => parameter.clone(),
+                    })
+                    .collect();
+                let return_type = if *typed { ": int" } else { "" };
+                output.push_str(&format!(
+                    "fn {}({}){} ",
+                    name,
+                    parameters.join(", "),
+                    return_type
+                ));
+                render_block(body, indent, output);
+            }
+            Node::Return(value) => {
+                output.push_str(&format!("return {};", render_expression(value)))
+            }
+            Node::Break => output.push_str("break;"),
+            Node::Continue => output.push_str("continue;"),
+            Node::CallStatement(name, arguments) => {
+                output.push_str(&format!("{};", render_call(name, arguments)))
+            }
+            Node::Block(statements) => render_block(statements, indent, output),
+            Node::Comment(text) => output.push_str(text),
+            expression => output.push_str(&render_expression(expression)),
+        }
+    }
+
+    fn render_expression(node: &Node) -> String {
+        match node {
+            Node::Literal(value) | Node::Name(value) => value.clone(),
+            Node::Unary(operator, operand) => {
+                let operand = render_expression(operand);
+                // Keeps `- -a` from being read as a decrement.
+                match operand.starts_with('-') {
+                    true => format!("{} {}", operator, operand),
+                    false => format!("{}{}", operator, operand),
+                }
+            }
+            Node::Binary(left, operator, right) => format!(
+                "{} {} {}",
+                render_expression(left),
+                operator,
+                render_expression(right)
+            ),
+            Node::Parenthesized(expression) => format!("({})", render_expression(expression)),
+            Node::Call(name, arguments) => render_call(name, arguments),
+            Node::Conditional(condition, when_true, when_false) => format!(
+                "if ({}) {} else {}",
+                render_expression(condition),
+                render_expression(when_true),
+                render_expression(when_false)
+            ),
+            Node::Commented(comment, expression) => {
+                format!("{} {}", comment, render_expression(expression))
+            }
+            _ => String::new(),
+        }
+    }
+
+    fn render_call(name: &str, arguments: &[Node]) -> String {
+        let arguments: Vec<String> = arguments.iter().map(render_expression).collect();
+        format!("{}({})", name, arguments.join(", "))
+    }
+
+    struct Generator {
+        state: u64,
+        names: usize,
+        variables: Vec<(String, Type, bool)>,
+        functions: Vec<(String, usize)>,
+        loops: usize,
+    }
+
+    impl Generator {
+        fn new(seed: u64) -> Self {
+            Self {
+                state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+                names: 0,
+                variables: Vec::new(),
+                functions: Vec::new(),
+                loops: 0,
+            }
+        }
+
+        // xorshift64, which is enough to get reproducible programs from a seed.
+        fn next(&mut self, bound: usize) -> usize {
+            self.state ^= self.state << 13;
+            self.state ^= self.state >> 7;
+            self.state ^= self.state << 17;
+            (self.state % bound as u64) as usize
+        }
+
+        fn fresh_name(&mut self, prefix: &str) -> String {
+            // Now and then a keyword, which both parsers must reject as a name.
+            if self.next(100) == 0 {
+                return ["let", "while", "if", "true", "to", "fn", "struct"][self.next(7)]
+                    .to_string();
+            }
+            self.names += 1;
+            format!("{}_{}", prefix, self.names)
+        }
+
+        fn program(&mut self) -> Vec<Node> {
+            let mut statements = Vec::new();
+            for _ in 0..self.next(3) {
+                statements.push(self.function());
+            }
+            for _ in 0..1 + self.next(4) {
+                statements.extend(self.statement(3));
+            }
+            statements
+        }
+
+        fn function(&mut self) -> Node {
+            let name = self.fresh_name("f");
+            let parameters: Vec<(String, bool)> = (0..self.next(3))
+                .map(|_| (self.fresh_name("p"), self.next(2) == 0))
+                .collect();
+            let typed = self.next(2) == 0;
+
+            let outer = std::mem::take(&mut self.variables);
+            for (parameter, _) in &parameters {
+                self.variables
+                    .push((parameter.clone(), Type::Integer, false));
+            }
+            let body = vec![Node::Return(Box::new(self.expression(Type::Integer, 2)))];
+            self.variables = outer;
+
+            self.functions.push((name.clone(), parameters.len()));
+            Node::Function(name, parameters, typed, body)
+        }
+
+        fn block(&mut self, depth: usize) -> Vec<Node> {
+            let scope = self.variables.len();
+            let mut statements = Vec::new();
+            let count = match self.next(8) {
+                0 => 0,
+                _ => 1 + self.next(3),
+            };
+            for _ in 0..count {
+                statements.extend(self.statement(depth));
+                if self.next(6) == 0 {
+                    statements.push(self.comment());
+                }
+                if self.loops > 0 && self.next(6) == 0 {
+                    statements.push(self.loop_control());
+                }
+            }
+            self.variables.truncate(scope);
+            statements
+        }
+
+        fn statement(&mut self, depth: usize) -> Vec<Node> {
+            let choice = if depth == 0 {
+                self.next(4)
+            } else {
+                self.next(9)
+            };
+            match choice {
+                0 | 1 => {
+                    let kind = self.any_type();
+                    let value = self.expression(kind, 2);
+                    let name = self.fresh_name("v");
+                    self.variables.push((name.clone(), kind, true));
+                    vec![Node::Let(name, Box::new(value))]
+                }
+                2 => {
+                    let kind = self.any_type();
+                    let value = self.expression(kind, 2);
+                    let name = self.fresh_name("c");
+                    self.variables.push((name.clone(), kind, false));
+                    vec![Node::Const(name, Box::new(value))]
+                }
+                3 => {
+                    let mutable: Vec<(String, Type)> = self
+                        .variables
+                        .iter()
+                        .filter(|(_, _, mutable)| *mutable)
+                        .map(|(name, kind, _)| (name.clone(), *kind))
+                        .collect();
+                    if mutable.is_empty() {
+                        return self.statement(0);
+                    }
+                    let (name, kind) = mutable[self.next(mutable.len())].clone();
+                    let operators: &[&'static str] = match kind {
+                        Type::Integer | Type::Float => &["=", "+=", "-=", "*=", "/=", "%="],
+                        Type::String => &["=", "+="],
+                        Type::Boolean => &["="],
+                    };
+                    if kind == Type::Integer && self.next(4) == 0 {
+                        return vec![Node::Increment(name, ["++", "--"][self.next(2)])];
+                    }
+                    let operator = operators[self.next(operators.len())];
+                    vec![Node::Assign(
+                        name,
+                        operator,
+                        Box::new(self.expression(kind, 2)),
+                    )]
+                }
+                4 => vec![self.if_statement(depth)],
+                5 => {
+                    let counter = self.fresh_name("w");
+                    let limit = self.next(5).to_string();
+                    self.variables.push((counter.clone(), Type::Integer, false));
+                    let mut body = vec![Node::Assign(
+                        counter.clone(),
+                        "=",
+                        Box::new(Node::Binary(
+                            Box::new(Node::Name(counter.clone())),
+                            "+",
+                            Box::new(Node::Literal("1".to_string())),
+                        )),
+                    )];
+                    body.extend(self.loop_body(depth - 1));
+                    vec![
+                        Node::Let(counter.clone(), Box::new(Node::Literal("0".to_string()))),
+                        Node::While(
+                            Box::new(Node::Binary(
+                                Box::new(Node::Name(counter)),
+                                "<",
+                                Box::new(Node::Literal(limit)),
+                            )),
+                            body,
+                        ),
+                    ]
+                }
+                6 => {
+                    let name = self.fresh_name("i");
+                    let lower = self.expression(Type::Integer, 1);
+                    let upper = self.expression(Type::Integer, 1);
+                    let scope = self.variables.len();
+                    self.variables.push((name.clone(), Type::Integer, false));
+                    let body = self.loop_body(depth - 1);
+                    self.variables.truncate(scope);
+                    vec![Node::For(name, Box::new(lower), Box::new(upper), body)]
+                }
+                7 if !self.functions.is_empty() => {
+                    let index = self.next(self.functions.len());
+                    let (name, arity) = self.functions[index].clone();
+                    let arguments = (0..arity)
+                        .map(|_| self.expression(Type::Integer, 1))
+                        .collect();
+                    vec![Node::CallStatement(name, arguments)]
+                }
+                _ => vec![Node::Block(self.block(depth - 1))],
+            }
+        }
+
+        fn if_statement(&mut self, depth: usize) -> Node {
+            let condition = self.expression(Type::Boolean, 2);
+            let then_block = self.block(depth - 1);
+            let else_branch = match self.next(4) {
+                0 | 1 => Some(Node::Block(self.block(depth - 1))),
+                2 => Some(self.if_statement(depth)),
+                _ => None,
+            };
+            Node::If(Box::new(condition), then_block, else_branch.map(Box::new))
+        }
+
+        fn loop_body(&mut self, depth: usize) -> Vec<Node> {
+            self.loops += 1;
+            let body = self.block(depth);
+            self.loops -= 1;
+            body
+        }
+
+        // Wrapped in an if most of the time, so that the rest of the body still runs.
+        fn loop_control(&mut self) -> Node {
+            let statement = [Node::Break, Node::Continue][self.next(2)].clone();
+            match self.next(3) {
+                0 => statement,
+                _ => Node::If(
+                    Box::new(self.expression(Type::Boolean, 1)),
+                    vec![statement],
+                    None,
+                ),
+            }
+        }
+
+        fn comment(&mut self) -> Node {
+            Node::Comment(
+                [
+                    "// a line comment { ;",
+                    "/* a block comment */",
+                    "/* an /* inner */ comment\n   over two lines */",
+                    "/* stars **/",
+                    "/* x // y */",
+                    "// ünïcode → ✓",
+                    "/* ünïcode → ✓ */",
+                ][self.next(7)],
+            )
+        }
+
+        fn any_type(&mut self) -> Type {
+            [Type::Integer, Type::Float, Type::Boolean, Type::String][self.next(4)]
+        }
+
+        fn expression(&mut self, kind: Type, depth: usize) -> Node {
+            if depth == 0 || self.next(3) == 0 {
+                return self.leaf(kind);
+            }
+            if self.next(12) == 0 {
+                let comment = ["/* a */", "/* **/", "/* é → ✓ */"][self.next(3)];
+                return Node::Commented(comment, Box::new(self.expression(kind, depth - 1)));
+            }
+
+            match (kind, self.next(5)) {
+                (Type::Integer, 0) if !self.functions.is_empty() => {
+                    let index = self.next(self.functions.len());
+                    let (name, arity) = self.functions[index].clone();
+                    let arguments = (0..arity)
+                        .map(|_| self.expression(Type::Integer, depth - 1))
+                        .collect();
+                    Node::Call(name, arguments)
+                }
+                (Type::Integer, 1) => {
+                    let operator = ["-", "~"][self.next(2)];
+                    Node::Unary(operator, Box::new(self.expression(kind, depth - 1)))
+                }
+                (Type::Float, 1) => Node::Unary("-", Box::new(self.expression(kind, depth - 1))),
+                (Type::Boolean, 1) => Node::Unary("!", Box::new(self.expression(kind, depth - 1))),
+                (_, 2) => Node::Parenthesized(Box::new(self.expression(kind, depth - 1))),
+                (_, 3) => Node::Conditional(
+                    Box::new(self.expression(Type::Boolean, depth - 1)),
+                    Box::new(self.expression(kind, depth - 1)),
+                    Box::new(self.expression(kind, depth - 1)),
+                ),
+                (Type::Boolean, _) => {
+                    let operand = match self.next(3) {
+                        0 => Type::Boolean,
+                        _ => self.any_type(),
+                    };
+                    let operators: &[&'static str] = match operand {
+                        Type::Boolean => &["&&", "||", "==", "!="],
+                        _ => &["==", "!=", "<", "<=", ">", ">="],
+                    };
+                    let operator = operators[self.next(operators.len())];
+                    self.binary(operand, operator, depth)
+                }
+                (Type::String, _) => self.binary(kind, "+", depth),
+                (Type::Integer, _) if self.next(3) == 0 => {
+                    let operator = ["&", "|", "^", "<<", ">>"][self.next(5)];
+                    self.binary(kind, operator, depth)
+                }
+                _ => {
+                    let operator = ["+", "-", "*", "/", "%", "**"][self.next(6)];
+                    self.binary(kind, operator, depth)
+                }
+            }
+        }
+
+        fn binary(&mut self, kind: Type, operator: &'static str, depth: usize) -> Node {
+            Node::Binary(
+                Box::new(self.expression(kind, depth - 1)),
+                operator,
+                Box::new(self.expression(kind, depth - 1)),
+            )
+        }
+
+        fn leaf(&mut self, kind: Type) -> Node {
+            let variables: Vec<String> = self
+                .variables
+                .iter()
+                .filter(|(_, variable, _)| *variable == kind)
+                .map(|(name, _, _)| name.clone())
+                .collect();
+            if !variables.is_empty() && self.next(2) == 0 {
+                return Node::Name(variables[self.next(variables.len())].clone());
+            }
+
+            let literal = match kind {
+                // Numbers that don't fit must be rejected by both parsers.
+                Type::Integer => match self.next(100) {
+                    0 => "99999999999".to_string(),
+                    1 => "2147483647".to_string(),
+                    _ => self.next(10).to_string(),
+                },
+                Type::Float => format!("{}.{}", self.next(10), self.next(10)),
+                Type::Boolean => ["true", "false"][self.next(2)].to_string(),
+                Type::String => {
+                    ["\"\"", "\"a\"", "\"b c\"", "\"\\n\"", "\"ünï ✓\""][self.next(5)].to_string()
+                }
+            };
+            Node::Literal(literal)
+        }
+    }
+
+    // Greedily applies the first simplification that keeps the parsers disagreeing
+    // until no simplification does.
+    fn minimize(mut program: Vec<Node>) -> Vec<Node> {
+        'outer: loop {
+            for candidate in shrink_block(&program) {
+                if find_mismatch(&candidate).is_some() {
+                    program = candidate;
+                    continue 'outer;
+                }
+            }
+            return program;
+        }
+    }
+
+    fn shrink_block(statements: &[Node]) -> Vec<Vec<Node>> {
+        let mut candidates = Vec::new();
+        for index in 0..statements.len() {
+            if statements.len() > 1 {
+                let mut candidate = statements.to_vec();
+                candidate.remove(index);
+                candidates.push(candidate);
+            }
+            for replacement in shrink(&statements[index]) {
+                let mut candidate = statements.to_vec();
+                candidate[index] = replacement;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+
+    fn shrink(node: &Node) -> Vec<Node> {
+        let mut candidates = Vec::new();
+        match node {
+            Node::Unary(operator, operand) => {
+                candidates.push((**operand).clone());
+                for operand in shrink(operand) {
+                    candidates.push(Node::Unary(operator, Box::new(operand)));
+                }
+            }
+            Node::Binary(left, operator, right) => {
+                candidates.push((**left).clone());
+                candidates.push((**right).clone());
+                for left in shrink(left) {
+                    candidates.push(Node::Binary(Box::new(left), operator, right.clone()));
+                }
+                for right in shrink(right) {
+                    candidates.push(Node::Binary(left.clone(), operator, Box::new(right)));
+                }
+            }
+            Node::Commented(_, expression) => candidates.push((**expression).clone()),
+            Node::Parenthesized(expression) => {
+                candidates.push((**expression).clone());
+                for expression in shrink(expression) {
+                    candidates.push(Node::Parenthesized(Box::new(expression)));
+                }
+            }
+            Node::Call(name, arguments) | Node::CallStatement(name, arguments) => {
+                for index in 0..arguments.len() {
+                    for argument in shrink(&arguments[index]) {
+                        let mut arguments = arguments.clone();
+                        arguments[index] = argument;
+                        candidates.push(match node {
+                            Node::Call(..) => Node::Call(name.clone(), arguments),
+                            _ => Node::CallStatement(name.clone(), arguments),
+                        });
+                    }
+                }
+            }
+            Node::Let(name, value) => {
+                for value in shrink(value) {
+                    candidates.push(Node::Let(name.clone(), Box::new(value)));
+                }
+            }
+            Node::Const(name, value) => {
+                for value in shrink(value) {
+                    candidates.push(Node::Const(name.clone(), Box::new(value)));
+                }
+            }
+            Node::Assign(name, operator, value) => {
+                for value in shrink(value) {
+                    candidates.push(Node::Assign(name.clone(), operator, Box::new(value)));
+                }
+            }
+            Node::Return(value) => {
+                for value in shrink(value) {
+                    candidates.push(Node::Return(Box::new(value)));
+                }
+            }
+            Node::Conditional(condition, when_true, when_false) => {
+                candidates.push((**when_true).clone());
+                candidates.push((**when_false).clone());
+                for condition in shrink(condition) {
+                    candidates.push(Node::Conditional(
+                        Box::new(condition),
+                        when_true.clone(),
+                        when_false.clone(),
+                    ));
+                }
+            }
+            Node::If(condition, then_block, else_branch) => {
+                candidates.push(Node::Block(then_block.clone()));
+                if let Some(else_branch) = else_branch {
+                    candidates.push((**else_branch).clone());
+                    candidates.push(Node::If(condition.clone(), then_block.clone(), None));
+                    for else_branch in shrink(else_branch) {
+                        if matches!(else_branch, Node::Block(_) | Node::If(..)) {
+                            candidates.push(Node::If(
+                                condition.clone(),
+                                then_block.clone(),
+                                Some(Box::new(else_branch)),
+                            ));
+                        }
+                    }
+                }
+                for condition in shrink(condition) {
+                    candidates.push(Node::If(
+                        Box::new(condition),
+                        then_block.clone(),
+                        else_branch.clone(),
+                    ));
+                }
+                for then_block in shrink_block(then_block) {
+                    candidates.push(Node::If(condition.clone(), then_block, else_branch.clone()));
+                }
+            }
+            Node::While(condition, body) => {
+                candidates.push(Node::Block(body.clone()));
+                for body in shrink_block(body) {
+                    candidates.push(Node::While(condition.clone(), body));
+                }
+            }
+            Node::For(name, lower, upper, body) => {
+                for body in shrink_block(body) {
+                    candidates.push(Node::For(name.clone(), lower.clone(), upper.clone(), body));
+                }
+            }
+            Node::Function(name, parameters, typed, body) => {
+                for body in shrink_block(body) {
+                    candidates.push(Node::Function(
+                        name.clone(),
+                        parameters.clone(),
+                        *typed,
+                        body,
+                    ));
+                }
+            }
+            Node::Block(statements) => {
+                candidates.extend(statements.iter().cloned());
+                for statements in shrink_block(statements) {
+                    candidates.push(Node::Block(statements));
+                }
+            }
+            Node::Literal(_)
+            | Node::Name(_)
+            | Node::Increment(..)
+            | Node::Comment(_)
+            | Node::Break
+            | Node::Continue => {}
+        }
+        candidates
+    }
+}
@@ -1,10 +1,20 @@
+use std::io::Cursor;
+
 use crate::{
-    generate,
+    language_server::{
+        server::LanguageServer,
+        transport::{read_message, write_message},
+    },
     reports::{text_place::TextPlace, text_type::TextType},
-    util::parser_type::ParserType,
-    Pair, Program, Report,
+    rocket,
+    util::{backend_type::BackendType, parser_type::ParserType},
+    FormatReport, Pair, Program, Report,
+};
+use rocket::{
+    http::Status,
+    local::blocking::Client,
+    serde::json::{self, serde_json::json, Value},
 };
-use rocket::{http::Status, local::blocking::Client, serde::json};
 
 #[test]
 #[ignore]
@@ -18,17 +28,22 @@ pub fn api_test_suit() {
     test_generate_const_reassignment();
     test_generate_assignment_without_declaration();
     test_generate_using_non_existing_variable();
+    test_generate_struct();
+    test_generate_deep_recursion();
+    test_format();
+    test_language_server();
 }
 
 #[test]
 #[ignore]
 fn test_generate_variables_assignments() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let a = 3; const b = 4; let res = 3 + a * b;  }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -73,12 +88,13 @@ fn test_generate_variables_assignments() {
 #[test]
 #[ignore]
 fn test_generate_for_loop() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let res = 0; for (j = 0 to 10) { res = res + j; } }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -103,12 +119,13 @@ fn test_generate_for_loop() {
 #[test]
 #[ignore]
 fn test_generate_while_loop() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let res = 0; while (res < 5) { res = res + 1; } }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -133,12 +150,13 @@ fn test_generate_while_loop() {
 #[test]
 #[ignore]
 fn test_generate_if_statement() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let a = 3; if (a == 3) { a = 4; } }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -163,12 +181,13 @@ fn test_generate_if_statement() {
 #[test]
 #[ignore]
 fn test_generate_else_statement() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let a = 3; if (a != 3) { a = 4; } else { a = 5; } }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -193,12 +212,13 @@ fn test_generate_else_statement() {
 #[test]
 #[ignore]
 fn test_generate_reassignment_variable_already_declare() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let a = 0; let a = 1; }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -233,12 +253,13 @@ fn test_generate_reassignment_variable_already_declare() {
 #[test]
 #[ignore]
 fn test_generate_const_reassignment() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ const a = 3; a = 4; }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -273,12 +294,13 @@ fn test_generate_const_reassignment() {
 #[test]
 #[ignore]
 fn test_generate_assignment_without_declaration() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ a = 4; }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -313,12 +335,13 @@ fn test_generate_assignment_without_declaration() {
 #[test]
 #[ignore]
 fn test_generate_using_non_existing_variable() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let res = 3 + a; }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -350,15 +373,89 @@ fn test_generate_using_non_existing_variable() {
     );
 }
 
+#[test]
+#[ignore]
+fn test_generate_struct() {
+    let rocket = rocket();
+    let client = Client::tracked(rocket).expect("valid rocket instance");
+
+    let data = Program {
+        code: "{ struct Point { x: int, name: string } let p = Point { x: 1, name: \"a\" }; p.x += 4; }"
+            .to_string(),
+        parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
+    };
+    let response = client.post("/generate").json(&data).dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let report: Report =
+        json::from_str(&response.into_string().unwrap()).expect("deserialize response body");
+    println!("{:?}", report);
+
+    assert_eq!(
+        check_result(
+            &Pair {
+                id: "p".to_string(),
+                value: "Point { x: 5, name: \"a\" }".to_string(),
+            },
+            &report
+        ),
+        true
+    );
+}
+
+#[test]
+#[ignore]
+fn test_generate_deep_recursion() {
+    let rocket = rocket();
+    let client = Client::tracked(rocket).expect("valid rocket instance");
+
+    let data = Program {
+        code: "{ fn down(n: int): int { if (n == 0) { return 0; } return down(n - 1) + 1; } let near = down(900); let res = down(100000); }"
+            .to_string(),
+        parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
+    };
+    let response = client.post("/generate").json(&data).dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let report: Report =
+        json::from_str(&response.into_string().unwrap()).expect("deserialize response body");
+    println!("{:?}", report);
+
+    assert_eq!(
+        check_result(
+            &Pair {
+                id: "near".to_string(),
+                value: "900".to_string(),
+            },
+            &report
+        ),
+        true
+    );
+    assert_eq!(
+        check_message(
+            "Execution was aborted after exceeding a call depth of 1000.",
+            &TextPlace::Runtime,
+            &TextType::Error,
+            &report
+        ),
+        true
+    );
+}
+
 #[test]
 #[ignore]
 fn test_generate_bad_scoping() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
-        code: "{ let a = true; { let b = 3; { let a = 3; } } }".to_string(),
+        code: "{ let a = 0; { let b = 3; } a = b; }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -381,7 +478,7 @@ fn test_generate_bad_scoping() {
 
     assert_eq!(
         check_message(
-            "Name a is already declared.",
+            "Name b is undefined.",
             &TextPlace::Semantic,
             &TextType::Error,
             &report
@@ -393,12 +490,13 @@ fn test_generate_bad_scoping() {
 #[test]
 #[ignore]
 fn test_generate_good_scoping() {
-    let rocket = rocket::build().mount("/", rocket::routes![generate]);
+    let rocket = rocket();
     let client = Client::tracked(rocket).expect("valid rocket instance");
 
     let data = Program {
         code: "{ let a = 0; { let b = 3; a = b; } { let b = 4; a = b; } }".to_string(),
         parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
     };
     let response = client.post("/generate").json(&data).dispatch();
 
@@ -440,3 +538,138 @@ fn check_message(message: &str, place: &TextPlace, text_type: &TextType, report:
     }
     false
 }
+
+#[test]
+#[ignore]
+fn test_format() {
+    let rocket = rocket();
+    let client = Client::tracked(rocket).expect("valid rocket instance");
+
+    let data = Program {
+        code: "{let a=3;\n\n\nif(a<4){a=a+1;}}".to_string(),
+        parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
+    };
+    let response = client.post("/format").json(&data).dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let report: FormatReport =
+        json::from_str(&response.into_string().unwrap()).expect("deserialize response body");
+
+    assert_eq!(
+        report.code,
+        "{\n    let a = 3;\n\n    if (a < 4) {\n        a = a + 1;\n    }\n}\n"
+    );
+    assert_eq!(report.diagnostics.is_empty(), true);
+
+    let data = Program {
+        code: "{ let a = ; }".to_string(),
+        parser: ParserType::Recursive,
+        backend: BackendType::TreeWalker,
+    };
+    let response = client.post("/format").json(&data).dispatch();
+    let report: FormatReport =
+        json::from_str(&response.into_string().unwrap()).expect("deserialize response body");
+
+    assert_eq!(report.code, "{ let a = ; }");
+    assert_eq!(report.diagnostics.len(), 1);
+}
+
+#[test]
+#[ignore]
+fn test_language_server() {
+    let code = "{\n    const b = 4;\n    let a = b + 1; // next\n    fn add(x: int, y: int): int { return x + y; }\n    a = add(a, b);\n}";
+    let document = json!({ "uri": "file:///test.rs" });
+    let at = |line: usize, character: usize| json!({ "textDocument": document, "position": { "line": line, "character": character } });
+    let range = |line: usize, start: usize, end: usize| json!({ "start": { "line": line, "character": start }, "end": { "line": line, "character": end } });
+
+    let messages = [
+        json!({ "id": 1, "method": "initialize", "params": {} }),
+        json!({ "method": "initialized", "params": {} }),
+        json!({ "method": "textDocument/didOpen", "params": { "textDocument": { "uri": "file:///test.rs", "text": code } } }),
+        json!({ "id": 2, "method": "textDocument/hover", "params": at(2, 12) }),
+        json!({ "id": 3, "method": "textDocument/definition", "params": at(4, 15) }),
+        json!({ "id": 4, "method": "textDocument/references", "params": { "textDocument": document, "position": at(1, 10)["position"], "context": { "includeDeclaration": true } } }),
+        json!({ "id": 5, "method": "textDocument/definition", "params": at(4, 9) }),
+        json!({ "id": 6, "method": "textDocument/documentSymbol", "params": { "textDocument": document } }),
+        json!({ "id": 7, "method": "textDocument/semanticTokens/full", "params": { "textDocument": document } }),
+        json!({ "method": "textDocument/didChange", "params": { "textDocument": document, "contentChanges": [{ "text": "{ let a = c; }" }] } }),
+        json!({ "id": 8, "method": "shutdown" }),
+        json!({ "method": "exit" }),
+    ];
+
+    let mut input = Vec::new();
+    for message in &messages {
+        write_message(&mut input, message).unwrap();
+    }
+    let mut output = Vec::new();
+    let exit_code = LanguageServer::new()
+        .run(&mut Cursor::new(input), &mut output)
+        .unwrap();
+    assert_eq!(exit_code, 0);
+
+    let mut output = Cursor::new(output);
+    let mut responses: Vec<Value> = Vec::new();
+    while let Some(response) = read_message(&mut output).unwrap() {
+        responses.push(response);
+    }
+    let result = |id: i32| {
+        responses
+            .iter()
+            .find(|response| response["id"] == id)
+            .map(|response| response["result"].clone())
+            .unwrap()
+    };
+    let diagnostics: Vec<&Value> = responses
+        .iter()
+        .filter(|response| response["method"] == "textDocument/publishDiagnostics")
+        .collect();
+
+    assert_eq!(
+        result(1)["capabilities"]["semanticTokensProvider"]["legend"]["tokenTypes"][6],
+        "comment"
+    );
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0]["params"]["diagnostics"], json!([]));
+    assert_eq!(
+        diagnostics[1]["params"]["diagnostics"][0]["range"],
+        range(0, 10, 11)
+    );
+    assert_eq!(result(2)["contents"]["value"], "```\nconst b: Integer\n```");
+    assert_eq!(result(3)["range"], range(1, 10, 11));
+    assert_eq!(
+        result(4)
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|location| location["range"].clone())
+            .collect::<Vec<Value>>(),
+        vec![range(1, 10, 11), range(2, 12, 13), range(4, 15, 16)]
+    );
+    assert_eq!(result(5)["range"], range(3, 7, 10));
+
+    let symbols = result(6);
+    assert_eq!(symbols[0]["name"], "b");
+    assert_eq!(symbols[0]["kind"], 14);
+    assert_eq!(symbols[1]["kind"], 13);
+    assert_eq!(symbols[2]["name"], "add");
+    assert_eq!(symbols[2]["kind"], 12);
+    assert_eq!(symbols[2]["children"].as_array().unwrap().len(), 2);
+
+    // `const` on line 1, then `b`, a read-only declaration, four characters later.
+    let data = result(7)["data"].clone();
+    assert_eq!(
+        data.as_array().unwrap()[..10],
+        json!([1, 4, 5, 0, 0, 0, 6, 1, 1, 3]).as_array().unwrap()[..]
+    );
+    assert_eq!(
+        data.as_array()
+            .unwrap()
+            .chunks(5)
+            .any(|token| token[3] == 6),
+        true
+    );
+
+    assert_eq!(result(8), Value::Null);
+}
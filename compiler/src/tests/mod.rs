@@ -1,2 +1,3 @@
+pub mod differential_testing;
 pub mod integration_testing;
 pub mod unit_testing;
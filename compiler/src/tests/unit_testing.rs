@@ -3,12 +3,21 @@ mod tests {
     use std::{cell::RefCell, rc::Rc};
 
     use crate::{
-        compile_program,
-        global_state::SYMBOL_TABLE,
-        reports::{diagnostics::Diagnostics, text_place::TextPlace, text_type::TextType},
+        cli, compile_program,
+        environment::Environment,
+        evaluation::execution_limits::ExecutionLimits,
+        format_program,
+        language_server::analysis::Analysis,
+        lexical_analyzer::lexer::Lexer,
+        repl::Repl,
+        reports::{
+            diagnostics::Diagnostics, renderer::render, source_map::SourceMap,
+            text_place::TextPlace, text_type::TextType,
+        },
+        syntax_analyzer::parser::Parser,
         tests::integration_testing::api_test_suit,
-        util::{literals::LiteralValue, parser_type::ParserType},
-        Program,
+        util::{backend_type::BackendType, literals::LiteralValue, parser_type::ParserType},
+        with_large_stack, Program,
     };
 
     #[test]
@@ -16,338 +25,2751 @@ mod tests {
         test_variables_assignments(&Program {
             code: "{ let a = 3; const b = 4; let res = 3 + a * b;  }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_variables_assignments(&Program {
             code: "{ let a = 3; const b = 4; let res = 3 + a * b;  }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_for_loop(&Program {
             code: "{ let res = 0; for (j = 0 to 10) { res = res + j; } }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_for_loop(&Program {
             code: "{ let res = 0; for (j = 0 to 10) { res = res + j; } }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_while_loop(&Program {
             code: "{ let res = 0; while (res < 5) { res = res + 1; } }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_while_loop(&Program {
             code: "{ let res = 0; while (res < 5) { res = res + 1; } }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_if_statement(&Program {
             code: "{ let a = 3; if (a == 3) { a = 4; } }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_if_statement(&Program {
             code: "{ let a = 3; if (a == 3) { a = 4; } }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_else_statement(&Program {
             code: "{ let a = 3; if (a != 3) { a = 4; } else { a = 5; } }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_else_statement(&Program {
             code: "{ let a = 3; if (a != 3) { a = 4; } else { a = 5; } }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_reassignment_variable_already_declared(&Program {
             code: "{ let a = 0; let a = 1; }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_reassignment_variable_already_declared(&Program {
             code: "{ let a = 0; let a = 1; }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_const_reassignment(&Program {
             code: "{ const a = 3; a = 4; }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_const_reassignment(&Program {
             code: "{ const a = 3; a = 4; }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_assignment_without_declaration(&Program {
             code: "{ a = 4; }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_assignment_without_declaration(&Program {
             code: "{ a = 4; }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_using_non_existing_variable(&Program {
             code: "{ let res = 3 + a; }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_using_non_existing_variable(&Program {
             code: "{ let res = 3 + a; }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_mixing_operators(&Program {
             code: "{ let a = true; let b = 3; let res = a + b; }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_mixing_operators(&Program {
             code: "{ let a = true; let b = 3; let res = a + b; }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_bad_scoping(&Program {
-            code: "{ let a = true; { let b = 3; { let a = 3; } } }".to_string(),
+            code: "{ let a = 0; { let b = 3; } a = b; }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_bad_scoping(&Program {
-            code: "{ let a = true; { let b = 3; { let a = 3; } } }".to_string(),
+            code: "{ let a = 0; { let b = 3; } a = b; }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_syntax_error_recovery(&Program {
+            code: "{ let a = ; let b = 4 let c = 5; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_missing_close_brace(&Program {
+            code: "{ let a = 1; while (a < 3) { a = a + 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_diagnostic_location(&Program {
+            code: "{\n    let a = 0;\n    a = b;\n}".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_diagnostic_location(&Program {
+            code: "{\n    let a = 0;\n    a = b;\n}".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_leading_equals(&Program {
+            code: "=".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_leading_equals(&Program {
+            code: "=".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_function_references(
+            "{ { fn f(): int { return 1; } let a = f(); } { fn f(): int { return 2; } let b = f(); } fn g(x) { let y = x; } }",
+        );
+
+        test_lr_syntax_error(&Program {
+            code: "{\n    let a = 1;\n    let b = a + ;\n}".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
         });
 
         test_good_scoping(&Program {
             code: "{ let a = 0; { let b = 3; a = b; } { let b = 4; a = b; } }".to_string(),
             parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
         });
         test_good_scoping(&Program {
             code: "{ let a = 0; { let b = 3; a = b; } { let b = 4; a = b; } }".to_string(),
             parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_shadowing(&Program {
+            code: "{ let a = 1; let res = 0; { let a = 10; res = a; } res = res + a; for (i = 0 to 2) { let a = i; } for (i = 0 to 2) { res = res + i; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_shadowing(&Program {
+            code: "{ let a = 1; let res = 0; { let a = 10; res = a; } res = res + a; for (i = 0 to 2) { let a = i; } for (i = 0 to 2) { res = res + i; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_functions(&Program {
+            code: "{ fn fact(n: int): int { if (n < 2) { return 1; } return n * fact(n - 1); } fn add(a: int, b: int): int { return a + b; } let res = add(fact(5), 3); }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_functions(&Program {
+            code: "{ fn fact(n: int): int { if (n < 2) { return 1; } return n * fact(n - 1); } fn add(a: int, b: int): int { return a + b; } let res = add(fact(5), 3); }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_call_statement(&Program {
+            code: "{ let counter = 0; fn increment(step: int) { counter = counter + step; } increment(2); increment(3); }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_call_statement(&Program {
+            code: "{ let counter = 0; fn increment(step: int) { counter = counter + step; } increment(2); increment(3); }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_function_declarations(&Program {
+            code: "{ let res = twice(is_even(10)); fn twice(b: bool): int { if (b) { return 2; } return 0; } fn is_even(n: int): bool { if (n == 0) { return true; } return is_odd(n - 1); } fn is_odd(n: int): bool { if (n == 0) { return false; } return is_even(n - 1); } fn unused(s: string): string { return s + \"!\"; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_function_declarations(&Program {
+            code: "{ let res = twice(is_even(10)); fn twice(b: bool): int { if (b) { return 2; } return 0; } fn is_even(n: int): bool { if (n == 0) { return true; } return is_odd(n - 1); } fn is_odd(n: int): bool { if (n == 0) { return false; } return is_even(n - 1); } fn unused(s: string): string { return s + \"!\"; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+        test_untyped_parameters(&Program {
+            code: "{ fn add(a, b) { return a + b; } fn twice(n) { return add(n, n); } let counter = 0; fn bump(step) { counter += step; } bump(add(1, 2)); let res = twice(add(2, 3)); }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_untyped_parameters(&Program {
+            code: "{ fn add(a, b) { return a + b; } fn twice(n) { return add(n, n); } let counter = 0; fn bump(step) { counter += step; } bump(add(1, 2)); let res = twice(add(2, 3)); }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+        test_function_errors(&Program {
+            code: "{ fn f(): int { let x = 1 + true; return x; } fn g(n: int) { n++; } fn h(flag: bool): int { return 1; } let a = h(1); let b = h(true); let c = g(2); fn k(p: Nope) { let z = 1; } fn m(): int { return false; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_function_errors(&Program {
+            code: "{ fn f(): int { let x = 1 + true; return x; } fn g(n: int) { n++; } fn h(flag: bool): int { return 1; } let a = h(1); let b = h(true); let c = g(2); fn k(p: Nope) { let z = 1; } fn m(): int { return false; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_captured_variable(&Program {
+            code: "{ let g = 1; let r = 0; { let x = 7; fn f(): int { return x; } r = f(); } fn outer(n: int): int { fn inner(): int { return n; } return inner(); } fn global(): int { let y = g; return y; } }"
+                .to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_captured_variable(&Program {
+            code: "{ let g = 1; let r = 0; { let x = 7; fn f(): int { return x; } r = f(); } fn outer(n: int): int { fn inner(): int { return n; } return inner(); } fn global(): int { let y = g; return y; } }"
+                .to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_wrong_argument_count(&Program {
+            code: "{ fn add(a: int, b: int): int { return a + b; } let res = add(1); }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_wrong_argument_count(&Program {
+            code: "{ fn add(a: int, b: int): int { return a + b; } let res = add(1); }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_strings(&Program {
+            code: "{ let greeting = \"Hello, \" + \"world\"; let same = greeting == \"Hello, world\"; let less = \"abc\" < \"abd\"; let quote = \"say \\\"hi\\\"\"; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_strings(&Program {
+            code: "{ let greeting = \"Hello, \" + \"world\"; let same = greeting == \"Hello, world\"; let less = \"abc\" < \"abd\"; let quote = \"say \\\"hi\\\"\"; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_unterminated_string(&Program {
+            code: "{ let a = \"abc; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_comments(&Program {
+            code: "// header\n{ let a = 8 / 2; // four\n  /* a /* nested */ comment */ let res = a /* inline */ / 2; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_comments(&Program {
+            code: "// header\n{ let a = 8 / 2; // four\n  /* a /* nested */ comment */ let res = a /* inline */ / 2; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_unterminated_comment(&Program {
+            code: "{ let a = 1; /* a /* nested */ comment }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_floats(&Program {
+            code: "{ let rate = 2.5; let total = rate * 2 * 2; let half = 1 / 2.0; let neg = -rate; let bigger = 3.5 > 3; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_floats(&Program {
+            code: "{ let rate = 2.5; let total = rate * 2 * 2; let half = 1 / 2.0; let neg = -rate; let bigger = 3.5 > 3; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_division_by_zero(&Program {
+            code: "{ let a = 0; let res = 10 / a; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_division_by_zero(&Program {
+            code: "{ let a = 0; let res = 10 / a; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_integer_overflow(&Program {
+            code: "{ let a = 2147483647; let res = a + 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_integer_overflow(&Program {
+            code: "{ let a = 2147483647; let res = a + 1; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_missing_return_value(&Program {
+            code: "{ fn sign(n: int): int { if (n > 0) { return 1; } } let res = sign(0); }"
+                .to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_missing_return_value(&Program {
+            code: "{ fn sign(n: int): int { if (n > 0) { return 1; } } let res = sign(0); }"
+                .to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_break_continue(&Program {
+            code: "{ let res = 0; let count = 0; for (i = 0 to 10) { if (i == 7) { break; } if (i == 2) { continue; } let j = 0; while (true) { j = j + 1; if (j > i) { break; } if (j == 1) { continue; } count = count + 1; } res = res + i; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_break_continue(&Program {
+            code: "{ let res = 0; let count = 0; for (i = 0 to 10) { if (i == 7) { break; } if (i == 2) { continue; } let j = 0; while (true) { j = j + 1; if (j > i) { break; } if (j == 1) { continue; } count = count + 1; } res = res + i; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_loop_control_outside_loop(&Program {
+            code: "{ fn f() { continue; } let a = 0; while (a < 1) { a = a + 1; f(); } break; }"
+                .to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_loop_control_outside_loop(&Program {
+            code: "{ fn f() { continue; } let a = 0; while (a < 1) { a = a + 1; f(); } break; }"
+                .to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_else_if_chain(&Program {
+            code: "{ let res = 0; for (i = 0 to 5) { if (i == 0) { res = res + 1; } else if (i == 1) { res = res + 10; } else if (i == 2) { res = res + 100; } else { res = res + 1000; } } let a = 0; let sign = 1 + if (a < 0) -1 else if (a == 0) 0 else 1; let big = if (res > 2000) \"big\" else \"small\"; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_else_if_chain(&Program {
+            code: "{ let res = 0; for (i = 0 to 5) { if (i == 0) { res = res + 1; } else if (i == 1) { res = res + 10; } else if (i == 2) { res = res + 100; } else { res = res + 1000; } } let a = 0; let sign = 1 + if (a < 0) -1 else if (a == 0) 0 else 1; let big = if (res > 2000) \"big\" else \"small\"; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_conditional_branch_types(&Program {
+            code: "{ let a = if (true) 1 else \"one\"; let b = if (1) 2 else 3; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_conditional_branch_types(&Program {
+            code: "{ let a = if (true) 1 else \"one\"; let b = if (1) 2 else 3; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_compound_assignments(&Program {
+            code: "{ let a = 10; a += 5; a -= 3; a *= 2; a /= 4; a %= 4; let b = 7 % 3; let c = 2 ** 3 ** 2; let d = -2 ** 2; let f = 7.5 % 2; let s = \"a\"; s += \"b\"; let i = 0; i++; i++; i--; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_compound_assignments(&Program {
+            code: "{ let a = 10; a += 5; a -= 3; a *= 2; a /= 4; a %= 4; let b = 7 % 3; let c = 2 ** 3 ** 2; let d = -2 ** 2; let f = 7.5 % 2; let s = \"a\"; s += \"b\"; let i = 0; i++; i++; i--; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_compound_assignment_errors(&Program {
+            code: "{ const a = 1; a += 2; a++; let b = true; b -= 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_compound_assignment_errors(&Program {
+            code: "{ const a = 1; a += 2; a++; let b = true; b -= 1; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_negative_exponent(&Program {
+            code: "{ let a = -1; let res = 2 ** a; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_negative_exponent(&Program {
+            code: "{ let a = -1; let res = 2 ** a; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_bitwise_operators(&Program {
+            code: "{ let flags = 12; let a = flags & 10; let o = flags | 3; let x = flags ^ 5; let n = ~flags; let l = 1 << 4; let r = -16 >> 2; let p = 1 | 2 ^ 3 & 4 << 1; let checksum = 0; for (i = 1 to 5) { checksum = (checksum << 1) ^ i; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_bitwise_operators(&Program {
+            code: "{ let flags = 12; let a = flags & 10; let o = flags | 3; let x = flags ^ 5; let n = ~flags; let l = 1 << 4; let r = -16 >> 2; let p = 1 | 2 ^ 3 & 4 << 1; let checksum = 0; for (i = 1 to 5) { checksum = (checksum << 1) ^ i; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_bitwise_operator_types(&Program {
+            code: "{ let a = 1.5 & 1; let b = ~true; let c = 1 & 1 == 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_bitwise_operator_types(&Program {
+            code: "{ let a = 1.5 & 1; let b = ~true; let c = 1 & 1 == 1; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_invalid_shift(&Program {
+            code: "{ let a = 32; let res = 1 << a; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_invalid_shift(&Program {
+            code: "{ let a = 32; let res = 1 << a; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_arrays(&Program {
+            code: "{ let a = [1, 2, 3]; let b = a; b[0] = 10; a[1] += 5; let m = [[1, 2], [3, 4]]; let row = m[1]; row[0] = 99; let size = len(a) + len(m[0]); fn sum(xs: [int]): int { let total = 0; for (i = 0 to len(xs)) { total += xs[i]; } return total; } let total = sum(a); let names = [\"x\", \"y\"]; let last = names[len(names) - 1]; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_arrays(&Program {
+            code: "{ let a = [1, 2, 3]; let b = a; b[0] = 10; a[1] += 5; let m = [[1, 2], [3, 4]]; let row = m[1]; row[0] = 99; let size = len(a) + len(m[0]); fn sum(xs: [int]): int { let total = 0; for (i = 0 to len(xs)) { total += xs[i]; } return total; } let total = sum(a); let names = [\"x\", \"y\"]; let last = names[len(names) - 1]; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_element_stores(&BackendType::Bytecode);
+
+        test_compound_index_once(&Program {
+            code: "{ let c = 0; fn next(): int { c++; return c - 1; } let a = [10, 20, 30]; a[next()] += 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_compound_index_once(&Program {
+            code: "{ let c = 0; fn next(): int { c++; return c - 1; } let a = [10, 20, 30]; a[next()] += 1; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+        test_compound_index_once(&Program {
+            code: "{ let c = 0; fn next(): int { c++; return c - 1; } let a = [10, 20, 30]; a[next()] += 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::Bytecode,
+        });
+        test_compound_index_once(&Program {
+            code: "{ let c = 0; fn next(): int { c++; return c - 1; } let a = [10, 20, 30]; a[next()] += 1; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::Assembly,
+        });
+
+        test_array_errors(&Program {
+            code: "{ let a = []; let b = [1, true]; let c = 1; let d = c[0]; let e = len(1); let f = len(b, b); let g = [1, 2]; g[0] = 1.5; g[true] = 2; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_array_errors(&Program {
+            code: "{ let a = []; let b = [1, true]; let c = 1; let d = c[0]; let e = len(1); let f = len(b, b); let g = [1, 2]; g[0] = 1.5; g[true] = 2; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_index_out_of_bounds(&Program {
+            code: "{ let a = [1, 2, 3]; let i = 3; let res = a[i]; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_index_out_of_bounds(&Program {
+            code: "{ let a = [1, 2, 3]; let i = 3; let res = a[i]; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_structs(&Program {
+            code: "{ struct Point { x: int, y: int } struct Shape { name: string, points: [Point], scale: float, closed: bool } let p = Point { y: 2, x: 1 }; let q = p; q.x = 10; q.y *= 3; let s = Shape { name: \"line\", points: [p, q], scale: 1.5, closed: false }; let pts = s.points; pts[0] = Point { x: 7, y: 8 }; fn norm(a: Point): int { return a.x * a.x + a.y * a.y; } let n = norm(s.points[1]) + Point { x: 0, y: 1 }.y; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_structs(&Program {
+            code: "{ struct Point { x: int, y: int } struct Shape { name: string, points: [Point], scale: float, closed: bool } let p = Point { y: 2, x: 1 }; let q = p; q.x = 10; q.y *= 3; let s = Shape { name: \"line\", points: [p, q], scale: 1.5, closed: false }; let pts = s.points; pts[0] = Point { x: 7, y: 8 }; fn norm(a: Point): int { return a.x * a.x + a.y * a.y; } let n = norm(s.points[1]) + Point { x: 0, y: 1 }.y; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_struct_scopes(&Program {
+            code: "{ struct P { v: int } let a = 0; let b = 0; { struct P { x: int } let p = P { x: 1 }; a = p.x; } { struct P { y: int, z: int } fn sum(q: P): int { return q.y + q.z; } b = sum(P { y: 2, z: 3 }); } let c = P { v: 4 }; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_struct_scopes(&Program {
+            code: "{ struct P { v: int } let a = 0; let b = 0; { struct P { x: int } let p = P { x: 1 }; a = p.x; } { struct P { y: int, z: int } fn sum(q: P): int { return q.y + q.z; } b = sum(P { y: 2, z: 3 }); } let c = P { v: 4 }; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+        test_struct_scope_errors(&Program {
+            code: "{ { struct P { x: int } } let q = P { x: 1 }; fn f(p: P) { let n = 0; } }"
+                .to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_struct_scope_errors(&Program {
+            code: "{ { struct P { x: int } } let q = P { x: 1 }; fn f(p: P) { let n = 0; } }"
+                .to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+        test_struct_errors(&Program {
+            code: "{ struct Point { x: int, x: int, z: Shape } struct Point { a: int } struct P { a: int, b: [string] } let p = P { a: \"s\", c: 1, a: 2 }; let q = P { a: 1, b: [\"x\"] }; q.c = 2; q.a = 1.5; let n = 3; let m = n.a; let r = Nope { a: 1 }; }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_struct_errors(&Program {
+            code: "{ struct Point { x: int, x: int, z: Shape } struct Point { a: int } struct P { a: int, b: [string] } let p = P { a: \"s\", c: 1, a: 2 }; let q = P { a: 1, b: [\"x\"] }; q.c = 2; q.a = 1.5; let n = 3; let m = n.a; let r = Nope { a: 1 }; }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_step_limit(&Program {
+            code: "{ let a = 1; while (true) { a = 2; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_step_limit(&Program {
+            code: "{ let a = 1; while (true) { a = 2; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_native_memory_limit(&Program {
+            code: "{ let a = [1, 2, 3, 4, 5, 6, 7, 8]; let b = a; while (true) { b = a; } }"
+                .to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::Assembly,
+        });
+
+        test_time_limit(&Program {
+            code: "{ let a = 1; while (true) { a = 2; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_time_limit(&Program {
+            code: "{ let a = 1; while (true) { a = 2; } }".to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_call_depth_limit(&Program {
+            code: "{ let a = 2; fn down(n: int): int { return down(n + 1); } let res = down(0); }"
+                .to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::TreeWalker,
+        });
+        test_call_depth_limit(&Program {
+            code: "{ let a = 2; fn down(n: int): int { return down(n + 1); } let res = down(0); }"
+                .to_string(),
+            parser: ParserType::Lr,
+            backend: BackendType::TreeWalker,
+        });
+
+        test_backends_agree("{ let a = 3; const b = 4; let res = 3 + a * b;  }");
+        test_backends_agree("{ let res = 0; for (j = 0 to 10) { res = res + j; } }");
+        test_backends_agree("{ let res = 0; while (res < 5) { res = res + 1; } }");
+        test_backends_agree("{ let a = 3; if (a == 3) { a = 4; } }");
+        test_backends_agree("{ let a = 3; if (a != 3) { a = 4; } else { a = 5; } }");
+        test_backends_reject("{ let a = 0; let a = 1; }");
+        test_backends_reject("{ const a = 3; a = 4; }");
+        test_backends_reject("{ a = 4; }");
+        test_backends_reject("{ let res = 3 + a; }");
+        test_backends_reject("{ let a = true; let b = 3; let res = a + b; }");
+        test_backends_reject("{ let a = 0; { let b = 3; } a = b; }");
+        test_backends_agree("{ let a = 0; { let b = 3; a = b; } { let b = 4; a = b; } }");
+        test_backends_agree("{ let a = 1; let res = 0; { let a = 10; res = a; } res = res + a; for (i = 0 to 2) { let a = i; } for (i = 0 to 2) { res = res + i; } }");
+        test_backends_agree("{ fn fact(n: int): int { if (n < 2) { return 1; } return n * fact(n - 1); } fn add(a: int, b: int): int { return a + b; } let res = add(fact(5), 3); }");
+        test_backends_agree("{ let res = twice(is_even(10)); fn twice(b: bool): int { if (b) { return 2; } return 0; } fn is_even(n: int): bool { if (n == 0) { return true; } return is_odd(n - 1); } fn is_odd(n: int): bool { if (n == 0) { return false; } return is_even(n - 1); } }");
+        test_backends_reject("{ let r = 0; { let x = 7; fn f(): int { return x; } r = f(); } }");
+        test_backends_agree("{ fn add(a, b) { return a + b; } fn twice(n) { return add(n, n); } let counter = 0; fn bump(step) { counter += step; } bump(add(1, 2)); let res = twice(add(2, 3)); }");
+        test_backends_reject(
+            "{ fn add(a, b) { return a + b; } let res = add(1.5, 2); fn name() { return \"x\"; } }",
+        );
+        test_backends_agree("{ let counter = 0; fn increment(step: int) { counter = counter + step; } increment(2); increment(3); }");
+        test_backends_reject("{ fn add(a: int, b: int): int { return a + b; } let res = add(1); }");
+        test_backends_agree("{ let greeting = \"Hello, \" + \"world\"; let same = greeting == \"Hello, world\"; let less = \"abc\" < \"abd\"; let quote = \"say \\\"hi\\\"\"; }");
+        test_backends_reject("{ let a = \"abc; }");
+        test_backends_agree("{ let rate = 2.5; let total = rate * 2 * 2; let half = 1 / 2.0; let neg = -rate; let bigger = 3.5 > 3; }");
+        test_backends_agree("{ let a = 0; let res = 10 / a; }");
+        test_backends_agree("{ let a = 2147483647; let res = a + 1; }");
+        test_backends_agree(
+            "{ fn sign(n: int): int { if (n > 0) { return 1; } } let res = sign(0); }",
+        );
+        test_backends_agree("{ let res = 0; let count = 0; for (i = 0 to 10) { if (i == 7) { break; } if (i == 2) { continue; } let j = 0; while (true) { j = j + 1; if (j > i) { break; } if (j == 1) { continue; } count = count + 1; } res = res + i; } }");
+        test_backends_agree("{ fn first(n: int): int { for (i = 0 to 10) { if (i * i >= n) { return i; } } return 10; } let res = 0; while (res < 100) { res = res + first(20); if (res > 50) { break; } } }");
+        test_backends_agree("{ let res = 0; for (i = 0 to 5) { if (i == 0) { res = res + 1; } else if (i == 1) { res = res + 10; } else if (i == 2) { res = res + 100; } else { res = res + 1000; } } let a = 0; let sign = 1 + if (a < 0) -1 else if (a == 0) 0 else 1; let big = if (res > 2000) \"big\" else \"small\"; }");
+        test_backends_agree("{ let a = 10; a += 5; a -= 3; a *= 2; a /= 4; a %= 4; let b = 7 % 3; let c = 2 ** 3 ** 2; let d = -2 ** 2; let f = 7.5 % 2; let s = \"a\"; s += \"b\"; let i = 0; i++; i++; i--; }");
+        test_backends_reject("{ const a = 1; a += 2; a++; let b = true; b -= 1; }");
+        test_backends_agree("{ let a = -1; let res = 2 ** a; }");
+        test_backends_agree(
+            "{ let a = 0; let res = 10 % a; let big = 2 ** 31; let x = 2.0 ** 0.5 + 7.5 % 2; }",
+        );
+        test_backends_agree("{ let flags = 12; let a = flags & 10; let o = flags | 3; let x = flags ^ 5; let n = ~flags; let l = 1 << 4; let r = -16 >> 2; let p = 1 | 2 ^ 3 & 4 << 1; let checksum = 0; for (i = 1 to 5) { checksum = (checksum << 1) ^ i; } }");
+        test_backends_reject("{ let a = 1.5 & 1; let b = ~true; let c = 1 & 1 == 1; }");
+        test_backends_agree("{ let a = 32; let res = 1 << a; }");
+        test_backends_agree(
+            "{ let a = -1; let res = 8 >> a; let big = 1 << 31; let all = ~0 ^ 255; }",
+        );
+        test_backends_reject("{ fn fib(n: int): int { return if (n < 2) n else fib(n - 1) + fib(n - 2); } let res = fib(10); let half = if (res > 50) res / 2 else 2.5; }");
+
+        test_backends_agree("{ let a = [1, 2, 3]; let b = a; b[0] = 10; a[1] += 5; let m = [[1, 2], [3, 4]]; let row = m[1]; row[0] = 99; let size = len(a) + len(m[0]); fn sum(xs: [int]): int { let total = 0; for (i = 0 to len(xs)) { total += xs[i]; } return total; } let total = sum(a); let names = [\"x\", \"y\"]; let last = names[len(names) - 1]; }");
+        // The default limit needs the stack the server and the command line evaluate on.
+        with_large_stack(|| {
+            test_backends_agree("{ let a = 2; fn down(n: int): int { if (n == 0) { return 0; } return down(n - 1) + 1; } let near = down(900); let res = down(100000); }")
+        });
+        test_backends_reject("{ let a = []; let b = [1, true]; let c = 1; let d = c[0]; let e = len(1); let f = len(b, b); let g = [1, 2]; g[0] = 1.5; g[true] = 2; }");
+        test_backends_agree("{ let c = 0; fn next(): int { c++; return c - 1; } let a = [10, 20, 30]; a[next()] += 1; a[next()] *= 2; }");
+        test_backends_agree("{ let a = [1, 2, 3]; let i = 3; let res = a[i]; }");
+        test_backends_agree("{ let a = [1, 2, 3]; a[-1] = 0; }");
+        test_backends_agree("{ let f = [1.5, 2.5]; f[1] *= 2; let flags = [true, false]; let any = flags[0] || flags[1]; let nested = [[\"a\"], [\"b\", \"c\"]]; }");
+
+        test_backends_agree("{ struct Point { x: int, y: int } struct Shape { name: string, points: [Point], scale: float, closed: bool } let p = Point { y: 2, x: 1 }; let q = p; q.x = 10; q.y *= 3; let s = Shape { name: \"line\", points: [p, q], scale: 1.5, closed: false }; let pts = s.points; pts[0] = Point { x: 7, y: 8 }; fn norm(a: Point): int { return a.x * a.x + a.y * a.y; } let n = norm(s.points[1]) + Point { x: 0, y: 1 }.y; }");
+        test_backends_agree("{ struct P { v: int } let a = 0; let b = 0; { struct P { x: int } let p = P { x: 1 }; a = p.x; } { struct P { y: int, z: int } fn sum(q: P): int { return q.y + q.z; } b = sum(P { y: 2, z: 3 }); } let c = P { v: 4 }; }");
+        test_backends_reject("{ struct Point { x: int, x: int, z: Shape } struct Point { a: int } struct P { a: int, b: [string] } let p = P { a: \"s\", c: 1, a: 2 }; let q = P { a: 1, b: [\"x\"] }; q.c = 2; q.a = 1.5; let n = 3; let m = n.a; let r = Nope { a: 1 }; }");
+        test_backends_agree("{ struct Inner { v: [int] } struct Outer { inner: Inner, label: string } let o = Outer { inner: Inner { v: [1, 2] }, label: \"o\" }; let copy = o; let i = copy.inner; i.v = [3]; copy.label = \"c\"; let idx = o.inner.v[5]; }");
+
+        test_step_limit(&Program {
+            code: "{ let a = 1; while (true) { a = 2; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::Bytecode,
+        });
+        test_time_limit(&Program {
+            code: "{ let a = 1; while (true) { a = 2; } }".to_string(),
+            parser: ParserType::Recursive,
+            backend: BackendType::Bytecode,
         });
 
-        api_test_suit();
+        test_round_trip("{ let a = 3; const b = 4; let res = 3 + a * b;  }");
+        test_round_trip(
+            "\n  {\n\tfn add(a:int,b :int):int  {\n\t\treturn a+b;\n\t}\n\n\tlet res = add( 1 ,2 ) ;\r\n}\n\n",
+        );
+        test_round_trip("{ if (a < 3) { a = 1; } else { a = -(2); } // }   ");
+        test_round_trip("{ if (a) { } else  if (b) {} else { a = if (b)1 else 2; } }");
+        test_round_trip("{ let a = ; b = \"x\" + ; }");
+        test_round_trip("{ a+=1 ; a ++; b = - -a % 2**c; }");
+        test_round_trip("{ let a = [1,[2 , 3]] ; a [0] = a[1][ 0]; }");
+        test_round_trip("{ struct P{x :int,y: [[P]] } let p = P{ x:1 ,y:[]}; p.x+= p . x; }");
+        test_round_trip("// a\n{ let a = 1; /* b /* c */\n */ a = a // d\n + 2; }\n// e");
+
+        test_format(
+            "{let a=3;const b =4;\n\n\n   fn add(x:int,y: int):int{return x+y;}\n  if(a<3){a=1;}else{ a = -(2);}\n  for(j=0 to 10){}  add(a, b);}",
+            "{\n    let a = 3;\n    const b = 4;\n\n    fn add(x: int, y: int): int {\n        return x + y;\n    }\n    if (a < 3) {\n        a = 1;\n    } else {\n        a = -(2);\n    }\n    for (j = 0 to 10) {}\n    add(a, b);\n}\n",
+        );
+        test_format(
+            "{ while (a>0&&!done) { { let s = \"x  y\" + 1.50; } } }",
+            "{\n    while (a > 0 && !done) {\n        {\n            let s = \"x  y\" + 1.50;\n        }\n    }\n}\n",
+        );
+
+        test_format(
+            "// header\n\n{ // open\n  let a = 1; // one\n  let b = a + /* inner */ 2;\n\n\n  // loop\n  while (b > 0) { b = b - 1; /* x /* y */ */\n  // end of loop\n  } // after\n}",
+            "// header\n\n{ // open\n    let a = 1; // one\n    /* inner */\n    let b = a + 2;\n\n    // loop\n    while (b > 0) {\n        b = b - 1; /* x /* y */ */\n        // end of loop\n    } // after\n}\n",
+        );
+
+        test_format(
+            "{ while (true) { break ; } for (i=0 to 3) {continue;} }",
+            "{\n    while (true) {\n        break;\n    }\n    for (i = 0 to 3) {\n        continue;\n    }\n}\n",
+        );
+
+        test_format(
+            "{ if (a<0) { s = -1; } else if(a==0){s=0;} else {s=1;} let b = if(a>0) a else -a; }",
+            "{\n    if (a < 0) {\n        s = -1;\n    } else if (a == 0) {\n        s = 0;\n    } else {\n        s = 1;\n    }\n    let b = if (a > 0) a else -a;\n}\n",
+        );
+
+        test_format(
+            "{ a+=1; b %=2 ; a ++; a--; let c = - -a % 2**b; }",
+            "{\n    a += 1;\n    b %= 2;\n    a++;\n    a--;\n    let c = - -a % 2 ** b;\n}\n",
+        );
+
+        test_format(
+            "{ let a = ~b&1|c^d<<2>>e; }",
+            "{\n    let a = ~b & 1 | c ^ d << 2 >> e;\n}\n",
+        );
+
+        test_format(
+            "{ let a=[ 1,2 ,[3]] ; a [0]+=a[len(a)-1][0]; }",
+            "{\n    let a = [1, 2, [3]];\n    a[0] += a[len(a) - 1][0];\n}\n",
+        );
+
+        test_format(
+            "{ struct Point{x:int,y :[[float]]} struct E {  } let p=Point{x:1,y:[[2.5]]}; p.x+=p.y[0][0]+E{}.z; }",
+            "{\n    struct Point { x: int, y: [[float]] }\n    struct E {}\n    let p = Point { x: 1, y: [[2.5]] };\n    p.x += p.y[0][0] + E {}.z;\n}\n",
+        );
+
+        test_command_line();
+        test_repl(ParserType::Recursive);
+        test_repl(ParserType::Lr);
+
+        api_test_suit();
+    }
+
+    fn test_variables_assignments(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(3))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "b"),
+            Some(LiteralValue::Integer(4))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(15))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_if_statement(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(4))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_else_statement(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(5))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_for_loop(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(45))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_while_loop(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(5))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_reassignment_variable_already_declared(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "a"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Name a is already declared.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_const_reassignment(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "a"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Const a cannot be redefined.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_assignment_without_declaration(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "a"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Name a is not declared yet.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_using_non_existing_variable(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "res"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Name a is undefined.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_mixing_operators(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "res"), None);
+        assert_eq!(get_value_from_key(&environment, "a"), None);
+        assert_eq!(get_value_from_key(&environment, "b"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Binary operator Plus is not defined for types Boolean and Integer.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_bad_scoping(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "a"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Name b is undefined.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_syntax_error_recovery(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(count_errors(Rc::clone(&diagnostics), &TextPlace::Syntax), 2);
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Unexpected token Semicolon \";\".",
+                &TextPlace::Syntax,
+                &TextType::Error
+            ),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Unexpected token Let \"let\".",
+                &TextPlace::Syntax,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_missing_close_brace(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(count_errors(Rc::clone(&diagnostics), &TextPlace::Syntax), 1);
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Unexpected token Eof \"EOF\".",
+                &TextPlace::Syntax,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    // Calls resolve to the function declared in their own block rather than to any function of
+    // that name, and names in a function that is never called are still resolved.
+    fn test_function_references(code: &str) {
+        let analysis = Analysis::new(code);
+        let position = |pattern: &str, nth: usize| {
+            code.match_indices(pattern).nth(nth).unwrap().0 + pattern.len() - 1
+        };
+        let first = position("fn f", 0);
+        let second = position("fn f", 1);
+
+        let references: Vec<usize> = analysis
+            .references(first, true)
+            .iter()
+            .map(|occurrence| occurrence.span.start())
+            .collect();
+        assert_eq!(references, vec![first, position("a = f", 0)]);
+        assert_eq!(
+            analysis
+                .definition(position("b = f", 0))
+                .map(|occurrence| occurrence.span.start()),
+            Some(second)
+        );
+        assert_eq!(
+            analysis.hover(position("a = f", 0)).map(|(_, text)| text),
+            Some("fn f(): Integer".to_string())
+        );
+        assert_eq!(
+            analysis.hover(position("fn g", 0)).map(|(_, text)| text),
+            Some("fn g(x: Integer)".to_string())
+        );
+        assert_eq!(
+            analysis
+                .definition(position("= x", 0))
+                .map(|occurrence| occurrence.span.start()),
+            Some(position("g(x", 0))
+        );
+    }
+
+    fn test_leading_equals(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            count_errors(Rc::clone(&diagnostics), &TextPlace::Syntax) > 0,
+            true
+        );
+        let diagnostic = diagnostics.borrow().filter_type(TextType::Error)[0].clone();
+        assert_eq!(diagnostic.get_message().contains("\"=\""), true);
+        assert_eq!((diagnostic.get_line(), diagnostic.get_column()), (1, 1));
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_lr_syntax_error(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(count_errors(Rc::clone(&diagnostics), &TextPlace::Syntax), 1);
+        let diagnostic = diagnostics.borrow().filter_type(TextType::Error)[0].clone();
+        assert_eq!(
+            diagnostic
+                .get_message()
+                .starts_with("Unexpected token \";\", expected one of "),
+            true
+        );
+        assert_eq!(diagnostic.get_message().contains("Identifier"), true);
+        assert_eq!(diagnostic.get_line(), 3);
+        assert_eq!(diagnostic.get_column(), 17);
+        assert_eq!(environment.borrow().get_variables().is_empty(), true);
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_diagnostic_location(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        let diagnostic = diagnostics.borrow().filter_type(TextType::Error)[0].clone();
+        assert_eq!(diagnostic.get_message(), "Name b is undefined.");
+        assert_eq!(diagnostic.get_line(), 3);
+        assert_eq!(diagnostic.get_column(), 9);
+
+        let rendered = render(&diagnostic, &SourceMap::new(&data.code));
+        assert_eq!(rendered.contains("3:9"), true);
+        assert_eq!(rendered.contains("    a = b;"), true);
+        assert_eq!(rendered.contains("^"), true);
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_good_scoping(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(4))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_shadowing(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(1))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(12))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_functions(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(123))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_call_statement(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "counter"),
+            Some(LiteralValue::Integer(5))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_function_declarations(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(2))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_untyped_parameters(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(10))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "counter"),
+            Some(LiteralValue::Integer(3))
+        );
+    }
+
+    fn test_function_errors(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        for message in [
+            "Binary operator Plus is not defined for types Integer and Boolean.",
+            "Invalid literal type, actual Integer and expected Boolean.",
+            "Function g doesn't return a value.",
+            "Type Nope is undefined.",
+            "Function m returns Integer but this expression is Boolean.",
+        ] {
+            assert_eq!(
+                check_specific_message_in_diagnostics(
+                    Rc::clone(&diagnostics),
+                    message,
+                    &TextPlace::Semantic,
+                    &TextType::Error
+                ),
+                true
+            );
+        }
+        assert_eq!(
+            diagnostics
+                .borrow()
+                .filter_type(TextType::Error)
+                .iter()
+                .filter(|diagnostic| diagnostic.get_message().starts_with("Invalid literal type"))
+                .count(),
+            1
+        );
+        assert_eq!(
+            find_span(
+                Rc::clone(&diagnostics),
+                "Function m returns Integer but this expression is Boolean."
+            ),
+            Some((data.code.find("return false").unwrap(), 6))
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_captured_variable(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        let errors: Vec<String> = diagnostics
+            .borrow()
+            .filter_type(TextType::Error)
+            .iter()
+            .map(|diagnostic| diagnostic.get_message().to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "Local variable x of an enclosing scope can't be used inside a function.",
+                "Local variable n of an enclosing scope can't be used inside a function.",
+            ]
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_wrong_argument_count(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(get_value_from_key(&environment, "res"), None);
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Function add requires 2 arguments but was given 1.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_strings(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "greeting"),
+            Some(LiteralValue::String("Hello, world".to_string()))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "same"),
+            Some(LiteralValue::Boolean(true))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "less"),
+            Some(LiteralValue::Boolean(true))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "quote"),
+            Some(LiteralValue::String("say \"hi\"".to_string()))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_unterminated_string(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Unterminated string literal.",
+                &TextPlace::Lexical,
+                &TextType::Error
+            ),
+            true
+        );
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_comments(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(2))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_unterminated_comment(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Unterminated block comment.",
+                &TextPlace::Lexical,
+                &TextType::Error
+            ),
+            true
+        );
+        let errors = diagnostics.borrow().filter_type(TextType::Error);
+        assert_eq!(errors[0].get_span().start(), 13);
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_floats(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            get_value_from_key(&environment, "rate"),
+            Some(LiteralValue::Float(2.5))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "total"),
+            Some(LiteralValue::Float(10.0))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "half"),
+            Some(LiteralValue::Float(0.5))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "neg"),
+            Some(LiteralValue::Float(-2.5))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "bigger"),
+            Some(LiteralValue::Boolean(true))
+        );
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_division_by_zero(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Division by zero.",
+                &TextPlace::Runtime,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_integer_overflow(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Integer overflow.",
+                &TextPlace::Runtime,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_missing_return_value(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Function sign finished without returning a value.",
+                &TextPlace::Runtime,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_break_continue(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(19))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "count"),
+            Some(LiteralValue::Integer(14))
+        );
+    }
+
+    fn test_else_if_chain(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "res"),
+            Some(LiteralValue::Integer(2111))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "sign"),
+            Some(LiteralValue::Integer(1))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "big"),
+            Some(LiteralValue::String("big".to_string()))
+        );
+    }
+
+    fn test_conditional_branch_types(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Branches of the conditional expression have different types Integer and String.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Invalid literal type, actual Integer and expected Boolean.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        assert_eq!(
+            find_span(
+                Rc::clone(&diagnostics),
+                "Invalid literal type, actual Integer and expected Boolean."
+            ),
+            Some((data.code.find("if (1)").unwrap() + 4, 1))
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_compound_assignments(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(2))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "b"),
+            Some(LiteralValue::Integer(1))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "c"),
+            Some(LiteralValue::Integer(512))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "d"),
+            Some(LiteralValue::Integer(4))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "f"),
+            Some(LiteralValue::Float(1.5))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "s"),
+            Some(LiteralValue::String("ab".to_string()))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "i"),
+            Some(LiteralValue::Integer(1))
+        );
+    }
+
+    fn test_compound_assignment_errors(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Const a cannot be redefined.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Binary operator Minus is not defined for types Boolean and Integer.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_negative_exponent(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Negative exponent in an integer power.",
+                &TextPlace::Runtime,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
+    }
+
+    fn test_bitwise_operators(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(8))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "o"),
+            Some(LiteralValue::Integer(15))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "x"),
+            Some(LiteralValue::Integer(9))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "n"),
+            Some(LiteralValue::Integer(-13))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "l"),
+            Some(LiteralValue::Integer(16))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "r"),
+            Some(LiteralValue::Integer(-4))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "p"),
+            Some(LiteralValue::Integer(3))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "checksum"),
+            Some(LiteralValue::Integer(2))
+        );
+    }
+
+    fn test_bitwise_operator_types(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Binary operator Ampersand is not defined for types Float and Integer.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Unary operator Tilde is not defined for type Boolean.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+        assert_eq!(
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Binary operator Ampersand is not defined for types Integer and Boolean.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
+            true
+        );
+
+        diagnostics.borrow_mut().print();
     }
 
-    fn test_variables_assignments(data: &Program) {
+    fn test_invalid_shift(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        assert_eq!(get_value_from_key("a"), Some(LiteralValue::Integer(3)));
-        assert_eq!(get_value_from_key("b"), Some(LiteralValue::Integer(4)));
-        assert_eq!(get_value_from_key("res"), Some(LiteralValue::Integer(15)));
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            false
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Shift amount is outside of the range 0 to 31.",
+                &TextPlace::Runtime,
+                &TextType::Error
+            ),
+            true
         );
 
         diagnostics.borrow_mut().print();
     }
 
-    fn test_if_statement(data: &Program) {
+    fn test_arrays(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        assert_eq!(get_value_from_key("a"), Some(LiteralValue::Integer(4)));
         assert_eq!(
             check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
             false
         );
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Array(vec![
+                LiteralValue::Integer(1),
+                LiteralValue::Integer(7),
+                LiteralValue::Integer(3)
+            ]))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "b"),
+            Some(LiteralValue::Array(vec![
+                LiteralValue::Integer(10),
+                LiteralValue::Integer(2),
+                LiteralValue::Integer(3)
+            ]))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "row"),
+            Some(LiteralValue::Array(vec![
+                LiteralValue::Integer(99),
+                LiteralValue::Integer(4)
+            ]))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "m"),
+            Some(LiteralValue::Array(vec![
+                LiteralValue::Array(vec![LiteralValue::Integer(1), LiteralValue::Integer(2)]),
+                LiteralValue::Array(vec![LiteralValue::Integer(3), LiteralValue::Integer(4)])
+            ]))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "size"),
+            Some(LiteralValue::Integer(5))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "total"),
+            Some(LiteralValue::Integer(11))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "last"),
+            Some(LiteralValue::String("y".to_string()))
+        );
+    }
+
+    // Storing an element must not copy the array, otherwise the stores below take a time
+    // proportional to the size of the array each and the program runs out of time.
+    fn test_element_stores(backend: &BackendType) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+        let code = format!(
+            "{{ let a = [{}]; fn fill(b: [int]): int {{ for (i = 0 to 20000) {{ b[i % 10000] = i; }} return b[9999]; }} for (i = 0 to 20000) {{ a[i % 10000] = i; }} let last = fill(a); }}",
+            vec!["0"; 10000].join(", ")
+        );
 
-        diagnostics.borrow_mut().print();
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &code,
+            &ParserType::Recursive,
+            backend,
+            &ExecutionLimits::default(),
+        );
+
+        assert_eq!(
+            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "last"),
+            Some(LiteralValue::Integer(19999))
+        );
     }
 
-    fn test_else_statement(data: &Program) {
+    fn test_compound_index_once(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        assert_eq!(get_value_from_key("a"), Some(LiteralValue::Integer(5)));
         assert_eq!(
             check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
             false
         );
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Array(vec![
+                LiteralValue::Integer(11),
+                LiteralValue::Integer(20),
+                LiteralValue::Integer(30)
+            ]))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "c"),
+            Some(LiteralValue::Integer(1))
+        );
+    }
+
+    fn test_array_errors(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        for message in [
+            "Array literal must have at least one element.",
+            "Elements of the array literal have different types Integer and Boolean.",
+            "Type Integer is not an array.",
+            "Function len requires 1 arguments but was given 2.",
+            "Invalid literal type, actual Float and expected Integer.",
+            "Invalid literal type, actual Boolean and expected Integer.",
+        ] {
+            assert_eq!(
+                check_specific_message_in_diagnostics(
+                    Rc::clone(&diagnostics),
+                    message,
+                    &TextPlace::Semantic,
+                    &TextType::Error
+                ),
+                true
+            );
+        }
+        assert_eq!(
+            find_span(
+                Rc::clone(&diagnostics),
+                "Invalid literal type, actual Boolean and expected Integer."
+            ),
+            Some((data.code.find("g[true]").unwrap() + 2, 4))
+        );
 
         diagnostics.borrow_mut().print();
     }
 
-    fn test_for_loop(data: &Program) {
+    fn test_index_out_of_bounds(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        assert_eq!(get_value_from_key("res"), Some(LiteralValue::Integer(45)));
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            false
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Index 3 is out of bounds for an array of length 3.",
+                &TextPlace::Runtime,
+                &TextType::Error
+            ),
+            true
         );
 
         diagnostics.borrow_mut().print();
     }
 
-    fn test_while_loop(data: &Program) {
+    fn test_structs(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        let point = |x, y| {
+            LiteralValue::Struct(
+                "Point".to_string(),
+                vec![
+                    ("x".to_string(), LiteralValue::Integer(x)),
+                    ("y".to_string(), LiteralValue::Integer(y)),
+                ],
+            )
+        };
 
-        assert_eq!(get_value_from_key("res"), Some(LiteralValue::Integer(5)));
         assert_eq!(
             check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
             false
         );
-
-        diagnostics.borrow_mut().print();
+        assert_eq!(get_value_from_key(&environment, "p"), Some(point(1, 2)));
+        assert_eq!(get_value_from_key(&environment, "q"), Some(point(10, 6)));
+        assert_eq!(
+            get_value_from_key(&environment, "pts"),
+            Some(LiteralValue::Array(vec![point(7, 8), point(10, 6)]))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "s").map(|value| value.to_string()),
+            Some(
+                "Shape { name: \"line\", points: [Point { x: 1, y: 2 }, Point { x: 10, y: 6 }], scale: 1.5, closed: false }"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "n"),
+            Some(LiteralValue::Integer(137))
+        );
     }
 
-    fn test_reassignment_variable_already_declared(data: &Program) {
+    fn test_struct_scopes(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        assert_eq!(get_value_from_key("a"), None);
         assert_eq!(
             check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            true
+            false
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(1))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "b"),
+            Some(LiteralValue::Integer(5))
+        );
+        assert_eq!(
+            get_value_from_key(&environment, "c").map(|value| value.to_string()),
+            Some("P { v: 4 }".to_string())
+        );
+    }
+
+    fn test_struct_scope_errors(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
         );
+
         assert_eq!(
             check_specific_message_in_diagnostics(
                 Rc::clone(&diagnostics),
-                "Name a is already declared.",
+                "Type P is undefined.",
                 &TextPlace::Semantic,
                 &TextType::Error
             ),
             true
         );
+        assert_eq!(
+            count_errors(Rc::clone(&diagnostics), &TextPlace::Semantic),
+            2
+        );
+    }
+
+    fn test_struct_errors(data: &Program) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
+
+        for message in [
+            "Field x is already declared.",
+            "Type Shape is undefined.",
+            "Struct Point is already declared.",
+            "Invalid literal type, actual String and expected Integer.",
+            "Struct P has no field c.",
+            "Field a is initialized more than once.",
+            "Field b of struct P is not initialized.",
+            "Invalid literal type, actual Float and expected Integer.",
+            "Type Integer is not a struct.",
+            "Type Nope is undefined.",
+        ] {
+            assert_eq!(
+                check_specific_message_in_diagnostics(
+                    Rc::clone(&diagnostics),
+                    message,
+                    &TextPlace::Semantic,
+                    &TextType::Error
+                ),
+                true
+            );
+        }
+
         diagnostics.borrow_mut().print();
     }
 
-    fn test_const_reassignment(data: &Program) {
+    fn test_loop_control_outside_loop(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits::default(),
+        );
 
-        assert_eq!(get_value_from_key("a"), None);
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
+            check_specific_message_in_diagnostics(
+                Rc::clone(&diagnostics),
+                "Break statement is only allowed inside a loop.",
+                &TextPlace::Semantic,
+                &TextType::Error
+            ),
             true
         );
+        // The function is called from a loop, but its body is not part of it.
         assert_eq!(
             check_specific_message_in_diagnostics(
                 Rc::clone(&diagnostics),
-                "Const a cannot be redefined.",
+                "Continue statement is only allowed inside a loop.",
                 &TextPlace::Semantic,
                 &TextType::Error
             ),
             true
         );
+
         diagnostics.borrow_mut().print();
     }
 
-    fn test_assignment_without_declaration(data: &Program) {
+    fn test_step_limit(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits {
+                max_steps: 100,
+                timeout_ms: 2_000,
+                max_call_depth: 1_000,
+                max_memory_mb: 256,
+            },
+        );
 
-        assert_eq!(get_value_from_key("a"), None);
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            true
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(2))
         );
         assert_eq!(
             check_specific_message_in_diagnostics(
                 Rc::clone(&diagnostics),
-                "Name a is not declared yet.",
-                &TextPlace::Semantic,
+                "Execution was aborted after exceeding 100 steps.",
+                &TextPlace::Runtime,
                 &TextType::Error
             ),
             true
         );
+
         diagnostics.borrow_mut().print();
     }
 
-    fn test_using_non_existing_variable(data: &Program) {
+    fn test_call_depth_limit(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits {
+                max_steps: u64::MAX,
+                timeout_ms: 2_000,
+                max_call_depth: 50,
+                max_memory_mb: 256,
+            },
+        );
 
-        assert_eq!(get_value_from_key("res"), None);
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            true
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(2))
         );
         assert_eq!(
             check_specific_message_in_diagnostics(
                 Rc::clone(&diagnostics),
-                "Name a is undefined.",
-                &TextPlace::Semantic,
+                "Execution was aborted after exceeding a call depth of 50.",
+                &TextPlace::Runtime,
                 &TextType::Error
             ),
             true
         );
+
         diagnostics.borrow_mut().print();
     }
 
-    fn test_mixing_operators(data: &Program) {
+    fn test_native_memory_limit(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
-
-        assert_eq!(get_value_from_key("res"), None);
-        assert_eq!(get_value_from_key("a"), None);
-        assert_eq!(get_value_from_key("b"), None);
-        assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            true
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits {
+                max_steps: u64::MAX,
+                timeout_ms: 2_000,
+                max_call_depth: 1_000,
+                max_memory_mb: 64,
+            },
         );
+
         assert_eq!(
             check_specific_message_in_diagnostics(
                 Rc::clone(&diagnostics),
-                "Binary operator Plus is not defined for types Boolean and Integer.",
-                &TextPlace::Semantic,
+                "Native execution failed: the program was killed by signal 11.",
+                &TextPlace::Runtime,
                 &TextType::Error
             ),
             true
         );
+
         diagnostics.borrow_mut().print();
     }
 
-    fn test_bad_scoping(data: &Program) {
+    fn test_time_limit(data: &Program) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            &data.code,
+            &data.parser,
+            &data.backend,
+            &ExecutionLimits {
+                max_steps: u64::MAX,
+                timeout_ms: 50,
+                max_call_depth: 1_000,
+                max_memory_mb: 256,
+            },
+        );
 
-        assert_eq!(get_value_from_key("a"), None);
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            true
+            get_value_from_key(&environment, "a"),
+            Some(LiteralValue::Integer(2))
         );
         assert_eq!(
             check_specific_message_in_diagnostics(
                 Rc::clone(&diagnostics),
-                "Name a is already declared.",
-                &TextPlace::Semantic,
+                "Execution was aborted after exceeding 50 ms.",
+                &TextPlace::Runtime,
                 &TextType::Error
             ),
             true
         );
+
         diagnostics.borrow_mut().print();
     }
 
-    fn test_good_scoping(data: &Program) {
+    fn test_round_trip(code: &str) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let mut lexer = Lexer::in_memory_reader(code, Rc::clone(&diagnostics));
+        let mut parser = Parser::new(Rc::clone(&diagnostics));
+        parser.create(&mut lexer);
+        let root = parser.parse();
+
+        assert_eq!(root.to_source(), code);
+
+        if diagnostics.borrow().filter_type(TextType::Error).is_empty() {
+            let mut position = 0;
+            for token in root.get_tokens() {
+                for trivia in token.leading_trivia() {
+                    assert_eq!(trivia.position(), position);
+                    position += trivia.length();
+                }
+                assert_eq!(token.position(), position);
+                position += token.length();
+                for trivia in token.trailing_trivia() {
+                    assert_eq!(trivia.position(), position);
+                    position += trivia.length();
+                }
+            }
+            assert_eq!(position, code.chars().count());
+        }
+    }
+
+    fn test_format(code: &str, expected: &str) {
         let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let formatted = format_program(Rc::clone(&diagnostics), code);
+        assert_eq!(formatted.as_deref(), Some(expected));
 
-        compile_program(Rc::clone(&diagnostics), &data.code, &data.parser);
+        let reformatted = format_program(Rc::clone(&diagnostics), expected);
+        assert_eq!(reformatted.as_deref(), Some(expected));
 
-        assert_eq!(get_value_from_key("a"), Some(LiteralValue::Integer(4)));
+        assert_eq!(format_program(diagnostics, "{ let a = ; }"), None);
+    }
+
+    fn test_command_line() {
+        let example = format!("{}/examples/example_1.txt", env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(cli::run(&["run".to_string(), example.clone()]), 0);
         assert_eq!(
-            check_for_errors_in_diagnostics(Rc::clone(&diagnostics)),
-            false
+            cli::run(&[
+                "check".to_string(),
+                "--parser".to_string(),
+                "lr".to_string(),
+                example
+            ]),
+            0
         );
 
-        diagnostics.borrow_mut().print();
+        let invalid = std::env::temp_dir().join(format!("rusty-cli-{}.txt", std::process::id()));
+        std::fs::write(&invalid, "{ let a = b; }").unwrap();
+        let invalid = invalid.to_string_lossy().to_string();
+        assert_eq!(cli::run(&["check".to_string(), invalid.clone()]), 1);
+        std::fs::remove_file(&invalid).unwrap();
+
+        assert_eq!(cli::run(&["compile".to_string()]), 2);
+        assert_eq!(cli::run(&["run".to_string(), "missing.txt".to_string()]), 2);
     }
 
-    fn get_value_from_key(name: &str) -> Option<LiteralValue> {
-        SYMBOL_TABLE
-            .lock()
-            .unwrap()
+    fn test_repl(parser: ParserType) {
+        let mut repl = Repl::new(parser);
+
+        assert_eq!(repl.submit("let a = 3;"), None);
+        assert_eq!(
+            repl.submit("fn square(x: int): int {\n    return x * x;\n}"),
+            None
+        );
+        assert_eq!(
+            repl.submit("square(a) + 1"),
+            Some(LiteralValue::Integer(10))
+        );
+        assert_eq!(repl.errors().is_empty(), true);
+
+        assert_eq!(repl.submit("let b = c;"), None);
+        assert_eq!(repl.errors()[0].get_message(), "Name c is undefined.");
+        assert_eq!(repl.errors()[0].get_span().start(), 8);
+        assert_eq!(
+            (repl.errors()[0].get_line(), repl.errors()[0].get_column()),
+            (1, 9)
+        );
+        assert_eq!(repl.submit("let d = 1;\nlet e = 99999999999;"), None);
+        assert_eq!(
+            repl.errors()[0].get_message(),
+            "The number 99999999999 isn't valid."
+        );
+        assert_eq!(
+            (repl.errors()[0].get_line(), repl.errors()[0].get_column()),
+            (2, 9)
+        );
+        assert_eq!(repl.submit("let b = a + 1;"), None);
+        assert_eq!(repl.errors().is_empty(), true);
+
+        assert_eq!(repl.submit("let c = 2; // a comment"), None);
+        assert_eq!(
+            repl.submit("c /* still c */"),
+            Some(LiteralValue::Integer(2))
+        );
+
+        assert_eq!(repl.submit("if (b > 3) { a = 0; }"), None);
+        assert_eq!(repl.submit("a"), Some(LiteralValue::Integer(0)));
+        assert_eq!(repl.submit("1 / a"), None);
+        assert_eq!(repl.errors()[0].get_message(), "Division by zero.");
+    }
+
+    fn run_on_backend(code: &str, backend: &BackendType) -> (Vec<(String, String)>, Vec<String>) {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            Rc::clone(&environment),
+            code,
+            &ParserType::Recursive,
+            backend,
+            &ExecutionLimits::default(),
+        );
+
+        let mut symbol_table: Vec<(String, String)> = environment
+            .borrow()
+            .get_variables()
+            .iter()
+            .map(|(symbol, value)| (symbol.id(), format!("{:?}", value)))
+            .collect();
+        symbol_table.sort();
+
+        let messages = diagnostics
+            .borrow()
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.get_message().to_string())
+            .collect();
+
+        (symbol_table, messages)
+    }
+
+    // Errors reported before the program runs, when no backend is involved yet.
+    fn count_compile_errors(code: &str) -> usize {
+        let diagnostics = Rc::new(RefCell::new(Diagnostics::new()));
+        let environment = Rc::new(RefCell::new(Environment::new()));
+
+        compile_program(
+            Rc::clone(&diagnostics),
+            environment,
+            code,
+            &ParserType::Recursive,
+            &BackendType::TreeWalker,
+            &ExecutionLimits::default(),
+        );
+
+        let errors = diagnostics.borrow().filter_type(TextType::Error);
+        errors
+            .iter()
+            .filter(|diagnostic| *diagnostic.get_place() != TextPlace::Runtime)
+            .count()
+    }
+
+    fn test_backends_agree(code: &str) {
+        assert_eq!(count_compile_errors(code), 0, "{}", code);
+
+        let expected = run_on_backend(code, &BackendType::TreeWalker);
+
+        assert_eq!(expected, run_on_backend(code, &BackendType::Bytecode));
+        assert_eq!(expected, run_on_backend(code, &BackendType::Assembly));
+    }
+
+    // Programs that fail to compile must report the same errors whatever backend is chosen.
+    fn test_backends_reject(code: &str) {
+        assert_eq!(count_compile_errors(code) > 0, true, "{}", code);
+
+        let expected = run_on_backend(code, &BackendType::TreeWalker);
+
+        assert_eq!(expected, run_on_backend(code, &BackendType::Bytecode));
+        assert_eq!(expected, run_on_backend(code, &BackendType::Assembly));
+    }
+
+    fn get_value_from_key(
+        environment: &Rc<RefCell<Environment>>,
+        name: &str,
+    ) -> Option<LiteralValue> {
+        environment
+            .borrow()
+            .get_variables()
             .iter()
             .find(|(symbol, _)| symbol.id() == name)
             .map(|(_, value)| value)
@@ -355,6 +2777,15 @@ mod tests {
             .clone()
     }
 
+    fn count_errors(diagnostics: Rc<RefCell<Diagnostics>>, place: &TextPlace) -> usize {
+        diagnostics
+            .borrow()
+            .filter_type(TextType::Error)
+            .iter()
+            .filter(|diagnostic| *diagnostic.get_place() == *place)
+            .count()
+    }
+
     fn check_for_errors_in_diagnostics(diagnostics: Rc<RefCell<Diagnostics>>) -> bool {
         for diagnostic in &diagnostics.borrow_mut().diagnostics {
             if *diagnostic.get_type() == TextType::Error {
@@ -364,6 +2795,21 @@ mod tests {
         false
     }
 
+    // The start and the length of the first diagnostic with the message.
+    fn find_span(diagnostics: Rc<RefCell<Diagnostics>>, message: &str) -> Option<(usize, usize)> {
+        diagnostics
+            .borrow()
+            .diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.get_message() == message)
+            .map(|diagnostic| {
+                (
+                    diagnostic.get_span().start(),
+                    diagnostic.get_span().length(),
+                )
+            })
+    }
+
     fn check_specific_message_in_diagnostics(
         diagnostics: Rc<RefCell<Diagnostics>>,
         message: &str,
@@ -0,0 +1,10 @@
+use rocket::serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq, Serialize, Clone, Copy)]
+#[serde(crate = "rocket::serde")]
+pub enum BackendType {
+    #[default]
+    TreeWalker,
+    Bytecode,
+    Assembly,
+}
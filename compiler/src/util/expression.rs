@@ -3,6 +3,7 @@ use std::{any::Any, fmt::Debug};
 use super::{
     literals::{LiteralType, LiteralValue},
     syntax_kind::SyntaxKind,
+    syntax_token::SyntaxToken,
 };
 
 pub trait ExpressionClone: Debug {
@@ -23,7 +24,8 @@ pub(crate) trait Expression: ExpressionClone + Any {
     fn get_kind(&self) -> &SyntaxKind;
     fn get_children(&self) -> Vec<Box<dyn Expression>>;
     fn get_value(&self) -> LiteralValue;
-    fn get_type(&self) -> &LiteralType;
+    fn get_type(&self) -> LiteralType;
+    fn get_tokens(&self) -> Vec<SyntaxToken>;
 }
 
 impl Clone for Box<dyn Expression> {
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionSymbol {
+    id: String,
+    index: usize,
+}
+
+impl FunctionSymbol {
+    pub fn new(id: String, index: usize) -> Self {
+        Self { id, index }
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
@@ -1,27 +1,54 @@
+use std::fmt::{Display, Formatter, Result};
+
 use rocket::serde::Serialize;
 
-#[derive(Serialize, Debug, Clone, Eq, PartialEq, Copy, Hash)]
+#[derive(Serialize, Debug, Clone, Eq, PartialEq, Hash)]
 #[serde(crate = "rocket::serde")]
 pub enum LiteralType {
     Integer,
+    Float,
     String,
     Boolean,
+    Array(Box<LiteralType>),
+    // struct types are nominal, the fields are looked up in the binder's type table
+    Struct(String),
+}
+
+impl LiteralType {
+    pub fn element_type(&self) -> Option<LiteralType> {
+        if let LiteralType::Array(element) = self {
+            Some(*element.clone())
+        } else {
+            None
+        }
+    }
 }
 
-#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Debug, Clone, PartialEq, PartialOrd)]
 #[serde(crate = "rocket::serde")]
 pub enum LiteralValue {
     Integer(i32),
+    Float(f64),
     String(String),
     Boolean(bool),
+    Array(Vec<LiteralValue>),
+    Struct(String, Vec<(String, LiteralValue)>),
 }
 
 impl LiteralValue {
-    pub fn get_type(&self) -> &LiteralType {
-        match *self {
-            LiteralValue::Boolean(_) => &LiteralType::Boolean,
-            LiteralValue::Integer(_) => &LiteralType::Integer,
-            LiteralValue::String(_) => &LiteralType::String,
+    pub fn get_type(&self) -> LiteralType {
+        match self {
+            LiteralValue::Boolean(_) => LiteralType::Boolean,
+            LiteralValue::Integer(_) => LiteralType::Integer,
+            LiteralValue::Float(_) => LiteralType::Float,
+            LiteralValue::String(_) => LiteralType::String,
+            // the binder rejects empty array literals, so there is always a first element
+            LiteralValue::Array(elements) => LiteralType::Array(Box::new(
+                elements
+                    .first()
+                    .map_or(LiteralType::Integer, LiteralValue::get_type),
+            )),
+            LiteralValue::Struct(name, _) => LiteralType::Struct(name.to_string()),
         }
     }
 
@@ -33,6 +60,15 @@ impl LiteralValue {
         }
     }
 
+    // integers are promoted, so mixed arithmetic can be done in floating point
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            LiteralValue::Float(value) => Some(*value),
+            LiteralValue::Integer(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
     pub fn as_boolean(&self) -> Option<bool> {
         if let LiteralValue::Boolean(value) = self {
             Some(*value)
@@ -48,4 +84,66 @@ impl LiteralValue {
             None
         }
     }
+
+    pub fn as_array(&self) -> Option<Vec<LiteralValue>> {
+        if let LiteralValue::Array(elements) = self {
+            Some(elements.clone())
+        } else {
+            None
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<Vec<(String, LiteralValue)>> {
+        if let LiteralValue::Struct(_, fields) = self {
+            Some(fields.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for LiteralValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            LiteralValue::Integer(value) => write!(f, "{}", value),
+            LiteralValue::Float(value) => write!(f, "{:?}", value),
+            LiteralValue::String(value) => write!(f, "{}", value),
+            LiteralValue::Boolean(value) => write!(f, "{}", value),
+            LiteralValue::Array(elements) => {
+                write!(f, "[")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write_element(f, element)?;
+                }
+                write!(f, "]")
+            }
+            LiteralValue::Struct(id, fields) => {
+                // Structs declared with the same name in different blocks get an id like `P#2`.
+                let name = id.split('#').next().unwrap_or(id);
+                write!(f, "{} {{", name)?;
+                for (index, (field, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, " {}: ", field)?;
+                    write_element(f, value)?;
+                }
+                if fields.is_empty() {
+                    write!(f, "}}")
+                } else {
+                    write!(f, " }}")
+                }
+            }
+        }
+    }
+}
+
+// Strings nested in arrays and structs are quoted, so their boundaries stay visible.
+fn write_element(f: &mut Formatter<'_>, value: &LiteralValue) -> Result {
+    match value {
+        LiteralValue::String(value) => write!(f, "{:?}", value),
+        _ => write!(f, "{}", value),
+    }
 }
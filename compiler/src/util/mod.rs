@@ -1,8 +1,12 @@
+pub mod backend_type;
 pub mod expression;
+pub mod function_symbol;
 pub mod literals;
 pub mod parser_type;
 pub mod statement;
+pub mod struct_symbol;
 pub mod syntax_kind;
 pub mod syntax_token;
+pub mod syntax_trivia;
 pub mod utils;
 pub mod variable_symbol;
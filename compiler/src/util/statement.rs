@@ -1,6 +1,6 @@
 use std::{any::Any, fmt::Debug};
 
-use super::syntax_kind::SyntaxKind;
+use super::{syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
 
 pub trait StatementClone: Debug {
     fn clone_box(&self) -> Box<dyn Statement>;
@@ -18,6 +18,15 @@ where
 pub trait Statement: StatementClone + Any {
     fn as_any(&self) -> &dyn Any;
     fn get_kind(&self) -> &SyntaxKind;
+    fn get_tokens(&self) -> Vec<SyntaxToken>;
+
+    // Rebuilds the source text of the statement, including its whitespace.
+    fn to_source(&self) -> String {
+        self.get_tokens()
+            .iter()
+            .map(|token| token.full_text())
+            .collect()
+    }
 }
 
 impl Clone for Box<dyn Statement> {
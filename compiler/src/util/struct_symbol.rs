@@ -0,0 +1,35 @@
+use super::literals::LiteralType;
+
+// The name is how the struct is written, the id tells apart structs of the same name declared
+// in different blocks and is what `LiteralType::Struct` holds.
+#[derive(Debug, Clone)]
+pub struct StructSymbol {
+    id: String,
+    name: String,
+    fields: Vec<(String, LiteralType)>,
+}
+
+impl StructSymbol {
+    pub fn new(id: String, name: String, fields: Vec<(String, LiteralType)>) -> Self {
+        Self { id, name, fields }
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    pub fn get_fields(&self) -> Vec<(String, LiteralType)> {
+        self.fields.clone()
+    }
+
+    pub fn get_field(&self, name: &str) -> Option<(usize, LiteralType)> {
+        self.fields
+            .iter()
+            .position(|(field, _)| field == name)
+            .map(|index| (index, self.fields[index].1.clone()))
+    }
+}
@@ -6,26 +6,49 @@ pub enum SyntaxKind {
     Eof,
     BadToken,
     Number,
+    StringLiteral,
     WhiteSpace,
+    LineComment,
+    BlockComment,
     Plus,
     Minus,
     Mul,
     Div,
+    Percent,
+    StarStar,
+    PlusEquals,
+    MinusEquals,
+    MulEquals,
+    DivEquals,
+    PercentEquals,
+    PlusPlus,
+    MinusMinus,
     OpenParenthesis,
     CloseParenthesis,
     Bang,
     AmpersandAmpersand,
     PipePipe,
+    Ampersand,
+    Pipe,
+    Caret,
+    Tilde,
+    LessLess,
+    GreaterGreater,
     EqualsEquals,
     BangEquals,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
     Semicolon,
     Equals,
     Less,
     LessOrEquals,
     Greater,
     GreaterOrEquals,
+    Comma,
+    Dot,
+    Colon,
 
     // keywords
     True,
@@ -38,6 +61,11 @@ pub enum SyntaxKind {
     While,
     For,
     To,
+    Fn,
+    Return,
+    Break,
+    Continue,
+    Struct,
 
     // expressions
     LiteralExpression,
@@ -45,6 +73,13 @@ pub enum SyntaxKind {
     ParenthesizedExpression,
     UnaryExpression,
     NameExpression,
+    CallExpression,
+    ConditionalExpression,
+    ArrayLiteralExpression,
+    IndexExpression,
+    StructLiteralExpression,
+    FieldExpression,
+    ErrorExpression,
 
     // statements
     StatementList,
@@ -55,6 +90,16 @@ pub enum SyntaxKind {
     ConstantDeclaration,
     WhileStatement,
     ForStatement,
+    FunctionDeclaration,
+    ReturnStatement,
+    BreakStatement,
+    ContinueStatement,
+    IncrementStatement,
+    IndexAssignment,
+    StructDeclaration,
+    FieldAssignment,
+    ExpressionStatement,
+    ErrorStatement,
 
     //special
     Variable,
@@ -65,9 +110,14 @@ pub enum SyntaxKind {
 impl SyntaxKind {
     pub fn get_binary_operator_precendence(&self) -> usize {
         match *self {
-            SyntaxKind::Div | SyntaxKind::Mul => 5,
-            SyntaxKind::Plus | SyntaxKind::Minus => 4,
-            SyntaxKind::EqualsEquals | SyntaxKind::BangEquals => 3,
+            SyntaxKind::StarStar => 10,
+            SyntaxKind::Div | SyntaxKind::Mul | SyntaxKind::Percent => 9,
+            SyntaxKind::Plus | SyntaxKind::Minus => 8,
+            SyntaxKind::LessLess | SyntaxKind::GreaterGreater => 7,
+            SyntaxKind::EqualsEquals | SyntaxKind::BangEquals => 6,
+            SyntaxKind::Ampersand => 5,
+            SyntaxKind::Caret => 4,
+            SyntaxKind::Pipe => 3,
             SyntaxKind::AmpersandAmpersand => 2,
             SyntaxKind::PipePipe => 1,
             _ => 0,
@@ -76,11 +126,23 @@ impl SyntaxKind {
 
     pub fn get_unary_operator_precendence(&self) -> usize {
         match *self {
-            SyntaxKind::Plus | SyntaxKind::Minus | SyntaxKind::Bang => 6,
+            SyntaxKind::Plus | SyntaxKind::Minus | SyntaxKind::Bang | SyntaxKind::Tilde => 11,
             _ => 0,
         }
     }
 
+    // The binary operator applied by a compound assignment, an increment or a decrement.
+    pub fn get_compound_operator(&self) -> Option<SyntaxKind> {
+        match *self {
+            SyntaxKind::PlusEquals | SyntaxKind::PlusPlus => Some(SyntaxKind::Plus),
+            SyntaxKind::MinusEquals | SyntaxKind::MinusMinus => Some(SyntaxKind::Minus),
+            SyntaxKind::MulEquals => Some(SyntaxKind::Mul),
+            SyntaxKind::DivEquals => Some(SyntaxKind::Div),
+            SyntaxKind::PercentEquals => Some(SyntaxKind::Percent),
+            _ => None,
+        }
+    }
+
     pub fn get_keyword_kind(text: &str) -> SyntaxKind {
         match text {
             "true" => SyntaxKind::True,
@@ -92,6 +154,11 @@ impl SyntaxKind {
             "while" => SyntaxKind::While,
             "for" => SyntaxKind::For,
             "to" => SyntaxKind::To,
+            "fn" => SyntaxKind::Fn,
+            "return" => SyntaxKind::Return,
+            "break" => SyntaxKind::Break,
+            "continue" => SyntaxKind::Continue,
+            "struct" => SyntaxKind::Struct,
             _ => SyntaxKind::IdentifierToken,
         }
     }
@@ -105,16 +172,34 @@ impl FromStr for SyntaxKind {
             "eof" => Ok(SyntaxKind::Eof),
             "badtoken" => Ok(SyntaxKind::BadToken),
             "number" => Ok(SyntaxKind::Number),
+            "stringliteral" => Ok(SyntaxKind::StringLiteral),
             "whitespace" => Ok(SyntaxKind::WhiteSpace),
+            "linecomment" => Ok(SyntaxKind::LineComment),
+            "blockcomment" => Ok(SyntaxKind::BlockComment),
             "plus" => Ok(SyntaxKind::Plus),
             "minus" => Ok(SyntaxKind::Minus),
             "mul" => Ok(SyntaxKind::Mul),
             "div" => Ok(SyntaxKind::Div),
+            "percent" => Ok(SyntaxKind::Percent),
+            "starstar" => Ok(SyntaxKind::StarStar),
+            "plusequals" => Ok(SyntaxKind::PlusEquals),
+            "minusequals" => Ok(SyntaxKind::MinusEquals),
+            "mulequals" => Ok(SyntaxKind::MulEquals),
+            "divequals" => Ok(SyntaxKind::DivEquals),
+            "percentequals" => Ok(SyntaxKind::PercentEquals),
+            "plusplus" => Ok(SyntaxKind::PlusPlus),
+            "minusminus" => Ok(SyntaxKind::MinusMinus),
             "openparenthesis" => Ok(SyntaxKind::OpenParenthesis),
             "closeparenthesis" => Ok(SyntaxKind::CloseParenthesis),
             "bang" => Ok(SyntaxKind::Bang),
             "ampersandampersand" => Ok(SyntaxKind::AmpersandAmpersand),
             "pipepipe" => Ok(SyntaxKind::PipePipe),
+            "ampersand" => Ok(SyntaxKind::Ampersand),
+            "pipe" => Ok(SyntaxKind::Pipe),
+            "caret" => Ok(SyntaxKind::Caret),
+            "tilde" => Ok(SyntaxKind::Tilde),
+            "lessless" => Ok(SyntaxKind::LessLess),
+            "greatergreater" => Ok(SyntaxKind::GreaterGreater),
             "equalsequals" => Ok(SyntaxKind::EqualsEquals),
             "bangequals" => Ok(SyntaxKind::BangEquals),
             "true" => Ok(SyntaxKind::True),
@@ -124,6 +209,7 @@ impl FromStr for SyntaxKind {
             "binaryexpression" => Ok(SyntaxKind::BinaryExpression),
             "parenthesizedexpression" => Ok(SyntaxKind::ParenthesizedExpression),
             "unaryexpression" => Ok(SyntaxKind::UnaryExpression),
+            "conditionalexpression" => Ok(SyntaxKind::ConditionalExpression),
             "greaterorequals" => Ok(SyntaxKind::GreaterOrEquals),
             "greater" => Ok(SyntaxKind::Greater),
             "lessorequals" => Ok(SyntaxKind::LessOrEquals),
@@ -133,6 +219,13 @@ impl FromStr for SyntaxKind {
             "(" => Ok(SyntaxKind::OpenParenthesis),
             ")" => Ok(SyntaxKind::CloseParenthesis),
             "=" => Ok(SyntaxKind::Equals),
+            "+=" => Ok(SyntaxKind::PlusEquals),
+            "-=" => Ok(SyntaxKind::MinusEquals),
+            "*=" => Ok(SyntaxKind::MulEquals),
+            "/=" => Ok(SyntaxKind::DivEquals),
+            "%=" => Ok(SyntaxKind::PercentEquals),
+            "++" => Ok(SyntaxKind::PlusPlus),
+            "--" => Ok(SyntaxKind::MinusMinus),
             "while" => Ok(SyntaxKind::While),
             "const" => Ok(SyntaxKind::Const),
             ";" => Ok(SyntaxKind::Semicolon),
@@ -142,7 +235,22 @@ impl FromStr for SyntaxKind {
             "closebrace" => Ok(SyntaxKind::CloseBrace),
             "{" => Ok(SyntaxKind::OpenBrace),
             "}" => Ok(SyntaxKind::CloseBrace),
+            "openbracket" => Ok(SyntaxKind::OpenBracket),
+            "closebracket" => Ok(SyntaxKind::CloseBracket),
+            "[" => Ok(SyntaxKind::OpenBracket),
+            "]" => Ok(SyntaxKind::CloseBracket),
             "let" => Ok(SyntaxKind::Let),
+            "fn" => Ok(SyntaxKind::Fn),
+            "return" => Ok(SyntaxKind::Return),
+            "break" => Ok(SyntaxKind::Break),
+            "continue" => Ok(SyntaxKind::Continue),
+            "struct" => Ok(SyntaxKind::Struct),
+            "comma" => Ok(SyntaxKind::Comma),
+            "," => Ok(SyntaxKind::Comma),
+            "dot" => Ok(SyntaxKind::Dot),
+            "." => Ok(SyntaxKind::Dot),
+            "colon" => Ok(SyntaxKind::Colon),
+            ":" => Ok(SyntaxKind::Colon),
             "none" => Ok(SyntaxKind::None),
             _ => Err(format!("'{}' is not a valid SyntaxKind", s)),
         }
@@ -4,6 +4,7 @@ use super::{
     expression::Expression,
     literals::{LiteralType, LiteralValue},
     syntax_kind::SyntaxKind,
+    syntax_trivia::SyntaxTrivia,
 };
 
 #[derive(Debug)]
@@ -13,6 +14,8 @@ pub struct SyntaxToken {
     position: usize,
     kind: SyntaxKind,
     length: usize,
+    leading_trivia: Vec<SyntaxTrivia>,
+    trailing_trivia: Vec<SyntaxTrivia>,
 }
 
 impl SyntaxToken {
@@ -29,6 +32,8 @@ impl SyntaxToken {
             position,
             kind,
             length,
+            leading_trivia: Vec::new(),
+            trailing_trivia: Vec::new(),
         }
     }
 
@@ -51,6 +56,43 @@ impl SyntaxToken {
     pub fn kind(&self) -> &SyntaxKind {
         &self.kind
     }
+
+    pub fn leading_trivia(&self) -> Vec<SyntaxTrivia> {
+        self.leading_trivia.clone()
+    }
+
+    pub fn trailing_trivia(&self) -> Vec<SyntaxTrivia> {
+        self.trailing_trivia.clone()
+    }
+
+    pub fn set_leading_trivia(&mut self, trivia: Vec<SyntaxTrivia>) {
+        self.leading_trivia = trivia;
+    }
+
+    pub fn set_trailing_trivia(&mut self, trivia: Vec<SyntaxTrivia>) {
+        self.trailing_trivia = trivia;
+    }
+
+    // The token as it was written, without trivia. The end of file has no text of its own.
+    pub fn text(&self) -> String {
+        if self.kind == SyntaxKind::Eof {
+            return String::new();
+        }
+        self.name()
+    }
+
+    // The token as it was written, together with the trivia around it.
+    pub fn full_text(&self) -> String {
+        let mut text = String::new();
+        for trivia in &self.leading_trivia {
+            text.push_str(&trivia.text());
+        }
+        text.push_str(&self.text());
+        for trivia in &self.trailing_trivia {
+            text.push_str(&trivia.text());
+        }
+        text
+    }
 }
 
 impl Clone for SyntaxToken {
@@ -61,6 +103,8 @@ impl Clone for SyntaxToken {
             position: self.position,
             kind: self.kind,
             length: self.length,
+            leading_trivia: self.leading_trivia.clone(),
+            trailing_trivia: self.trailing_trivia.clone(),
         }
     }
 }
@@ -82,7 +126,11 @@ impl Expression for SyntaxToken {
         self.value.clone()
     }
 
-    fn get_type(&self) -> &LiteralType {
+    fn get_type(&self) -> LiteralType {
         self.value.get_type()
     }
+
+    fn get_tokens(&self) -> Vec<SyntaxToken> {
+        vec![self.clone()]
+    }
 }
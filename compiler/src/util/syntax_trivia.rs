@@ -0,0 +1,36 @@
+use super::syntax_kind::SyntaxKind;
+
+// Source text that the parser skips over, such as whitespace, kept on the neighbouring
+// token so the original program can be rebuilt from the syntax tree.
+#[derive(Debug, Clone)]
+pub struct SyntaxTrivia {
+    kind: SyntaxKind,
+    text: String,
+    position: usize,
+}
+
+impl SyntaxTrivia {
+    pub fn new(kind: SyntaxKind, text: String, position: usize) -> Self {
+        Self {
+            kind,
+            text,
+            position,
+        }
+    }
+
+    pub fn kind(&self) -> &SyntaxKind {
+        &self.kind
+    }
+
+    pub fn text(&self) -> String {
+        self.text.to_string()
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn length(&self) -> usize {
+        self.text.chars().count()
+    }
+}
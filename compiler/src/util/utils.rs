@@ -8,11 +8,26 @@ pub fn transform_str(s: &str) -> String {
         "minus" => String::from("-"),
         "mul" => String::from("*"),
         "div" => String::from("/"),
+        "percent" => String::from("%"),
+        "starstar" => String::from("**"),
+        "plusequals" => String::from("+="),
+        "minusequals" => String::from("-="),
+        "mulequals" => String::from("*="),
+        "divequals" => String::from("/="),
+        "percentequals" => String::from("%="),
+        "plusplus" => String::from("++"),
+        "minusminus" => String::from("--"),
         "openparenthesis" => String::from("("),
         "closeparenthesis" => String::from(")"),
         "bang" => String::from("!"),
         "ampersandampersand" => String::from("&&"),
         "pipepipe" => String::from("||"),
+        "ampersand" => String::from("&"),
+        "pipe" => String::from("|"),
+        "caret" => String::from("^"),
+        "tilde" => String::from("~"),
+        "lessless" => String::from("<<"),
+        "greatergreater" => String::from(">>"),
         "equalsequals" => String::from("=="),
         "bangequals" => String::from("!="),
         "true" => String::from("true"),
@@ -30,3 +45,23 @@ pub fn transform_str(s: &str) -> String {
         _ => String::from("other"),
     }
 }
+
+pub fn unescape(text: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = text.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            '"' => result.push('"'),
+            '\\' => result.push('\\'),
+            _ => return None,
+        }
+    }
+    Some(result)
+}
@@ -10,11 +10,12 @@ pub struct VariableSymbol {
     kind: LiteralType,
     read_only: bool,
     is_global: bool,
+    index: usize,
 }
 
 impl PartialEq for VariableSymbol {
     fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+        self.id == other.id && self.index == other.index
     }
 }
 
@@ -23,16 +24,24 @@ impl Eq for VariableSymbol {}
 impl Hash for VariableSymbol {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state);
+        self.index.hash(state);
     }
 }
 
 impl VariableSymbol {
-    pub fn new(id: String, kind: LiteralType, read_only: bool, is_global: bool) -> Self {
+    pub fn new(
+        id: String,
+        kind: LiteralType,
+        read_only: bool,
+        is_global: bool,
+        index: usize,
+    ) -> Self {
         Self {
             id,
             kind,
             read_only,
             is_global,
+            index,
         }
     }
 
@@ -41,7 +50,7 @@ impl VariableSymbol {
     }
 
     pub fn get_type(&self) -> LiteralType {
-        self.kind
+        self.kind.clone()
     }
 
     pub fn is_read_only(&self) -> bool {